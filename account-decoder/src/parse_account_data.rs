@@ -96,6 +96,8 @@ pub struct AccountAdditionalData {
     pub ppl_token_symbol: Option<String>,
     pub ppl_token_name: Option<String>,
     pub ppl_token_icon: Option<String>,
+    /// Lamports held by a ppl-token account, which back the balance of wrapped PUT accounts
+    pub ppl_token_account_lamports: Option<u128>,
 }
 
 pub fn parse_account_data(
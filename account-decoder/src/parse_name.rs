@@ -27,45 +27,22 @@ pub fn parse_name(
     match data[0] {
         // AccountType::Domain
         1 => {
-            let domain_account : DomainAccount = DomainAccount::deserialize (&mut data)
-                .map_err(|_| ParseAccountError::AccountNotParsable(ParsableAccount::PplName))?;
+            let (ui_domain_account, expire_time) = parse_domain_account(data)?;
             let time_now = SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_secs() as i64;
-            if time_now > domain_account.expire_time {
+            if time_now > expire_time {
                 return Err(ParseAccountError::AccountNotParsable(
                     ParsableAccount::PplName,
                 ));
             }
-            let duration = Duration::from_secs(domain_account.expire_time as u64);
-            let expired_time = DateTime::<Utc>::from(UNIX_EPOCH.add(duration));
-            // Formats the combined date and time with the specified format string.
-            let ui_expired_time = expired_time.format("%Y-%m-%d %H:%M:%S").to_string();
-            Ok(NameAccountType::Domain(UiDomainAccount {
-                account_type: domain_account.account_type.to_string(),
-                account_state: domain_account.account_state.to_string(),
-                parent_key: domain_account.parent_key.to_string(),
-                owner: domain_account.owner.to_string(),
-                expire_time: ui_expired_time,
-                max_space: domain_account.max_space,
-                domain_name: domain_account.domain_name
-            }))
+            Ok(NameAccountType::Domain(ui_domain_account))
         }
         // AccountType::DomainResolve
         2 => {
-            let resolve_account : DomainResolveAccount = DomainResolveAccount::deserialize (&mut data)
-                .map_err(|_| ParseAccountError::AccountNotParsable(ParsableAccount::PplName))?;
-            let value_str = if resolve_account.value.is_some() {
-                bs58::encode(resolve_account.value.unwrap()).into_string()
-            } else { "None".to_string() };
-            Ok(NameAccountType::DomainResolve(UiDomainResolveAccount {
-                account_type: resolve_account.account_type.to_string(),
-                account_state: resolve_account.account_state.to_string(),
-                parent_key: resolve_account.parent_key.to_string(),
-                value: value_str,
-                domain_name: resolve_account.domain_name
-            }))
+            let (ui_resolve_account, _) = parse_domain_resolve_account(data)?;
+            Ok(NameAccountType::DomainResolve(ui_resolve_account))
         }
         // AccountType::AddressResolve
         3 => {
@@ -87,6 +64,63 @@ pub fn parse_name(
     }
 }
 
+/// Parse a domain account without rejecting it once expired, also returning its expire time as a
+/// unix timestamp so callers can report the expiry state themselves
+pub fn parse_domain_account(data: &[u8]) -> Result<(UiDomainAccount, i64), ParseAccountError> {
+    let mut data = data;
+    if data.first() != Some(&1) {
+        return Err(ParseAccountError::AccountNotParsable(
+            ParsableAccount::PplName,
+        ));
+    }
+    let domain_account: DomainAccount = DomainAccount::deserialize(&mut data)
+        .map_err(|_| ParseAccountError::AccountNotParsable(ParsableAccount::PplName))?;
+    let duration = Duration::from_secs(domain_account.expire_time as u64);
+    let expired_time = DateTime::<Utc>::from(UNIX_EPOCH.add(duration));
+    // Formats the combined date and time with the specified format string.
+    let ui_expired_time = expired_time.format("%Y-%m-%d %H:%M:%S").to_string();
+    Ok((
+        UiDomainAccount {
+            account_type: domain_account.account_type.to_string(),
+            account_state: domain_account.account_state.to_string(),
+            parent_key: domain_account.parent_key.to_string(),
+            owner: domain_account.owner.to_string(),
+            expire_time: ui_expired_time,
+            max_space: domain_account.max_space,
+            domain_name: domain_account.domain_name,
+        },
+        domain_account.expire_time,
+    ))
+}
+
+/// Parse a domain resolve account, also returning its value base-58 encoded, or `None` if the
+/// record has not been set, since the UI account reports a missing value as `"None"`
+pub fn parse_domain_resolve_account(
+    data: &[u8],
+) -> Result<(UiDomainResolveAccount, Option<String>), ParseAccountError> {
+    let mut data = data;
+    if data.first() != Some(&2) {
+        return Err(ParseAccountError::AccountNotParsable(
+            ParsableAccount::PplName,
+        ));
+    }
+    let resolve_account: DomainResolveAccount = DomainResolveAccount::deserialize(&mut data)
+        .map_err(|_| ParseAccountError::AccountNotParsable(ParsableAccount::PplName))?;
+    let value = resolve_account
+        .value
+        .map(|value| bs58::encode(value).into_string());
+    Ok((
+        UiDomainResolveAccount {
+            account_type: resolve_account.account_type.to_string(),
+            account_state: resolve_account.account_state.to_string(),
+            parent_key: resolve_account.parent_key.to_string(),
+            value: value.clone().unwrap_or_else(|| "None".to_string()),
+            domain_name: resolve_account.domain_name,
+        },
+        value,
+    ))
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase", tag = "type", content = "info")]
 #[allow(clippy::large_enum_variant)]
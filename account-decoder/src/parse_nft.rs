@@ -1,18 +1,28 @@
 use {
-    crate::{
-        parse_account_data::{ParsableAccount, ParseAccountError},
-        StringAmount,
-    },
+    crate::parse_account_data::{ParsableAccount, ParseAccountError},
     put_sdk::pubkey::Pubkey,
 
 };
 use ppl_nft::state::{AccountState, MetaAccount, NftMint};
 use ppl_nft::put_program::program_pack::Pack;
+use ppl_nft::put_program::pubkey::Pubkey as PplNftPubkey;
 
-fn nft_program_id() -> Pubkey {
+// A helper function to convert ppl_nft::id() as ppl_sdk::pubkey::Pubkey to
+// put_sdk::pubkey::Pubkey
+pub fn nft_program_id() -> Pubkey {
     Pubkey::new_from_array(ppl_nft::id().to_bytes())
 }
 
+// A helper function to convert a put_sdk::pubkey::Pubkey to ppl_sdk::pubkey::Pubkey
+pub fn ppl_nft_pubkey(pubkey: &Pubkey) -> PplNftPubkey {
+    PplNftPubkey::new_from_array(pubkey.to_bytes())
+}
+
+// A helper function to convert a ppl_sdk::pubkey::Pubkey to put_sdk::pubkey::Pubkey
+pub fn pubkey_from_ppl_nft(pubkey: &PplNftPubkey) -> Pubkey {
+    Pubkey::new_from_array(pubkey.to_bytes())
+}
+
 // Check if the provided program id as a known SPL Token program id
 pub fn is_known_nft_token_id(program_id: &Pubkey) -> bool {
     *program_id == nft_program_id()
@@ -37,7 +47,19 @@ pub fn parse_nft(
             token_id: account.token_id,
             token_uri: account.token_uri
         }))
-        //todo need mint unpack?
+    } else if data.len() == NftMint::get_packed_len() {
+        let mint = NftMint::unpack(data)
+            .map_err(|_| ParseAccountError::AccountNotParsable(ParsableAccount::PplNft))?;
+        Ok(TokenAccountType::Mint(UiNftMint {
+            mint_authority: mint.mint_authority.to_string(),
+            total_supply: mint.total_supply,
+            supply: mint.supply,
+            is_initialized: mint.is_initialized,
+            freeze_authority: mint.freeze_authority.map(|pubkey| pubkey.to_string()),
+            name: mint.name,
+            symbol: mint.symbol,
+            icon_uri: mint.icon_uri,
+        }))
     } else {
         Err(ParseAccountError::AccountNotParsable(
             ParsableAccount::PplNft,
@@ -50,7 +72,7 @@ pub fn parse_nft(
 #[allow(clippy::large_enum_variant)]
 pub enum TokenAccountType {
     Account(UiNFTAccount),
-    Mint(UiMint),
+    Mint(UiNftMint),
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -93,27 +115,36 @@ impl From<AccountState> for UiAccountState {
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct UiMint {
-    pub mint_authority: Option<String>,
-    pub supply: StringAmount,
-    pub decimals: u8,
+pub struct UiNftMint {
+    /// Authority allowed to mint new nfts of this collection
+    pub mint_authority: String,
+    /// Maximum number of nfts that can be minted
+    pub total_supply: u64,
+    /// Number of nfts minted so far, new nfts take the next token id
+    pub supply: u64,
     pub is_initialized: bool,
+    /// Optional authority to freeze nft accounts of this collection
     pub freeze_authority: Option<String>,
+    /// The collection name
+    pub name: String,
+    /// The collection symbol
+    pub symbol: String,
+    /// The collection icon
+    pub icon_uri: String,
 }
 
+/// Get the mint of an nft account
 pub fn get_token_account_mint(data: &[u8]) -> Option<Pubkey> {
-    if data.len() == NftMint::get_packed_len() {
-        Some(Pubkey::try_from(data).unwrap())
+    if data.len() == MetaAccount::get_packed_len() {
+        Some(Pubkey::try_from(&data[0..32]).unwrap())
     } else {
         None
     }
 }
 
-
-
 #[cfg(test)]
 mod test {
-    use super::*;
+    use {super::*, serde_json::json};
 
     #[test]
     fn test_parse_token() {
@@ -143,4 +174,82 @@ mod test {
             }),
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_nft_mint() {
+        let mint_authority = Pubkey::from([4; 32]);
+        let freeze_authority = Pubkey::from([5; 32]);
+        let mut mint_data = vec![0; NftMint::get_packed_len()];
+        let mut mint = NftMint::unpack_unchecked(&mint_data).unwrap();
+        mint.mint_authority = mint_authority;
+        mint.total_supply = 100;
+        mint.supply = 42;
+        mint.is_initialized = true;
+        mint.freeze_authority = Option::Some(freeze_authority);
+        mint.name = "Collection".to_string();
+        mint.symbol = "COL".to_string();
+        mint.icon_uri = "https://example.com/icon.png".to_string();
+        NftMint::pack(mint, &mut mint_data).unwrap();
+
+        let expected = TokenAccountType::Mint(UiNftMint {
+            mint_authority: mint_authority.to_string(),
+            total_supply: 100,
+            supply: 42,
+            is_initialized: true,
+            freeze_authority: Some(freeze_authority.to_string()),
+            name: "Collection".to_string(),
+            symbol: "COL".to_string(),
+            icon_uri: "https://example.com/icon.png".to_string(),
+        });
+        assert_eq!(parse_nft(&mint_data).unwrap(), expected);
+        assert!(get_token_account_mint(&mint_data).is_none());
+
+        // jsonParsed output round-trips back into the same mint
+        let value = serde_json::to_value(&expected).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "type": "mint",
+                "info": {
+                    "mintAuthority": mint_authority.to_string(),
+                    "totalSupply": 100,
+                    "supply": 42,
+                    "isInitialized": true,
+                    "freezeAuthority": freeze_authority.to_string(),
+                    "name": "Collection",
+                    "symbol": "COL",
+                    "iconUri": "https://example.com/icon.png",
+                },
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<TokenAccountType>(value).unwrap(),
+            expected
+        );
+
+        // A mint without a freeze authority
+        let mut mint_data = vec![0; NftMint::get_packed_len()];
+        let mut mint = NftMint::unpack_unchecked(&mint_data).unwrap();
+        mint.mint_authority = mint_authority;
+        mint.is_initialized = true;
+        NftMint::pack(mint, &mut mint_data).unwrap();
+        match parse_nft(&mint_data).unwrap() {
+            TokenAccountType::Mint(ui_mint) => assert_eq!(ui_mint.freeze_authority, None),
+            _ => panic!("expected a mint"),
+        }
+    }
+
+    #[test]
+    fn test_get_token_account_mint() {
+        let mint_pubkey = Pubkey::from([2; 32]);
+        let mut account_data = vec![0; MetaAccount::get_packed_len()];
+        let mut account = MetaAccount::unpack_unchecked(&account_data).unwrap();
+        account.mint = mint_pubkey;
+        account.owner = Pubkey::from([3; 32]);
+        account.state = AccountState::Initialized;
+        MetaAccount::pack(account, &mut account_data).unwrap();
+
+        assert_eq!(get_token_account_mint(&account_data), Some(mint_pubkey));
+        assert_eq!(get_token_account_mint(&account_data[1..]), None);
+    }
+}
@@ -3,6 +3,10 @@ use crate::parse_account_data::AccountAdditionalData;
 use {
     crate::{
         parse_account_data::{ParsableAccount, ParseAccountError},
+        parse_token_extension::{
+            extended_account_type, parse_extensions, UiExtension, ACCOUNTTYPE_ACCOUNT,
+            ACCOUNTTYPE_MINT,
+        },
         StringAmount, StringDecimals,
     },
     put_sdk::pubkey::Pubkey,
@@ -17,18 +21,52 @@ use {
 
 // A helper function to convert ppl_token::id() as ppl_sdk::pubkey::Pubkey to
 // put_sdk::pubkey::Pubkey
-fn ppl_token_id() -> Pubkey {
+pub fn ppl_token_id() -> Pubkey {
     Pubkey::new_from_array(ppl_token::id().to_bytes())
 }
 
+mod ppl_token_2022 {
+    put_sdk::declare_id!("CgK4eAi5GLCzdvF7f9JEgDqBUkWgYf3bASeZ759mJpbi");
+}
+
+// The id of the PPL Token 2022 program, whose accounts and mints may carry extensions
+pub fn ppl_token_2022_id() -> Pubkey {
+    ppl_token_2022::id()
+}
+
 // Returns all known PPL Token program ids
 pub fn ppl_token_ids() -> Vec<Pubkey> {
-    vec![ppl_token_id()]
+    vec![ppl_token_id(), ppl_token_2022_id()]
 }
 
 // Check if the provided program id as a known PPL Token program id
 pub fn is_known_ppl_token_id(program_id: &Pubkey) -> bool {
-    *program_id == ppl_token_id()
+    *program_id == ppl_token_id() || *program_id == ppl_token_2022_id()
+}
+
+// Check if the data has the layout of a token account, with or without extensions
+pub fn is_valid_token_account_data(data: &[u8]) -> bool {
+    data.len() == Account::get_packed_len()
+        || extended_account_type(data) == Some(ACCOUNTTYPE_ACCOUNT)
+}
+
+// Unpack the base state of a token account, ignoring any extensions
+pub fn unpack_token_account(data: &[u8]) -> Option<Account> {
+    if !is_valid_token_account_data(data) {
+        return None;
+    }
+    Account::unpack(&data[..Account::get_packed_len()]).ok()
+}
+
+// Unpack the base state of a mint, ignoring any extensions
+pub fn unpack_token_mint(data: &[u8]) -> Option<Mint> {
+    if data.len() == Mint::get_packed_len() {
+        Mint::unpack(data).ok()
+    } else if extended_account_type(data) == Some(ACCOUNTTYPE_MINT) {
+        Mint::unpack(&data[..Mint::get_packed_len()]).ok()
+    } else {
+        None
+    }
 }
 
 // A helper function to convert ppl_token::native_mint::id() as ppl_sdk::pubkey::Pubkey to
@@ -43,6 +81,25 @@ pub fn ppl_token_native_min_info() -> Pubkey {
 }
 
 
+// Check if the provided mint is the wrapped PUT native mint
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+    *mint == ppl_token_native_mint()
+}
+
+// The balance of a token account. Wrapped PUT accounts hold their balance as the lamports above
+// the rent exempt reserve, which runs ahead of `amount` until the account is synced, so their
+// lamports are used when known
+pub fn token_account_balance(account: &Account, lamports: Option<u128>) -> u128 {
+    match (account.is_native, lamports) {
+        (COption::Some(rent_exempt_reserve), Some(lamports))
+            if account.mint == ppl_token::native_mint::id() =>
+        {
+            lamports.saturating_sub(rent_exempt_reserve as u128)
+        }
+        _ => account.amount,
+    }
+}
+
 // The program id of the `ppl_token_native_mint` account
 pub fn ppl_token_native_mint_program_id() -> Pubkey {
     ppl_token_id()
@@ -62,18 +119,27 @@ pub fn parse_token(
     data: &[u8],
     additional_data: AccountAdditionalData
 ) -> Result<TokenAccountType, ParseAccountError> {
-    if data.len() == Account::get_packed_len() {
-        let account = Account::unpack(data)
-            .map_err(|_| ParseAccountError::AccountNotParsable(ParsableAccount::PplToken))?;
-        let decimals = additional_data.ppl_token_decimals.ok_or_else(|| {
-            ParseAccountError::AdditionalDataMissing(
-                "no mint_decimals provided to parse ppl-token account".to_string(),
-            )
-        })?;
+    let account_type = extended_account_type(data);
+    if data.len() == Account::get_packed_len() || account_type == Some(ACCOUNTTYPE_ACCOUNT) {
+        let account = unpack_token_account(data).ok_or(ParseAccountError::AccountNotParsable(
+            ParsableAccount::PplToken,
+        ))?;
+        let decimals = additional_data
+            .ppl_token_decimals
+            .or_else(|| {
+                (account.mint == ppl_token::native_mint::id())
+                    .then_some(ppl_token::native_mint::DECIMALS)
+            })
+            .ok_or_else(|| {
+                ParseAccountError::AdditionalDataMissing(
+                    "no mint_decimals provided to parse ppl-token account".to_string(),
+                )
+            })?;
+        let balance = token_account_balance(&account, additional_data.ppl_token_account_lamports);
         Ok(TokenAccountType::Account(UiTokenAccount {
             mint: account.mint.to_string(),
             owner: account.owner.to_string(),
-            token_amount: token_amount_to_ui_amount(account.amount, decimals),
+            token_amount: token_amount_to_ui_amount(balance, decimals),
             delegate: match account.delegate {
                 COption::Some(pubkey) => Some(pubkey.to_string()),
                 COption::None => None,
@@ -96,10 +162,12 @@ pub fn parse_token(
                 COption::Some(pubkey) => Some(pubkey.to_string()),
                 COption::None => None,
             },
+            extensions: parse_extensions(data),
         }))
-    } else if data.len() == Mint::get_packed_len() {
-        let mint = Mint::unpack(data)
-            .map_err(|_| ParseAccountError::AccountNotParsable(ParsableAccount::PplToken))?;
+    } else if data.len() == Mint::get_packed_len() || account_type == Some(ACCOUNTTYPE_MINT) {
+        let mint = unpack_token_mint(data).ok_or(ParseAccountError::AccountNotParsable(
+            ParsableAccount::PplToken,
+        ))?;
         Ok(TokenAccountType::Mint(UiMint {
             mint_authority: match mint.mint_authority {
                 COption::Some(pubkey) => Some(pubkey.to_string()),
@@ -124,6 +192,7 @@ pub fn parse_token(
                 COption::Some(pubkey) => Some(pubkey.to_string()),
                 COption::None => None,
             },
+            extensions: parse_extensions(data),
         }))
     } else if data.len() == Multisig::get_packed_len() {
         let multisig = Multisig::unpack(data)
@@ -176,6 +245,8 @@ pub struct UiTokenAccount {
     pub delegated_amount: Option<UiTokenAmount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub close_authority: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub extensions: Vec<UiExtension>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -270,6 +341,8 @@ pub struct UiMint {
     pub decimals: u8,
     pub is_initialized: bool,
     pub freeze_authority: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub extensions: Vec<UiExtension>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -282,9 +355,11 @@ pub struct UiMultisig {
 }
 
 pub fn get_token_account_mint(data: &[u8],pubkey:Option<&Pubkey>) -> Option<Pubkey> {
-    if data.len() == Account::get_packed_len() {
+    if is_valid_token_account_data(data) {
         Some(Pubkey::try_from(&data[0..32]).unwrap())
-    } else if data.len() == Mint::get_packed_len() {
+    } else if data.len() == Mint::get_packed_len()
+        || extended_account_type(data) == Some(ACCOUNTTYPE_MINT)
+    {
         match pubkey {
             Some(pubkey) =>Some(*pubkey),
             None => None,
@@ -333,6 +408,7 @@ mod test {
                 rent_exempt_reserve: None,
                 delegated_amount: None,
                 close_authority: Some(owner_pubkey.to_string()),
+                extensions: vec![],
             }),
         );
 
@@ -356,6 +432,7 @@ mod test {
                 decimals: 3,
                 is_initialized: true,
                 freeze_authority: Some(owner_pubkey.to_string()),
+                extensions: vec![],
             }),
         );
 
@@ -392,6 +469,105 @@ mod test {
         assert!(parse_token(&bad_data, AccountAdditionalData::default()).is_err());
     }
 
+    #[test]
+    fn test_parse_native_token_account() {
+        let owner_pubkey = PplTokenPubkey::from([3; 32]);
+        let rent_exempt_reserve = 2_039_280;
+        let mut account_data = vec![0; Account::get_packed_len()];
+        let mut account = Account::unpack_unchecked(&account_data).unwrap();
+        account.mint = ppl_token::native_mint::id();
+        account.owner = owner_pubkey;
+        account.amount = 1_000_000_000;
+        account.state = AccountState::Initialized;
+        account.is_native = COption::Some(rent_exempt_reserve);
+        Account::pack(account, &mut account_data).unwrap();
+
+        // The native mint decimals are known without a mint lookup
+        let parsed = parse_token(&account_data, AccountAdditionalData::default()).unwrap();
+        let TokenAccountType::Account(ui_account) = parsed else {
+            panic!("expected a token account");
+        };
+        assert!(ui_account.is_native);
+        assert_eq!(ui_account.token_amount.decimals, 9);
+        assert_eq!(ui_account.token_amount.amount, "1000000000");
+
+        // Lamports transferred in without a sync are reported above the rent exempt reserve
+        let mut aad = AccountAdditionalData::default();
+        aad.ppl_token_account_lamports = Some(1_500_000_000 + rent_exempt_reserve as u128);
+        let parsed = parse_token(&account_data, aad).unwrap();
+        let TokenAccountType::Account(ui_account) = parsed else {
+            panic!("expected a token account");
+        };
+        assert_eq!(ui_account.token_amount.amount, "1500000000");
+        assert_eq!(ui_account.token_amount.ui_amount_string, "1.5");
+        assert_eq!(
+            ui_account.rent_exempt_reserve.unwrap().amount,
+            rent_exempt_reserve.to_string()
+        );
+
+        assert!(is_native_mint(&ppl_token_native_mint()));
+        assert!(!is_native_mint(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_parse_token_with_extensions() {
+        let mint_pubkey = PplTokenPubkey::from([2; 32]);
+        let owner_pubkey = PplTokenPubkey::from([3; 32]);
+        let mut account_data = vec![0; Account::get_packed_len()];
+        let mut account = Account::unpack_unchecked(&account_data).unwrap();
+        account.mint = mint_pubkey;
+        account.owner = owner_pubkey;
+        account.amount = 42;
+        account.state = AccountState::Initialized;
+        Account::pack(account, &mut account_data).unwrap();
+        account_data.push(ACCOUNTTYPE_ACCOUNT);
+        // MemoTransfer { require_incoming_transfer_memos: true }
+        account_data.extend_from_slice(&[8, 0, 1, 0, 1]);
+        assert!(is_valid_token_account_data(&account_data));
+        assert_eq!(
+            get_token_account_mint(&account_data, None),
+            Some(pubkey_from_ppl_token(&mint_pubkey))
+        );
+
+        let mut aad = AccountAdditionalData::default();
+        aad.ppl_token_decimals = Some(2);
+        let TokenAccountType::Account(ui_account) = parse_token(&account_data, aad).unwrap() else {
+            panic!("expected a token account");
+        };
+        assert_eq!(ui_account.owner, owner_pubkey.to_string());
+        assert_eq!(ui_account.token_amount.amount, "42");
+        assert_eq!(
+            ui_account.extensions,
+            vec![UiExtension::MemoTransfer(
+                crate::parse_token_extension::UiMemoTransfer {
+                    require_incoming_transfer_memos: true,
+                }
+            )]
+        );
+
+        let mut mint_data = vec![0; Account::get_packed_len()];
+        let mut mint = Mint::unpack_unchecked(&mint_data[..Mint::get_packed_len()]).unwrap();
+        mint.supply = 42;
+        mint.decimals = 3;
+        mint.is_initialized = true;
+        Mint::pack(mint, &mut mint_data[..Mint::get_packed_len()]).unwrap();
+        mint_data.push(ACCOUNTTYPE_MINT);
+        // NonTransferable
+        mint_data.extend_from_slice(&[9, 0, 0, 0]);
+        assert!(!is_valid_token_account_data(&mint_data));
+        assert_eq!(unpack_token_mint(&mint_data).unwrap().decimals, 3);
+        let TokenAccountType::Mint(ui_mint) =
+            parse_token(&mint_data, AccountAdditionalData::default()).unwrap()
+        else {
+            panic!("expected a mint");
+        };
+        assert_eq!(ui_mint.supply, "42");
+        assert_eq!(ui_mint.extensions, vec![UiExtension::NonTransferable]);
+
+        // An unknown account type is not parsable
+        mint_data[Account::get_packed_len()] = 0;
+        assert!(parse_token(&mint_data, AccountAdditionalData::default()).is_err());
+    }
 
     // oa 1.1.0
     // #[test]
@@ -494,4 +670,25 @@ mod test {
         );
         assert_eq!(token_amount.ui_amount, None);
     }
+
+    #[test]
+    fn test_runtime_inline_ppl_token_2022_matches() {
+        use {
+            crate::parse_token_extension::ACCOUNT_TYPE_OFFSET, put_runtime::inline_ppl_token_2022,
+        };
+
+        assert_eq!(inline_ppl_token_2022::id(), ppl_token_2022_id());
+        assert_eq!(
+            inline_ppl_token_2022::ACCOUNT_TYPE_OFFSET,
+            ACCOUNT_TYPE_OFFSET
+        );
+        assert_eq!(
+            inline_ppl_token_2022::ACCOUNTTYPE_ACCOUNT,
+            ACCOUNTTYPE_ACCOUNT
+        );
+        assert_eq!(
+            inline_ppl_token_2022::PPL_TOKEN_MULTISIG_LENGTH,
+            Multisig::get_packed_len()
+        );
+    }
 }
@@ -0,0 +1,384 @@
+//! Decoding of the token-2022-style extensions appended to PPL token accounts and mints.
+//!
+//! An extended account starts with the base `Account` (or a `Mint` zero-padded to the length of
+//! an `Account`), followed by a single account type byte and then a list of TLV entries, each made
+//! of a little-endian `u16` extension type, a little-endian `u16` value length and the value.
+//! Amounts inside extension values are `u128`, matching the base token state.
+use {
+    crate::parse_token::UiAccountState,
+    ppl_token::{
+        put_program::program_pack::Pack,
+        state::{Account, Multisig},
+    },
+    put_sdk::{clock::UnixTimestamp, pubkey::Pubkey},
+};
+
+/// Offset of the account type byte of an extended account
+pub const ACCOUNT_TYPE_OFFSET: usize = Account::LEN;
+
+// `AccountType` ordinal values
+pub const ACCOUNTTYPE_MINT: u8 = 1;
+pub const ACCOUNTTYPE_ACCOUNT: u8 = 2;
+
+const TLV_TYPE_LENGTH: usize = 2;
+const TLV_LENGTH_LENGTH: usize = 2;
+
+// `ExtensionType` ordinal values
+const EXTENSION_UNINITIALIZED: u16 = 0;
+const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+const EXTENSION_TRANSFER_FEE_AMOUNT: u16 = 2;
+const EXTENSION_MINT_CLOSE_AUTHORITY: u16 = 3;
+const EXTENSION_DEFAULT_ACCOUNT_STATE: u16 = 6;
+const EXTENSION_IMMUTABLE_OWNER: u16 = 7;
+const EXTENSION_MEMO_TRANSFER: u16 = 8;
+const EXTENSION_NON_TRANSFERABLE: u16 = 9;
+const EXTENSION_INTEREST_BEARING_CONFIG: u16 = 10;
+const EXTENSION_CPI_GUARD: u16 = 11;
+const EXTENSION_PERMANENT_DELEGATE: u16 = 12;
+const EXTENSION_NON_TRANSFERABLE_ACCOUNT: u16 = 13;
+
+/// Returns the account type of an extended token account or mint, or `None` if the data does
+/// not carry extensions
+pub fn extended_account_type(data: &[u8]) -> Option<u8> {
+    if data.len() <= ACCOUNT_TYPE_OFFSET || data.len() == Multisig::LEN {
+        return None;
+    }
+    match data[ACCOUNT_TYPE_OFFSET] {
+        account_type @ (ACCOUNTTYPE_MINT | ACCOUNTTYPE_ACCOUNT) => Some(account_type),
+        _ => None,
+    }
+}
+
+/// Decodes the extensions of a token account or mint. Accounts without extensions return an
+/// empty list
+pub fn parse_extensions(data: &[u8]) -> Vec<UiExtension> {
+    if extended_account_type(data).is_none() {
+        return vec![];
+    }
+    let mut extensions = vec![];
+    let mut tlv_data = &data[ACCOUNT_TYPE_OFFSET + 1..];
+    while tlv_data.len() >= TLV_TYPE_LENGTH + TLV_LENGTH_LENGTH {
+        let extension_type = read_u16(tlv_data, 0);
+        if extension_type == EXTENSION_UNINITIALIZED {
+            break;
+        }
+        let length = read_u16(tlv_data, TLV_TYPE_LENGTH) as usize;
+        let value_start = TLV_TYPE_LENGTH + TLV_LENGTH_LENGTH;
+        let Some(value) = tlv_data.get(value_start..value_start + length) else {
+            extensions.push(UiExtension::UnparseableExtension);
+            break;
+        };
+        extensions.push(parse_extension(extension_type, value));
+        tlv_data = &tlv_data[value_start + length..];
+    }
+    extensions
+}
+
+fn parse_extension(extension_type: u16, value: &[u8]) -> UiExtension {
+    let extension = match extension_type {
+        EXTENSION_TRANSFER_FEE_CONFIG => {
+            parse_transfer_fee_config(value).map(UiExtension::TransferFeeConfig)
+        }
+        EXTENSION_TRANSFER_FEE_AMOUNT => (value.len() == 16).then(|| {
+            UiExtension::TransferFeeAmount(UiTransferFeeAmount {
+                withheld_amount: read_u128(value, 0),
+            })
+        }),
+        EXTENSION_MINT_CLOSE_AUTHORITY => (value.len() == 32).then(|| {
+            UiExtension::MintCloseAuthority(UiMintCloseAuthority {
+                close_authority: read_optional_pubkey(value, 0),
+            })
+        }),
+        EXTENSION_DEFAULT_ACCOUNT_STATE => match value {
+            [state] => parse_account_state(*state).map(|account_state| {
+                UiExtension::DefaultAccountState(UiDefaultAccountState { account_state })
+            }),
+            _ => None,
+        },
+        EXTENSION_IMMUTABLE_OWNER => Some(UiExtension::ImmutableOwner),
+        EXTENSION_MEMO_TRANSFER => match value {
+            [require_incoming_transfer_memos] => Some(UiExtension::MemoTransfer(UiMemoTransfer {
+                require_incoming_transfer_memos: *require_incoming_transfer_memos != 0,
+            })),
+            _ => None,
+        },
+        EXTENSION_NON_TRANSFERABLE => Some(UiExtension::NonTransferable),
+        EXTENSION_INTEREST_BEARING_CONFIG => (value.len() == 52).then(|| {
+            UiExtension::InterestBearingConfig(UiInterestBearingConfig {
+                rate_authority: read_optional_pubkey(value, 0),
+                initialization_timestamp: read_u64(value, 32) as UnixTimestamp,
+                pre_update_average_rate: read_u16(value, 40) as i16,
+                last_update_timestamp: read_u64(value, 42) as UnixTimestamp,
+                current_rate: read_u16(value, 50) as i16,
+            })
+        }),
+        EXTENSION_CPI_GUARD => match value {
+            [lock_cpi] => Some(UiExtension::CpiGuard(UiCpiGuard {
+                lock_cpi: *lock_cpi != 0,
+            })),
+            _ => None,
+        },
+        EXTENSION_PERMANENT_DELEGATE => (value.len() == 32).then(|| {
+            UiExtension::PermanentDelegate(UiPermanentDelegate {
+                delegate: read_optional_pubkey(value, 0),
+            })
+        }),
+        EXTENSION_NON_TRANSFERABLE_ACCOUNT => Some(UiExtension::NonTransferableAccount),
+        _ => None,
+    };
+    extension.unwrap_or(UiExtension::UnparseableExtension)
+}
+
+/*
+    TransferFeeConfig {
+        transfer_fee_config_authority: OptionalNonZeroPubkey,
+        withdraw_withheld_authority: OptionalNonZeroPubkey,
+        withheld_amount: u128,
+        older_transfer_fee: TransferFee,
+        newer_transfer_fee: TransferFee,
+    }
+    TransferFee {
+        epoch: u64,
+        maximum_fee: u128,
+        transfer_fee_basis_points: u16,
+    }
+*/
+const TRANSFER_FEE_LENGTH: usize = 8 + 16 + 2;
+const TRANSFER_FEE_CONFIG_LENGTH: usize = 32 + 32 + 16 + 2 * TRANSFER_FEE_LENGTH;
+
+fn parse_transfer_fee_config(value: &[u8]) -> Option<UiTransferFeeConfig> {
+    if value.len() != TRANSFER_FEE_CONFIG_LENGTH {
+        return None;
+    }
+    Some(UiTransferFeeConfig {
+        transfer_fee_config_authority: read_optional_pubkey(value, 0),
+        withdraw_withheld_authority: read_optional_pubkey(value, 32),
+        withheld_amount: read_u128(value, 64),
+        older_transfer_fee: parse_transfer_fee(&value[80..80 + TRANSFER_FEE_LENGTH]),
+        newer_transfer_fee: parse_transfer_fee(&value[80 + TRANSFER_FEE_LENGTH..]),
+    })
+}
+
+fn parse_transfer_fee(value: &[u8]) -> UiTransferFee {
+    UiTransferFee {
+        epoch: read_u64(value, 0),
+        maximum_fee: read_u128(value, 8),
+        transfer_fee_basis_points: read_u16(value, 24),
+    }
+}
+
+fn parse_account_state(state: u8) -> Option<UiAccountState> {
+    match state {
+        0 => Some(UiAccountState::Uninitialized),
+        1 => Some(UiAccountState::Initialized),
+        2 => Some(UiAccountState::Frozen),
+        _ => None,
+    }
+}
+
+// Callers check the value length before reading
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_u128(data: &[u8], offset: usize) -> u128 {
+    u128::from_le_bytes(data[offset..offset + 16].try_into().unwrap())
+}
+
+// An all-zero pubkey stands for `None`
+fn read_optional_pubkey(data: &[u8], offset: usize) -> Option<String> {
+    let pubkey = Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+    (pubkey != Pubkey::default()).then(|| pubkey.to_string())
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "extension", content = "state")]
+pub enum UiExtension {
+    TransferFeeConfig(UiTransferFeeConfig),
+    TransferFeeAmount(UiTransferFeeAmount),
+    MintCloseAuthority(UiMintCloseAuthority),
+    DefaultAccountState(UiDefaultAccountState),
+    ImmutableOwner,
+    MemoTransfer(UiMemoTransfer),
+    NonTransferable,
+    InterestBearingConfig(UiInterestBearingConfig),
+    CpiGuard(UiCpiGuard),
+    PermanentDelegate(UiPermanentDelegate),
+    NonTransferableAccount,
+    UnparseableExtension,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTransferFee {
+    pub epoch: u64,
+    pub maximum_fee: u128,
+    pub transfer_fee_basis_points: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTransferFeeConfig {
+    pub transfer_fee_config_authority: Option<String>,
+    pub withdraw_withheld_authority: Option<String>,
+    pub withheld_amount: u128,
+    pub older_transfer_fee: UiTransferFee,
+    pub newer_transfer_fee: UiTransferFee,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTransferFeeAmount {
+    pub withheld_amount: u128,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiMintCloseAuthority {
+    pub close_authority: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiDefaultAccountState {
+    pub account_state: UiAccountState,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiMemoTransfer {
+    pub require_incoming_transfer_memos: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiInterestBearingConfig {
+    pub rate_authority: Option<String>,
+    pub initialization_timestamp: UnixTimestamp,
+    pub pre_update_average_rate: i16,
+    pub last_update_timestamp: UnixTimestamp,
+    pub current_rate: i16,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiCpiGuard {
+    pub lock_cpi: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiPermanentDelegate {
+    pub delegate: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn push_extension(data: &mut Vec<u8>, extension_type: u16, value: &[u8]) {
+        data.extend_from_slice(&extension_type.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+    }
+
+    #[test]
+    fn test_parse_extensions() {
+        let authority = Pubkey::new_unique();
+
+        let mut account_data = vec![0; Account::LEN];
+        assert_eq!(extended_account_type(&account_data), None);
+        assert!(parse_extensions(&account_data).is_empty());
+
+        account_data.push(ACCOUNTTYPE_ACCOUNT);
+        push_extension(&mut account_data, EXTENSION_IMMUTABLE_OWNER, &[]);
+        push_extension(&mut account_data, EXTENSION_MEMO_TRANSFER, &[1]);
+        push_extension(
+            &mut account_data,
+            EXTENSION_TRANSFER_FEE_AMOUNT,
+            &42u128.to_le_bytes(),
+        );
+        push_extension(&mut account_data, 100, &[1, 2, 3]);
+        assert_eq!(
+            extended_account_type(&account_data),
+            Some(ACCOUNTTYPE_ACCOUNT)
+        );
+        assert_eq!(
+            parse_extensions(&account_data),
+            vec![
+                UiExtension::ImmutableOwner,
+                UiExtension::MemoTransfer(UiMemoTransfer {
+                    require_incoming_transfer_memos: true,
+                }),
+                UiExtension::TransferFeeAmount(UiTransferFeeAmount {
+                    withheld_amount: 42,
+                }),
+                UiExtension::UnparseableExtension,
+            ]
+        );
+
+        let mut transfer_fee_config = vec![];
+        transfer_fee_config.extend_from_slice(authority.as_ref());
+        transfer_fee_config.extend_from_slice(&[0; 32]);
+        transfer_fee_config.extend_from_slice(&7u128.to_le_bytes());
+        for (epoch, maximum_fee, basis_points) in [(1u64, 100u128, 10u16), (2, 200, 20)] {
+            transfer_fee_config.extend_from_slice(&epoch.to_le_bytes());
+            transfer_fee_config.extend_from_slice(&maximum_fee.to_le_bytes());
+            transfer_fee_config.extend_from_slice(&basis_points.to_le_bytes());
+        }
+        let mut mint_data = vec![0; Account::LEN];
+        mint_data.push(ACCOUNTTYPE_MINT);
+        push_extension(
+            &mut mint_data,
+            EXTENSION_TRANSFER_FEE_CONFIG,
+            &transfer_fee_config,
+        );
+        push_extension(&mut mint_data, EXTENSION_DEFAULT_ACCOUNT_STATE, &[2]);
+        // Trailing padding is ignored
+        mint_data.extend_from_slice(&[0; 8]);
+        assert_eq!(extended_account_type(&mint_data), Some(ACCOUNTTYPE_MINT));
+        assert_eq!(
+            parse_extensions(&mint_data),
+            vec![
+                UiExtension::TransferFeeConfig(UiTransferFeeConfig {
+                    transfer_fee_config_authority: Some(authority.to_string()),
+                    withdraw_withheld_authority: None,
+                    withheld_amount: 7,
+                    older_transfer_fee: UiTransferFee {
+                        epoch: 1,
+                        maximum_fee: 100,
+                        transfer_fee_basis_points: 10,
+                    },
+                    newer_transfer_fee: UiTransferFee {
+                        epoch: 2,
+                        maximum_fee: 200,
+                        transfer_fee_basis_points: 20,
+                    },
+                }),
+                UiExtension::DefaultAccountState(UiDefaultAccountState {
+                    account_state: UiAccountState::Frozen,
+                }),
+            ]
+        );
+
+        // A value running past the end of the data is reported as unparseable
+        let mut truncated_data = vec![0; Account::LEN];
+        truncated_data.push(ACCOUNTTYPE_ACCOUNT);
+        truncated_data.extend_from_slice(&EXTENSION_MEMO_TRANSFER.to_le_bytes());
+        truncated_data.extend_from_slice(&4u16.to_le_bytes());
+        truncated_data.push(1);
+        assert_eq!(
+            parse_extensions(&truncated_data),
+            vec![UiExtension::UnparseableExtension]
+        );
+
+        // Multisig accounts are never extended
+        let mut multisig_data = vec![0; Multisig::LEN];
+        multisig_data[ACCOUNT_TYPE_OFFSET] = ACCOUNTTYPE_ACCOUNT;
+        assert_eq!(extended_account_type(&multisig_data), None);
+    }
+}
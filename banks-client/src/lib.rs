@@ -7,7 +7,7 @@
 
 pub use {
     crate::error::BanksClientError,
-    put_banks_interface::{BanksClient as TarpcClient, TransactionStatus},
+    put_banks_interface::{BanksAccountFilter, BanksClient as TarpcClient, TransactionStatus},
 };
 use {
     borsh::BorshDeserialize,
@@ -175,6 +175,39 @@ impl BanksClient {
             .map_err(Into::into)
     }
 
+    pub fn get_multiple_accounts_with_commitment_and_context(
+        &mut self,
+        ctx: Context,
+        addresses: Vec<Pubkey>,
+        commitment: CommitmentLevel,
+    ) -> impl Future<Output = Result<Vec<Option<Account>>, BanksClientError>> + '_ {
+        self.inner
+            .get_multiple_accounts_with_commitment_and_context(ctx, addresses, commitment)
+            .map_err(Into::into)
+    }
+
+    pub fn get_program_accounts_with_commitment_and_context(
+        &mut self,
+        ctx: Context,
+        program_id: Pubkey,
+        filters: Vec<BanksAccountFilter>,
+        commitment: CommitmentLevel,
+    ) -> impl Future<Output = Result<Option<Vec<(Pubkey, Account)>>, BanksClientError>> + '_ {
+        self.inner
+            .get_program_accounts_with_commitment_and_context(ctx, program_id, filters, commitment)
+            .map_err(Into::into)
+    }
+
+    pub fn get_signature_statuses_with_context(
+        &mut self,
+        ctx: Context,
+        signatures: Vec<Signature>,
+    ) -> impl Future<Output = Result<Vec<Option<TransactionStatus>>, BanksClientError>> + '_ {
+        self.inner
+            .get_signature_statuses_with_context(ctx, signatures)
+            .map_err(Into::into)
+    }
+
     /// Send a transaction and return immediately. The server will resend the
     /// transaction until either it is accepted by the cluster or the transaction's
     /// blockhash expires.
@@ -390,6 +423,60 @@ impl BanksClient {
         self.get_account_with_commitment(address, CommitmentLevel::default())
     }
 
+    /// Return the accounts at the given addresses at the slot corresponding to the given
+    /// commitment level, in the same order. Accounts that are not found are None.
+    pub fn get_multiple_accounts_with_commitment(
+        &mut self,
+        addresses: Vec<Pubkey>,
+        commitment: CommitmentLevel,
+    ) -> impl Future<Output = Result<Vec<Option<Account>>, BanksClientError>> + '_ {
+        self.get_multiple_accounts_with_commitment_and_context(
+            context::current(),
+            addresses,
+            commitment,
+        )
+    }
+
+    /// Return the accounts at the given addresses at the time of the most recent root slot,
+    /// in the same order. Accounts that are not found are None.
+    pub fn get_multiple_accounts(
+        &mut self,
+        addresses: Vec<Pubkey>,
+    ) -> impl Future<Output = Result<Vec<Option<Account>>, BanksClientError>> + '_ {
+        self.get_multiple_accounts_with_commitment(addresses, CommitmentLevel::default())
+    }
+
+    /// Return the accounts owned by the given program that match all of the given filters,
+    /// at the slot corresponding to the given commitment level.
+    pub fn get_program_accounts_with_commitment(
+        &mut self,
+        program_id: Pubkey,
+        filters: Vec<BanksAccountFilter>,
+        commitment: CommitmentLevel,
+    ) -> impl Future<Output = Result<Vec<(Pubkey, Account)>, BanksClientError>> + '_ {
+        self.get_program_accounts_with_commitment_and_context(
+            context::current(),
+            program_id,
+            filters,
+            commitment,
+        )
+        .map(|result| {
+            result?.ok_or(BanksClientError::ClientError(
+                "Program accounts scan failed",
+            ))
+        })
+    }
+
+    /// Return the accounts owned by the given program that match all of the given filters,
+    /// at the time of the most recent root slot.
+    pub fn get_program_accounts(
+        &mut self,
+        program_id: Pubkey,
+        filters: Vec<BanksAccountFilter>,
+    ) -> impl Future<Output = Result<Vec<(Pubkey, Account)>, BanksClientError>> + '_ {
+        self.get_program_accounts_with_commitment(program_id, filters, CommitmentLevel::default())
+    }
+
     /// Return the unpacked account data at the given address
     /// If the account is not found, an error is returned
     pub fn get_packed_account_data<T: Pack>(
@@ -468,6 +555,14 @@ impl BanksClient {
         statuses.into_iter().collect()
     }
 
+    /// Same as get_transaction_statuses, but fetches all of the statuses in a single request.
+    pub fn get_signature_statuses(
+        &mut self,
+        signatures: Vec<Signature>,
+    ) -> impl Future<Output = Result<Vec<Option<TransactionStatus>>, BanksClientError>> + '_ {
+        self.get_signature_statuses_with_context(context::current(), signatures)
+    }
+
     pub fn get_latest_blockhash(
         &mut self,
     ) -> impl Future<Output = Result<Hash, BanksClientError>> + '_ {
@@ -558,7 +653,8 @@ mod tests {
             genesis_utils::create_genesis_config,
         },
         put_sdk::{
-            message::Message, signature::Signer, system_instruction, transaction::Transaction,
+            account::AccountSharedData, message::Message, signature::Signer, system_instruction,
+            transaction::Transaction,
         },
         std::sync::{Arc, RwLock},
         tarpc::transport,
@@ -661,4 +757,92 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    #[allow(clippy::result_large_err)]
+    fn test_banks_server_batch_queries() -> Result<(), BanksClientError> {
+        let genesis = create_genesis_config(10);
+        let bank = Bank::new_for_tests(&genesis.genesis_config);
+        let program_id = put_sdk::pubkey::new_rand();
+        let keyed_accounts: Vec<_> = (0..3u8)
+            .map(|i| {
+                let account = Account {
+                    lamports: 1,
+                    data: vec![i; 2 + i as usize],
+                    owner: program_id,
+                    ..Account::default()
+                };
+                (put_sdk::pubkey::new_rand(), account)
+            })
+            .collect();
+        for (pubkey, account) in &keyed_accounts {
+            bank.store_account(pubkey, &AccountSharedData::from(account.clone()));
+        }
+        let slot = bank.slot();
+        let block_commitment_cache = Arc::new(RwLock::new(
+            BlockCommitmentCache::new_for_tests_with_slots(slot, slot),
+        ));
+        let bank_forks = Arc::new(RwLock::new(BankForks::new(bank)));
+
+        let bob_pubkey = put_sdk::pubkey::new_rand();
+        let mint_pubkey = genesis.mint_keypair.pubkey();
+        let instruction = system_instruction::transfer(&mint_pubkey, &bob_pubkey, 1);
+        let message = Message::new(&[instruction], Some(&mint_pubkey));
+
+        Runtime::new()?.block_on(async {
+            let client_transport =
+                start_local_server(bank_forks, block_commitment_cache, Duration::from_millis(1))
+                    .await;
+            let mut banks_client = start_client(client_transport).await?;
+
+            let addresses = vec![
+                keyed_accounts[0].0,
+                put_sdk::pubkey::new_rand(),
+                keyed_accounts[2].0,
+            ];
+            assert_eq!(
+                banks_client.get_multiple_accounts(addresses).await?,
+                vec![
+                    Some(keyed_accounts[0].1.clone()),
+                    None,
+                    Some(keyed_accounts[2].1.clone()),
+                ]
+            );
+
+            let mut program_accounts = banks_client
+                .get_program_accounts(program_id, vec![])
+                .await?;
+            program_accounts.sort_by_key(|(pubkey, _)| *pubkey);
+            let mut expected_accounts = keyed_accounts.clone();
+            expected_accounts.sort_by_key(|(pubkey, _)| *pubkey);
+            assert_eq!(program_accounts, expected_accounts);
+            assert_eq!(
+                banks_client
+                    .get_program_accounts(program_id, vec![BanksAccountFilter::DataSize(3)])
+                    .await?,
+                vec![keyed_accounts[1].clone()]
+            );
+            let memcmp = BanksAccountFilter::Memcmp {
+                offset: 1,
+                bytes: vec![2],
+            };
+            assert_eq!(
+                banks_client
+                    .get_program_accounts(program_id, vec![memcmp])
+                    .await?,
+                vec![keyed_accounts[2].clone()]
+            );
+
+            let recent_blockhash = banks_client.get_latest_blockhash().await?;
+            let transaction = Transaction::new(&[&genesis.mint_keypair], message, recent_blockhash);
+            let signature = transaction.signatures[0];
+            banks_client.process_transaction(transaction).await.unwrap();
+            let statuses = banks_client
+                .get_signature_statuses(vec![signature, Signature::default()])
+                .await?;
+            assert!(statuses[0].as_ref().unwrap().err.is_none());
+            assert_eq!(statuses[1], None);
+            Ok(())
+        })
+    }
 }
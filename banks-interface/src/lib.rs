@@ -59,6 +59,28 @@ pub struct BanksTransactionResultWithMetadata {
     pub metadata: Option<TransactionMetadata>,
 }
 
+/// Filter applied to the accounts of a program scan
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BanksAccountFilter {
+    /// Accounts whose data is exactly this many bytes long
+    DataSize(u64),
+    /// Accounts whose data holds `bytes` at `offset`
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+impl BanksAccountFilter {
+    pub fn allows(&self, data: &[u8]) -> bool {
+        match self {
+            Self::DataSize(size) => data.len() as u64 == *size,
+            Self::Memcmp { offset, bytes } => offset
+                .checked_add(bytes.len())
+                .and_then(|end| data.get(*offset..end))
+                .map(|data| data == bytes.as_slice())
+                .unwrap_or(false),
+        }
+    }
+}
+
 #[tarpc::service]
 pub trait Banks {
     async fn send_transaction_with_context(transaction: VersionedTransaction);
@@ -71,6 +93,9 @@ pub trait Banks {
     ) -> (FeeCalculator, Hash, Slot);
     async fn get_transaction_status_with_context(signature: Signature)
         -> Option<TransactionStatus>;
+    async fn get_signature_statuses_with_context(
+        signatures: Vec<Signature>,
+    ) -> Vec<Option<TransactionStatus>>;
     async fn get_slot_with_context(commitment: CommitmentLevel) -> Slot;
     async fn get_block_height_with_context(commitment: CommitmentLevel) -> u64;
     async fn process_transaction_with_preflight_and_commitment_and_context(
@@ -92,6 +117,16 @@ pub trait Banks {
         address: Pubkey,
         commitment: CommitmentLevel,
     ) -> Option<Account>;
+    async fn get_multiple_accounts_with_commitment_and_context(
+        addresses: Vec<Pubkey>,
+        commitment: CommitmentLevel,
+    ) -> Vec<Option<Account>>;
+    /// Return None if the scan could not be completed
+    async fn get_program_accounts_with_commitment_and_context(
+        program_id: Pubkey,
+        filters: Vec<BanksAccountFilter>,
+        commitment: CommitmentLevel,
+    ) -> Option<Vec<(Pubkey, Account)>>;
     async fn get_latest_blockhash_with_context() -> Hash;
     async fn get_latest_blockhash_with_commitment_and_context(
         commitment: CommitmentLevel,
@@ -114,4 +149,21 @@ mod tests {
         let (client_transport, _server_transport) = transport::channel::unbounded();
         BanksClient::new(client::Config::default(), client_transport);
     }
+
+    #[test]
+    fn test_banks_account_filter_allows() {
+        let data = [1, 2, 3, 4];
+        assert!(BanksAccountFilter::DataSize(4).allows(&data));
+        assert!(!BanksAccountFilter::DataSize(5).allows(&data));
+
+        let memcmp = |offset, bytes: &[u8]| BanksAccountFilter::Memcmp {
+            offset,
+            bytes: bytes.to_vec(),
+        };
+        assert!(memcmp(0, &[1, 2]).allows(&data));
+        assert!(memcmp(2, &[3, 4]).allows(&data));
+        assert!(!memcmp(1, &[3, 4]).allows(&data));
+        assert!(!memcmp(3, &[4, 5]).allows(&data));
+        assert!(!memcmp(usize::MAX, &[1]).allows(&data));
+    }
 }
@@ -3,18 +3,19 @@ use {
     crossbeam_channel::{unbounded, Receiver, Sender},
     futures::{future, prelude::stream::StreamExt},
     put_banks_interface::{
-        Banks, BanksRequest, BanksResponse, BanksTransactionResultWithMetadata,
+        Banks, BanksAccountFilter, BanksRequest, BanksResponse, BanksTransactionResultWithMetadata,
         BanksTransactionResultWithSimulation, TransactionConfirmationStatus, TransactionMetadata,
         TransactionSimulationDetails, TransactionStatus,
     },
     put_client::connection_cache::ConnectionCache,
     put_runtime::{
+        accounts_index::ScanConfig,
         bank::{Bank, TransactionExecutionResult, TransactionSimulationResult},
         bank_forks::BankForks,
         commitment::BlockCommitmentCache,
     },
     put_sdk::{
-        account::Account,
+        account::{Account, ReadableAccount},
         clock::Slot,
         commitment_config::CommitmentLevel,
         feature_set::FeatureSet,
@@ -138,6 +139,38 @@ impl BanksServer {
         self.bank_forks.read().unwrap()[self.slot(commitment)].clone()
     }
 
+    fn get_transaction_status(&self, signature: &Signature) -> Option<TransactionStatus> {
+        let bank = self.bank(CommitmentLevel::Processed);
+        let (slot, status) = bank.get_signature_status_slot(signature)?;
+        let r_block_commitment_cache = self.block_commitment_cache.read().unwrap();
+
+        let optimistically_confirmed_bank = self.bank(CommitmentLevel::Confirmed);
+        let optimistically_confirmed =
+            optimistically_confirmed_bank.get_signature_status_slot(signature);
+
+        let confirmations = if r_block_commitment_cache.root() >= slot
+            && r_block_commitment_cache.highest_super_majority_root() >= slot
+        {
+            None
+        } else {
+            r_block_commitment_cache
+                .get_confirmation_count(slot)
+                .or(Some(0))
+        };
+        Some(TransactionStatus {
+            slot,
+            confirmations,
+            err: status.err(),
+            confirmation_status: if confirmations.is_none() {
+                Some(TransactionConfirmationStatus::Finalized)
+            } else if optimistically_confirmed.is_some() {
+                Some(TransactionConfirmationStatus::Confirmed)
+            } else {
+                Some(TransactionConfirmationStatus::Processed)
+            },
+        })
+    }
+
     async fn poll_signature_status(
         self,
         signature: &Signature,
@@ -252,35 +285,18 @@ impl Banks for BanksServer {
         _: Context,
         signature: Signature,
     ) -> Option<TransactionStatus> {
-        let bank = self.bank(CommitmentLevel::Processed);
-        let (slot, status) = bank.get_signature_status_slot(&signature)?;
-        let r_block_commitment_cache = self.block_commitment_cache.read().unwrap();
-
-        let optimistically_confirmed_bank = self.bank(CommitmentLevel::Confirmed);
-        let optimistically_confirmed =
-            optimistically_confirmed_bank.get_signature_status_slot(&signature);
+        self.get_transaction_status(&signature)
+    }
 
-        let confirmations = if r_block_commitment_cache.root() >= slot
-            && r_block_commitment_cache.highest_super_majority_root() >= slot
-        {
-            None
-        } else {
-            r_block_commitment_cache
-                .get_confirmation_count(slot)
-                .or(Some(0))
-        };
-        Some(TransactionStatus {
-            slot,
-            confirmations,
-            err: status.err(),
-            confirmation_status: if confirmations.is_none() {
-                Some(TransactionConfirmationStatus::Finalized)
-            } else if optimistically_confirmed.is_some() {
-                Some(TransactionConfirmationStatus::Confirmed)
-            } else {
-                Some(TransactionConfirmationStatus::Processed)
-            },
-        })
+    async fn get_signature_statuses_with_context(
+        self,
+        _: Context,
+        signatures: Vec<Signature>,
+    ) -> Vec<Option<TransactionStatus>> {
+        signatures
+            .iter()
+            .map(|signature| self.get_transaction_status(signature))
+            .collect()
     }
 
     async fn get_slot_with_context(self, _: Context, commitment: CommitmentLevel) -> Slot {
@@ -393,6 +409,42 @@ impl Banks for BanksServer {
         bank.get_account(&address).map(Account::from)
     }
 
+    async fn get_multiple_accounts_with_commitment_and_context(
+        self,
+        _: Context,
+        addresses: Vec<Pubkey>,
+        commitment: CommitmentLevel,
+    ) -> Vec<Option<Account>> {
+        let bank = self.bank(commitment);
+        addresses
+            .iter()
+            .map(|address| bank.get_account(address).map(Account::from))
+            .collect()
+    }
+
+    async fn get_program_accounts_with_commitment_and_context(
+        self,
+        _: Context,
+        program_id: Pubkey,
+        filters: Vec<BanksAccountFilter>,
+        commitment: CommitmentLevel,
+    ) -> Option<Vec<(Pubkey, Account)>> {
+        let bank = self.bank(commitment);
+        let keyed_accounts = bank
+            .get_filtered_program_accounts(
+                &program_id,
+                |account| filters.iter().all(|filter| filter.allows(account.data())),
+                &ScanConfig::default(),
+            )
+            .ok()?;
+        Some(
+            keyed_accounts
+                .into_iter()
+                .map(|(pubkey, account)| (pubkey, Account::from(account)))
+                .collect(),
+        )
+    }
+
     async fn get_latest_blockhash_with_context(self, _: Context) -> Hash {
         let bank = self.bank(CommitmentLevel::default());
         bank.last_blockhash()
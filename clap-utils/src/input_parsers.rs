@@ -10,7 +10,7 @@ use {
         clock::UnixTimestamp,
         commitment_config::CommitmentConfig,
         genesis_config::ClusterType,
-        native_token::put_to_lamports,
+        native_token::try_put_to_lamports,
         pubkey::Pubkey,
         signature::{read_keypair_file, Keypair, Signature, Signer},
     },
@@ -184,7 +184,7 @@ pub fn resolve_signer(
 }
 
 pub fn lamports_of_put(matches: &ArgMatches<'_>, name: &str) -> Option<u128> {
-    value_of(matches, name).and_then(|p:String| Some(put_to_lamports(&p)))
+    value_of::<String>(matches, name).and_then(|amount| try_put_to_lamports(&amount).ok())
 }
 
 pub fn cluster_type_of(matches: &ArgMatches<'_>, name: &str) -> Option<ClusterType> {
@@ -4,6 +4,7 @@ use {
     put_sdk::{
         clock::{Epoch, Slot},
         hash::Hash,
+        native_token::try_put_to_lamports,
         pubkey::{Pubkey, MAX_SEED_LEN},
         signature::{read_keypair_file, Signature},
     },
@@ -278,46 +279,19 @@ pub fn is_amount<T>(amount: T) -> Result<(), String>
 where
     T: AsRef<str> + Display,
 {
-    let num:Vec<&str> = amount.as_ref().split('.').collect();
-    if num.len() == 1 && num[0].parse::<u128>().is_ok()  {
-        Ok(())
-    }else if num.len() == 2 && num[0].parse::<u128>().is_ok() && num[1].parse::<u128>().is_ok() {
-        Ok(())
-    }else {
-        Err(format!(
-            "Unable to parse input amount as integer or float, provided: {}",
-            amount
-        ))
-    }
-    // if amount.as_ref().parse::<u128>().is_ok() || amount.as_ref().parse::<f64>().is_ok() {
-    //     Ok(())
-    // } else {
-    //     Err(format!(
-    //         "Unable to parse input amount as integer or float, provided: {}",
-    //         amount
-    //     ))
-    // }
+    try_put_to_lamports(amount.as_ref())
+        .map(|_| ())
+        .map_err(|err| format!("Unable to parse input amount {amount}: {err}"))
 }
 
 pub fn is_amount_or_all<T>(amount: T) -> Result<(), String>
 where
     T: AsRef<str> + Display,
 {
-    if  amount.as_ref() == "ALL"
-    {
+    if amount.as_ref() == "ALL" {
         Ok(())
     } else {
-        let num:Vec<&str> = amount.as_ref().split('.').collect();
-        if num.len() == 1 && num[0].parse::<u128>().is_ok()  {
-            Ok(())
-        }else if num.len() == 2 && num[0].parse::<u128>().is_ok() && num[1].parse::<u128>().is_ok() {
-            Ok(())
-        }else {
-            Err(format!(
-                "Unable to parse input amount as integer or float, provided: {}",
-                amount
-            ))
-        }
+        is_amount(amount)
     }
 }
 
@@ -481,4 +455,18 @@ mod tests {
         assert!(is_niceness_adjustment_valid("128").is_err());
         assert!(is_niceness_adjustment_valid("-129").is_err());
     }
+
+    #[test]
+    fn test_is_amount() {
+        assert_eq!(is_amount("1"), Ok(()));
+        assert_eq!(is_amount("1.5"), Ok(()));
+        assert_eq!(is_amount("123456789.123456789"), Ok(()));
+        assert!(is_amount("1e9").is_err());
+        assert!(is_amount("1.2.3").is_err());
+        assert!(is_amount("0.0000000001").is_err());
+        assert!(is_amount("ALL").is_err());
+        assert_eq!(is_amount_or_all("ALL"), Ok(()));
+        assert_eq!(is_amount_or_all("0.5"), Ok(()));
+        assert!(is_amount_or_all("all").is_err());
+    }
 }
@@ -4,6 +4,7 @@ use {
     put_sdk::{
         clock::{Epoch, Slot},
         hash::Hash,
+        native_token::try_put_to_lamports,
         pubkey::{Pubkey, MAX_SEED_LEN},
         signature::{read_keypair_file, Signature},
     },
@@ -272,28 +273,19 @@ pub fn is_amount<T>(amount: T) -> Result<(), String>
 where
     T: AsRef<str> + Display,
 {
-    if amount.as_ref().parse::<u64>().is_ok() || amount.as_ref().parse::<f64>().is_ok() {
-        Ok(())
-    } else {
-        Err(format!(
-            "Unable to parse input amount as integer or float, provided: {amount}"
-        ))
-    }
+    try_put_to_lamports(amount.as_ref())
+        .map(|_| ())
+        .map_err(|err| format!("Unable to parse input amount {amount}: {err}"))
 }
 
 pub fn is_amount_or_all<T>(amount: T) -> Result<(), String>
 where
     T: AsRef<str> + Display,
 {
-    if amount.as_ref().parse::<u64>().is_ok()
-        || amount.as_ref().parse::<f64>().is_ok()
-        || amount.as_ref() == "ALL"
-    {
+    if amount.as_ref() == "ALL" {
         Ok(())
     } else {
-        Err(format!(
-            "Unable to parse input amount as integer or float, provided: {amount}"
-        ))
+        is_amount(amount)
     }
 }
 
@@ -16,8 +16,9 @@ use {
     serde::{Deserialize, Serialize},
     serde_json::{Map, Value},
     put_account_decoder::{
-        parse_account_data::AccountAdditionalData, parse_token::UiTokenAccount, UiAccount,
-        UiAccountEncoding, UiDataSliceConfig,
+        parse_account_data::AccountAdditionalData,
+        parse_token::{UiTokenAccount, UiTokenAmount},
+        UiAccount, UiAccountEncoding, UiDataSliceConfig,
     },
     put_clap_utils::keypair::SignOnly,
     put_rpc_client_api::response::{
@@ -2362,6 +2363,350 @@ impl fmt::Display for CliAddressLookupTableCreated {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliNftCollection {
+    pub address: String,
+    pub name: String,
+    pub symbol: String,
+    pub icon_uri: String,
+    pub mint_authority: String,
+    pub freeze_authority: Option<String>,
+    pub total_supply: u64,
+    pub supply: u64,
+}
+impl QuietDisplay for CliNftCollection {}
+impl VerboseDisplay for CliNftCollection {}
+impl fmt::Display for CliNftCollection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "Collection Address:", &self.address)?;
+        writeln_name_value(f, "Name:", &self.name)?;
+        writeln_name_value(f, "Symbol:", &self.symbol)?;
+        writeln_name_value(f, "Icon URI:", &self.icon_uri)?;
+        writeln_name_value(f, "Mint Authority:", &self.mint_authority)?;
+        writeln_name_value(
+            f,
+            "Freeze Authority:",
+            self.freeze_authority.as_deref().unwrap_or("None"),
+        )?;
+        writeln_name_value(f, "Total Supply:", &self.total_supply.to_string())?;
+        writeln_name_value(f, "Minted:", &self.supply.to_string())?;
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliNft {
+    pub address: String,
+    pub mint: String,
+    pub owner: String,
+    pub token_id: u64,
+    pub token_uri: String,
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_authority: Option<String>,
+}
+impl QuietDisplay for CliNft {}
+impl VerboseDisplay for CliNft {}
+impl fmt::Display for CliNft {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "NFT Address:", &self.address)?;
+        writeln_name_value(f, "Collection:", &self.mint)?;
+        writeln_name_value(f, "Owner:", &self.owner)?;
+        writeln_name_value(f, "Token Id:", &self.token_id.to_string())?;
+        writeln_name_value(f, "Token URI:", &self.token_uri)?;
+        writeln_name_value(f, "State:", &self.state)?;
+        if let Some(close_authority) = &self.close_authority {
+            writeln_name_value(f, "Close Authority:", close_authority)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliNfts {
+    pub owner: String,
+    pub nfts: Vec<CliNft>,
+}
+impl QuietDisplay for CliNfts {}
+impl VerboseDisplay for CliNfts {
+    fn write_str(&self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        writeln!(w)?;
+        writeln!(w, "{} {}", style("Owner:").bold(), self.owner)?;
+        for nft in &self.nfts {
+            write!(w, "{nft}")?;
+        }
+        Ok(())
+    }
+}
+impl fmt::Display for CliNfts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "Owner:", &self.owner)?;
+        if self.nfts.is_empty() {
+            writeln_name_value(f, "NFTs:", "None")?;
+            return Ok(());
+        }
+        writeln!(
+            f,
+            "{}",
+            style(format!(
+                "{:<44}  {:<44}  {:>8}  {}",
+                "NFT Address", "Collection", "Token Id", "State"
+            ))
+            .bold()
+        )?;
+        for nft in &self.nfts {
+            writeln!(
+                f,
+                "{:<44}  {:<44}  {:>8}  {}",
+                nft.address, nft.mint, nft.token_id, nft.state
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliNftCreated {
+    pub address: String,
+    pub signature: String,
+}
+impl QuietDisplay for CliNftCreated {}
+impl VerboseDisplay for CliNftCreated {}
+impl fmt::Display for CliNftCreated {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "Signature:", &self.signature)?;
+        writeln_name_value(f, "Address:", &self.address)?;
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliTokenBalance {
+    pub address: String,
+    pub mint: String,
+    pub owner: String,
+    pub token_amount: UiTokenAmount,
+    pub is_native: bool,
+}
+impl QuietDisplay for CliTokenBalance {}
+impl VerboseDisplay for CliTokenBalance {
+    fn write_str(&self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        writeln!(w)?;
+        writeln!(w, "{} {}", style("Token Account:").bold(), self.address)?;
+        writeln!(w, "{} {}", style("Mint:").bold(), self.mint)?;
+        writeln!(w, "{} {}", style("Owner:").bold(), self.owner)?;
+        writeln!(
+            w,
+            "{} {}",
+            style("Balance:").bold(),
+            self.token_amount.real_number_string()
+        )?;
+        writeln!(
+            w,
+            "{} {}",
+            style("Decimals:").bold(),
+            self.token_amount.decimals
+        )?;
+        Ok(())
+    }
+}
+impl fmt::Display for CliTokenBalance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.token_amount.real_number_string_trimmed())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliTokenAccounts {
+    pub owner: String,
+    pub accounts: Vec<CliTokenBalance>,
+}
+impl QuietDisplay for CliTokenAccounts {}
+impl VerboseDisplay for CliTokenAccounts {
+    fn write_str(&self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        writeln!(w)?;
+        writeln!(w, "{} {}", style("Owner:").bold(), self.owner)?;
+        for account in &self.accounts {
+            VerboseDisplay::write_str(account, w)?;
+        }
+        Ok(())
+    }
+}
+impl fmt::Display for CliTokenAccounts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "Owner:", &self.owner)?;
+        if self.accounts.is_empty() {
+            writeln_name_value(f, "Token Accounts:", "None")?;
+            return Ok(());
+        }
+        writeln!(
+            f,
+            "{}",
+            style(format!(
+                "{:<44}  {:<44}  {}",
+                "Token Account", "Mint", "Balance"
+            ))
+            .bold()
+        )?;
+        for account in &self.accounts {
+            let native = if account.is_native { " (native)" } else { "" };
+            writeln!(
+                f,
+                "{:<44}  {:<44}  {}{}",
+                account.address,
+                account.mint,
+                account.token_amount.real_number_string_trimmed(),
+                native
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliTokenCreated {
+    pub address: String,
+    pub signature: String,
+}
+impl QuietDisplay for CliTokenCreated {}
+impl VerboseDisplay for CliTokenCreated {}
+impl fmt::Display for CliTokenCreated {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "Signature:", &self.signature)?;
+        writeln_name_value(f, "Address:", &self.address)?;
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliDomain {
+    pub name: String,
+    pub address: String,
+    pub owner: String,
+    pub parent: String,
+    pub state: String,
+    pub max_space: u16,
+    pub expire_time: UnixTimestamp,
+    pub expired: bool,
+}
+impl QuietDisplay for CliDomain {}
+impl VerboseDisplay for CliDomain {}
+impl fmt::Display for CliDomain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "Domain:", &self.name)?;
+        writeln_name_value(f, "Domain Address:", &self.address)?;
+        writeln_name_value(f, "Owner:", &self.owner)?;
+        writeln_name_value(f, "Top Domain Address:", &self.parent)?;
+        writeln_name_value(f, "State:", &self.state)?;
+        writeln_name_value(f, "Max Record Space:", &self.max_space.to_string())?;
+        let expire_time = unix_timestamp_to_string(self.expire_time);
+        if self.expired {
+            writeln_name_value(f, "Expires:", &format!("{expire_time} (expired)"))?;
+        } else {
+            writeln_name_value(f, "Expires:", &expire_time)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliDomains {
+    pub owner: String,
+    pub domains: Vec<CliDomain>,
+}
+impl QuietDisplay for CliDomains {}
+impl VerboseDisplay for CliDomains {
+    fn write_str(&self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        writeln!(w)?;
+        writeln!(w, "{} {}", style("Owner:").bold(), self.owner)?;
+        for domain in &self.domains {
+            write!(w, "{domain}")?;
+        }
+        Ok(())
+    }
+}
+impl fmt::Display for CliDomains {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "Owner:", &self.owner)?;
+        if self.domains.is_empty() {
+            writeln_name_value(f, "Domains:", "None")?;
+            return Ok(());
+        }
+        writeln!(
+            f,
+            "{}",
+            style(format!(
+                "{:<32}  {:<44}  {}",
+                "Domain", "Address", "Expires"
+            ))
+            .bold()
+        )?;
+        for domain in &self.domains {
+            let expire_time = unix_timestamp_to_string(domain.expire_time);
+            writeln!(
+                f,
+                "{:<32}  {:<44}  {}{}",
+                domain.name,
+                domain.address,
+                expire_time,
+                if domain.expired { " (expired)" } else { "" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliDomainResolution {
+    pub name: String,
+    pub domain_address: String,
+    pub owner: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolve_address: Option<String>,
+    pub value: Option<String>,
+    pub expire_time: UnixTimestamp,
+    pub expired: bool,
+}
+impl QuietDisplay for CliDomainResolution {}
+impl VerboseDisplay for CliDomainResolution {}
+impl fmt::Display for CliDomainResolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        writeln_name_value(f, "Name:", &self.name)?;
+        writeln_name_value(f, "Value:", self.value.as_deref().unwrap_or("None"))?;
+        writeln_name_value(f, "Domain Address:", &self.domain_address)?;
+        if let Some(resolve_address) = &self.resolve_address {
+            writeln_name_value(f, "Record Address:", resolve_address)?;
+        }
+        writeln_name_value(f, "Owner:", &self.owner)?;
+        let expire_time = unix_timestamp_to_string(self.expire_time);
+        if self.expired {
+            writeln_name_value(f, "Expires:", &format!("{expire_time} (expired)"))?;
+        } else {
+            writeln_name_value(f, "Expires:", &expire_time)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ReturnSignersConfig {
     pub dump_transaction_message: bool,
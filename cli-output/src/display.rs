@@ -18,9 +18,9 @@ use {
         transaction::{TransactionError, TransactionVersion, VersionedTransaction},
     },
     put_transaction_status::{
-        Rewards, UiReturnDataEncoding, UiTransactionReturnData, UiTransactionStatusMeta,
+        extract_memos::is_known_ppl_memo_id, Rewards, UiReturnDataEncoding,
+        UiTransactionReturnData, UiTransactionStatusMeta,
     },
-    ppl_memo::{id as ppl_memo_id},
     std::{collections::HashMap, fmt, io, time::Duration},
 };
 
@@ -41,12 +41,6 @@ impl Default for BuildBalanceMessageConfig {
     }
 }
 
-fn is_memo_program(k: &Pubkey) -> bool {
-    let k_str = k.to_string();
-    // (k_str == ppl_memo_v1_id().to_string()) || (k_str == ppl_memo_id().to_string())
-    k_str == ppl_memo_id().to_string()
-}
-
 pub fn build_balance_message_with_config(
     lamports: u128,
     config: &BuildBalanceMessageConfig,
@@ -456,7 +450,7 @@ fn write_instruction<'a, W: io::Write>(
                 writeln!(w, "{prefix}  {system_instruction:?}")?;
                 raw = false;
             }
-        } else if is_memo_program(program_pubkey) {
+        } else if is_known_ppl_memo_id(program_pubkey) {
             if let Ok(s) = std::str::from_utf8(&instruction.data) {
                 writeln!(w, "{prefix}  Data: \"{s}\"")?;
                 raw = false;
@@ -809,6 +803,8 @@ mod test {
                 data: vec![1, 2, 3],
             }),
             compute_units_consumed: Some(1234u64),
+            pre_nft_holdings: None,
+            post_nft_holdings: None,
         };
 
         let output = {
@@ -888,6 +884,8 @@ Rewards:
                 data: vec![1, 2, 3],
             }),
             compute_units_consumed: Some(2345u64),
+            pre_nft_holdings: None,
+            post_nft_holdings: None,
         };
 
         let output = {
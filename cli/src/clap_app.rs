@@ -1,7 +1,8 @@
 use {
     crate::{
-        address_lookup_table::AddressLookupTableSubCommands, cli::*, cluster_query::*, feature::*,
-        inflation::*, nonce::*, program::*, stake::*, validator_info::*, vote::*, wallet::*,
+        address_lookup_table::AddressLookupTableSubCommands, cli::*, cluster_query::*,
+        domain::DomainSubCommands, feature::*, inflation::*, nft::NftSubCommands, nonce::*,
+        program::*, stake::*, token::TokenSubCommands, validator_info::*, vote::*, wallet::*,
     },
     clap::{App, AppSettings, Arg, ArgGroup, SubCommand},
     put_clap_utils::{self, hidden_unless_forced, input_validators::*, keypair::*},
@@ -144,6 +145,9 @@ pub fn get_clap_app<'ab, 'v>(name: &str, about: &'ab str, version: &'v str) -> A
         .nonce_subcommands()
         .program_subcommands()
         .address_lookup_table_subcommands()
+        .nft_subcommands()
+        .domain_subcommands()
+        .token_subcommands()
         .stake_subcommands()
         .validator_info_subcommands()
         .vote_subcommands()
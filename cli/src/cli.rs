@@ -1,7 +1,8 @@
 use {
     crate::{
-        address_lookup_table::*, clap_app::*, cluster_query::*, feature::*, inflation::*, nonce::*,
-        program::*, spend_utils::*, stake::*, validator_info::*, vote::*, wallet::*,
+        address_lookup_table::*, clap_app::*, cluster_query::*, domain::*, feature::*,
+        inflation::*, nft::*, nonce::*, program::*, spend_utils::*, stake::*, token::*,
+        validator_info::*, vote::*, wallet::*,
     },
     clap::{crate_description, crate_name, value_t_or_exit, ArgMatches, Shell},
     log::*,
@@ -419,7 +420,7 @@ pub enum CliCommand {
     },
     Transfer {
         amount: SpendAmount,
-        to: Pubkey,
+        to: TransferRecipient,
         from: SignerIndex,
         sign_only: bool,
         dump_transaction_message: bool,
@@ -439,6 +440,12 @@ pub enum CliCommand {
     },
     // Address lookup table commands
     AddressLookupTable(AddressLookupTableCliCommand),
+    // NFT commands
+    Nft(NftCliCommand),
+    // Domain commands
+    Domain(DomainCliCommand),
+    // Token commands
+    Token(TokenCliCommand),
     SignOffchainMessage {
         message: OffchainMessage,
     },
@@ -688,6 +695,11 @@ pub fn parse_command(
         ("address-lookup-table", Some(matches)) => {
             parse_address_lookup_table_subcommand(matches, default_signer, wallet_manager)
         }
+        ("nft", Some(matches)) => parse_nft_subcommand(matches, default_signer, wallet_manager),
+        ("domain", Some(matches)) => {
+            parse_domain_subcommand(matches, default_signer, wallet_manager)
+        }
+        ("token", Some(matches)) => parse_token_subcommand(matches, default_signer, wallet_manager),
         ("wait-for-max-stake", Some(matches)) => {
             let max_stake_percent = value_t_or_exit!(matches, "max_percent", f32);
             Ok(CliCommandInfo {
@@ -1634,6 +1646,12 @@ pub fn process_command(config: &CliConfig) -> ProcessResult {
         CliCommand::AddressLookupTable(subcommand) => {
             process_address_lookup_table_subcommand(rpc_client, config, subcommand)
         }
+        // NFT Commands
+        CliCommand::Nft(subcommand) => process_nft_subcommand(rpc_client, config, subcommand),
+        // Domain Commands
+        CliCommand::Domain(subcommand) => process_domain_subcommand(rpc_client, config, subcommand),
+        // Token Commands
+        CliCommand::Token(subcommand) => process_token_subcommand(rpc_client, config, subcommand),
         CliCommand::SignOffchainMessage { message } => {
             process_sign_offchain_message(config, message)
         }
@@ -2424,7 +2442,7 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::Transfer {
                     amount: SpendAmount::Some(42_000_000_000),
-                    to: to_pubkey,
+                    to: TransferRecipient::Address(to_pubkey),
                     from: 0,
                     sign_only: false,
                     dump_transaction_message: false,
@@ -2452,7 +2470,7 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::Transfer {
                     amount: SpendAmount::All,
-                    to: to_pubkey,
+                    to: TransferRecipient::Address(to_pubkey),
                     from: 0,
                     sign_only: false,
                     dump_transaction_message: false,
@@ -2471,6 +2489,46 @@ mod tests {
             }
         );
 
+        // Test Transfer to a domain
+        let test_transfer =
+            test_commands
+                .clone()
+                .get_matches_from(vec!["test", "transfer", "alice.put", "42"]);
+        assert_eq!(
+            parse_command(&test_transfer, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Transfer {
+                    amount: SpendAmount::Some(42_000_000_000),
+                    to: TransferRecipient::Domain("alice.put".to_string()),
+                    from: 0,
+                    sign_only: false,
+                    dump_transaction_message: false,
+                    allow_unfunded_recipient: false,
+                    no_wait: false,
+                    blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
+                    nonce_account: None,
+                    nonce_authority: 0,
+                    memo: None,
+                    fee_payer: 0,
+                    derived_address_seed: None,
+                    derived_address_program_id: None,
+                    compute_unit_price: None,
+                },
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+        let blockhash = Hash::new_unique();
+        let test_transfer = test_commands.clone().get_matches_from(vec![
+            "test",
+            "transfer",
+            "alice.put",
+            "42",
+            "--sign-only",
+            "--blockhash",
+            &blockhash.to_string(),
+        ]);
+        assert!(parse_command(&test_transfer, &default_signer, &mut None).is_err());
+
         // Test Transfer no-wait and --allow-unfunded-recipient
         let test_transfer = test_commands.clone().get_matches_from(vec![
             "test",
@@ -2485,7 +2543,7 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::Transfer {
                     amount: SpendAmount::Some(42_000_000_000),
-                    to: to_pubkey,
+                    to: TransferRecipient::Address(to_pubkey),
                     from: 0,
                     sign_only: false,
                     dump_transaction_message: false,
@@ -2521,7 +2579,7 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::Transfer {
                     amount: SpendAmount::Some(42_000_000_000),
-                    to: to_pubkey,
+                    to: TransferRecipient::Address(to_pubkey),
                     from: 0,
                     sign_only: true,
                     dump_transaction_message: false,
@@ -2562,7 +2620,7 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::Transfer {
                     amount: SpendAmount::Some(42_000_000_000),
-                    to: to_pubkey,
+                    to: TransferRecipient::Address(to_pubkey),
                     from: 0,
                     sign_only: false,
                     dump_transaction_message: false,
@@ -2607,7 +2665,7 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::Transfer {
                     amount: SpendAmount::Some(42_000_000_000),
-                    to: to_pubkey,
+                    to: TransferRecipient::Address(to_pubkey),
                     from: 0,
                     sign_only: false,
                     dump_transaction_message: false,
@@ -2650,7 +2708,7 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::Transfer {
                     amount: SpendAmount::Some(42_000_000_000),
-                    to: to_pubkey,
+                    to: TransferRecipient::Address(to_pubkey),
                     from: 0,
                     sign_only: false,
                     dump_transaction_message: false,
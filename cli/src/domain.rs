@@ -0,0 +1,908 @@
+use {
+    crate::{
+        cli::{CliCommand, CliCommandInfo, CliConfig, CliError, ProcessResult},
+        transaction::{parse_transaction_signers, process_transaction, TransactionConfig},
+    },
+    clap::{App, AppSettings, Arg, ArgMatches, SubCommand},
+    ppl_name::{
+        instruction::{get_domain, NameInstruction},
+        state::{get_seeds_and_key, AccountType},
+    },
+    put_account_decoder::{
+        parse_name::{is_known_name_id, parse_domain_account, UiDomainAccount},
+        UiAccountEncoding,
+    },
+    put_clap_utils::{
+        compute_unit_price::compute_unit_price_arg,
+        fee_payer::fee_payer_arg,
+        input_parsers::*,
+        input_validators::*,
+        keypair::{DefaultSigner, SignerIndex},
+        memo::memo_arg,
+        nonce::*,
+        offline::*,
+    },
+    put_cli_output::{CliDomain, CliDomainResolution, CliDomains, CliSignature},
+    put_remote_wallet::remote_wallet::RemoteWalletManager,
+    put_rpc_client::rpc_client::RpcClient,
+    put_rpc_client_api::{
+        config::{RpcAccountInfoConfig, RpcDomainAccountsConfig, RpcDomainExpiryFilter},
+        response::RpcDomainExpiryState,
+    },
+    put_sdk::{
+        clock::UnixTimestamp,
+        hash::hashv,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Signature, Signer},
+        system_program, sysvar,
+    },
+    std::{
+        fmt::Display,
+        path::Path,
+        str::FromStr,
+        sync::Arc,
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DomainCliCommand {
+    Register {
+        name: String,
+        owner_pubkey: Pubkey,
+        transaction_config: TransactionConfig,
+    },
+    Renew {
+        name: String,
+        transaction_config: TransactionConfig,
+    },
+    Resolve {
+        name: String,
+    },
+    SetRecord {
+        name: String,
+        value: Pubkey,
+        owner: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    Transfer {
+        name: String,
+        new_owner_pubkey: Pubkey,
+        owner: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    List {
+        owner_pubkey: Pubkey,
+    },
+    Show {
+        name: String,
+    },
+}
+
+// Return an error if a string is not shaped like a domain name, e.g. `alice.put`
+pub fn is_domain_name<T>(string: T) -> Result<(), String>
+where
+    T: AsRef<str> + Display,
+{
+    let name = string.as_ref().trim_end_matches('.');
+    let labels: Vec<&str> = name.split('.').collect();
+    if labels.len() < 2
+        || labels.iter().any(|label| {
+            label.is_empty()
+                || label
+                    .chars()
+                    .any(|c| c.is_whitespace() || c == '/' || c == '\\')
+        })
+    {
+        return Err(format!(
+            "Unable to parse input as domain name, provided: {string}"
+        ));
+    }
+    Ok(())
+}
+
+// Return an error if a string is neither a valid pubkey nor a domain name
+pub fn is_valid_pubkey_or_domain<T>(string: T) -> Result<(), String>
+where
+    T: AsRef<str> + Display,
+{
+    is_valid_pubkey(string.as_ref()).or_else(|err| is_domain_name(string).map_err(|_| err))
+}
+
+/// Returns the domain name in `value` if it should be resolved through the name service rather
+/// than parsed as an address or keypair path
+pub fn domain_name_of(value: &str) -> Option<String> {
+    if Pubkey::from_str(value).is_ok() || Path::new(value).exists() {
+        return None;
+    }
+    is_domain_name(value)
+        .ok()
+        .map(|_| value.trim_end_matches('.').to_string())
+}
+
+pub trait DomainSubCommands {
+    fn domain_subcommands(self) -> Self;
+}
+
+impl DomainSubCommands for App<'_, '_> {
+    fn domain_subcommands(self) -> Self {
+        self.subcommand(
+            SubCommand::with_name("domain")
+                .about("Name service domain commands")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("register")
+                        .about("Register a new domain under an existing top domain")
+                        .arg(
+                            Arg::with_name("name")
+                                .index(1)
+                                .value_name("DOMAIN")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_domain_name)
+                                .help("The domain to register, e.g. alice.put"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("OWNER_ADDRESS"),
+                                "Owner of the new domain [default: the default configured keypair]. "),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("renew")
+                        .about("Extend the registration of a domain, paying its top domain")
+                        .arg(
+                            Arg::with_name("name")
+                                .index(1)
+                                .value_name("DOMAIN")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_domain_name)
+                                .help("The domain to renew"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("resolve")
+                        .about("Resolve a name to the address it points to")
+                        .arg(
+                            Arg::with_name("name")
+                                .index(1)
+                                .value_name("NAME")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_domain_name)
+                                .help("The name to resolve"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("set-record")
+                        .about("Point a name at an address")
+                        .arg(
+                            Arg::with_name("name")
+                                .index(1)
+                                .value_name("NAME")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_domain_name)
+                                .help("The name to update"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("value")
+                                .index(2)
+                                .value_name("ADDRESS")
+                                .required(true),
+                                "The address the name resolves to. "),
+                        )
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Owner of the domain [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("transfer")
+                        .about("Transfer a domain to a new owner")
+                        .arg(
+                            Arg::with_name("name")
+                                .index(1)
+                                .value_name("DOMAIN")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_domain_name)
+                                .help("The domain to transfer"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("new_owner")
+                                .index(2)
+                                .value_name("NEW_OWNER_ADDRESS")
+                                .required(true),
+                                "The new owner of the domain. "),
+                        )
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Current owner of the domain [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("list")
+                        .about("List the domains held by an owner")
+                        .arg(
+                            pubkey!(Arg::with_name("owner")
+                                .index(1)
+                                .value_name("OWNER_ADDRESS"),
+                                "Owner of the domains [default: the default configured keypair]. "),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("show")
+                        .about("Display information about a domain")
+                        .arg(
+                            Arg::with_name("name")
+                                .index(1)
+                                .value_name("DOMAIN")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_domain_name)
+                                .help("The domain to show"),
+                        ),
+                ),
+        )
+    }
+}
+
+pub fn parse_domain_subcommand(
+    matches: &ArgMatches<'_>,
+    default_signer: &DefaultSigner,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<CliCommandInfo, CliError> {
+    let (subcommand, sub_matches) = matches.subcommand();
+
+    let response = match (subcommand, sub_matches) {
+        ("register", Some(matches)) => {
+            let name = domain_name_arg(matches);
+            let owner_pubkey = match pubkey_of_signer(matches, "owner", wallet_manager)? {
+                Some(owner_pubkey) => owner_pubkey,
+                None => default_signer
+                    .signer_from_path(matches, wallet_manager)?
+                    .pubkey(),
+            };
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![])?;
+
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::Register {
+                    name,
+                    owner_pubkey,
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("renew", Some(matches)) => {
+            let name = domain_name_arg(matches);
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![])?;
+
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::Renew {
+                    name,
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("resolve", Some(matches)) => CliCommandInfo {
+            command: CliCommand::Domain(DomainCliCommand::Resolve {
+                name: domain_name_arg(matches),
+            }),
+            signers: vec![],
+        },
+        ("set-record", Some(matches)) => {
+            let name = domain_name_arg(matches);
+            let value = pubkey_of_signer(matches, "value", wallet_manager)?.unwrap();
+            let (owner, owner_pubkey) = signer_of(matches, "owner", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![owner])?;
+
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::SetRecord {
+                    name,
+                    value,
+                    owner: signer_info.index_of(owner_pubkey).unwrap(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("transfer", Some(matches)) => {
+            let name = domain_name_arg(matches);
+            let new_owner_pubkey = pubkey_of_signer(matches, "new_owner", wallet_manager)?.unwrap();
+            let (owner, owner_pubkey) = signer_of(matches, "owner", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![owner])?;
+
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::Transfer {
+                    name,
+                    new_owner_pubkey,
+                    owner: signer_info.index_of(owner_pubkey).unwrap(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("list", Some(matches)) => {
+            let owner_pubkey = match pubkey_of_signer(matches, "owner", wallet_manager)? {
+                Some(owner_pubkey) => owner_pubkey,
+                None => default_signer
+                    .signer_from_path(matches, wallet_manager)?
+                    .pubkey(),
+            };
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::List { owner_pubkey }),
+                signers: vec![],
+            }
+        }
+        ("show", Some(matches)) => CliCommandInfo {
+            command: CliCommand::Domain(DomainCliCommand::Show {
+                name: domain_name_arg(matches),
+            }),
+            signers: vec![],
+        },
+        _ => unreachable!(),
+    };
+    Ok(response)
+}
+
+fn domain_name_arg(matches: &ArgMatches<'_>) -> String {
+    matches
+        .value_of("name")
+        .unwrap()
+        .trim_end_matches('.')
+        .to_string()
+}
+
+pub fn process_domain_subcommand(
+    rpc_client: Arc<RpcClient>,
+    config: &CliConfig,
+    subcommand: &DomainCliCommand,
+) -> ProcessResult {
+    match subcommand {
+        DomainCliCommand::Register {
+            name,
+            owner_pubkey,
+            transaction_config,
+        } => {
+            let domain_address = domain_address(name)?;
+            let top_domain_address = top_domain_address(name)?;
+            let (top_domain, _) = get_domain_account(&rpc_client, config, &top_domain_address)?;
+            let receipt = Pubkey::from_str(&top_domain.owner)?;
+            let payer = config.signers[transaction_config.fee_payer].pubkey();
+
+            let ix = Instruction::new_with_borsh(
+                ppl_name::id(),
+                &NameInstruction::CreateDomain {
+                    domain_name: name.clone(),
+                },
+                vec![
+                    AccountMeta::new(domain_address, false),
+                    AccountMeta::new_readonly(*owner_pubkey, false),
+                    AccountMeta::new_readonly(top_domain_address, false),
+                    AccountMeta::new(payer, true),
+                    AccountMeta::new(receipt, false),
+                    AccountMeta::new_readonly(system_program::id(), false),
+                    AccountMeta::new_readonly(sysvar::rent::id(), false),
+                ],
+            );
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ix],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        DomainCliCommand::Renew {
+            name,
+            transaction_config,
+        } => {
+            let domain_address = domain_address(name)?;
+            let (domain, _) = get_domain_account(&rpc_client, config, &domain_address)?;
+            let top_domain_address = Pubkey::from_str(&domain.parent_key)?;
+            let (top_domain, _) = get_domain_account(&rpc_client, config, &top_domain_address)?;
+            let receipt = Pubkey::from_str(&top_domain.owner)?;
+            let payer = config.signers[transaction_config.fee_payer].pubkey();
+
+            let ix = Instruction::new_with_borsh(
+                ppl_name::id(),
+                &NameInstruction::Renewal,
+                vec![
+                    AccountMeta::new(domain_address, false),
+                    AccountMeta::new_readonly(top_domain_address, false),
+                    AccountMeta::new(payer, true),
+                    AccountMeta::new(receipt, false),
+                    AccountMeta::new_readonly(system_program::id(), false),
+                ],
+            );
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ix],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        DomainCliCommand::Resolve { name } => process_resolve_domain(&rpc_client, config, name),
+        DomainCliCommand::SetRecord {
+            name,
+            value,
+            owner,
+            transaction_config,
+        } => {
+            let domain_address = domain_address(name)?;
+            let (domain, _) = get_domain_account(&rpc_client, config, &domain_address)?;
+            let top_domain_address = Pubkey::from_str(&domain.parent_key)?;
+            let owner = config.signers[*owner].pubkey();
+            let payer = config.signers[transaction_config.fee_payer].pubkey();
+            let resolved = rpc_client
+                .resolve_domain_with_commitment(name, config.commitment)?
+                .value;
+
+            let ix = match resolved.and_then(|resolved| {
+                resolved
+                    .resolve_account
+                    .map(|resolve_account| (resolve_account, resolved.value))
+            }) {
+                Some((resolve_account, old_value)) => {
+                    let (top_domain, _) =
+                        get_domain_account(&rpc_client, config, &top_domain_address)?;
+                    let receipt = Pubkey::from_str(&top_domain.owner)?;
+                    let old_value = match old_value {
+                        Some(old_value) => Pubkey::from_str(&old_value)?,
+                        None => Pubkey::default(),
+                    };
+                    Instruction::new_with_borsh(
+                        ppl_name::id(),
+                        &NameInstruction::UpdateDomainResolveAccount {
+                            domain_name: name.clone(),
+                            new_value: value.to_bytes().to_vec(),
+                        },
+                        vec![
+                            AccountMeta::new(Pubkey::from_str(&resolve_account)?, false),
+                            AccountMeta::new_readonly(owner, true),
+                            AccountMeta::new_readonly(domain_address, false),
+                            AccountMeta::new(address_resolve_address(&old_value)?, false),
+                            AccountMeta::new(address_resolve_address(value)?, false),
+                            AccountMeta::new(payer, true),
+                            AccountMeta::new_readonly(top_domain_address, false),
+                            AccountMeta::new(receipt, false),
+                            AccountMeta::new_readonly(system_program::id(), false),
+                            AccountMeta::new_readonly(sysvar::rent::id(), false),
+                        ],
+                    )
+                }
+                None => Instruction::new_with_borsh(
+                    ppl_name::id(),
+                    &NameInstruction::CreateDomainResolveAccount {
+                        domain_name: name.clone(),
+                        value: value.to_bytes().to_vec(),
+                    },
+                    vec![
+                        AccountMeta::new_readonly(domain_address, false),
+                        AccountMeta::new_readonly(owner, true),
+                        AccountMeta::new_readonly(top_domain_address, false),
+                        AccountMeta::new(payer, true),
+                        AccountMeta::new(domain_resolve_address(name)?, false),
+                        AccountMeta::new_readonly(system_program::id(), false),
+                        AccountMeta::new_readonly(sysvar::rent::id(), false),
+                    ],
+                ),
+            };
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ix],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        DomainCliCommand::Transfer {
+            name,
+            new_owner_pubkey,
+            owner,
+            transaction_config,
+        } => {
+            let ix = Instruction::new_with_borsh(
+                ppl_name::id(),
+                &NameInstruction::Transfer,
+                vec![
+                    AccountMeta::new(domain_address(name)?, false),
+                    AccountMeta::new_readonly(config.signers[*owner].pubkey(), true),
+                    AccountMeta::new_readonly(*new_owner_pubkey, false),
+                ],
+            );
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ix],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        DomainCliCommand::List { owner_pubkey } => {
+            process_list_domains(&rpc_client, config, owner_pubkey)
+        }
+        DomainCliCommand::Show { name } => process_show_domain(&rpc_client, config, name),
+    }
+}
+
+fn name_program_address(seed: &[u8], account_type: AccountType) -> Result<Pubkey, CliError> {
+    get_seeds_and_key(
+        &ppl_name::id(),
+        Some(hashv(&[seed]).to_bytes().to_vec()),
+        account_type,
+        None,
+    )
+    .map(|(pubkey, _)| pubkey)
+    .map_err(|err| CliError::BadParameter(format!("Unable to derive name account: {err:?}")))
+}
+
+/// Address of the domain account holding `name`, or the domain `name` is a record of
+pub fn domain_address(name: &str) -> Result<Pubkey, CliError> {
+    name_program_address(get_domain(name).as_bytes(), AccountType::Domain)
+}
+
+fn top_domain_address(name: &str) -> Result<Pubkey, CliError> {
+    let top_domain = name.rsplit('.').next().unwrap_or_default();
+    name_program_address(top_domain.as_bytes(), AccountType::Domain)
+}
+
+fn domain_resolve_address(name: &str) -> Result<Pubkey, CliError> {
+    name_program_address(name.as_bytes(), AccountType::DomainResolve)
+}
+
+fn address_resolve_address(address: &Pubkey) -> Result<Pubkey, CliError> {
+    name_program_address(address.as_ref(), AccountType::AddressResolve)
+}
+
+fn get_domain_account(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    address: &Pubkey,
+) -> Result<(UiDomainAccount, UnixTimestamp), CliError> {
+    let account = rpc_client
+        .get_account_with_commitment(address, config.commitment)?
+        .value
+        .ok_or_else(|| CliError::RpcRequestError(format!("Domain account {address} not found")))?;
+    if !is_known_name_id(&account.owner) {
+        return Err(CliError::RpcRequestError(format!(
+            "{address} is not owned by the name program"
+        )));
+    }
+    parse_domain_account(&account.data)
+        .map_err(|_| CliError::RpcRequestError(format!("{address} is not a domain account")))
+}
+
+fn is_expired(expire_time: UnixTimestamp) -> bool {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as UnixTimestamp)
+        .unwrap_or_default();
+    now > expire_time
+}
+
+/// Resolve a domain name used as a recipient to the address it points to
+pub fn resolve_domain_recipient(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    name: &str,
+) -> Result<Pubkey, CliError> {
+    let resolved = rpc_client
+        .resolve_domain_with_commitment(name, config.commitment)?
+        .value
+        .ok_or_else(|| CliError::BadParameter(format!("Domain {name} is not registered")))?;
+    if resolved.expired {
+        return Err(CliError::BadParameter(format!("Domain {name} has expired")));
+    }
+    let value = resolved
+        .value
+        .ok_or_else(|| CliError::BadParameter(format!("Domain {name} has no address record")))?;
+    Pubkey::from_str(&value).map_err(|_| {
+        CliError::BadParameter(format!("Domain {name} does not resolve to an address"))
+    })
+}
+
+fn format_signature(config: &CliConfig, signature: Signature) -> String {
+    config.output_format.formatted_string(&CliSignature {
+        signature: signature.to_string(),
+    })
+}
+
+fn process_resolve_domain(rpc_client: &RpcClient, config: &CliConfig, name: &str) -> ProcessResult {
+    let resolved = rpc_client
+        .resolve_domain_with_commitment(name, config.commitment)?
+        .value
+        .ok_or_else(|| CliError::RpcRequestError(format!("Domain {name} is not registered")))?;
+    Ok(config.output_format.formatted_string(&CliDomainResolution {
+        name: resolved.name,
+        domain_address: resolved.domain_account,
+        owner: resolved.owner,
+        resolve_address: resolved.resolve_account,
+        value: resolved.value,
+        expire_time: resolved.expire_time,
+        expired: resolved.expired,
+    }))
+}
+
+fn cli_domain(address: String, domain: UiDomainAccount, expire_time: UnixTimestamp) -> CliDomain {
+    CliDomain {
+        name: domain.domain_name,
+        address,
+        owner: domain.owner,
+        parent: domain.parent_key,
+        state: domain.account_state,
+        max_space: domain.max_space,
+        expire_time,
+        expired: is_expired(expire_time),
+    }
+}
+
+fn process_show_domain(rpc_client: &RpcClient, config: &CliConfig, name: &str) -> ProcessResult {
+    let address = domain_address(name)?;
+    let (domain, expire_time) = get_domain_account(rpc_client, config, &address)?;
+    Ok(config
+        .output_format
+        .formatted_string(&cli_domain(address.to_string(), domain, expire_time)))
+}
+
+fn process_list_domains(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    owner_pubkey: &Pubkey,
+) -> ProcessResult {
+    // Request raw domain accounts, as the parsed ones only carry a formatted expire time. Domain
+    // resolve accounts are always returned parsed, and are skipped
+    let keyed_accounts = rpc_client
+        .get_domain_accounts_by_owner_with_config(
+            owner_pubkey,
+            RpcDomainAccountsConfig {
+                expiry_filter: Some(RpcDomainExpiryFilter::Flag),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    commitment: Some(config.commitment),
+                    ..RpcAccountInfoConfig::default()
+                },
+                ..RpcDomainAccountsConfig::default()
+            },
+        )?
+        .value;
+
+    let mut domains = vec![];
+    for keyed_account in keyed_accounts {
+        let Some((domain, expire_time)) = keyed_account
+            .account
+            .decode::<put_sdk::account::Account>()
+            .and_then(|account| parse_domain_account(&account.data).ok())
+        else {
+            continue;
+        };
+        let mut domain = cli_domain(keyed_account.pubkey, domain, expire_time);
+        if let Some(expiry_state) = keyed_account.expiry_state {
+            domain.expired = expiry_state == RpcDomainExpiryState::Expired;
+        }
+        domains.push(domain);
+    }
+    domains.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(config.output_format.formatted_string(&CliDomains {
+        owner: owner_pubkey.to_string(),
+        domains,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{clap_app::get_clap_app, cli::parse_command},
+        put_rpc_client_nonce_utils::blockhash_query::BlockhashQuery,
+        put_sdk::{
+            hash::Hash,
+            signature::{read_keypair_file, write_keypair, Keypair},
+        },
+        tempfile::NamedTempFile,
+    };
+
+    fn make_tmp_file() -> (String, NamedTempFile) {
+        let tmp_file = NamedTempFile::new().unwrap();
+        (String::from(tmp_file.path().to_str().unwrap()), tmp_file)
+    }
+
+    #[test]
+    fn test_domain_name_of() {
+        assert_eq!(domain_name_of("alice.put"), Some("alice.put".to_string()));
+        assert_eq!(
+            domain_name_of("pay.alice.put."),
+            Some("pay.alice.put".to_string())
+        );
+        assert_eq!(domain_name_of("alice"), None);
+        assert_eq!(domain_name_of("alice..put"), None);
+        assert_eq!(domain_name_of(&Pubkey::new_unique().to_string()), None);
+
+        let (keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&Keypair::new(), tmp_file.as_file_mut()).unwrap();
+        assert_eq!(domain_name_of(&keypair_file), None);
+    }
+
+    #[test]
+    fn test_parse_domain_subcommand() {
+        let test_commands = get_clap_app("test", "desc", "version");
+
+        let default_keypair = Keypair::new();
+        let (default_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&default_keypair, tmp_file.as_file_mut()).unwrap();
+        let default_signer = DefaultSigner::new("", &default_keypair_file);
+
+        let owner_keypair = Keypair::new();
+        let (owner_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&owner_keypair, tmp_file.as_file_mut()).unwrap();
+
+        let default_transaction_config = || TransactionConfig {
+            sign_only: false,
+            dump_transaction_message: false,
+            blockhash_query: BlockhashQuery::default(),
+            nonce_account: None,
+            nonce_authority: 0,
+            memo: None,
+            fee_payer: 0,
+            compute_unit_price: None,
+        };
+
+        // Test Register Subcommand
+        let test_register =
+            test_commands
+                .clone()
+                .get_matches_from(vec!["test", "domain", "register", "alice.put"]);
+        assert_eq!(
+            parse_command(&test_register, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::Register {
+                    name: "alice.put".to_string(),
+                    owner_pubkey: default_keypair.pubkey(),
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+        let test_register = test_commands
+            .clone()
+            .get_matches_from_safe(vec!["test", "domain", "register", "alice"]);
+        assert!(test_register.is_err());
+
+        // Test SetRecord Subcommand, signing offline
+        let value = Pubkey::new_unique();
+        let blockhash = Hash::new_unique();
+        let test_set_record = test_commands.clone().get_matches_from(vec![
+            "test",
+            "domain",
+            "set-record",
+            "pay.alice.put",
+            &value.to_string(),
+            "--owner",
+            &owner_keypair_file,
+            "--sign-only",
+            "--blockhash",
+            &blockhash.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_set_record, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::SetRecord {
+                    name: "pay.alice.put".to_string(),
+                    value,
+                    owner: 1,
+                    transaction_config: TransactionConfig {
+                        sign_only: true,
+                        blockhash_query: BlockhashQuery::None(blockhash),
+                        ..default_transaction_config()
+                    },
+                }),
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&owner_keypair_file).unwrap().into(),
+                ],
+            }
+        );
+
+        // Test Transfer Subcommand
+        let new_owner = Pubkey::new_unique();
+        let test_transfer = test_commands.clone().get_matches_from(vec![
+            "test",
+            "domain",
+            "transfer",
+            "alice.put",
+            &new_owner.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_transfer, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::Transfer {
+                    name: "alice.put".to_string(),
+                    new_owner_pubkey: new_owner,
+                    owner: 0,
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+
+        // Test Renew Subcommand, with a separate fee payer
+        let test_renew = test_commands.clone().get_matches_from(vec![
+            "test",
+            "domain",
+            "renew",
+            "alice.put",
+            "--fee-payer",
+            &owner_keypair_file,
+        ]);
+        assert_eq!(
+            parse_command(&test_renew, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::Renew {
+                    name: "alice.put".to_string(),
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![read_keypair_file(&owner_keypair_file).unwrap().into()],
+            }
+        );
+
+        // Test Resolve Subcommand
+        let test_resolve = test_commands.clone().get_matches_from(vec![
+            "test",
+            "domain",
+            "resolve",
+            "pay.alice.put.",
+        ]);
+        assert_eq!(
+            parse_command(&test_resolve, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Domain(DomainCliCommand::Resolve {
+                    name: "pay.alice.put".to_string(),
+                }),
+                signers: vec![],
+            }
+        );
+    }
+}
@@ -28,14 +28,18 @@ pub mod clap_app;
 pub mod cli;
 pub mod cluster_query;
 pub mod compute_unit_price;
+pub mod domain;
 pub mod feature;
 pub mod inflation;
 pub mod memo;
+pub mod nft;
 pub mod nonce;
 pub mod program;
 pub mod spend_utils;
 pub mod stake;
 pub mod test_utils;
+pub mod token;
+pub mod transaction;
 pub mod validator_info;
 pub mod vote;
 pub mod wallet;
@@ -0,0 +1,1108 @@
+use {
+    crate::{
+        cli::{CliCommand, CliCommandInfo, CliConfig, CliError, ProcessResult},
+        transaction::{parse_transaction_signers, process_transaction, TransactionConfig},
+    },
+    clap::{App, AppSettings, Arg, ArgMatches, SubCommand},
+    ppl_nft::instruction::{self as nft_instruction, AuthorityType, InitializeMintArgs},
+    put_account_decoder::{
+        parse_nft::{nft_program_id, parse_nft, ppl_nft_pubkey, TokenAccountType, UiNFTAccount},
+        UiAccountData,
+    },
+    put_clap_utils::{
+        compute_unit_price::compute_unit_price_arg,
+        fee_payer::fee_payer_arg,
+        input_parsers::*,
+        input_validators::*,
+        keypair::{DefaultSigner, SignerIndex},
+        memo::memo_arg,
+        nonce::*,
+        offline::*,
+    },
+    put_cli_output::{CliNft, CliNftCollection, CliNftCreated, CliNfts, CliSignature},
+    put_remote_wallet::remote_wallet::RemoteWalletManager,
+    put_rpc_client::rpc_client::RpcClient,
+    put_rpc_client_api::request::TokenAccountsFilter,
+    put_sdk::{
+        pubkey::Pubkey,
+        signature::{Signature, Signer},
+    },
+    put_transaction_status::parse_nft::ppl_nft_instruction,
+    std::{str::FromStr, sync::Arc},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NftAuthorityType {
+    MintTokens,
+    FreezeAccount,
+    CloseAccount,
+}
+
+impl From<NftAuthorityType> for AuthorityType {
+    fn from(authority_type: NftAuthorityType) -> Self {
+        match authority_type {
+            NftAuthorityType::MintTokens => AuthorityType::MintTokens,
+            NftAuthorityType::FreezeAccount => AuthorityType::FreezeAccount,
+            NftAuthorityType::CloseAccount => AuthorityType::CloseAccount,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NftCliCommand {
+    CreateCollection {
+        mint: SignerIndex,
+        name: String,
+        symbol: String,
+        icon_uri: String,
+        total_supply: u64,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        transaction_config: TransactionConfig,
+    },
+    Mint {
+        mint_pubkey: Pubkey,
+        nft: SignerIndex,
+        owner_pubkey: Pubkey,
+        mint_authority: SignerIndex,
+        uri: String,
+        transaction_config: TransactionConfig,
+    },
+    Transfer {
+        nft_pubkey: Pubkey,
+        recipient_pubkey: Pubkey,
+        owner: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    Burn {
+        nft_pubkey: Pubkey,
+        owner: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    Freeze {
+        nft_pubkey: Pubkey,
+        mint_pubkey: Pubkey,
+        freeze_authority: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    Thaw {
+        nft_pubkey: Pubkey,
+        mint_pubkey: Pubkey,
+        freeze_authority: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    SetAuthority {
+        account_pubkey: Pubkey,
+        authority_type: NftAuthorityType,
+        new_authority: Option<Pubkey>,
+        authority: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    Show {
+        pubkey: Pubkey,
+    },
+    List {
+        owner_pubkey: Pubkey,
+        mint_pubkey: Option<Pubkey>,
+    },
+}
+
+pub trait NftSubCommands {
+    fn nft_subcommands(self) -> Self;
+}
+
+impl NftSubCommands for App<'_, '_> {
+    fn nft_subcommands(self) -> Self {
+        self.subcommand(
+            SubCommand::with_name("nft")
+                .about("NFT collection and token commands")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("create-collection")
+                        .about("Create a new NFT collection")
+                        .arg(
+                            Arg::with_name("mint")
+                                .index(1)
+                                .value_name("COLLECTION_KEYPAIR")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_valid_signer)
+                                .help("Keypair of the new collection account"),
+                        )
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .value_name("NAME")
+                                .takes_value(true)
+                                .required(true)
+                                .help("Collection name"),
+                        )
+                        .arg(
+                            Arg::with_name("symbol")
+                                .long("symbol")
+                                .value_name("SYMBOL")
+                                .takes_value(true)
+                                .required(true)
+                                .help("Collection symbol"),
+                        )
+                        .arg(
+                            Arg::with_name("icon_uri")
+                                .long("icon-uri")
+                                .value_name("URI")
+                                .takes_value(true)
+                                .default_value("")
+                                .help("Collection icon URI"),
+                        )
+                        .arg(
+                            Arg::with_name("total_supply")
+                                .long("total-supply")
+                                .value_name("NUMBER")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_parsable::<u64>)
+                                .help("Maximum number of NFTs that can be minted from the collection"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("mint_authority")
+                                .long("mint-authority")
+                                .value_name("MINT_AUTHORITY_ADDRESS"),
+                                "Authority allowed to mint NFTs of the collection [default: the default configured keypair]. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("freeze_authority")
+                                .long("freeze-authority")
+                                .value_name("FREEZE_AUTHORITY_ADDRESS"),
+                                "Authority allowed to freeze NFTs of the collection [default: none]. "),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("mint")
+                        .about("Mint a new NFT from a collection")
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .index(1)
+                                .value_name("COLLECTION_ADDRESS")
+                                .required(true),
+                                "Collection to mint from. "),
+                        )
+                        .arg(
+                            Arg::with_name("nft")
+                                .index(2)
+                                .value_name("NFT_KEYPAIR")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_valid_signer)
+                                .help("Keypair of the new NFT account"),
+                        )
+                        .arg(
+                            Arg::with_name("uri")
+                                .long("uri")
+                                .value_name("URI")
+                                .takes_value(true)
+                                .required(true)
+                                .help("Token URI of the new NFT"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("OWNER_ADDRESS"),
+                                "Owner of the new NFT [default: the default configured keypair]. "),
+                        )
+                        .arg(
+                            Arg::with_name("mint_authority")
+                                .long("mint-authority")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Collection mint authority [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("transfer")
+                        .about("Transfer an NFT to another owner")
+                        .arg(
+                            pubkey!(Arg::with_name("nft")
+                                .index(1)
+                                .value_name("NFT_ADDRESS")
+                                .required(true),
+                                "NFT to transfer. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("recipient")
+                                .index(2)
+                                .value_name("RECIPIENT_ADDRESS")
+                                .required(true),
+                                "New owner of the NFT. "),
+                        )
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Current owner of the NFT [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("show")
+                        .about("Display information about an NFT or an NFT collection")
+                        .arg(
+                            pubkey!(Arg::with_name("address")
+                                .index(1)
+                                .value_name("ADDRESS")
+                                .required(true),
+                                "NFT or collection to show. "),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("list")
+                        .about("List the NFTs held by an owner")
+                        .arg(
+                            pubkey!(Arg::with_name("owner")
+                                .index(1)
+                                .value_name("OWNER_ADDRESS"),
+                                "Owner of the NFTs [default: the default configured keypair]. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .long("collection")
+                                .value_name("COLLECTION_ADDRESS"),
+                                "Only list NFTs of this collection. "),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("burn")
+                        .about("Burn an NFT")
+                        .arg(
+                            pubkey!(Arg::with_name("nft")
+                                .index(1)
+                                .value_name("NFT_ADDRESS")
+                                .required(true),
+                                "NFT to burn. "),
+                        )
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Owner of the NFT [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("freeze")
+                        .about("Freeze an NFT, preventing it from being transferred")
+                        .arg(
+                            pubkey!(Arg::with_name("nft")
+                                .index(1)
+                                .value_name("NFT_ADDRESS")
+                                .required(true),
+                                "NFT to freeze. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .index(2)
+                                .value_name("COLLECTION_ADDRESS")
+                                .required(true),
+                                "Collection of the NFT. "),
+                        )
+                        .arg(
+                            Arg::with_name("freeze_authority")
+                                .long("freeze-authority")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Collection freeze authority [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("thaw")
+                        .about("Thaw a frozen NFT")
+                        .arg(
+                            pubkey!(Arg::with_name("nft")
+                                .index(1)
+                                .value_name("NFT_ADDRESS")
+                                .required(true),
+                                "NFT to thaw. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .index(2)
+                                .value_name("COLLECTION_ADDRESS")
+                                .required(true),
+                                "Collection of the NFT. "),
+                        )
+                        .arg(
+                            Arg::with_name("freeze_authority")
+                                .long("freeze-authority")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Collection freeze authority [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("set-authority")
+                        .about("Change or remove an authority of an NFT or an NFT collection")
+                        .arg(
+                            pubkey!(Arg::with_name("account")
+                                .index(1)
+                                .value_name("ADDRESS")
+                                .required(true),
+                                "NFT or collection whose authority is changed. "),
+                        )
+                        .arg(
+                            Arg::with_name("authority_type")
+                                .index(2)
+                                .value_name("AUTHORITY_TYPE")
+                                .takes_value(true)
+                                .required(true)
+                                .possible_values(&["mint", "freeze", "close"])
+                                .help("The authority to change"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("new_authority")
+                                .index(3)
+                                .value_name("NEW_AUTHORITY_ADDRESS")
+                                .required_unless("disable"),
+                                "The new authority. "),
+                        )
+                        .arg(
+                            Arg::with_name("disable")
+                                .long("disable")
+                                .takes_value(false)
+                                .conflicts_with("new_authority")
+                                .help("Remove the authority; this cannot be undone"),
+                        )
+                        .arg(
+                            Arg::with_name("authority")
+                                .long("authority")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Current authority [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                ),
+        )
+    }
+}
+
+pub fn parse_nft_subcommand(
+    matches: &ArgMatches<'_>,
+    default_signer: &DefaultSigner,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<CliCommandInfo, CliError> {
+    let (subcommand, sub_matches) = matches.subcommand();
+
+    let response = match (subcommand, sub_matches) {
+        ("create-collection", Some(matches)) => {
+            let (mint, mint_pubkey) = signer_of(matches, "mint", wallet_manager)?;
+            let mint_authority = match pubkey_of_signer(matches, "mint_authority", wallet_manager)?
+            {
+                Some(mint_authority) => mint_authority,
+                None => default_signer
+                    .signer_from_path(matches, wallet_manager)?
+                    .pubkey(),
+            };
+            let freeze_authority = pubkey_of_signer(matches, "freeze_authority", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![mint])?;
+
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::CreateCollection {
+                    mint: signer_info.index_of(mint_pubkey).unwrap(),
+                    name: matches.value_of("name").unwrap().to_string(),
+                    symbol: matches.value_of("symbol").unwrap().to_string(),
+                    icon_uri: matches.value_of("icon_uri").unwrap().to_string(),
+                    total_supply: value_of(matches, "total_supply").unwrap(),
+                    mint_authority,
+                    freeze_authority,
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("mint", Some(matches)) => {
+            let mint_pubkey = pubkey_of_signer(matches, "mint", wallet_manager)?.unwrap();
+            let (nft, nft_pubkey) = signer_of(matches, "nft", wallet_manager)?;
+            let owner_pubkey = match pubkey_of_signer(matches, "owner", wallet_manager)? {
+                Some(owner_pubkey) => owner_pubkey,
+                None => default_signer
+                    .signer_from_path(matches, wallet_manager)?
+                    .pubkey(),
+            };
+            let (mint_authority, mint_authority_pubkey) =
+                signer_of(matches, "mint_authority", wallet_manager)?;
+
+            let (signer_info, transaction_config) = parse_transaction_signers(
+                matches,
+                default_signer,
+                wallet_manager,
+                vec![nft, mint_authority],
+            )?;
+
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::Mint {
+                    mint_pubkey,
+                    nft: signer_info.index_of(nft_pubkey).unwrap(),
+                    owner_pubkey,
+                    mint_authority: signer_info.index_of(mint_authority_pubkey).unwrap(),
+                    uri: matches.value_of("uri").unwrap().to_string(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("transfer", Some(matches)) => {
+            let nft_pubkey = pubkey_of_signer(matches, "nft", wallet_manager)?.unwrap();
+            let recipient_pubkey = pubkey_of_signer(matches, "recipient", wallet_manager)?.unwrap();
+            let (owner, owner_pubkey) = signer_of(matches, "owner", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![owner])?;
+
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::Transfer {
+                    nft_pubkey,
+                    recipient_pubkey,
+                    owner: signer_info.index_of(owner_pubkey).unwrap(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("burn", Some(matches)) => {
+            let nft_pubkey = pubkey_of_signer(matches, "nft", wallet_manager)?.unwrap();
+            let (owner, owner_pubkey) = signer_of(matches, "owner", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![owner])?;
+
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::Burn {
+                    nft_pubkey,
+                    owner: signer_info.index_of(owner_pubkey).unwrap(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        (freeze_or_thaw @ ("freeze" | "thaw"), Some(matches)) => {
+            let nft_pubkey = pubkey_of_signer(matches, "nft", wallet_manager)?.unwrap();
+            let mint_pubkey = pubkey_of_signer(matches, "mint", wallet_manager)?.unwrap();
+            let (freeze_authority, freeze_authority_pubkey) =
+                signer_of(matches, "freeze_authority", wallet_manager)?;
+
+            let (signer_info, transaction_config) = parse_transaction_signers(
+                matches,
+                default_signer,
+                wallet_manager,
+                vec![freeze_authority],
+            )?;
+            let freeze_authority = signer_info.index_of(freeze_authority_pubkey).unwrap();
+
+            let command = if freeze_or_thaw == "freeze" {
+                NftCliCommand::Freeze {
+                    nft_pubkey,
+                    mint_pubkey,
+                    freeze_authority,
+                    transaction_config,
+                }
+            } else {
+                NftCliCommand::Thaw {
+                    nft_pubkey,
+                    mint_pubkey,
+                    freeze_authority,
+                    transaction_config,
+                }
+            };
+            CliCommandInfo {
+                command: CliCommand::Nft(command),
+                signers: signer_info.signers,
+            }
+        }
+        ("set-authority", Some(matches)) => {
+            let account_pubkey = pubkey_of_signer(matches, "account", wallet_manager)?.unwrap();
+            let authority_type = match matches.value_of("authority_type").unwrap() {
+                "mint" => NftAuthorityType::MintTokens,
+                "freeze" => NftAuthorityType::FreezeAccount,
+                "close" => NftAuthorityType::CloseAccount,
+                _ => unreachable!(),
+            };
+            let new_authority = pubkey_of_signer(matches, "new_authority", wallet_manager)?;
+            let (authority, authority_pubkey) = signer_of(matches, "authority", wallet_manager)?;
+
+            let (signer_info, transaction_config) = parse_transaction_signers(
+                matches,
+                default_signer,
+                wallet_manager,
+                vec![authority],
+            )?;
+
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::SetAuthority {
+                    account_pubkey,
+                    authority_type,
+                    new_authority,
+                    authority: signer_info.index_of(authority_pubkey).unwrap(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("show", Some(matches)) => {
+            let pubkey = pubkey_of_signer(matches, "address", wallet_manager)?.unwrap();
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::Show { pubkey }),
+                signers: vec![],
+            }
+        }
+        ("list", Some(matches)) => {
+            let owner_pubkey = match pubkey_of_signer(matches, "owner", wallet_manager)? {
+                Some(owner_pubkey) => owner_pubkey,
+                None => default_signer
+                    .signer_from_path(matches, wallet_manager)?
+                    .pubkey(),
+            };
+            let mint_pubkey = pubkey_of_signer(matches, "mint", wallet_manager)?;
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::List {
+                    owner_pubkey,
+                    mint_pubkey,
+                }),
+                signers: vec![],
+            }
+        }
+        _ => unreachable!(),
+    };
+    Ok(response)
+}
+
+pub fn process_nft_subcommand(
+    rpc_client: Arc<RpcClient>,
+    config: &CliConfig,
+    subcommand: &NftCliCommand,
+) -> ProcessResult {
+    match subcommand {
+        NftCliCommand::CreateCollection {
+            mint,
+            name,
+            symbol,
+            icon_uri,
+            total_supply,
+            mint_authority,
+            freeze_authority,
+            transaction_config,
+        } => {
+            let mint_pubkey = config.signers[*mint].pubkey();
+            let payer_pubkey = config.signers[transaction_config.fee_payer].pubkey();
+            let args = InitializeMintArgs {
+                total_supply: *total_supply,
+                mint_authority: ppl_nft_pubkey(mint_authority),
+                freeze_authority: freeze_authority.as_ref().map(ppl_nft_pubkey),
+                name: name.clone(),
+                symbol: symbol.clone(),
+                icon_uri: icon_uri.clone(),
+            };
+            let ix = nft_instruction::initialize_mint(
+                &ppl_nft::id(),
+                &ppl_nft_pubkey(&mint_pubkey),
+                &ppl_nft_pubkey(&payer_pubkey),
+                args,
+            )?;
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ppl_nft_instruction(ix)],
+                transaction_config,
+                |signature| {
+                    config.output_format.formatted_string(&CliNftCreated {
+                        address: mint_pubkey.to_string(),
+                        signature: signature.to_string(),
+                    })
+                },
+            )
+        }
+        NftCliCommand::Mint {
+            mint_pubkey,
+            nft,
+            owner_pubkey,
+            mint_authority,
+            uri,
+            transaction_config,
+        } => {
+            let nft_pubkey = config.signers[*nft].pubkey();
+            let mint_authority_pubkey = config.signers[*mint_authority].pubkey();
+            let ix = nft_instruction::mint_to(
+                &ppl_nft::id(),
+                &ppl_nft_pubkey(&nft_pubkey),
+                &ppl_nft_pubkey(mint_pubkey),
+                &ppl_nft_pubkey(owner_pubkey),
+                &ppl_nft_pubkey(&mint_authority_pubkey),
+                uri.clone(),
+            )?;
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ppl_nft_instruction(ix)],
+                transaction_config,
+                |signature| {
+                    config.output_format.formatted_string(&CliNftCreated {
+                        address: nft_pubkey.to_string(),
+                        signature: signature.to_string(),
+                    })
+                },
+            )
+        }
+        NftCliCommand::Transfer {
+            nft_pubkey,
+            recipient_pubkey,
+            owner,
+            transaction_config,
+        } => {
+            let owner_pubkey = config.signers[*owner].pubkey();
+            let ix = nft_instruction::transfer(
+                &ppl_nft::id(),
+                &ppl_nft_pubkey(&owner_pubkey),
+                &ppl_nft_pubkey(recipient_pubkey),
+                &ppl_nft_pubkey(nft_pubkey),
+            )?;
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ppl_nft_instruction(ix)],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        NftCliCommand::Burn {
+            nft_pubkey,
+            owner,
+            transaction_config,
+        } => {
+            let owner_pubkey = config.signers[*owner].pubkey();
+            let ix = nft_instruction::burn(
+                &ppl_nft::id(),
+                &ppl_nft_pubkey(nft_pubkey),
+                &ppl_nft_pubkey(&owner_pubkey),
+            )?;
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ppl_nft_instruction(ix)],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        NftCliCommand::Freeze {
+            nft_pubkey,
+            mint_pubkey,
+            freeze_authority,
+            transaction_config,
+        } => {
+            let freeze_authority_pubkey = config.signers[*freeze_authority].pubkey();
+            let ix = nft_instruction::freeze(
+                &ppl_nft::id(),
+                &ppl_nft_pubkey(nft_pubkey),
+                &ppl_nft_pubkey(&freeze_authority_pubkey),
+                &ppl_nft_pubkey(mint_pubkey),
+            )?;
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ppl_nft_instruction(ix)],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        NftCliCommand::Thaw {
+            nft_pubkey,
+            mint_pubkey,
+            freeze_authority,
+            transaction_config,
+        } => {
+            let freeze_authority_pubkey = config.signers[*freeze_authority].pubkey();
+            let ix = nft_instruction::thaw(
+                &ppl_nft::id(),
+                &ppl_nft_pubkey(nft_pubkey),
+                &ppl_nft_pubkey(&freeze_authority_pubkey),
+                &ppl_nft_pubkey(mint_pubkey),
+            )?;
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ppl_nft_instruction(ix)],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        NftCliCommand::SetAuthority {
+            account_pubkey,
+            authority_type,
+            new_authority,
+            authority,
+            transaction_config,
+        } => {
+            let authority_pubkey = config.signers[*authority].pubkey();
+            let new_authority = new_authority.as_ref().map(ppl_nft_pubkey);
+            let ix = nft_instruction::set_authority(
+                &ppl_nft::id(),
+                &ppl_nft_pubkey(account_pubkey),
+                new_authority.as_ref(),
+                (*authority_type).into(),
+                &ppl_nft_pubkey(&authority_pubkey),
+            )?;
+            process_transaction(
+                &rpc_client,
+                config,
+                vec![ppl_nft_instruction(ix)],
+                transaction_config,
+                |signature| format_signature(config, signature),
+            )
+        }
+        NftCliCommand::Show { pubkey } => process_show_nft(&rpc_client, config, pubkey),
+        NftCliCommand::List {
+            owner_pubkey,
+            mint_pubkey,
+        } => process_list_nfts(&rpc_client, config, owner_pubkey, mint_pubkey.as_ref()),
+    }
+}
+
+fn format_signature(config: &CliConfig, signature: Signature) -> String {
+    config.output_format.formatted_string(&CliSignature {
+        signature: signature.to_string(),
+    })
+}
+
+fn cli_nft(address: &Pubkey, account: UiNFTAccount) -> CliNft {
+    CliNft {
+        address: address.to_string(),
+        mint: account.mint,
+        owner: account.owner,
+        token_id: account.token_id,
+        token_uri: account.token_uri,
+        state: format!("{:?}", account.state),
+        close_authority: account.close_authority,
+    }
+}
+
+fn process_show_nft(rpc_client: &RpcClient, config: &CliConfig, pubkey: &Pubkey) -> ProcessResult {
+    let account = rpc_client
+        .get_account_with_commitment(pubkey, config.commitment)?
+        .value
+        .ok_or_else(|| CliError::RpcRequestError(format!("{pubkey} account does not exist")))?;
+    if account.owner != nft_program_id() {
+        return Err(
+            CliError::RpcRequestError(format!("{pubkey} is not owned by the NFT program")).into(),
+        );
+    }
+
+    match parse_nft(&account.data)? {
+        TokenAccountType::Account(nft) => {
+            Ok(config.output_format.formatted_string(&cli_nft(pubkey, nft)))
+        }
+        TokenAccountType::Mint(mint) => {
+            Ok(config.output_format.formatted_string(&CliNftCollection {
+                address: pubkey.to_string(),
+                name: mint.name,
+                symbol: mint.symbol,
+                icon_uri: mint.icon_uri,
+                mint_authority: mint.mint_authority,
+                freeze_authority: mint.freeze_authority,
+                total_supply: mint.total_supply,
+                supply: mint.supply,
+            }))
+        }
+    }
+}
+
+fn process_list_nfts(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    owner_pubkey: &Pubkey,
+    mint_pubkey: Option<&Pubkey>,
+) -> ProcessResult {
+    let filter = match mint_pubkey {
+        Some(mint_pubkey) => TokenAccountsFilter::Mint(*mint_pubkey),
+        None => TokenAccountsFilter::ProgramId(nft_program_id()),
+    };
+    let keyed_accounts = rpc_client.get_nft_accounts_by_owner(owner_pubkey, filter)?;
+
+    let mut nfts = vec![];
+    for keyed_account in keyed_accounts {
+        let address = Pubkey::from_str(&keyed_account.pubkey)?;
+        let nft = match &keyed_account.account.data {
+            UiAccountData::Json(parsed_account) => {
+                serde_json::from_value::<TokenAccountType>(parsed_account.parsed.clone()).ok()
+            }
+            _ => keyed_account
+                .account
+                .decode::<put_sdk::account::Account>()
+                .and_then(|account| parse_nft(&account.data).ok()),
+        };
+        if let Some(TokenAccountType::Account(nft)) = nft {
+            nfts.push(cli_nft(&address, nft));
+        }
+    }
+    nfts.sort_by(|a, b| a.mint.cmp(&b.mint).then(a.token_id.cmp(&b.token_id)));
+
+    Ok(config.output_format.formatted_string(&CliNfts {
+        owner: owner_pubkey.to_string(),
+        nfts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{clap_app::get_clap_app, cli::parse_command},
+        put_rpc_client_nonce_utils::blockhash_query::BlockhashQuery,
+        put_sdk::{
+            hash::Hash,
+            signature::{read_keypair_file, write_keypair, Keypair},
+        },
+        tempfile::NamedTempFile,
+    };
+
+    fn make_tmp_file() -> (String, NamedTempFile) {
+        let tmp_file = NamedTempFile::new().unwrap();
+        (String::from(tmp_file.path().to_str().unwrap()), tmp_file)
+    }
+
+    #[test]
+    fn test_parse_nft_subcommand() {
+        let test_commands = get_clap_app("test", "desc", "version");
+
+        let default_keypair = Keypair::new();
+        let (default_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&default_keypair, tmp_file.as_file_mut()).unwrap();
+        let default_signer = DefaultSigner::new("", &default_keypair_file);
+
+        let mint_keypair = Keypair::new();
+        let (mint_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&mint_keypair, tmp_file.as_file_mut()).unwrap();
+
+        let nft_keypair = Keypair::new();
+        let (nft_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&nft_keypair, tmp_file.as_file_mut()).unwrap();
+
+        let default_transaction_config = || TransactionConfig {
+            sign_only: false,
+            dump_transaction_message: false,
+            blockhash_query: BlockhashQuery::default(),
+            nonce_account: None,
+            nonce_authority: 0,
+            memo: None,
+            fee_payer: 0,
+            compute_unit_price: None,
+        };
+
+        // Test CreateCollection Subcommand
+        let freeze_authority = Pubkey::new_unique();
+        let test_create_collection = test_commands.clone().get_matches_from(vec![
+            "test",
+            "nft",
+            "create-collection",
+            &mint_keypair_file,
+            "--name",
+            "Test",
+            "--symbol",
+            "TST",
+            "--total-supply",
+            "100",
+            "--freeze-authority",
+            &freeze_authority.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_create_collection, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::CreateCollection {
+                    mint: 1,
+                    name: "Test".to_string(),
+                    symbol: "TST".to_string(),
+                    icon_uri: "".to_string(),
+                    total_supply: 100,
+                    mint_authority: default_keypair.pubkey(),
+                    freeze_authority: Some(freeze_authority),
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&mint_keypair_file).unwrap().into(),
+                ],
+            }
+        );
+
+        // Test Mint Subcommand
+        let mint_pubkey = mint_keypair.pubkey();
+        let owner = Pubkey::new_unique();
+        let test_mint = test_commands.clone().get_matches_from(vec![
+            "test",
+            "nft",
+            "mint",
+            &mint_pubkey.to_string(),
+            &nft_keypair_file,
+            "--uri",
+            "https://example.com/1.json",
+            "--owner",
+            &owner.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_mint, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::Mint {
+                    mint_pubkey,
+                    nft: 1,
+                    owner_pubkey: owner,
+                    mint_authority: 0,
+                    uri: "https://example.com/1.json".to_string(),
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&nft_keypair_file).unwrap().into(),
+                ],
+            }
+        );
+
+        // Test Transfer Subcommand, signing offline
+        let nft_pubkey = nft_keypair.pubkey();
+        let recipient = Pubkey::new_unique();
+        let blockhash = Hash::new_unique();
+        let test_transfer = test_commands.clone().get_matches_from(vec![
+            "test",
+            "nft",
+            "transfer",
+            &nft_pubkey.to_string(),
+            &recipient.to_string(),
+            "--sign-only",
+            "--blockhash",
+            &blockhash.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_transfer, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::Transfer {
+                    nft_pubkey,
+                    recipient_pubkey: recipient,
+                    owner: 0,
+                    transaction_config: TransactionConfig {
+                        sign_only: true,
+                        blockhash_query: BlockhashQuery::None(blockhash),
+                        ..default_transaction_config()
+                    },
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+
+        // Test Freeze Subcommand
+        let test_freeze = test_commands.clone().get_matches_from(vec![
+            "test",
+            "nft",
+            "freeze",
+            &nft_pubkey.to_string(),
+            &mint_pubkey.to_string(),
+            "--freeze-authority",
+            &mint_keypair_file,
+        ]);
+        assert_eq!(
+            parse_command(&test_freeze, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::Freeze {
+                    nft_pubkey,
+                    mint_pubkey,
+                    freeze_authority: 1,
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&mint_keypair_file).unwrap().into(),
+                ],
+            }
+        );
+
+        // Test SetAuthority Subcommand
+        let test_set_authority = test_commands.clone().get_matches_from(vec![
+            "test",
+            "nft",
+            "set-authority",
+            &mint_pubkey.to_string(),
+            "freeze",
+            "--disable",
+        ]);
+        assert_eq!(
+            parse_command(&test_set_authority, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::SetAuthority {
+                    account_pubkey: mint_pubkey,
+                    authority_type: NftAuthorityType::FreezeAccount,
+                    new_authority: None,
+                    authority: 0,
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+        let test_set_authority = test_commands.clone().get_matches_from_safe(vec![
+            "test",
+            "nft",
+            "set-authority",
+            &mint_pubkey.to_string(),
+            "freeze",
+        ]);
+        assert!(test_set_authority.is_err());
+
+        // Test List Subcommand
+        let test_list = test_commands
+            .clone()
+            .get_matches_from(vec!["test", "nft", "list"]);
+        assert_eq!(
+            parse_command(&test_list, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Nft(NftCliCommand::List {
+                    owner_pubkey: default_keypair.pubkey(),
+                    mint_pubkey: None,
+                }),
+                signers: vec![],
+            }
+        );
+    }
+}
@@ -0,0 +1,1342 @@
+use {
+    crate::{
+        cli::{CliCommand, CliCommandInfo, CliConfig, CliError, ProcessResult},
+        transaction::{parse_transaction_signers, process_transaction, TransactionConfig},
+    },
+    clap::{App, AppSettings, Arg, ArgMatches, SubCommand},
+    ppl_associated_token_account::{create_associated_token_account, get_associated_token_address},
+    ppl_token::{
+        instruction as token_instruction,
+        put_program::program_pack::Pack,
+        state::{Account as PplTokenAccount, Mint},
+    },
+    put_account_decoder::{
+        parse_token::{
+            ppl_token_id, ppl_token_pubkey, pubkey_from_ppl_token, TokenAccountType, UiTokenAccount,
+        },
+        UiAccountData,
+    },
+    put_clap_utils::{
+        compute_unit_price::compute_unit_price_arg,
+        fee_payer::fee_payer_arg,
+        input_parsers::*,
+        input_validators::*,
+        keypair::{DefaultSigner, SignerIndex},
+        memo::memo_arg,
+        nonce::*,
+        offline::*,
+    },
+    put_cli_output::{CliSignature, CliTokenAccounts, CliTokenBalance, CliTokenCreated},
+    put_remote_wallet::remote_wallet::RemoteWalletManager,
+    put_rpc_client::rpc_client::RpcClient,
+    put_rpc_client_api::request::TokenAccountsFilter,
+    put_sdk::{
+        native_token::parse_amount,
+        pubkey::Pubkey,
+        signature::{Signature, Signer},
+        system_instruction,
+        wrapped_put::{create_wrapped_put_account, unwrap_put, wrap_put, wrapped_put_address},
+    },
+    put_transaction_status::parse_token::ppl_token_instruction,
+    std::{str::FromStr, sync::Arc},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TokenCliCommand {
+    CreateMint {
+        mint: SignerIndex,
+        decimals: u8,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        transaction_config: TransactionConfig,
+    },
+    CreateAccount {
+        mint_pubkey: Pubkey,
+        owner_pubkey: Pubkey,
+        account: Option<SignerIndex>,
+        transaction_config: TransactionConfig,
+    },
+    Mint {
+        mint_pubkey: Pubkey,
+        amount: String,
+        recipient_pubkey: Pubkey,
+        mint_authority: SignerIndex,
+        mint_decimals: Option<u8>,
+        transaction_config: TransactionConfig,
+    },
+    Transfer {
+        mint_pubkey: Pubkey,
+        amount: Option<String>,
+        recipient_pubkey: Pubkey,
+        from_pubkey: Option<Pubkey>,
+        owner: SignerIndex,
+        fund_recipient: bool,
+        mint_decimals: Option<u8>,
+        transaction_config: TransactionConfig,
+    },
+    Balance {
+        token_account_pubkey: Pubkey,
+    },
+    Accounts {
+        owner_pubkey: Pubkey,
+        mint_pubkey: Option<Pubkey>,
+    },
+    Wrap {
+        lamports: u128,
+        owner: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    Unwrap {
+        owner: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+    Close {
+        account_pubkey: Pubkey,
+        recipient_pubkey: Option<Pubkey>,
+        owner: SignerIndex,
+        transaction_config: TransactionConfig,
+    },
+}
+
+pub trait TokenSubCommands {
+    fn token_subcommands(self) -> Self;
+}
+
+impl TokenSubCommands for App<'_, '_> {
+    fn token_subcommands(self) -> Self {
+        self.subcommand(
+            SubCommand::with_name("token")
+                .about("PPL token commands")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("create-mint")
+                        .about("Create a new token mint")
+                        .arg(
+                            Arg::with_name("mint")
+                                .index(1)
+                                .value_name("MINT_KEYPAIR")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_valid_signer)
+                                .help("Keypair of the new mint account"),
+                        )
+                        .arg(
+                            Arg::with_name("decimals")
+                                .long("decimals")
+                                .value_name("DECIMALS")
+                                .takes_value(true)
+                                .default_value("9")
+                                .validator(is_parsable::<u8>)
+                                .help("Number of base 10 digits to the right of the decimal place"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("mint_authority")
+                                .long("mint-authority")
+                                .value_name("MINT_AUTHORITY_ADDRESS"),
+                                "Authority allowed to mint new tokens [default: the default configured keypair]. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("freeze_authority")
+                                .long("freeze-authority")
+                                .value_name("FREEZE_AUTHORITY_ADDRESS"),
+                                "Authority allowed to freeze token accounts of the mint [default: none]. "),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("create-account")
+                        .about("Create a token account, the associated token account of the owner by default")
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .index(1)
+                                .value_name("MINT_ADDRESS")
+                                .required(true),
+                                "Mint of the new token account. "),
+                        )
+                        .arg(
+                            Arg::with_name("account")
+                                .index(2)
+                                .value_name("ACCOUNT_KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Keypair of an auxiliary token account to create instead of the associated token account"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("OWNER_ADDRESS"),
+                                "Owner of the new token account [default: the default configured keypair]. "),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("mint")
+                        .about("Mint new tokens")
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .index(1)
+                                .value_name("MINT_ADDRESS")
+                                .required(true),
+                                "Mint of the tokens. "),
+                        )
+                        .arg(
+                            Arg::with_name("amount")
+                                .index(2)
+                                .value_name("AMOUNT")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_amount)
+                                .help("Amount of tokens to mint"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("recipient")
+                                .index(3)
+                                .value_name("RECIPIENT_TOKEN_ACCOUNT_ADDRESS"),
+                                "Token account receiving the new tokens [default: the associated token account of the default configured keypair]. "),
+                        )
+                        .arg(
+                            Arg::with_name("mint_authority")
+                                .long("mint-authority")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Mint authority [default: the default configured keypair]"),
+                        )
+                        .arg(mint_decimals_arg())
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("transfer")
+                        .about("Transfer tokens to the associated token account of a wallet")
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .index(1)
+                                .value_name("MINT_ADDRESS")
+                                .required(true),
+                                "Mint of the tokens. "),
+                        )
+                        .arg(
+                            Arg::with_name("amount")
+                                .index(2)
+                                .value_name("AMOUNT")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_amount_or_all)
+                                .help("Amount of tokens to send, or ALL to send the whole balance"),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("recipient")
+                                .index(3)
+                                .value_name("RECIPIENT_ADDRESS")
+                                .required(true),
+                                "Wallet or token account receiving the tokens. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("from")
+                                .long("from")
+                                .value_name("SOURCE_TOKEN_ACCOUNT_ADDRESS"),
+                                "Token account to send from [default: the associated token account of the owner]. "),
+                        )
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Owner of the source token account [default: the default configured keypair]"),
+                        )
+                        .arg(
+                            Arg::with_name("fund_recipient")
+                                .long("fund-recipient")
+                                .takes_value(false)
+                                .help("Create the associated token account of the recipient if it does not exist, paid for by the fee payer"),
+                        )
+                        .arg(mint_decimals_arg())
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("balance")
+                        .about("Get the token balance of an owner or a token account")
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .index(1)
+                                .value_name("MINT_ADDRESS")
+                                .required_unless("address"),
+                                "Mint of the tokens; the associated token account of the owner is queried. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("OWNER_ADDRESS")
+                                .conflicts_with("address"),
+                                "Owner of the associated token account [default: the default configured keypair]. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("address")
+                                .long("address")
+                                .value_name("TOKEN_ACCOUNT_ADDRESS")
+                                .conflicts_with("mint"),
+                                "Token account to query. "),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("accounts")
+                        .about("List the token accounts of an owner")
+                        .arg(
+                            pubkey!(Arg::with_name("owner")
+                                .index(1)
+                                .value_name("OWNER_ADDRESS"),
+                                "Owner of the token accounts [default: the default configured keypair]. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("mint")
+                                .long("mint")
+                                .value_name("MINT_ADDRESS"),
+                                "Only list token accounts of this mint. "),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("wrap")
+                        .about("Wrap PUT into the associated native mint token account of the owner")
+                        .arg(
+                            Arg::with_name("amount")
+                                .index(1)
+                                .value_name("AMOUNT")
+                                .takes_value(true)
+                                .required(true)
+                                .validator(is_amount)
+                                .help("Amount of PUT to wrap"),
+                        )
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Wallet funding the wrapped PUT [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("unwrap")
+                        .about("Close the associated native mint token account of the owner, returning its PUT")
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Owner of the wrapped PUT [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("close")
+                        .about("Close an empty token account")
+                        .arg(
+                            pubkey!(Arg::with_name("account")
+                                .index(1)
+                                .value_name("TOKEN_ACCOUNT_ADDRESS")
+                                .required(true),
+                                "Token account to close. "),
+                        )
+                        .arg(
+                            pubkey!(Arg::with_name("recipient")
+                                .long("recipient")
+                                .value_name("RECIPIENT_ADDRESS"),
+                                "Account receiving the remaining lamports [default: the owner]. "),
+                        )
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .value_name("KEYPAIR")
+                                .takes_value(true)
+                                .validator(is_valid_signer)
+                                .help("Owner or close authority of the token account [default: the default configured keypair]"),
+                        )
+                        .offline_args()
+                        .nonce_args(false)
+                        .arg(fee_payer_arg())
+                        .arg(memo_arg())
+                        .arg(compute_unit_price_arg()),
+                ),
+        )
+    }
+}
+
+fn mint_decimals_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("mint_decimals")
+        .long("mint-decimals")
+        .value_name("DECIMALS")
+        .takes_value(true)
+        .validator(is_parsable::<u8>)
+        .help("Decimals of the mint; required with --sign-only, fetched from the mint otherwise")
+}
+
+/// Address of the associated token account of `wallet` for `mint`
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    pubkey_from_ppl_token(&get_associated_token_address(
+        &ppl_token_pubkey(wallet),
+        &ppl_token_pubkey(mint),
+    ))
+}
+
+// Converts a decimal token amount into base units without going through a float, so that amounts
+// with many decimals are not rounded
+fn token_amount_from_ui_amount(amount: &str, decimals: u8) -> Result<u128, CliError> {
+    parse_amount(amount, decimals)
+        .map_err(|err| CliError::BadParameter(format!("Invalid token amount {amount}: {err}")))
+}
+
+fn owner_pubkey_of(
+    matches: &ArgMatches<'_>,
+    name: &str,
+    default_signer: &DefaultSigner,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<Pubkey, CliError> {
+    match pubkey_of_signer(matches, name, wallet_manager)? {
+        Some(pubkey) => Ok(pubkey),
+        None => Ok(default_signer
+            .signer_from_path(matches, wallet_manager)?
+            .pubkey()),
+    }
+}
+
+pub fn parse_token_subcommand(
+    matches: &ArgMatches<'_>,
+    default_signer: &DefaultSigner,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<CliCommandInfo, CliError> {
+    let (subcommand, sub_matches) = matches.subcommand();
+
+    let response = match (subcommand, sub_matches) {
+        ("create-mint", Some(matches)) => {
+            let (mint, mint_pubkey) = signer_of(matches, "mint", wallet_manager)?;
+            let mint_authority =
+                owner_pubkey_of(matches, "mint_authority", default_signer, wallet_manager)?;
+            let freeze_authority = pubkey_of_signer(matches, "freeze_authority", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![mint])?;
+
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::CreateMint {
+                    mint: signer_info.index_of(mint_pubkey).unwrap(),
+                    decimals: value_of(matches, "decimals").unwrap(),
+                    mint_authority,
+                    freeze_authority,
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("create-account", Some(matches)) => {
+            let mint_pubkey = pubkey_of_signer(matches, "mint", wallet_manager)?.unwrap();
+            let owner_pubkey = owner_pubkey_of(matches, "owner", default_signer, wallet_manager)?;
+            let (account, account_pubkey) = signer_of(matches, "account", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![account])?;
+
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::CreateAccount {
+                    mint_pubkey,
+                    owner_pubkey,
+                    account: signer_info.index_of_or_none(account_pubkey),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("mint", Some(matches)) => {
+            let mint_pubkey = pubkey_of_signer(matches, "mint", wallet_manager)?.unwrap();
+            let recipient_pubkey = match pubkey_of_signer(matches, "recipient", wallet_manager)? {
+                Some(recipient_pubkey) => recipient_pubkey,
+                None => associated_token_address(
+                    &default_signer
+                        .signer_from_path(matches, wallet_manager)?
+                        .pubkey(),
+                    &mint_pubkey,
+                ),
+            };
+            let (mint_authority, mint_authority_pubkey) =
+                signer_of(matches, "mint_authority", wallet_manager)?;
+
+            let (signer_info, transaction_config) = parse_transaction_signers(
+                matches,
+                default_signer,
+                wallet_manager,
+                vec![mint_authority],
+            )?;
+
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Mint {
+                    mint_pubkey,
+                    amount: matches.value_of("amount").unwrap().to_string(),
+                    recipient_pubkey,
+                    mint_authority: signer_info.index_of(mint_authority_pubkey).unwrap(),
+                    mint_decimals: value_of(matches, "mint_decimals"),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("transfer", Some(matches)) => {
+            let mint_pubkey = pubkey_of_signer(matches, "mint", wallet_manager)?.unwrap();
+            let amount = match matches.value_of("amount").unwrap() {
+                "ALL" => None,
+                amount => Some(amount.to_string()),
+            };
+            let recipient_pubkey = pubkey_of_signer(matches, "recipient", wallet_manager)?.unwrap();
+            let from_pubkey = pubkey_of_signer(matches, "from", wallet_manager)?;
+            let (owner, owner_pubkey) = signer_of(matches, "owner", wallet_manager)?;
+            let mint_decimals = value_of(matches, "mint_decimals");
+            if matches.is_present(SIGN_ONLY_ARG.name)
+                && (amount.is_none() || mint_decimals.is_none())
+            {
+                return Err(CliError::BadParameter(
+                    "--sign-only requires an explicit amount and --mint-decimals".to_string(),
+                ));
+            }
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![owner])?;
+
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Transfer {
+                    mint_pubkey,
+                    amount,
+                    recipient_pubkey,
+                    from_pubkey,
+                    owner: signer_info.index_of(owner_pubkey).unwrap(),
+                    fund_recipient: matches.is_present("fund_recipient"),
+                    mint_decimals,
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("balance", Some(matches)) => {
+            let token_account_pubkey = match pubkey_of_signer(matches, "address", wallet_manager)? {
+                Some(address) => address,
+                None => {
+                    let mint_pubkey = pubkey_of_signer(matches, "mint", wallet_manager)?.unwrap();
+                    let owner_pubkey =
+                        owner_pubkey_of(matches, "owner", default_signer, wallet_manager)?;
+                    associated_token_address(&owner_pubkey, &mint_pubkey)
+                }
+            };
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Balance {
+                    token_account_pubkey,
+                }),
+                signers: vec![],
+            }
+        }
+        ("accounts", Some(matches)) => {
+            let owner_pubkey = owner_pubkey_of(matches, "owner", default_signer, wallet_manager)?;
+            let mint_pubkey = pubkey_of_signer(matches, "mint", wallet_manager)?;
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Accounts {
+                    owner_pubkey,
+                    mint_pubkey,
+                }),
+                signers: vec![],
+            }
+        }
+        ("wrap", Some(matches)) => {
+            let lamports = lamports_of_put(matches, "amount").unwrap();
+            let (owner, owner_pubkey) = signer_of(matches, "owner", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![owner])?;
+
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Wrap {
+                    lamports,
+                    owner: signer_info.index_of(owner_pubkey).unwrap(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("unwrap", Some(matches)) => {
+            let (owner, owner_pubkey) = signer_of(matches, "owner", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![owner])?;
+
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Unwrap {
+                    owner: signer_info.index_of(owner_pubkey).unwrap(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        ("close", Some(matches)) => {
+            let account_pubkey = pubkey_of_signer(matches, "account", wallet_manager)?.unwrap();
+            let recipient_pubkey = pubkey_of_signer(matches, "recipient", wallet_manager)?;
+            let (owner, owner_pubkey) = signer_of(matches, "owner", wallet_manager)?;
+
+            let (signer_info, transaction_config) =
+                parse_transaction_signers(matches, default_signer, wallet_manager, vec![owner])?;
+
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Close {
+                    account_pubkey,
+                    recipient_pubkey,
+                    owner: signer_info.index_of(owner_pubkey).unwrap(),
+                    transaction_config,
+                }),
+                signers: signer_info.signers,
+            }
+        }
+        _ => unreachable!(),
+    };
+    Ok(response)
+}
+
+pub fn process_token_subcommand(
+    rpc_client: Arc<RpcClient>,
+    config: &CliConfig,
+    subcommand: &TokenCliCommand,
+) -> ProcessResult {
+    match subcommand {
+        TokenCliCommand::CreateMint {
+            mint,
+            decimals,
+            mint_authority,
+            freeze_authority,
+            transaction_config,
+        } => {
+            let mint_pubkey = config.signers[*mint].pubkey();
+            let payer_pubkey = config.signers[transaction_config.fee_payer].pubkey();
+            let lamports = rpc_client.get_minimum_balance_for_rent_exemption(Mint::LEN)?;
+            let freeze_authority = freeze_authority.as_ref().map(ppl_token_pubkey);
+            let ixs = vec![
+                system_instruction::create_account(
+                    &payer_pubkey,
+                    &mint_pubkey,
+                    lamports,
+                    Mint::LEN as u64,
+                    &ppl_token_id(),
+                ),
+                ppl_token_instruction(token_instruction::initialize_mint(
+                    &ppl_token::id(),
+                    &ppl_token_pubkey(&mint_pubkey),
+                    &ppl_token_pubkey(mint_authority),
+                    freeze_authority.as_ref(),
+                    *decimals,
+                )?),
+            ];
+            process_transaction(&rpc_client, config, ixs, transaction_config, |signature| {
+                format_created(config, &mint_pubkey, signature)
+            })
+        }
+        TokenCliCommand::CreateAccount {
+            mint_pubkey,
+            owner_pubkey,
+            account,
+            transaction_config,
+        } => {
+            let payer_pubkey = config.signers[transaction_config.fee_payer].pubkey();
+            let (account_pubkey, ixs) = match account {
+                Some(account) => {
+                    let account_pubkey = config.signers[*account].pubkey();
+                    let lamports =
+                        rpc_client.get_minimum_balance_for_rent_exemption(PplTokenAccount::LEN)?;
+                    let ixs = vec![
+                        system_instruction::create_account(
+                            &payer_pubkey,
+                            &account_pubkey,
+                            lamports,
+                            PplTokenAccount::LEN as u64,
+                            &ppl_token_id(),
+                        ),
+                        ppl_token_instruction(token_instruction::initialize_account(
+                            &ppl_token::id(),
+                            &ppl_token_pubkey(&account_pubkey),
+                            &ppl_token_pubkey(mint_pubkey),
+                            &ppl_token_pubkey(owner_pubkey),
+                        )?),
+                    ];
+                    (account_pubkey, ixs)
+                }
+                None => (
+                    associated_token_address(owner_pubkey, mint_pubkey),
+                    vec![ppl_token_instruction(create_associated_token_account(
+                        &ppl_token_pubkey(&payer_pubkey),
+                        &ppl_token_pubkey(owner_pubkey),
+                        &ppl_token_pubkey(mint_pubkey),
+                    ))],
+                ),
+            };
+            process_transaction(&rpc_client, config, ixs, transaction_config, |signature| {
+                format_created(config, &account_pubkey, signature)
+            })
+        }
+        TokenCliCommand::Mint {
+            mint_pubkey,
+            amount,
+            recipient_pubkey,
+            mint_authority,
+            mint_decimals,
+            transaction_config,
+        } => {
+            let decimals = resolve_mint_decimals(&rpc_client, config, mint_pubkey, *mint_decimals)?;
+            let amount = token_amount_from_ui_amount(amount, decimals)?;
+            let mint_authority_pubkey = config.signers[*mint_authority].pubkey();
+            let ixs = vec![ppl_token_instruction(token_instruction::mint_to_checked(
+                &ppl_token::id(),
+                &ppl_token_pubkey(mint_pubkey),
+                &ppl_token_pubkey(recipient_pubkey),
+                &ppl_token_pubkey(&mint_authority_pubkey),
+                &[],
+                amount,
+                decimals,
+            )?)];
+            process_transaction(&rpc_client, config, ixs, transaction_config, |signature| {
+                format_signature(config, signature)
+            })
+        }
+        TokenCliCommand::Transfer {
+            mint_pubkey,
+            amount,
+            recipient_pubkey,
+            from_pubkey,
+            owner,
+            fund_recipient,
+            mint_decimals,
+            transaction_config,
+        } => {
+            let owner_pubkey = config.signers[*owner].pubkey();
+            let payer_pubkey = config.signers[transaction_config.fee_payer].pubkey();
+            let from_pubkey =
+                from_pubkey.unwrap_or_else(|| associated_token_address(&owner_pubkey, mint_pubkey));
+            let decimals = resolve_mint_decimals(&rpc_client, config, mint_pubkey, *mint_decimals)?;
+            let amount = match amount {
+                Some(amount) => token_amount_from_ui_amount(amount, decimals)?,
+                None => get_token_account(&rpc_client, config, &from_pubkey)?.amount,
+            };
+            let (destination_pubkey, create_destination) = resolve_transfer_destination(
+                &rpc_client,
+                config,
+                recipient_pubkey,
+                mint_pubkey,
+                *fund_recipient,
+                transaction_config.sign_only,
+            )?;
+
+            let mut ixs = vec![];
+            if create_destination {
+                ixs.push(ppl_token_instruction(create_associated_token_account(
+                    &ppl_token_pubkey(&payer_pubkey),
+                    &ppl_token_pubkey(recipient_pubkey),
+                    &ppl_token_pubkey(mint_pubkey),
+                )));
+            }
+            ixs.push(ppl_token_instruction(token_instruction::transfer_checked(
+                &ppl_token::id(),
+                &ppl_token_pubkey(&from_pubkey),
+                &ppl_token_pubkey(mint_pubkey),
+                &ppl_token_pubkey(&destination_pubkey),
+                &ppl_token_pubkey(&owner_pubkey),
+                &[],
+                amount,
+                decimals,
+            )?));
+            process_transaction(&rpc_client, config, ixs, transaction_config, |signature| {
+                format_signature(config, signature)
+            })
+        }
+        TokenCliCommand::Balance {
+            token_account_pubkey,
+        } => process_token_balance(&rpc_client, config, token_account_pubkey),
+        TokenCliCommand::Accounts {
+            owner_pubkey,
+            mint_pubkey,
+        } => process_token_accounts(&rpc_client, config, owner_pubkey, mint_pubkey.as_ref()),
+        TokenCliCommand::Wrap {
+            lamports,
+            owner,
+            transaction_config,
+        } => {
+            let owner_pubkey = config.signers[*owner].pubkey();
+            let payer_pubkey = config.signers[transaction_config.fee_payer].pubkey();
+            let account_pubkey = wrapped_put_address(&owner_pubkey);
+
+            let mut ixs = vec![];
+            if !transaction_config.sign_only
+                && rpc_client
+                    .get_account_with_commitment(&account_pubkey, config.commitment)?
+                    .value
+                    .is_none()
+            {
+                ixs.push(create_wrapped_put_account(&payer_pubkey, &owner_pubkey));
+            }
+            ixs.extend(wrap_put(&owner_pubkey, *lamports)?);
+            process_transaction(&rpc_client, config, ixs, transaction_config, |signature| {
+                format_created(config, &account_pubkey, signature)
+            })
+        }
+        TokenCliCommand::Unwrap {
+            owner,
+            transaction_config,
+        } => {
+            let owner_pubkey = config.signers[*owner].pubkey();
+            let ixs = vec![unwrap_put(&owner_pubkey, &owner_pubkey)?];
+            process_transaction(&rpc_client, config, ixs, transaction_config, |signature| {
+                format_signature(config, signature)
+            })
+        }
+        TokenCliCommand::Close {
+            account_pubkey,
+            recipient_pubkey,
+            owner,
+            transaction_config,
+        } => {
+            let owner_pubkey = config.signers[*owner].pubkey();
+            let recipient_pubkey = recipient_pubkey.unwrap_or(owner_pubkey);
+            let ixs = vec![ppl_token_instruction(token_instruction::close_account(
+                &ppl_token::id(),
+                &ppl_token_pubkey(account_pubkey),
+                &ppl_token_pubkey(&recipient_pubkey),
+                &ppl_token_pubkey(&owner_pubkey),
+                &[],
+            )?)];
+            process_transaction(&rpc_client, config, ixs, transaction_config, |signature| {
+                format_signature(config, signature)
+            })
+        }
+    }
+}
+
+fn format_signature(config: &CliConfig, signature: Signature) -> String {
+    config.output_format.formatted_string(&CliSignature {
+        signature: signature.to_string(),
+    })
+}
+
+fn format_created(config: &CliConfig, address: &Pubkey, signature: Signature) -> String {
+    config.output_format.formatted_string(&CliTokenCreated {
+        address: address.to_string(),
+        signature: signature.to_string(),
+    })
+}
+
+fn get_token_account(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    pubkey: &Pubkey,
+) -> Result<PplTokenAccount, CliError> {
+    let account = rpc_client
+        .get_account_with_commitment(pubkey, config.commitment)?
+        .value
+        .ok_or_else(|| CliError::RpcRequestError(format!("{pubkey} account does not exist")))?;
+    PplTokenAccount::unpack(&account.data)
+        .map_err(|_| CliError::RpcRequestError(format!("{pubkey} is not a token account")))
+}
+
+// Returns the token account receiving a transfer, and whether its associated token account must
+// be created first. The recipient may be a token account of the mint itself, otherwise tokens go
+// to its associated token account. Offline signing can't check either account, so the associated
+// token account is created only when asked to.
+fn resolve_transfer_destination(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    recipient_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    fund_recipient: bool,
+    sign_only: bool,
+) -> Result<(Pubkey, bool), CliError> {
+    let associated_token_pubkey = associated_token_address(recipient_pubkey, mint_pubkey);
+    if sign_only {
+        return Ok((associated_token_pubkey, fund_recipient));
+    }
+
+    let recipient_account = rpc_client
+        .get_account_with_commitment(recipient_pubkey, config.commitment)?
+        .value;
+    if let Some(recipient_account) = recipient_account {
+        if recipient_account.owner == ppl_token_id() {
+            if let Ok(token_account) = PplTokenAccount::unpack(&recipient_account.data) {
+                let token_mint = pubkey_from_ppl_token(&token_account.mint);
+                if token_mint != *mint_pubkey {
+                    return Err(CliError::BadParameter(format!(
+                        "Recipient {recipient_pubkey} is a token account for mint {token_mint}, \
+                         not {mint_pubkey}"
+                    )));
+                }
+                return Ok((*recipient_pubkey, false));
+            }
+        }
+    }
+
+    let associated_token_account_exists = rpc_client
+        .get_account_with_commitment(&associated_token_pubkey, config.commitment)?
+        .value
+        .is_some();
+    if associated_token_account_exists {
+        Ok((associated_token_pubkey, false))
+    } else if fund_recipient {
+        Ok((associated_token_pubkey, true))
+    } else {
+        Err(CliError::BadParameter(format!(
+            "Recipient {recipient_pubkey} has no token account for mint {mint_pubkey}, \
+             add --fund-recipient to create it"
+        )))
+    }
+}
+
+fn resolve_mint_decimals(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    mint_pubkey: &Pubkey,
+    mint_decimals: Option<u8>,
+) -> Result<u8, CliError> {
+    if let Some(decimals) = mint_decimals {
+        return Ok(decimals);
+    }
+    let account = rpc_client
+        .get_account_with_commitment(mint_pubkey, config.commitment)?
+        .value
+        .ok_or_else(|| {
+            CliError::RpcRequestError(format!("{mint_pubkey} account does not exist"))
+        })?;
+    Mint::unpack(&account.data)
+        .map(|mint| mint.decimals)
+        .map_err(|_| CliError::RpcRequestError(format!("{mint_pubkey} is not a token mint")))
+}
+
+fn cli_token_balance(address: &Pubkey, account: UiTokenAccount) -> CliTokenBalance {
+    CliTokenBalance {
+        address: address.to_string(),
+        mint: account.mint,
+        owner: account.owner,
+        token_amount: account.token_amount,
+        is_native: account.is_native,
+    }
+}
+
+fn process_token_balance(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    token_account_pubkey: &Pubkey,
+) -> ProcessResult {
+    let account = rpc_client
+        .get_token_account_with_commitment(token_account_pubkey, config.commitment)?
+        .value
+        .ok_or_else(|| {
+            CliError::RpcRequestError(format!(
+                "{token_account_pubkey} is not an initialized token account"
+            ))
+        })?;
+    Ok(config
+        .output_format
+        .formatted_string(&cli_token_balance(token_account_pubkey, account)))
+}
+
+fn process_token_accounts(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    owner_pubkey: &Pubkey,
+    mint_pubkey: Option<&Pubkey>,
+) -> ProcessResult {
+    let filter = match mint_pubkey {
+        Some(mint_pubkey) => TokenAccountsFilter::Mint(*mint_pubkey),
+        None => TokenAccountsFilter::ProgramId(ppl_token_id()),
+    };
+    let keyed_accounts = rpc_client
+        .get_token_accounts_by_owner_with_commitment(owner_pubkey, filter, config.commitment)?
+        .value;
+
+    let mut accounts = vec![];
+    for keyed_account in keyed_accounts {
+        let address = Pubkey::from_str(&keyed_account.pubkey)?;
+        if let UiAccountData::Json(parsed_account) = &keyed_account.account.data {
+            if let Ok(TokenAccountType::Account(account)) =
+                serde_json::from_value::<TokenAccountType>(parsed_account.parsed.clone())
+            {
+                accounts.push(cli_token_balance(&address, account));
+            }
+        }
+    }
+    accounts.sort_by(|a, b| a.mint.cmp(&b.mint).then(a.address.cmp(&b.address)));
+
+    Ok(config.output_format.formatted_string(&CliTokenAccounts {
+        owner: owner_pubkey.to_string(),
+        accounts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{clap_app::get_clap_app, cli::parse_command},
+        ppl_token::state::AccountState,
+        put_account_decoder::{UiAccount, UiAccountEncoding},
+        put_rpc_client_api::{
+            request::RpcRequest,
+            response::{Response, RpcResponseContext},
+        },
+        put_rpc_client_nonce_utils::blockhash_query::BlockhashQuery,
+        put_sdk::{
+            account::Account,
+            hash::Hash,
+            signature::{read_keypair_file, write_keypair, Keypair},
+        },
+        serde_json::json,
+        std::collections::HashMap,
+        tempfile::NamedTempFile,
+    };
+
+    fn make_tmp_file() -> (String, NamedTempFile) {
+        let tmp_file = NamedTempFile::new().unwrap();
+        (String::from(tmp_file.path().to_str().unwrap()), tmp_file)
+    }
+
+    #[test]
+    fn test_token_amount_from_ui_amount() {
+        assert_eq!(token_amount_from_ui_amount("1", 9).unwrap(), 1_000_000_000);
+        assert_eq!(token_amount_from_ui_amount("1.5", 2).unwrap(), 150);
+        assert_eq!(token_amount_from_ui_amount(".25", 2).unwrap(), 25);
+        assert_eq!(token_amount_from_ui_amount("42", 0).unwrap(), 42);
+        assert_eq!(token_amount_from_ui_amount("0.000000001", 9).unwrap(), 1);
+        assert!(token_amount_from_ui_amount("1.234", 2).is_err());
+        assert!(token_amount_from_ui_amount("1.2x", 2).is_err());
+        assert!(token_amount_from_ui_amount("-1", 2).is_err());
+    }
+
+    fn rpc_client_with_account(pubkey: &Pubkey, account: Option<&Account>) -> RpcClient {
+        let account_info_response = json!(Response {
+            context: RpcResponseContext {
+                slot: 1,
+                api_version: None
+            },
+            value: account.map(|account| {
+                UiAccount::encode(pubkey, account, UiAccountEncoding::Base58, None, None)
+            }),
+        });
+        let mut mocks = HashMap::new();
+        mocks.insert(RpcRequest::GetAccountInfo, account_info_response);
+        RpcClient::new_mock_with_mocks("".to_string(), mocks)
+    }
+
+    #[test]
+    fn test_resolve_transfer_destination() {
+        let config = CliConfig::default();
+        let mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let associated_token = associated_token_address(&recipient, &mint);
+
+        // Offline signing only creates the associated token account when asked to
+        let rpc_client = rpc_client_with_account(&recipient, None);
+        for fund_recipient in [false, true] {
+            assert_eq!(
+                resolve_transfer_destination(
+                    &rpc_client,
+                    &config,
+                    &recipient,
+                    &mint,
+                    fund_recipient,
+                    true
+                )
+                .unwrap(),
+                (associated_token, fund_recipient)
+            );
+        }
+
+        // Missing associated token account
+        assert!(resolve_transfer_destination(
+            &rpc_client,
+            &config,
+            &recipient,
+            &mint,
+            false,
+            false
+        )
+        .is_err());
+        assert_eq!(
+            resolve_transfer_destination(&rpc_client, &config, &recipient, &mint, true, false)
+                .unwrap(),
+            (associated_token, true)
+        );
+
+        // Recipient is a token account of the mint
+        let mut data = vec![0; PplTokenAccount::LEN];
+        PplTokenAccount::pack(
+            PplTokenAccount {
+                mint: ppl_token_pubkey(&mint),
+                state: AccountState::Initialized,
+                ..PplTokenAccount::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        let token_account = Account {
+            lamports: 1,
+            data,
+            owner: ppl_token_id(),
+            ..Account::default()
+        };
+        let rpc_client = rpc_client_with_account(&recipient, Some(&token_account));
+        for fund_recipient in [false, true] {
+            assert_eq!(
+                resolve_transfer_destination(
+                    &rpc_client,
+                    &config,
+                    &recipient,
+                    &mint,
+                    fund_recipient,
+                    false
+                )
+                .unwrap(),
+                (recipient, false)
+            );
+        }
+        assert!(resolve_transfer_destination(
+            &rpc_client,
+            &config,
+            &recipient,
+            &Pubkey::new_unique(),
+            true,
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_token_subcommand() {
+        let test_commands = get_clap_app("test", "desc", "version");
+
+        let default_keypair = Keypair::new();
+        let (default_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&default_keypair, tmp_file.as_file_mut()).unwrap();
+        let default_signer = DefaultSigner::new("", &default_keypair_file);
+
+        let mint_keypair = Keypair::new();
+        let (mint_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&mint_keypair, tmp_file.as_file_mut()).unwrap();
+
+        let default_transaction_config = || TransactionConfig {
+            sign_only: false,
+            dump_transaction_message: false,
+            blockhash_query: BlockhashQuery::default(),
+            nonce_account: None,
+            nonce_authority: 0,
+            memo: None,
+            fee_payer: 0,
+            compute_unit_price: None,
+        };
+
+        // Test CreateMint Subcommand
+        let test_create_mint = test_commands.clone().get_matches_from(vec![
+            "test",
+            "token",
+            "create-mint",
+            &mint_keypair_file,
+            "--decimals",
+            "6",
+        ]);
+        assert_eq!(
+            parse_command(&test_create_mint, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::CreateMint {
+                    mint: 1,
+                    decimals: 6,
+                    mint_authority: default_keypair.pubkey(),
+                    freeze_authority: None,
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&mint_keypair_file).unwrap().into(),
+                ],
+            }
+        );
+
+        // Test CreateAccount Subcommand, defaulting to the associated token account
+        let mint_pubkey = mint_keypair.pubkey();
+        let test_create_account = test_commands.clone().get_matches_from(vec![
+            "test",
+            "token",
+            "create-account",
+            &mint_pubkey.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_create_account, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::CreateAccount {
+                    mint_pubkey,
+                    owner_pubkey: default_keypair.pubkey(),
+                    account: None,
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+
+        // Test Mint Subcommand
+        let test_mint = test_commands.clone().get_matches_from(vec![
+            "test",
+            "token",
+            "mint",
+            &mint_pubkey.to_string(),
+            "10.5",
+        ]);
+        assert_eq!(
+            parse_command(&test_mint, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Mint {
+                    mint_pubkey,
+                    amount: "10.5".to_string(),
+                    recipient_pubkey: associated_token_address(
+                        &default_keypair.pubkey(),
+                        &mint_pubkey
+                    ),
+                    mint_authority: 0,
+                    mint_decimals: None,
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+
+        // Test Transfer Subcommand, signing offline
+        let recipient = Pubkey::new_unique();
+        let blockhash = Hash::new_unique();
+        let test_transfer = test_commands.clone().get_matches_from(vec![
+            "test",
+            "token",
+            "transfer",
+            &mint_pubkey.to_string(),
+            "1",
+            &recipient.to_string(),
+            "--fund-recipient",
+            "--mint-decimals",
+            "6",
+            "--sign-only",
+            "--blockhash",
+            &blockhash.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_transfer, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Transfer {
+                    mint_pubkey,
+                    amount: Some("1".to_string()),
+                    recipient_pubkey: recipient,
+                    from_pubkey: None,
+                    owner: 0,
+                    fund_recipient: true,
+                    mint_decimals: Some(6),
+                    transaction_config: TransactionConfig {
+                        sign_only: true,
+                        blockhash_query: BlockhashQuery::None(blockhash),
+                        ..default_transaction_config()
+                    },
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+
+        // Test Transfer Subcommand, ALL cannot be signed offline
+        let test_transfer_all = test_commands.clone().get_matches_from(vec![
+            "test",
+            "token",
+            "transfer",
+            &mint_pubkey.to_string(),
+            "ALL",
+            &recipient.to_string(),
+            "--sign-only",
+            "--blockhash",
+            &blockhash.to_string(),
+        ]);
+        assert!(parse_command(&test_transfer_all, &default_signer, &mut None).is_err());
+
+        // Test Balance Subcommand
+        let owner = Pubkey::new_unique();
+        let test_balance = test_commands.clone().get_matches_from(vec![
+            "test",
+            "token",
+            "balance",
+            &mint_pubkey.to_string(),
+            "--owner",
+            &owner.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_balance, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Balance {
+                    token_account_pubkey: associated_token_address(&owner, &mint_pubkey),
+                }),
+                signers: vec![],
+            }
+        );
+
+        // Test Wrap Subcommand
+        let test_wrap = test_commands
+            .clone()
+            .get_matches_from(vec!["test", "token", "wrap", "1.5"]);
+        assert_eq!(
+            parse_command(&test_wrap, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Wrap {
+                    lamports: 1_500_000_000,
+                    owner: 0,
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+
+        // Test Close Subcommand
+        let account = Pubkey::new_unique();
+        let test_close = test_commands.clone().get_matches_from(vec![
+            "test",
+            "token",
+            "close",
+            &account.to_string(),
+            "--recipient",
+            &recipient.to_string(),
+        ]);
+        assert_eq!(
+            parse_command(&test_close, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::Token(TokenCliCommand::Close {
+                    account_pubkey: account,
+                    recipient_pubkey: Some(recipient),
+                    owner: 0,
+                    transaction_config: default_transaction_config(),
+                }),
+                signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
+            }
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use {
+    crate::{
+        checks::check_account_for_fee_with_commitment,
+        cli::{CliConfig, CliError, ProcessResult},
+        compute_unit_price::WithComputeUnitPrice,
+        memo::WithMemo,
+        nonce::check_nonce_account,
+    },
+    clap::ArgMatches,
+    put_clap_utils::{
+        compute_unit_price::COMPUTE_UNIT_PRICE_ARG,
+        fee_payer::FEE_PAYER_ARG,
+        input_parsers::*,
+        keypair::{CliSignerInfo, DefaultSigner, SignerIndex},
+        memo::MEMO_ARG,
+        nonce::*,
+        offline::*,
+    },
+    put_cli_output::{return_signers_with_config, ReturnSignersConfig},
+    put_remote_wallet::remote_wallet::RemoteWalletManager,
+    put_rpc_client::rpc_client::RpcClient,
+    put_rpc_client_nonce_utils::blockhash_query::BlockhashQuery,
+    put_sdk::{
+        instruction::Instruction,
+        message::Message,
+        pubkey::Pubkey,
+        signature::{Signature, Signer},
+        transaction::Transaction,
+    },
+    std::sync::Arc,
+};
+
+/// Offline signing, nonce and fee payer settings shared by the `put nft`, `put domain` and
+/// `put token` commands that submit a transaction
+#[derive(Debug, PartialEq, Eq)]
+pub struct TransactionConfig {
+    pub sign_only: bool,
+    pub dump_transaction_message: bool,
+    pub blockhash_query: BlockhashQuery,
+    pub nonce_account: Option<Pubkey>,
+    pub nonce_authority: SignerIndex,
+    pub memo: Option<String>,
+    pub fee_payer: SignerIndex,
+    pub compute_unit_price: Option<u128>,
+}
+
+// Resolves the fee payer and nonce authority alongside the command's own signers, placing the fee
+// payer first as every other transaction building command does
+pub fn parse_transaction_signers(
+    matches: &ArgMatches<'_>,
+    default_signer: &DefaultSigner,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+    command_signers: Vec<Option<Box<dyn Signer>>>,
+) -> Result<(CliSignerInfo, TransactionConfig), CliError> {
+    let sign_only = matches.is_present(SIGN_ONLY_ARG.name);
+    let dump_transaction_message = matches.is_present(DUMP_TRANSACTION_MESSAGE.name);
+    let blockhash_query = BlockhashQuery::new_from_matches(matches);
+    let nonce_account = pubkey_of(matches, NONCE_ARG.name);
+    let memo = matches.value_of(MEMO_ARG.name).map(String::from);
+    let (nonce_authority, nonce_authority_pubkey) =
+        signer_of(matches, NONCE_AUTHORITY_ARG.name, wallet_manager)?;
+    let (fee_payer, fee_payer_pubkey) = signer_of(matches, FEE_PAYER_ARG.name, wallet_manager)?;
+    let compute_unit_price = value_of(matches, COMPUTE_UNIT_PRICE_ARG.name);
+
+    let mut bulk_signers = vec![fee_payer];
+    bulk_signers.extend(command_signers);
+    if nonce_account.is_some() {
+        bulk_signers.push(nonce_authority);
+    }
+    let signer_info =
+        default_signer.generate_unique_signers(bulk_signers, matches, wallet_manager)?;
+
+    let transaction_config = TransactionConfig {
+        sign_only,
+        dump_transaction_message,
+        blockhash_query,
+        nonce_account,
+        nonce_authority: signer_info.index_of(nonce_authority_pubkey).unwrap(),
+        memo,
+        fee_payer: signer_info.index_of(fee_payer_pubkey).unwrap(),
+        compute_unit_price,
+    };
+    Ok((signer_info, transaction_config))
+}
+
+/// Build a transaction from `ixs` and either sign it partially for offline use, or sign, check and
+/// send it, formatting the signature with `format_success`
+pub fn process_transaction<F>(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    ixs: Vec<Instruction>,
+    transaction_config: &TransactionConfig,
+    format_success: F,
+) -> ProcessResult
+where
+    F: FnOnce(Signature) -> String,
+{
+    let TransactionConfig {
+        sign_only,
+        dump_transaction_message,
+        blockhash_query,
+        nonce_account,
+        nonce_authority,
+        memo,
+        fee_payer,
+        compute_unit_price,
+    } = transaction_config;
+
+    let recent_blockhash = blockhash_query.get_blockhash(rpc_client, config.commitment)?;
+    let ixs = ixs
+        .with_memo(memo.as_ref())
+        .with_compute_unit_price(compute_unit_price.as_ref());
+    let nonce_authority = config.signers[*nonce_authority];
+    let fee_payer = config.signers[*fee_payer];
+
+    let message = if let Some(nonce_account) = nonce_account {
+        Message::new_with_nonce(
+            ixs,
+            Some(&fee_payer.pubkey()),
+            nonce_account,
+            &nonce_authority.pubkey(),
+        )
+    } else {
+        Message::new(&ixs, Some(&fee_payer.pubkey()))
+    };
+    let mut tx = Transaction::new_unsigned(message);
+
+    if *sign_only {
+        tx.try_partial_sign(&config.signers, recent_blockhash)?;
+        return_signers_with_config(
+            &tx,
+            &config.output_format,
+            &ReturnSignersConfig {
+                dump_transaction_message: *dump_transaction_message,
+            },
+        )
+    } else {
+        tx.try_sign(&config.signers, recent_blockhash)?;
+        if let Some(nonce_account) = nonce_account {
+            let nonce_account = put_rpc_client_nonce_utils::get_account_with_commitment(
+                rpc_client,
+                nonce_account,
+                config.commitment,
+            )?;
+            check_nonce_account(&nonce_account, &nonce_authority.pubkey(), &recent_blockhash)?;
+        }
+        check_account_for_fee_with_commitment(
+            rpc_client,
+            &fee_payer.pubkey(),
+            &tx.message,
+            config.commitment,
+        )?;
+        let signature = rpc_client.send_and_confirm_transaction_with_spinner(&tx)?;
+        Ok(format_success(signature))
+    }
+}
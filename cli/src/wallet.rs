@@ -5,6 +5,7 @@ use {
             CliConfig, CliError, ProcessResult,
         },
         compute_unit_price::WithComputeUnitPrice,
+        domain::{domain_name_of, is_valid_pubkey_or_domain, resolve_domain_recipient},
         memo::WithMemo,
         nonce::check_nonce_account,
         spend_utils::{resolve_spend_tx_and_check_account_balances, SpendAmount},
@@ -255,11 +256,16 @@ impl WalletSubCommands for App<'_, '_> {
                 .about("Transfer funds between system accounts")
                 .alias("pay")
                 .arg(
-                    pubkey!(Arg::with_name("to")
+                    Arg::with_name("to")
                         .index(1)
                         .value_name("RECIPIENT_ADDRESS")
-                        .required(true),
-                        "The account address of recipient. "),
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_valid_pubkey_or_domain)
+                        .help(concat!(
+                            "The account address of recipient, or a domain name resolving to it. ",
+                            ACCOUNT_STRING!()
+                        )),
                 )
                 .arg(
                     Arg::with_name("amount")
@@ -539,14 +545,33 @@ pub fn parse_find_program_derived_address(
     })
 }
 
+/// Recipient of `put transfer`; domain names are resolved through the name service when the
+/// transfer is processed
+#[derive(Debug, PartialEq, Eq)]
+pub enum TransferRecipient {
+    Address(Pubkey),
+    Domain(String),
+}
+
 pub fn parse_transfer(
     matches: &ArgMatches<'_>,
     default_signer: &DefaultSigner,
     wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
 ) -> Result<CliCommandInfo, CliError> {
     let amount = SpendAmount::new_from_matches(matches, "amount");
-    let to = pubkey_of_signer(matches, "to", wallet_manager)?.unwrap();
     let sign_only = matches.is_present(SIGN_ONLY_ARG.name);
+    let to = match matches.value_of("to").and_then(domain_name_of) {
+        Some(_) if sign_only => {
+            return Err(CliError::BadParameter(
+                "Domain recipients are resolved online; pass the recipient address with --sign-only"
+                    .to_string(),
+            ));
+        }
+        Some(name) => TransferRecipient::Domain(name),
+        None => {
+            TransferRecipient::Address(pubkey_of_signer(matches, "to", wallet_manager)?.unwrap())
+        }
+    };
     let dump_transaction_message = matches.is_present(DUMP_TRANSACTION_MESSAGE.name);
     let no_wait = matches.is_present("no_wait");
     let blockhash_query = BlockhashQuery::new_from_matches(matches);
@@ -863,7 +888,7 @@ pub fn process_transfer(
     rpc_client: &RpcClient,
     config: &CliConfig,
     amount: SpendAmount,
-    to: &Pubkey,
+    to: &TransferRecipient,
     from: SignerIndex,
     sign_only: bool,
     dump_transaction_message: bool,
@@ -881,6 +906,11 @@ pub fn process_transfer(
     let from = config.signers[from];
     let mut from_pubkey = from.pubkey();
 
+    let to = &match to {
+        TransferRecipient::Address(to) => *to,
+        TransferRecipient::Domain(name) => resolve_domain_recipient(rpc_client, config, name)?,
+    };
+
     let recent_blockhash = blockhash_query.get_blockhash(rpc_client, config.commitment)?;
 
     if !sign_only && !allow_unfunded_recipient {
@@ -5,6 +5,7 @@ use {
         cli::{process_command, request_and_confirm_airdrop, CliCommand, CliConfig},
         spend_utils::SpendAmount,
         test_utils::check_ready,
+        wallet::TransferRecipient,
     },
     put_cli_output::{parse_sign_only_reply_string, OutputFormat},
     put_faucet::faucet::run_local_faucet,
@@ -343,7 +344,7 @@ fn test_create_account_with_seed() {
     process_command(&authority_config).unwrap_err();
     authority_config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("10.0")),
-        to: to_address,
+        to: TransferRecipient::Address(to_address),
         from: 0,
         sign_only: true,
         dump_transaction_message: true,
@@ -370,7 +371,7 @@ fn test_create_account_with_seed() {
     submit_config.signers = vec![&authority_presigner];
     submit_config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("10.0")),
-        to: to_address,
+        to: TransferRecipient::Address(to_address),
         from: 0,
         sign_only: false,
         dump_transaction_message: true,
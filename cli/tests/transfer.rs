@@ -6,6 +6,7 @@ use {
         cli::{process_command, request_and_confirm_airdrop, CliCommand, CliConfig},
         spend_utils::SpendAmount,
         test_utils::check_ready,
+        wallet::TransferRecipient,
     },
     put_cli_output::{parse_sign_only_reply_string, OutputFormat},
     put_faucet::faucet::run_local_faucet,
@@ -64,7 +65,7 @@ fn test_transfer() {
     // Plain ole transfer
     config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("1.0")),
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -92,7 +93,7 @@ fn test_transfer() {
     // Plain ole transfer, failure due to InsufficientFundsForSpendAndFee
     config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("4.0")),
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -133,7 +134,7 @@ fn test_transfer() {
     let blockhash = rpc_client.get_latest_blockhash().unwrap();
     offline.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("0.5")),
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: true,
         dump_transaction_message: false,
@@ -156,7 +157,7 @@ fn test_transfer() {
     config.signers = vec![&offline_presigner];
     config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("0.5")),
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -218,7 +219,7 @@ fn test_transfer() {
     config.signers = vec![&default_signer];
     config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("1.0")),
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -287,7 +288,7 @@ fn test_transfer() {
     offline.signers = vec![&default_offline_signer];
     offline.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("0.4")),
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: true,
         dump_transaction_message: false,
@@ -309,7 +310,7 @@ fn test_transfer() {
     config.signers = vec![&offline_presigner];
     config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("0.4")),
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -399,7 +400,7 @@ fn test_transfer_multisession_signing() {
     process_command(&fee_payer_config).unwrap_err();
     fee_payer_config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports(&42.0.to_string())),
-        to: to_pubkey,
+        to: TransferRecipient::Address(to_pubkey),
         from: 1,
         sign_only: true,
         dump_transaction_message: false,
@@ -431,7 +432,7 @@ fn test_transfer_multisession_signing() {
     process_command(&from_config).unwrap_err();
     from_config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports(&42.0.to_string())),
-        to: to_pubkey,
+        to: TransferRecipient::Address(to_pubkey),
         from: 1,
         sign_only: true,
         dump_transaction_message: false,
@@ -460,7 +461,7 @@ fn test_transfer_multisession_signing() {
     config.signers = vec![&fee_payer_presigner, &from_presigner];
     config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports(&42.0.to_string())),
-        to: to_pubkey,
+        to: TransferRecipient::Address(to_pubkey),
         from: 1,
         sign_only: false,
         dump_transaction_message: false,
@@ -529,7 +530,7 @@ fn test_transfer_all() {
     // Plain ole transfer
     config.command = CliCommand::Transfer {
         amount: SpendAmount::All,
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -587,7 +588,7 @@ fn test_transfer_unfunded_recipient() {
     // Plain ole transfer
     config.command = CliCommand::Transfer {
         amount: SpendAmount::All,
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -656,7 +657,7 @@ fn test_transfer_with_seed() {
     // Transfer with seed
     config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(put_to_lamports("5.0")),
-        to: recipient_pubkey,
+        to: TransferRecipient::Address(recipient_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
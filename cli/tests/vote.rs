@@ -4,6 +4,7 @@ use {
         check_balance,
         cli::{process_command, request_and_confirm_airdrop, CliCommand, CliConfig},
         spend_utils::SpendAmount,
+        wallet::TransferRecipient,
     },
     put_cli_output::{parse_sign_only_reply_string, OutputFormat},
     put_faucet::faucet::run_local_faucet,
@@ -75,7 +76,7 @@ fn test_vote_authorize_and_withdraw() {
     config.signers = vec![&default_signer];
     config.command = CliCommand::Transfer {
         amount: SpendAmount::Some(10_000),
-        to: vote_account_pubkey,
+        to: TransferRecipient::Address(vote_account_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -302,7 +303,7 @@ fn test_offline_vote_authorize_and_withdraw() {
     config_payer.signers = vec![&default_signer];
     config_payer.command = CliCommand::Transfer {
         amount: SpendAmount::Some(10_000),
-        to: vote_account_pubkey,
+        to: TransferRecipient::Address(vote_account_pubkey),
         from: 0,
         sign_only: false,
         dump_transaction_message: false,
@@ -2,7 +2,8 @@ use {
     crate::leader_slot_banking_stage_timing_metrics::LeaderExecuteAndCommitTimings,
     itertools::Itertools,
     put_ledger::{
-        blockstore_processor::TransactionStatusSender, token_balances::collect_token_balances,
+        blockstore_processor::TransactionStatusSender,
+        token_balances::{collect_nft_holdings, collect_token_balances},
     },
     put_measure::measure_us,
     put_runtime::{
@@ -18,7 +19,7 @@ use {
     },
     put_sdk::{pubkey::Pubkey, saturating_add_assign},
     put_transaction_status::{
-        token_balances::TransactionTokenBalancesSet, TransactionTokenBalance,
+        token_balances::TransactionTokenBalancesSet, TransactionNftHolding, TransactionTokenBalance,
     },
     std::{collections::HashMap, sync::Arc},
 };
@@ -33,6 +34,7 @@ pub enum CommitTransactionDetails {
 pub(super) struct PreBalanceInfo {
     pub native: Vec<Vec<u128>>,
     pub token: Vec<Vec<TransactionTokenBalance>>,
+    pub nft: Vec<Vec<TransactionNftHolding>>,
     pub mint_decimals: HashMap<Pubkey, u8>,
 }
 
@@ -145,6 +147,7 @@ impl Committer {
             let post_balances = bank.collect_balances(batch);
             let post_token_balances =
                 collect_token_balances(bank, batch, &mut pre_balance_info.mint_decimals);
+            let post_nft_holdings = collect_nft_holdings(bank, batch);
             let mut transaction_index = starting_transaction_index.unwrap_or_default();
             let batch_transaction_indexes: Vec<_> = tx_results
                 .execution_results
@@ -170,6 +173,8 @@ impl Committer {
                 TransactionTokenBalancesSet::new(
                     std::mem::take(&mut pre_balance_info.token),
                     post_token_balances,
+                    std::mem::take(&mut pre_balance_info.nft),
+                    post_nft_holdings,
                 ),
                 tx_results.rent_debits,
                 batch_transaction_indexes,
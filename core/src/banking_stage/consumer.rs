@@ -12,7 +12,7 @@ use {
         unprocessed_transaction_storage::{ConsumeScannerPayload, UnprocessedTransactionStorage},
     },
     itertools::Itertools,
-    put_ledger::token_balances::collect_token_balances,
+    put_ledger::token_balances::{collect_nft_holdings, collect_token_balances},
     put_measure::{measure::Measure, measure_us},
     put_poh::poh_recorder::{
         BankStart, PohRecorderError, RecordTransactionsSummary, RecordTransactionsTimings,
@@ -539,7 +539,8 @@ impl Consumer {
             if transaction_status_sender_enabled {
                 pre_balance_info.native = bank.collect_balances(batch);
                 pre_balance_info.token =
-                    collect_token_balances(bank, batch, &mut pre_balance_info.mint_decimals)
+                    collect_token_balances(bank, batch, &mut pre_balance_info.mint_decimals);
+                pre_balance_info.nft = collect_nft_holdings(bank, batch);
             }
         });
         execute_and_commit_timings.collect_balances_us = collect_balances_us;
@@ -1809,6 +1810,8 @@ mod tests {
                     rewards: Some(vec![]),
                     loaded_addresses: sanitized_tx.get_loaded_addresses(),
                     compute_units_consumed: Some(0),
+                    pre_nft_holdings: Some(vec![]),
+                    post_nft_holdings: Some(vec![]),
                     ..TransactionStatusMeta::default()
                 }
             );
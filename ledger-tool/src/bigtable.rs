@@ -22,6 +22,7 @@ use {
     },
     put_sdk::{clock::Slot, pubkey::Pubkey, signature::Signature},
     put_storage_bigtable::CredentialType,
+    put_storage_interface::{Error as StorageError, LongTermStorage},
     put_transaction_status::{
         BlockEncodingOptions, ConfirmedBlock, EncodeError, TransactionDetails,
         UiTransactionEncoding, VersionedConfirmedBlock,
@@ -44,9 +45,11 @@ async fn upload(
     force_reupload: bool,
     config: put_storage_bigtable::LedgerStorageConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let bigtable = put_storage_bigtable::LedgerStorage::new_with_config(config)
-        .await
-        .map_err(|err| format!("Failed to connect to storage: {err:?}"))?;
+    let bigtable: Arc<dyn LongTermStorage> = Arc::new(
+        put_storage_bigtable::LedgerStorage::new_with_config(config)
+            .await
+            .map_err(|err| format!("Failed to connect to storage: {err:?}"))?,
+    );
 
     let config = ConfirmedBlockUploadConfig {
         force_reupload,
@@ -95,7 +98,7 @@ async fn delete_slots(
         .await
         .map_err(|err| format!("Failed to connect to storage: {err:?}"))?;
 
-    put_ledger::bigtable_delete::delete_confirmed_blocks(bigtable, slots, dry_run).await
+    put_ledger::bigtable_delete::delete_confirmed_blocks(Arc::new(bigtable), slots, dry_run).await
 }
 
 async fn first_available_block(
@@ -425,6 +428,27 @@ async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
     })
     .await?;
 
+    copy_confirmed_blocks(
+        Arc::new(source_bigtable),
+        Arc::new(destination_bigtable),
+        from_slot,
+        to_slot,
+        args.force,
+        args.dry_run,
+    )
+    .await
+}
+
+/// Copy the confirmed blocks in `from_slot..=to_slot` from `source` to `destination`, which may
+/// be different storage backends
+pub(crate) async fn copy_confirmed_blocks(
+    source: Arc<dyn LongTermStorage>,
+    destination: Arc<dyn LongTermStorage>,
+    from_slot: Slot,
+    to_slot: Slot,
+    force: bool,
+    dry_run: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
     let (s, r) = unbounded::<u64>();
     for i in from_slot..=to_slot {
         s.send(i).unwrap();
@@ -441,8 +465,8 @@ async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
     let tasks = (0..workers)
         .map(|i| {
             let r = r.clone();
-            let source_bigtable_clone = source_bigtable.clone();
-            let destination_bigtable_clone = destination_bigtable.clone();
+            let source = source.clone();
+            let destination = destination.clone();
 
             let success_slots_clone = Arc::clone(&success_slots);
             let skip_slots_clone = Arc::clone(&skip_slots);
@@ -452,8 +476,8 @@ async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
                 while let Ok(slot) = r.try_recv() {
                     debug!("worker {}: received slot {}", i, slot);
 
-                    if !args.force {
-                        match destination_bigtable_clone.confirmed_block_exists(slot).await {
+                    if !force {
+                        match destination.confirmed_block_exists(slot).await {
                             Ok(exist) => {
                                 if exist {
                                     skip_slots_clone.lock().unwrap().push(slot);
@@ -461,15 +485,15 @@ async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
                                 }
                             }
                             Err(err) => {
-                                error!("confirmed_block_exists() failed from the destination Bigtable, slot: {}, err: {}", slot, err);
+                                error!("confirmed_block_exists() failed from the destination {}, slot: {}, err: {}", destination.name(), slot, err);
                                 failed_slots_clone.lock().unwrap().push(slot);
                                 continue;
                             }
                         };
                     }
 
-                    if args.dry_run {
-                        match source_bigtable_clone.confirmed_block_exists(slot).await {
+                    if dry_run {
+                        match source.confirmed_block_exists(slot).await {
                             Ok(exist) => {
                                 if exist {
                                     debug!("will write block: {}", slot);
@@ -481,14 +505,14 @@ async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
                                 }
                             }
                             Err(err) => {
-                                error!("failed to get a confirmed block from the source Bigtable, slot: {}, err: {}", slot, err);
+                                error!("failed to get a confirmed block from the source {}, slot: {}, err: {}", source.name(), slot, err);
                                 failed_slots_clone.lock().unwrap().push(slot);
                                 continue;
                             }
                         };
                     } else {
                         let confirmed_block =
-                        match source_bigtable_clone.get_confirmed_block(slot).await {
+                        match source.get_confirmed_block(slot).await {
                             Ok(block) => match VersionedConfirmedBlock::try_from(block) {
                                 Ok(block) => block,
                                 Err(err) => {
@@ -497,7 +521,7 @@ async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
                                     continue;
                                 }
                             },
-                            Err(put_storage_bigtable::Error::BlockNotFound(slot)) => {
+                            Err(StorageError::BlockNotFound(slot)) => {
                                 debug!("block not found, slot: {}", slot);
                                 block_not_found_slots_clone.lock().unwrap().push(slot);
                                 continue;
@@ -509,7 +533,7 @@ async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
                             }
                         };
 
-                        match destination_bigtable_clone
+                        match destination
                             .upload_confirmed_block(slot, confirmed_block)
                             .await
                         {
@@ -558,16 +582,16 @@ async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-struct GetBigtableArgs {
-    read_only: bool,
-    instance_name: String,
-    app_profile_id: String,
-    timeout: Option<std::time::Duration>,
-    emulated_source: Option<String>,
-    crediential_path: Option<String>,
+pub(crate) struct GetBigtableArgs {
+    pub(crate) read_only: bool,
+    pub(crate) instance_name: String,
+    pub(crate) app_profile_id: String,
+    pub(crate) timeout: Option<std::time::Duration>,
+    pub(crate) emulated_source: Option<String>,
+    pub(crate) crediential_path: Option<String>,
 }
 
-async fn get_bigtable(
+pub(crate) async fn get_bigtable(
     args: GetBigtableArgs,
 ) -> put_storage_bigtable::Result<put_storage_bigtable::LedgerStorage> {
     if let Some(endpoint) = args.emulated_source {
@@ -957,7 +981,7 @@ impl BigTableSubCommand for App<'_, '_> {
     }
 }
 
-fn get_global_subcommand_arg<T: FromStr>(
+pub(crate) fn get_global_subcommand_arg<T: FromStr>(
     matches: &ArgMatches<'_>,
     sub_matches: Option<&clap::ArgMatches>,
     name: &str,
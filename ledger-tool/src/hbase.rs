@@ -1,6 +1,11 @@
 //! The `hbase` subcommand
 use {
-    crate::ledger_path::canonicalize_ledger_path,
+    crate::{
+        bigtable::{
+            copy_confirmed_blocks, get_bigtable, get_global_subcommand_arg, GetBigtableArgs,
+        },
+        ledger_path::canonicalize_ledger_path,
+    },
     clap::{
         value_t, value_t_or_exit, values_t_or_exit, App, AppSettings, Arg, ArgMatches, SubCommand,
     },
@@ -16,6 +21,7 @@ use {
     },
     put_ledger::{blockstore::Blockstore, blockstore_options::AccessType,},
     put_sdk::{clock::Slot, pubkey::Pubkey, signature::Signature},
+    put_storage_interface::LongTermStorage,
     put_transaction_status::{
         BlockEncodingOptions, ConfirmedBlock, EncodeError, TransactionDetails,
         UiTransactionEncoding,
@@ -31,34 +37,52 @@ use {
 
 async fn upload(
     thrift2_url: String,
+    namespace: &str,
     blockstore: Blockstore,
     starting_slot: Slot,
     ending_slot: Option<Slot>,
     force_reupload: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url,None)
+    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)
         .map_err(|err| format!("Failed to connect to storage: {:?}", err))?;
 
     put_ledger::hbase_upload::upload_confirmed_blocks(
         Arc::new(blockstore),
-        hbase,
+        Arc::new(hbase),
         starting_slot,
         ending_slot,
         force_reupload,
         Arc::new(AtomicBool::new(false)),
     )
     .await
+    .map(|_last_slot_checked| ())
+}
+
+async fn delete_slots(thrift2_url: String, namespace: &str, slots: Vec<Slot>, dry_run: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)
+        .map_err(|err| format!("Failed to connect to storage: {:?}", err))?;
+
+    put_ledger::hbase_delete::delete_confirmed_blocks(Arc::new(hbase), slots, dry_run).await
 }
 
-async fn delete_slots(thrift2_url: String, slots: Vec<Slot>, dry_run: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url,None)
+async fn create_tables(
+    thrift2_url: String,
+    namespace: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)
         .map_err(|err| format!("Failed to connect to storage: {:?}", err))?;
 
-    put_ledger::hbase_delete::delete_confirmed_blocks(hbase, slots, dry_run)
+    let created = hbase.create_tables()?;
+    if created.is_empty() {
+        println!("All tables already exist in namespace {}", namespace);
+    } else {
+        println!("Created tables in namespace {}: {}", namespace, created.join(", "));
+    }
+    Ok(())
 }
 
-async fn first_available_block(thrift2_url: String) -> Result<(), Box<dyn std::error::Error>> {
-    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url,None)?;
+async fn first_available_block(thrift2_url: String, namespace: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)?;
     match hbase.get_first_available_block()? {
         Some(block) => println!("{}", block),
         None => println!("No blocks available"),
@@ -67,8 +91,8 @@ async fn first_available_block(thrift2_url: String) -> Result<(), Box<dyn std::e
     Ok(())
 }
 
-async fn block(thrift2_url: String, slot: Slot, output_format: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
-    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url,None)
+async fn block(thrift2_url: String, namespace: &str, slot: Slot, output_format: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
+    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)
         .map_err(|err| format!("Failed to connect to storage: {:?}", err))?;
 
     let confirmed_block = hbase.get_confirmed_block(slot)?;
@@ -98,8 +122,8 @@ async fn block(thrift2_url: String, slot: Slot, output_format: OutputFormat) ->
     Ok(())
 }
 
-async fn blocks(thrift2_url: String, starting_slot: Slot, limit: usize) -> Result<(), Box<dyn std::error::Error>> {
-    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url,None)
+async fn blocks(thrift2_url: String, namespace: &str, starting_slot: Slot, limit: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)
         .map_err(|err| format!("Failed to connect to storage: {:?}", err))?;
 
     let slots = hbase.get_confirmed_blocks(starting_slot, limit)?;
@@ -111,13 +135,14 @@ async fn blocks(thrift2_url: String, starting_slot: Slot, limit: usize) -> Resul
 
 async fn compare_blocks(
     thrift2_url: String,
+    namespace: &str,
     starting_slot: Slot,
     limit: usize,
     credential_path: String,
 ) -> Result<(), Box<dyn std::error::Error>> {
     assert!(!credential_path.is_empty());
 
-    let owned_hbase = put_storage_hbase::LedgerStorage::new(thrift2_url,None)
+    let owned_hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)
         .map_err(|err| format!("failed to connect to owned hbase: {:?}", err))?;
     let owned_hbase_slots = owned_hbase
         .get_confirmed_blocks(starting_slot, limit)?;
@@ -126,7 +151,7 @@ async fn compare_blocks(
         owned_hbase_slots.len()
     );
     let reference_hbase =
-        put_storage_hbase::LedgerStorage::new(credential_path,None)
+        put_storage_hbase::LedgerStorage::new(credential_path, namespace, None)
             .map_err(|err| format!("failed to connect to reference hbase: {:?}", err))?;
 
     let reference_hbase_slots = reference_hbase
@@ -151,11 +176,12 @@ async fn compare_blocks(
 
 async fn confirm(
     thrift2_url: String,
+    namespace: &str,
     signature: &Signature,
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url,None)
+    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)
         .map_err(|err| format!("Failed to connect to storage: {:?}", err))?;
 
     let transaction_status = hbase.get_signature_status(signature)?;
@@ -198,6 +224,7 @@ async fn confirm(
 
 pub async fn transaction_history(
     thrift2_url: String,
+    namespace: &str,
     address: &Pubkey,
     mut limit: usize,
     mut before: Option<Signature>,
@@ -206,7 +233,7 @@ pub async fn transaction_history(
     show_transactions: bool,
     query_chunk_size: usize,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url,None)?;
+    let hbase = put_storage_hbase::LedgerStorage::new(thrift2_url, namespace, None)?;
 
     let mut loaded_block: Option<(Slot, ConfirmedBlock)> = None;
     while limit > 0 {
@@ -290,6 +317,95 @@ pub async fn transaction_history(
     Ok(())
 }
 
+struct CopyArgs {
+    from_slot: Slot,
+    to_slot: Option<Slot>,
+
+    source_hbase_rpc: Option<String>,
+    source_namespace: String,
+    source_instance_name: String,
+    source_app_profile_id: String,
+    emulated_source: Option<String>,
+    source_credential_path: Option<String>,
+
+    destination_hbase_rpc: String,
+    destination_namespace: String,
+
+    force: bool,
+    dry_run: bool,
+}
+
+impl CopyArgs {
+    pub fn process(arg_matches: &ArgMatches) -> Self {
+        CopyArgs {
+            from_slot: value_t!(arg_matches, "starting_slot", Slot).unwrap_or(0),
+            to_slot: value_t!(arg_matches, "ending_slot", Slot).ok(),
+
+            source_hbase_rpc: value_t!(arg_matches, "source_hbase_rpc", String).ok(),
+            source_namespace: value_t_or_exit!(arg_matches, "source_namespace", String),
+            source_instance_name: value_t_or_exit!(arg_matches, "source_instance_name", String),
+            source_app_profile_id: value_t_or_exit!(arg_matches, "source_app_profile_id", String),
+            emulated_source: value_t!(arg_matches, "emulated_source", String).ok(),
+            source_credential_path: value_t!(arg_matches, "source_credential_path", String).ok(),
+
+            destination_hbase_rpc: value_t_or_exit!(arg_matches, "destination_hbase_rpc", String),
+            destination_namespace: value_t_or_exit!(
+                arg_matches,
+                "destination_namespace",
+                String
+            ),
+
+            force: arg_matches.is_present("force"),
+            dry_run: arg_matches.is_present("dry_run"),
+        }
+    }
+}
+
+async fn copy(args: CopyArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let from_slot = args.from_slot;
+    let to_slot = args.to_slot.unwrap_or(from_slot);
+
+    if from_slot > to_slot {
+        return Err("starting slot should be less than or equal to ending slot")?;
+    }
+
+    // The source is either another HBase or a BigTable instance
+    let source: Arc<dyn LongTermStorage> = match args.source_hbase_rpc {
+        Some(source_hbase_rpc) => Arc::new(
+            put_storage_hbase::LedgerStorage::new(source_hbase_rpc, &args.source_namespace, None)
+                .map_err(|err| format!("Failed to connect to source storage: {:?}", err))?,
+        ),
+        None => Arc::new(
+            get_bigtable(GetBigtableArgs {
+                read_only: true,
+                instance_name: args.source_instance_name,
+                app_profile_id: args.source_app_profile_id,
+                timeout: None,
+                emulated_source: args.emulated_source,
+                crediential_path: args.source_credential_path,
+            })
+            .await?,
+        ),
+    };
+
+    let destination = put_storage_hbase::LedgerStorage::new(
+        args.destination_hbase_rpc,
+        &args.destination_namespace,
+        None,
+    )
+    .map_err(|err| format!("Failed to connect to destination storage: {:?}", err))?;
+
+    copy_confirmed_blocks(
+        source,
+        Arc::new(destination),
+        from_slot,
+        to_slot,
+        args.force,
+        args.dry_run,
+    )
+    .await
+}
+
 pub trait HbaseSubCommand {
     fn hbase_subcommand(self) -> Self;
 }
@@ -301,6 +417,27 @@ impl HbaseSubCommand for App<'_, '_> {
                 .about("Ledger data on a Hbase instance")
                 .setting(AppSettings::InferSubcommands)
                 .setting(AppSettings::SubcommandRequiredElseHelp)
+                .arg(
+                    Arg::with_name("namespace")
+                        .global(true)
+                        .long("namespace")
+                        .takes_value(true)
+                        .value_name("NAMESPACE")
+                        .default_value(put_storage_hbase::DEFAULT_HBASE_NAMESPACE)
+                        .help("Hbase namespace holding the ledger tables"),
+                )
+                .subcommand(
+                    SubCommand::with_name("create-tables")
+                        .about("Create the namespace and ledger tables in Hbase if they are missing")
+                        .arg(
+                            Arg::with_name("hbase_rpc")
+                                .long("hbase-rpc")
+                                .value_name("HBASE_RPC")
+                                .takes_value(true)
+                                .index(1)
+                                .help("Hbase thrift2 RPC"),
+                        ),
+                )
                 .subcommand(
                     SubCommand::with_name("upload")
                         .about("Upload the ledger to Hbase")
@@ -568,6 +705,106 @@ impl HbaseSubCommand for App<'_, '_> {
                                 .takes_value(false)
                                 .help("Display the full transactions"),
                         ),
+                )
+                .subcommand(
+                    SubCommand::with_name("copy")
+                        .about("Copy blocks to Hbase from another Hbase or from a Bigtable")
+                        .arg(
+                            Arg::with_name("source_hbase_rpc")
+                                .long("source-hbase-rpc")
+                                .value_name("SOURCE_HBASE_RPC")
+                                .takes_value(true)
+                                .conflicts_with_all(&["source_credential_path", "emulated_source"])
+                                .required_unless_one(&["source_credential_path", "emulated_source"])
+                                .help("Source Hbase thrift2 RPC"),
+                        )
+                        .arg(
+                            Arg::with_name("source_namespace")
+                                .long("source-namespace")
+                                .value_name("SOURCE_NAMESPACE")
+                                .takes_value(true)
+                                .default_value(put_storage_hbase::DEFAULT_HBASE_NAMESPACE)
+                                .help("Source Hbase namespace"),
+                        )
+                        .arg(
+                            Arg::with_name("source_credential_path")
+                                .long("source-credential-path")
+                                .value_name("SOURCE_CREDENTIAL_PATH")
+                                .takes_value(true)
+                                .conflicts_with("emulated_source")
+                                .help(
+                                    "Source Bigtable credential filepath (credential may be readonly)",
+                                ),
+                        )
+                        .arg(
+                            Arg::with_name("emulated_source")
+                                .long("emulated-source")
+                                .value_name("EMULATED_SOURCE")
+                                .takes_value(true)
+                                .conflicts_with("source_credential_path")
+                                .help("Source Bigtable emulated source"),
+                        )
+                        .arg(
+                            Arg::with_name("source_instance_name")
+                                .long("source-instance-name")
+                                .takes_value(true)
+                                .value_name("SOURCE_INSTANCE_NAME")
+                                .default_value(put_storage_bigtable::DEFAULT_INSTANCE_NAME)
+                                .help("Source Bigtable instance name"),
+                        )
+                        .arg(
+                            Arg::with_name("source_app_profile_id")
+                                .long("source-app-profile-id")
+                                .takes_value(true)
+                                .value_name("SOURCE_APP_PROFILE_ID")
+                                .default_value(put_storage_bigtable::DEFAULT_APP_PROFILE_ID)
+                                .help("Source Bigtable app profile id"),
+                        )
+                        .arg(
+                            Arg::with_name("destination_hbase_rpc")
+                                .long("destination-hbase-rpc")
+                                .value_name("DESTINATION_HBASE_RPC")
+                                .takes_value(true)
+                                .required(true)
+                                .help("Destination Hbase thrift2 RPC"),
+                        )
+                        .arg(
+                            Arg::with_name("destination_namespace")
+                                .long("destination-namespace")
+                                .value_name("DESTINATION_NAMESPACE")
+                                .takes_value(true)
+                                .default_value(put_storage_hbase::DEFAULT_HBASE_NAMESPACE)
+                                .help("Destination Hbase namespace"),
+                        )
+                        .arg(
+                            Arg::with_name("starting_slot")
+                                .long("starting-slot")
+                                .validator(is_slot)
+                                .value_name("START_SLOT")
+                                .takes_value(true)
+                                .required(true)
+                                .help("Start copying at this slot"),
+                        )
+                        .arg(
+                            Arg::with_name("ending_slot")
+                                .long("ending-slot")
+                                .validator(is_slot)
+                                .value_name("END_SLOT")
+                                .takes_value(true)
+                                .help("Stop copying at this slot (inclusive, START_SLOT ..= END_SLOT)"),
+                        )
+                        .arg(
+                            Arg::with_name("force")
+                                .long("force")
+                                .takes_value(false)
+                                .help("Force copy of blocks already present in destination Hbase"),
+                        )
+                        .arg(
+                            Arg::with_name("dry_run")
+                                .long("dry-run")
+                                .takes_value(false)
+                                .help("Dry run. It won't upload any blocks"),
+                        ),
                 ),
         )
     }
@@ -580,7 +817,19 @@ pub fn hbase_process_command(ledger_path: &Path, matches: &ArgMatches<'_>) {
     let force_update_to_open = matches.is_present("force_update_to_open");
     let output_format = OutputFormat::from_matches(matches, "output_format", verbose);
 
-    let future = match matches.subcommand() {
+    let (subcommand, sub_matches) = matches.subcommand();
+    let namespace: String = get_global_subcommand_arg(
+        matches,
+        sub_matches,
+        "namespace",
+        put_storage_hbase::DEFAULT_HBASE_NAMESPACE,
+    );
+
+    let future = match (subcommand, sub_matches) {
+        ("create-tables", Some(arg_matches)) => {
+            let rpc = value_t!(arg_matches, "hbase_rpc", String).unwrap();
+            runtime.block_on(create_tables(rpc, &namespace))
+        }
         ("upload", Some(arg_matches)) => {
             let starting_slot = value_t!(arg_matches, "starting_slot", Slot).unwrap_or(0);
             let ending_slot = value_t!(arg_matches, "ending_slot", Slot).ok();
@@ -595,6 +844,7 @@ pub fn hbase_process_command(ledger_path: &Path, matches: &ArgMatches<'_>) {
 
             runtime.block_on(upload(
                 rpc,
+                &namespace,
                 blockstore,
                 starting_slot,
                 ending_slot,
@@ -605,23 +855,23 @@ pub fn hbase_process_command(ledger_path: &Path, matches: &ArgMatches<'_>) {
             let slots = values_t_or_exit!(arg_matches, "slots", Slot);
             let rpc = value_t!(arg_matches, "hbase_rpc", String).unwrap();
             let dry_run = !arg_matches.is_present("force");
-            runtime.block_on(delete_slots(rpc,slots, dry_run))
+            runtime.block_on(delete_slots(rpc, &namespace, slots, dry_run))
         }
         ("first-available-block", Some(arg_matches)) => {
             let rpc = value_t!(arg_matches, "hbase_rpc", String).unwrap();
-            runtime.block_on(first_available_block(rpc))
+            runtime.block_on(first_available_block(rpc, &namespace))
         }
         ("block", Some(arg_matches)) => {
             let slot = value_t_or_exit!(arg_matches, "slot", Slot);
             let rpc = value_t!(arg_matches, "hbase_rpc", String).unwrap();
-            runtime.block_on(block(rpc,slot, output_format))
+            runtime.block_on(block(rpc, &namespace, slot, output_format))
         }
         ("blocks", Some(arg_matches)) => {
             let starting_slot = value_t_or_exit!(arg_matches, "starting_slot", Slot);
             let rpc = value_t!(arg_matches, "hbase_rpc", String).unwrap();
             let limit = value_t_or_exit!(arg_matches, "limit", usize);
 
-            runtime.block_on(blocks(rpc,starting_slot, limit))
+            runtime.block_on(blocks(rpc, &namespace, starting_slot, limit))
         }
         ("compare-blocks", Some(arg_matches)) => {
             let starting_slot = value_t_or_exit!(arg_matches, "starting_slot", Slot);
@@ -632,6 +882,7 @@ pub fn hbase_process_command(ledger_path: &Path, matches: &ArgMatches<'_>) {
 
             runtime.block_on(compare_blocks(
                 rpc,
+                &namespace,
                 starting_slot,
                 limit,
                 reference_credential_filepath,
@@ -645,7 +896,7 @@ pub fn hbase_process_command(ledger_path: &Path, matches: &ArgMatches<'_>) {
                 .expect("Invalid signature");
 
             let rpc = value_t!(arg_matches, "hbase_rpc", String).unwrap();
-            runtime.block_on(confirm(rpc,&signature, verbose, output_format))
+            runtime.block_on(confirm(rpc, &namespace, &signature, verbose, output_format))
         }
         ("transaction-history", Some(arg_matches)) => {
             let address = pubkey_of(arg_matches, "address").unwrap();
@@ -662,6 +913,7 @@ pub fn hbase_process_command(ledger_path: &Path, matches: &ArgMatches<'_>) {
 
             runtime.block_on(transaction_history(
                 rpc,
+                &namespace,
                 &address,
                 limit,
                 before,
@@ -671,6 +923,7 @@ pub fn hbase_process_command(ledger_path: &Path, matches: &ArgMatches<'_>) {
                 query_chunk_size,
             ))
         }
+        ("copy", Some(arg_matches)) => runtime.block_on(copy(CopyArgs::process(arg_matches))),
         _ => unreachable!(),
     };
 
@@ -1,10 +1,16 @@
-use {log::*, put_measure::measure::Measure, put_sdk::clock::Slot, std::result::Result};
+use {
+    log::*,
+    put_measure::measure::Measure,
+    put_sdk::clock::Slot,
+    put_storage_interface::LongTermStorage,
+    std::{result::Result, sync::Arc},
+};
 
 // Attempt to delete this many blocks in parallel
 const NUM_BLOCKS_TO_DELETE_IN_PARALLEL: usize = 32;
 
 pub async fn delete_confirmed_blocks(
-    bigtable: put_storage_bigtable::LedgerStorage,
+    bigtable: Arc<dyn LongTermStorage>,
     blocks_to_delete: Vec<Slot>,
     dry_run: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
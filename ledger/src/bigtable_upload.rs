@@ -4,6 +4,7 @@ use {
     log::*,
     put_measure::measure::Measure,
     put_sdk::clock::Slot,
+    put_storage_interface::LongTermStorage,
     std::{
         cmp::{max, min},
         collections::HashSet,
@@ -41,12 +42,12 @@ struct BlockstoreLoadStats {
     pub elapsed: Duration,
 }
 
-/// Uploads a range of blocks from a Blockstore to bigtable LedgerStorage
+/// Uploads a range of blocks from a Blockstore to bigtable long-term storage
 /// Returns the Slot of the last block checked. If no blocks in the range `[staring_slot,
 /// ending_slot]` are found in Blockstore, this value is equal to `ending_slot`.
 pub async fn upload_confirmed_blocks(
     blockstore: Arc<Blockstore>,
-    bigtable: put_storage_bigtable::LedgerStorage,
+    bigtable: Arc<dyn LongTermStorage>,
     starting_slot: Slot,
     ending_slot: Slot,
     config: ConfirmedBlockUploadConfig,
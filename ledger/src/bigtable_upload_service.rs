@@ -4,6 +4,7 @@ use {
         blockstore::Blockstore,
     },
     put_runtime::commitment::BlockCommitmentCache,
+    put_storage_interface::LongTermStorage,
     std::{
         cmp::min,
         sync::{
@@ -81,6 +82,7 @@ impl BigTableUploadService {
         config: ConfirmedBlockUploadConfig,
         exit: Arc<AtomicBool>,
     ) {
+        let bigtable_ledger_storage: Arc<dyn LongTermStorage> = Arc::new(bigtable_ledger_storage);
         let mut start_slot = blockstore.get_first_available_block().unwrap_or_default();
         loop {
             if exit.load(Ordering::Relaxed) {
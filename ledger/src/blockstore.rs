@@ -6977,6 +6977,8 @@ pub mod tests {
                     loaded_addresses: LoadedAddresses::default(),
                     return_data: Some(TransactionReturnData::default()),
                     compute_units_consumed,
+                    pre_nft_holdings: Some(vec![]),
+                    post_nft_holdings: Some(vec![]),
                 }
                 .into();
                 blockstore
@@ -6996,6 +6998,8 @@ pub mod tests {
                     loaded_addresses: LoadedAddresses::default(),
                     return_data: Some(TransactionReturnData::default()),
                     compute_units_consumed,
+                    pre_nft_holdings: Some(vec![]),
+                    post_nft_holdings: Some(vec![]),
                 }
                 .into();
                 blockstore
@@ -7015,6 +7019,8 @@ pub mod tests {
                     loaded_addresses: LoadedAddresses::default(),
                     return_data: Some(TransactionReturnData::default()),
                     compute_units_consumed,
+                    pre_nft_holdings: Some(vec![]),
+                    post_nft_holdings: Some(vec![]),
                 }
                 .into();
                 blockstore
@@ -7036,6 +7042,8 @@ pub mod tests {
                         loaded_addresses: LoadedAddresses::default(),
                         return_data: Some(TransactionReturnData::default()),
                         compute_units_consumed,
+                        pre_nft_holdings: Some(vec![]),
+                        post_nft_holdings: Some(vec![]),
                     },
                 }
             })
@@ -7178,6 +7186,8 @@ pub mod tests {
             loaded_addresses: test_loaded_addresses.clone(),
             return_data: Some(test_return_data.clone()),
             compute_units_consumed: compute_units_consumed_1,
+            pre_nft_holdings: Some(vec![]),
+            post_nft_holdings: Some(vec![]),
         }
         .into();
         assert!(transaction_status_cf
@@ -7198,6 +7208,8 @@ pub mod tests {
             loaded_addresses,
             return_data,
             compute_units_consumed,
+            pre_nft_holdings,
+            post_nft_holdings,
         } = transaction_status_cf
             .get_protobuf_or_bincode::<StoredTransactionStatusMeta>((0, Signature::default(), 0))
             .unwrap()
@@ -7216,6 +7228,8 @@ pub mod tests {
         assert_eq!(loaded_addresses, test_loaded_addresses);
         assert_eq!(return_data.unwrap(), test_return_data);
         assert_eq!(compute_units_consumed, compute_units_consumed_1);
+        assert_eq!(pre_nft_holdings.unwrap(), vec![]);
+        assert_eq!(post_nft_holdings.unwrap(), vec![]);
 
         // insert value
         let status = TransactionStatusMeta {
@@ -7231,6 +7245,8 @@ pub mod tests {
             loaded_addresses: test_loaded_addresses.clone(),
             return_data: Some(test_return_data.clone()),
             compute_units_consumed: compute_units_consumed_2,
+            pre_nft_holdings: Some(vec![]),
+            post_nft_holdings: Some(vec![]),
         }
         .into();
         assert!(transaction_status_cf
@@ -7251,6 +7267,8 @@ pub mod tests {
             loaded_addresses,
             return_data,
             compute_units_consumed,
+            pre_nft_holdings,
+            post_nft_holdings,
         } = transaction_status_cf
             .get_protobuf_or_bincode::<StoredTransactionStatusMeta>((
                 0,
@@ -7275,6 +7293,8 @@ pub mod tests {
         assert_eq!(loaded_addresses, test_loaded_addresses);
         assert_eq!(return_data.unwrap(), test_return_data);
         assert_eq!(compute_units_consumed, compute_units_consumed_2);
+        assert_eq!(pre_nft_holdings.unwrap(), vec![]);
+        assert_eq!(post_nft_holdings.unwrap(), vec![]);
     }
 
     #[test]
@@ -7505,6 +7525,8 @@ pub mod tests {
             loaded_addresses: LoadedAddresses::default(),
             return_data: Some(TransactionReturnData::default()),
             compute_units_consumed: Some(42u64),
+            pre_nft_holdings: Some(vec![]),
+            post_nft_holdings: Some(vec![]),
         }
         .into();
 
@@ -7699,6 +7721,8 @@ pub mod tests {
             loaded_addresses: LoadedAddresses::default(),
             return_data: Some(TransactionReturnData::default()),
             compute_units_consumed: Some(42u64),
+            pre_nft_holdings: Some(vec![]),
+            post_nft_holdings: Some(vec![]),
         }
         .into();
 
@@ -7875,6 +7899,8 @@ pub mod tests {
                     loaded_addresses: LoadedAddresses::default(),
                     return_data: return_data.clone(),
                     compute_units_consumed: Some(42),
+                    pre_nft_holdings: Some(vec![]),
+                    post_nft_holdings: Some(vec![]),
                 }
                 .into();
                 blockstore
@@ -7896,6 +7922,8 @@ pub mod tests {
                         loaded_addresses: LoadedAddresses::default(),
                         return_data,
                         compute_units_consumed: Some(42),
+                        pre_nft_holdings: Some(vec![]),
+                        post_nft_holdings: Some(vec![]),
                     },
                 }
             })
@@ -7995,6 +8023,8 @@ pub mod tests {
                     loaded_addresses: LoadedAddresses::default(),
                     return_data: return_data.clone(),
                     compute_units_consumed: Some(42u64),
+                    pre_nft_holdings: Some(vec![]),
+                    post_nft_holdings: Some(vec![]),
                 }
                 .into();
                 blockstore
@@ -8016,6 +8046,8 @@ pub mod tests {
                         loaded_addresses: LoadedAddresses::default(),
                         return_data,
                         compute_units_consumed: Some(42u64),
+                        pre_nft_holdings: Some(vec![]),
+                        post_nft_holdings: Some(vec![]),
                     },
                 }
             })
@@ -8784,6 +8816,8 @@ pub mod tests {
                 loaded_addresses: LoadedAddresses::default(),
                 return_data: Some(TransactionReturnData::default()),
                 compute_units_consumed: None,
+                pre_nft_holdings: Some(vec![]),
+                post_nft_holdings: Some(vec![]),
             }
             .into();
             transaction_status_cf
@@ -9535,6 +9569,8 @@ pub mod tests {
                 data: vec![1, 2, 3],
             }),
             compute_units_consumed: Some(23456),
+            pre_nft_holdings: Some(vec![]),
+            post_nft_holdings: Some(vec![]),
         };
         let deprecated_status: StoredTransactionStatusMeta = status.clone().try_into().unwrap();
         let protobuf_status: generated::TransactionStatusMeta = status.into();
@@ -6,7 +6,7 @@ use {
         blockstore_meta::SlotMeta,
         entry_notifier_service::{EntryNotification, EntryNotifierSender},
         leader_schedule_cache::LeaderScheduleCache,
-        token_balances::collect_token_balances,
+        token_balances::{collect_nft_holdings, collect_token_balances},
     },
     chrono_humanize::{Accuracy, HumanTime, Tense},
     crossbeam_channel::Sender,
@@ -149,10 +149,13 @@ fn execute_batch(
 
     let mut mint_decimals: HashMap<Pubkey, u8> = HashMap::new();
 
-    let pre_token_balances = if record_token_balances {
-        collect_token_balances(bank, batch, &mut mint_decimals)
+    let (pre_token_balances, pre_nft_holdings) = if record_token_balances {
+        (
+            collect_token_balances(bank, batch, &mut mint_decimals),
+            collect_nft_holdings(bank, batch),
+        )
     } else {
-        vec![]
+        (vec![], vec![])
     };
 
     let (tx_results, balances) = batch.bank().load_execute_and_commit_transactions(
@@ -187,14 +190,21 @@ fn execute_batch(
 
     if let Some(transaction_status_sender) = transaction_status_sender {
         let transactions = batch.sanitized_transactions().to_vec();
-        let post_token_balances = if record_token_balances {
-            collect_token_balances(bank, batch, &mut mint_decimals)
+        let (post_token_balances, post_nft_holdings) = if record_token_balances {
+            (
+                collect_token_balances(bank, batch, &mut mint_decimals),
+                collect_nft_holdings(bank, batch),
+            )
         } else {
-            vec![]
+            (vec![], vec![])
         };
 
-        let token_balances =
-            TransactionTokenBalancesSet::new(pre_token_balances, post_token_balances);
+        let token_balances = TransactionTokenBalancesSet::new(
+            pre_token_balances,
+            post_token_balances,
+            pre_nft_holdings,
+            post_nft_holdings,
+        );
 
         transaction_status_sender.send_transaction_status_batch(
             bank.clone(),
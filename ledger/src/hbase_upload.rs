@@ -4,6 +4,7 @@ use {
     log::*,
     put_measure::measure::Measure,
     put_sdk::clock::Slot,
+    put_storage_interface::LongTermStorage,
     std::{
         collections::HashSet,
         result::Result,
@@ -21,64 +22,52 @@ const NUM_BLOCKS_TO_UPLOAD_IN_PARALLEL: usize = 32;
 // Read up to this many blocks from blockstore before blocking on the upload process
 const BLOCK_READ_AHEAD_DEPTH: usize = NUM_BLOCKS_TO_UPLOAD_IN_PARALLEL * 2;
 
+/// Uploads a range of blocks from a Blockstore to hbase long-term storage
+/// Returns the Slot of the last block checked. If no blocks in the range `[starting_slot,
+/// ending_slot]` are found in Blockstore, this value is equal to `ending_slot`, or to
+/// `starting_slot` if no `ending_slot` was provided.
 pub async fn upload_confirmed_blocks(
     blockstore: Arc<Blockstore>,
-    hbase: put_storage_hbase::LedgerStorage,
+    hbase: Arc<dyn LongTermStorage>,
     starting_slot: Slot,
     ending_slot: Option<Slot>,
     force_reupload: bool,
     exit: Arc<AtomicBool>,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<Slot, Box<dyn std::error::Error>> {
     let mut measure = Measure::start("entire upload");
 
     info!("Loading ledger slots starting at {}...", starting_slot);
-
-    // let  get_db_blocks = Measure::start("get_db_blocks");
-    // let blockstore_slots: Vec<_> = blockstore
-    //     .slot_meta_iterator(starting_slot)
-    //     .map_err(|err| {
-    //         format!(
-    //             "Failed to load entries starting from slot {}: {:?}",
-    //             starting_slot, err
-    //         )
-    //     })?
-    //     .filter_map(|(slot, _slot_meta)| {
-    //         if let Some(ending_slot) = &ending_slot {
-    //             if slot > *ending_slot {
-    //                 return None;
-    //             }
-    //         }
-    //         Some(slot)
-    //     })
-    //     .collect();
-    // get_db_blocks.stop();
-    // info!("get blocks times:{} s", get_db_blocks.as_s());
-    // if blockstore_slots.is_empty() {
-    //     return Err(format!(
-    //         "Ledger has no slots from {} to {:?}",
-    //         starting_slot, ending_slot
-    //     )
-    //     .into());
-    // }
-    let end_solt = ending_slot.unwrap_or(starting_slot+1000);
-    let mut  blockstore_slots: Vec<u64> = vec![];
-    for i in starting_slot..end_solt {
-        blockstore_slots.push(i)
+    let blockstore_slots: Vec<_> = blockstore
+        .rooted_slot_iterator(starting_slot)
+        .map_err(|err| {
+            format!(
+                "Failed to load entries starting from slot {}: {:?}",
+                starting_slot, err
+            )
+        })?
+        .take_while(|slot| ending_slot.map_or(true, |ending_slot| *slot <= ending_slot))
+        .collect();
+
+    if blockstore_slots.is_empty() {
+        warn!(
+            "Ledger has no slots from {} to {:?}",
+            starting_slot, ending_slot
+        );
+        return Ok(ending_slot.unwrap_or(starting_slot));
     }
-
+    let last_blockstore_slot = *blockstore_slots.last().unwrap();
 
     info!(
         "Found {} slots in the range ({}, {})",
         blockstore_slots.len(),
         blockstore_slots.first().unwrap(),
-        blockstore_slots.last().unwrap()
+        last_blockstore_slot
     );
 
     // Gather the blocks that are already present in hbase, by slot
     let hbase_slots = if !force_reupload {
         let mut hbase_slots = vec![];
         let first_blockstore_slot = *blockstore_slots.first().unwrap();
-        let last_blockstore_slot = *blockstore_slots.last().unwrap();
         info!(
             "Loading list of hbase blocks between slots {} and {}...",
             first_blockstore_slot, last_blockstore_slot
@@ -87,7 +76,7 @@ pub async fn upload_confirmed_blocks(
         let mut start_slot = *blockstore_slots.first().unwrap();
         while start_slot <= last_blockstore_slot {
             let mut next_hbase_slots = loop {
-                match hbase.get_confirmed_blocks(start_slot, 1000) {
+                match hbase.get_confirmed_blocks(start_slot, 1000).await {
                     Ok(slots) => break slots,
                     Err(err) => {
                         error!("get_confirmed_blocks for {} failed: {:?}", start_slot, err);
@@ -124,7 +113,7 @@ pub async fn upload_confirmed_blocks(
     };
     if blocks_to_upload.is_empty() {
         info!("No blocks need to be uploaded to hbase");
-        return Ok(());
+        return Ok(last_blockstore_slot);
     }
     info!(
         "{} blocks to be uploaded to the bucket in the range ({}, {})",
@@ -211,6 +200,6 @@ pub async fn upload_confirmed_blocks(
     if failures > 0 {
         Err(format!("Incomplete upload, {} operations failed", failures).into())
     } else {
-        Ok(())
+        Ok(last_blockstore_slot)
     }
 }
@@ -0,0 +1,134 @@
+use {
+    crate::{blockstore::Blockstore, hbase_upload},
+    put_runtime::commitment::BlockCommitmentCache,
+    put_sdk::clock::Slot,
+    put_storage_interface::LongTermStorage,
+    std::{
+        cmp::{max, min},
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Arc, RwLock,
+        },
+        thread::{self, Builder, JoinHandle},
+    },
+    tokio::runtime::Runtime,
+};
+
+// Check at most this many slots per upload pass
+const MAX_NUM_SLOTS_TO_CHECK: u64 = 64;
+
+pub struct HBaseUploadService {
+    thread: JoinHandle<()>,
+}
+
+impl HBaseUploadService {
+    pub fn new(
+        runtime: Arc<Runtime>,
+        hbase_ledger_storage: put_storage_hbase::LedgerStorage,
+        blockstore: Arc<Blockstore>,
+        block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
+        max_complete_transaction_status_slot: Arc<AtomicU64>,
+        max_complete_rewards_slot: Arc<AtomicU64>,
+        exit: Arc<AtomicBool>,
+    ) -> Self {
+        info!("Starting HBase upload service");
+        let thread = Builder::new()
+            .name("putHBaseUpload".to_string())
+            .spawn(move || {
+                Self::run(
+                    runtime,
+                    hbase_ledger_storage,
+                    blockstore,
+                    block_commitment_cache,
+                    max_complete_transaction_status_slot,
+                    max_complete_rewards_slot,
+                    exit,
+                )
+            })
+            .unwrap();
+
+        Self { thread }
+    }
+
+    // Resume after the highest block already stored in HBase, or from the first block available in
+    // blockstore if HBase is empty or cannot be queried
+    fn get_start_slot(
+        hbase_ledger_storage: &put_storage_hbase::LedgerStorage,
+        blockstore: &Blockstore,
+    ) -> Slot {
+        let first_available_block = blockstore.get_first_available_block().unwrap_or_default();
+        match hbase_ledger_storage.get_last_available_block() {
+            Ok(Some(last_uploaded_slot)) => {
+                info!("hbase: resuming upload after slot {}", last_uploaded_slot);
+                max(first_available_block, last_uploaded_slot.saturating_add(1))
+            }
+            Ok(None) => first_available_block,
+            Err(err) => {
+                warn!("hbase: get_last_available_block: {}", err);
+                first_available_block
+            }
+        }
+    }
+
+    fn run(
+        runtime: Arc<Runtime>,
+        hbase_ledger_storage: put_storage_hbase::LedgerStorage,
+        blockstore: Arc<Blockstore>,
+        block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
+        max_complete_transaction_status_slot: Arc<AtomicU64>,
+        max_complete_rewards_slot: Arc<AtomicU64>,
+        exit: Arc<AtomicBool>,
+    ) {
+        let mut start_slot = Self::get_start_slot(&hbase_ledger_storage, &blockstore);
+        let long_term_storage: Arc<dyn LongTermStorage> = Arc::new(hbase_ledger_storage.clone());
+        loop {
+            if exit.load(Ordering::Relaxed) {
+                break;
+            }
+
+            // The highest slot eligible for upload is the highest root that has complete
+            // transaction-status metadata and rewards
+            let highest_complete_root = [
+                max_complete_transaction_status_slot.load(Ordering::SeqCst),
+                max_complete_rewards_slot.load(Ordering::SeqCst),
+                block_commitment_cache.read().unwrap().root(),
+            ]
+            .into_iter()
+            .min()
+            .expect("root and max_complete slots exist");
+            let end_slot = min(
+                highest_complete_root,
+                start_slot.saturating_add(MAX_NUM_SLOTS_TO_CHECK),
+            );
+
+            if end_slot <= start_slot {
+                std::thread::sleep(std::time::Duration::from_secs(1));
+                continue;
+            }
+
+            let result = runtime.block_on(hbase_upload::upload_confirmed_blocks(
+                blockstore.clone(),
+                long_term_storage.clone(),
+                start_slot,
+                Some(end_slot),
+                false,
+                exit.clone(),
+            ));
+
+            match result {
+                Ok(last_slot_checked) => start_slot = last_slot_checked,
+                Err(err) => {
+                    warn!("hbase: upload_confirmed_blocks: {}", err);
+                    std::thread::sleep(std::time::Duration::from_secs(2));
+                    if start_slot == 0 {
+                        start_slot = Self::get_start_slot(&hbase_ledger_storage, &blockstore);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn join(self) -> thread::Result<()> {
+        self.thread.join()
+    }
+}
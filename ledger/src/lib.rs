@@ -8,6 +8,7 @@ pub mod bigtable_upload_service;
 pub mod block_error;
 pub mod hbase_delete;
 pub mod hbase_upload;
+pub mod hbase_upload_service;
 #[macro_use]
 pub mod blockstore;
 pub mod ancestor_iterator;
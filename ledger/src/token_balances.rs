@@ -1,18 +1,19 @@
 use {
-    put_account_decoder::parse_token::{
-        is_known_ppl_token_id, ppl_token_native_mint,pubkey_from_ppl_token,
-        token_amount_to_ui_amount, UiTokenAmount,
+    put_account_decoder::{
+        parse_nft::{is_known_nft_token_id, parse_nft, TokenAccountType as NftAccountType},
+        parse_token::{
+            is_known_ppl_token_id, ppl_token_native_mint, pubkey_from_ppl_token,
+            token_account_balance, token_amount_to_ui_amount, unpack_token_account,
+            unpack_token_mint, UiTokenAmount,
+        },
     },
     put_measure::measure::Measure,
     put_metrics::datapoint_debug,
     put_runtime::{bank::Bank, transaction_batch::TransactionBatch},
     put_sdk::{account::ReadableAccount, pubkey::Pubkey},
     put_transaction_status::{
-        token_balances::TransactionTokenBalances, TransactionTokenBalance,
-    },
-    ppl_token::{
-        put_program::program_pack::Pack,
-        state::{Account as TokenAccount, Mint},
+        token_balances::{TransactionNftHoldings, TransactionTokenBalances},
+        TransactionNftHolding, TransactionTokenBalance,
     },
     std::collections::HashMap,
 };
@@ -27,9 +28,7 @@ fn get_mint_decimals(bank: &Bank, mint: &Pubkey) -> Option<u8> {
             return None;
         }
 
-        let decimals = Mint::unpack(mint_account.data())
-            .map(|mint| mint.decimals)
-            .ok()?;
+        let decimals = unpack_token_mint(mint_account.data()).map(|mint| mint.decimals)?;
 
         Some(decimals)
     }
@@ -100,7 +99,7 @@ fn collect_token_balance_from_account(
         return None;
     }
 
-    let token_account = TokenAccount::unpack(account.data()).ok()?;
+    let token_account = unpack_token_account(account.data())?;
     let mint = pubkey_from_ppl_token(&token_account.mint);
 
     let decimals = mint_decimals.get(&mint).cloned().or_else(|| {
@@ -112,17 +111,92 @@ fn collect_token_balance_from_account(
     Some(TokenBalanceData {
         mint: token_account.mint.to_string(),
         owner: token_account.owner.to_string(),
-        ui_token_amount: token_amount_to_ui_amount(token_account.amount, decimals),
+        ui_token_amount: token_amount_to_ui_amount(
+            token_account_balance(&token_account, Some(account.lamports())),
+            decimals,
+        ),
         program_id: account.owner().to_string(),
     })
 }
 
+pub fn collect_nft_holdings(bank: &Bank, batch: &TransactionBatch) -> TransactionNftHoldings {
+    let mut holdings: TransactionNftHoldings = vec![];
+    let mut collect_time = Measure::start("collect_nft_holdings");
+
+    for transaction in batch.sanitized_transactions() {
+        let account_keys = transaction.message().account_keys();
+        let has_nft_program = account_keys.iter().any(is_known_nft_token_id);
+
+        let mut transaction_holdings: Vec<TransactionNftHolding> = vec![];
+        if has_nft_program {
+            for (index, account_id) in account_keys.iter().enumerate() {
+                if transaction.message().is_invoked(index) || is_known_nft_token_id(account_id) {
+                    continue;
+                }
+
+                if let Some(NftHoldingData {
+                    mint,
+                    token_id,
+                    owner,
+                }) = collect_nft_holding_from_account(bank, account_id)
+                {
+                    transaction_holdings.push(TransactionNftHolding {
+                        account_index: index as u8,
+                        mint,
+                        token_id,
+                        owner,
+                    });
+                }
+            }
+        }
+        holdings.push(transaction_holdings);
+    }
+    collect_time.stop();
+    datapoint_debug!(
+        "collect_nft_holdings",
+        ("collect_time_us", collect_time.as_us(), i64),
+    );
+    holdings
+}
+
+#[derive(Debug, PartialEq)]
+struct NftHoldingData {
+    mint: String,
+    token_id: u64,
+    owner: String,
+}
+
+fn collect_nft_holding_from_account(bank: &Bank, account_id: &Pubkey) -> Option<NftHoldingData> {
+    let account = bank.get_account(account_id)?;
+
+    if !is_known_nft_token_id(account.owner()) {
+        return None;
+    }
+
+    match parse_nft(account.data()).ok()? {
+        NftAccountType::Account(nft_account) => Some(NftHoldingData {
+            mint: nft_account.mint,
+            token_id: nft_account.token_id,
+            owner: nft_account.owner,
+        }),
+        NftAccountType::Mint(_) => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use {
         super::*,
+        ppl_nft::state::{AccountState as NftAccountState, MetaAccount},
+        put_account_decoder::{
+            parse_token::ppl_token_2022_id,
+            parse_token_extension::{ACCOUNTTYPE_ACCOUNT, ACCOUNTTYPE_MINT},
+        },
         put_sdk::{account::Account, genesis_config::create_genesis_config},
-        ppl_token::put_program::program_option::COption,
+        ppl_token::{
+            put_program::{program_option::COption, program_pack::Pack},
+            state::{Account as TokenAccount, Mint},
+        },
         std::collections::BTreeMap,
     };
 
@@ -209,6 +283,28 @@ mod test {
             rent_epoch: 0,
         };
 
+        // Wrapped PUT holding more lamports than its last synced amount
+        let wrapped_put_data = TokenAccount {
+            mint: ppl_token::native_mint::id(),
+            owner: token_owner,
+            amount: 42,
+            delegate: COption::None,
+            state: ppl_token::state::AccountState::Initialized,
+            is_native: COption::Some(100),
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        let mut data = [0; TokenAccount::LEN];
+        TokenAccount::pack(wrapped_put_data, &mut data).unwrap();
+
+        let wrapped_put_account = Account {
+            lamports: 100 + 1_500_000_000,
+            data: data.to_vec(),
+            owner: ppl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
         let mut accounts = BTreeMap::new();
 
         let account_pubkey = Pubkey::new_unique();
@@ -221,6 +317,8 @@ mod test {
         accounts.insert(other_account_pubkey, other_account);
         let other_mint_account_pubkey = Pubkey::new_unique();
         accounts.insert(other_mint_account_pubkey, other_mint_token_account);
+        let wrapped_put_account_pubkey = Pubkey::new_unique();
+        accounts.insert(wrapped_put_account_pubkey, wrapped_put_account);
 
         genesis_config.accounts = accounts;
 
@@ -274,196 +372,256 @@ mod test {
             ),
             None
         );
+
+        // Wrapped PUT reports its lamports above the rent exempt reserve, not its synced amount
+        assert_eq!(
+            collect_token_balance_from_account(
+                &bank,
+                &wrapped_put_account_pubkey,
+                &mut mint_decimals
+            ),
+            Some(TokenBalanceData {
+                mint: ppl_token::native_mint::id().to_string(),
+                owner: token_owner.to_string(),
+                ui_token_amount: UiTokenAmount {
+                    ui_amount: Some(1.5),
+                    decimals: 9,
+                    amount: "1500000000".to_string(),
+                    ui_amount_string: "1.5".to_string(),
+                },
+                program_id: ppl_token::id().to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_collect_nft_holding_from_account() {
+        let (mut genesis_config, _mint_keypair) = create_genesis_config(500);
+        let nft_program_id = Pubkey::new_from_array(ppl_nft::id().to_bytes());
+
+        let mint_pubkey = Pubkey::new_unique();
+        let nft_owner = Pubkey::new_unique();
+        let mut data = vec![0; MetaAccount::get_packed_len()];
+        let mut meta_account = MetaAccount::unpack_unchecked(&data).unwrap();
+        meta_account.mint = mint_pubkey;
+        meta_account.owner = nft_owner;
+        meta_account.state = NftAccountState::Initialized;
+        meta_account.token_id = 7;
+        meta_account.token_uri = "https://example.com/7.json".to_string();
+        MetaAccount::pack(meta_account, &mut data).unwrap();
+
+        let nft_account = Account {
+            lamports: 100,
+            data: data.clone(),
+            owner: nft_program_id,
+            executable: false,
+            rent_epoch: 0,
+        };
+        let other_account = Account {
+            lamports: 100,
+            data,
+            owner: Pubkey::new_unique(), // !is_known_nft_token_id
+            executable: false,
+            rent_epoch: 0,
+        };
+        let invalid_nft_account = Account {
+            lamports: 100,
+            data: vec![1; 10],
+            owner: nft_program_id,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let mut accounts = BTreeMap::new();
+        let nft_account_pubkey = Pubkey::new_unique();
+        accounts.insert(nft_account_pubkey, nft_account);
+        let other_account_pubkey = Pubkey::new_unique();
+        accounts.insert(other_account_pubkey, other_account);
+        let invalid_nft_account_pubkey = Pubkey::new_unique();
+        accounts.insert(invalid_nft_account_pubkey, invalid_nft_account);
+        genesis_config.accounts = accounts;
+
+        let bank = Bank::new_for_tests(&genesis_config);
+
+        // MetaAccount owned by the nft program works
+        assert_eq!(
+            collect_nft_holding_from_account(&bank, &nft_account_pubkey),
+            Some(NftHoldingData {
+                mint: mint_pubkey.to_string(),
+                token_id: 7,
+                owner: nft_owner.to_string(),
+            })
+        );
+
+        // MetaAccount is not owned by the nft program
+        assert_eq!(
+            collect_nft_holding_from_account(&bank, &other_account_pubkey),
+            None
+        );
+
+        // Account owned by the nft program without MetaAccount state
+        assert_eq!(
+            collect_nft_holding_from_account(&bank, &invalid_nft_account_pubkey),
+            None
+        );
+
+        // Missing account
+        assert_eq!(
+            collect_nft_holding_from_account(&bank, &Pubkey::new_unique()),
+            None
+        );
     }
 
-    // #[test]
-    // fn test_collect_token_balance_from_ppl_token_2022_account() {
-    //     let (mut genesis_config, _mint_keypair) = create_genesis_config(500);
-
-    //     // Add a variety of accounts, token and not
-    //     let account = Account::new(42, 55, &Pubkey::new_unique());
-
-    //     let mint_authority = Pubkey::new_unique();
-    //     let mint_size =
-    //         ExtensionType::get_account_len::<Mint>(&[ExtensionType::MintCloseAuthority]);
-    //     let mint_base = Mint {
-    //         mint_authority: COption::None,
-    //         supply: 4242,
-    //         decimals: 2,
-    //         is_initialized: true,
-    //         freeze_authority: COption::None,
-    //     };
-    //     let mut mint_data = vec![0; mint_size];
-    //     let mut mint_state =
-    //         StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut mint_data).unwrap();
-    //     mint_state.base = mint_base;
-    //     mint_state.pack_base();
-    //     mint_state.init_account_type().unwrap();
-    //     let mut mint_close_authority = mint_state
-    //         .init_extension::<MintCloseAuthority>(true)
-    //         .unwrap();
-    //     mint_close_authority.close_authority =
-    //         OptionalNonZeroPubkey::try_from(Some(mint_authority)).unwrap();
-
-    //     let mint_pubkey = Pubkey::new_unique();
-    //     let mint = Account {
-    //         lamports: 100,
-    //         data: mint_data.to_vec(),
-    //         owner: ppl_token_2022::id(),
-    //         executable: false,
-    //         rent_epoch: 0,
-    //     };
-    //     let other_mint_pubkey = Pubkey::new_unique();
-    //     let other_mint = Account {
-    //         lamports: 100,
-    //         data: mint_data.to_vec(),
-    //         owner: Pubkey::new_unique(),
-    //         executable: false,
-    //         rent_epoch: 0,
-    //     };
-
-    //     let token_owner = Pubkey::new_unique();
-    //     let token_base = TokenAccount {
-    //         mint: mint_pubkey,
-    //         owner: token_owner,
-    //         amount: 42,
-    //         delegate: COption::None,
-    //         state: ppl_token_2022::state::AccountState::Initialized,
-    //         is_native: COption::Some(100),
-    //         delegated_amount: 0,
-    //         close_authority: COption::None,
-    //     };
-    //     let account_size = ExtensionType::get_account_len::<TokenAccount>(&[
-    //         ExtensionType::ImmutableOwner,
-    //         ExtensionType::MemoTransfer,
-    //     ]);
-    //     let mut account_data = vec![0; account_size];
-    //     let mut account_state =
-    //         StateWithExtensionsMut::<TokenAccount>::unpack_uninitialized(&mut account_data)
-    //             .unwrap();
-    //     account_state.base = token_base;
-    //     account_state.pack_base();
-    //     account_state.init_account_type().unwrap();
-    //     account_state
-    //         .init_extension::<ImmutableOwner>(true)
-    //         .unwrap();
-    //     let mut memo_transfer = account_state.init_extension::<MemoTransfer>(true).unwrap();
-    //     memo_transfer.require_incoming_transfer_memos = true.into();
-
-    //     let ppl_token_account = Account {
-    //         lamports: 100,
-    //         data: account_data.to_vec(),
-    //         owner: ppl_token_2022::id(),
-    //         executable: false,
-    //         rent_epoch: 0,
-    //     };
-    //     let other_account = Account {
-    //         lamports: 100,
-    //         data: account_data.to_vec(),
-    //         owner: Pubkey::new_unique(),
-    //         executable: false,
-    //         rent_epoch: 0,
-    //     };
-
-    //     let other_mint_token_base = TokenAccount {
-    //         mint: other_mint_pubkey,
-    //         owner: token_owner,
-    //         amount: 42,
-    //         delegate: COption::None,
-    //         state: ppl_token_2022::state::AccountState::Initialized,
-    //         is_native: COption::Some(100),
-    //         delegated_amount: 0,
-    //         close_authority: COption::None,
-    //     };
-    //     let account_size = ExtensionType::get_account_len::<TokenAccount>(&[
-    //         ExtensionType::ImmutableOwner,
-    //         ExtensionType::MemoTransfer,
-    //     ]);
-    //     let mut account_data = vec![0; account_size];
-    //     let mut account_state =
-    //         StateWithExtensionsMut::<TokenAccount>::unpack_uninitialized(&mut account_data)
-    //             .unwrap();
-    //     account_state.base = other_mint_token_base;
-    //     account_state.pack_base();
-    //     account_state.init_account_type().unwrap();
-    //     account_state
-    //         .init_extension::<ImmutableOwner>(true)
-    //         .unwrap();
-    //     let mut memo_transfer = account_state.init_extension::<MemoTransfer>(true).unwrap();
-    //     memo_transfer.require_incoming_transfer_memos = true.into();
-
-    //     let other_mint_token_account = Account {
-    //         lamports: 100,
-    //         data: account_data.to_vec(),
-    //         owner: ppl_token_2022::id(),
-    //         executable: false,
-    //         rent_epoch: 0,
-    //     };
-
-    //     let mut accounts = BTreeMap::new();
-
-    //     let account_pubkey = Pubkey::new_unique();
-    //     accounts.insert(account_pubkey, account);
-    //     accounts.insert(mint_pubkey, mint);
-    //     accounts.insert(other_mint_pubkey, other_mint);
-    //     let ppl_token_account_pubkey = Pubkey::new_unique();
-    //     accounts.insert(ppl_token_account_pubkey, ppl_token_account);
-    //     let other_account_pubkey = Pubkey::new_unique();
-    //     accounts.insert(other_account_pubkey, other_account);
-    //     let other_mint_account_pubkey = Pubkey::new_unique();
-    //     accounts.insert(other_mint_account_pubkey, other_mint_token_account);
-
-    //     genesis_config.accounts = accounts;
-
-    //     let bank = Bank::new_for_tests(&genesis_config);
-    //     let mut mint_decimals = HashMap::new();
-
-    //     // Account is not owned by ppl_token (nor does it have TokenAccount state)
-    //     assert_eq!(
-    //         collect_token_balance_from_account(&bank, &account_pubkey, &mut mint_decimals),
-    //         None
-    //     );
-
-    //     // Mint does not have TokenAccount state
-    //     assert_eq!(
-    //         collect_token_balance_from_account(&bank, &mint_pubkey, &mut mint_decimals),
-    //         None
-    //     );
-
-    //     // TokenAccount owned by ppl_token_2022::id() works
-    //     assert_eq!(
-    //         collect_token_balance_from_account(
-    //             &bank,
-    //             &ppl_token_account_pubkey,
-    //             &mut mint_decimals
-    //         ),
-    //         Some(TokenBalanceData {
-    //             mint: mint_pubkey.to_string(),
-    //             owner: token_owner.to_string(),
-    //             ui_token_amount: UiTokenAmount {
-    //                 ui_amount: Some(0.42),
-    //                 decimals: 2,
-    //                 amount: "42".to_string(),
-    //                 ui_amount_string: "0.42".to_string(),
-    //             },
-    //             program_id: ppl_token_2022::id().to_string(),
-    //         })
-    //     );
-
-    //     // TokenAccount is not owned by known ppl-token program_id
-    //     assert_eq!(
-    //         collect_token_balance_from_account(&bank, &other_account_pubkey, &mut mint_decimals),
-    //         None
-    //     );
-
-    //     // TokenAccount's mint is not owned by known ppl-token program_id
-    //     assert_eq!(
-    //         collect_token_balance_from_account(
-    //             &bank,
-    //             &other_mint_account_pubkey,
-    //             &mut mint_decimals
-    //         ),
-    //         None
-    //     );
-    // }
+    #[test]
+    fn test_collect_token_balance_from_ppl_token_2022_account() {
+        let (mut genesis_config, _mint_keypair) = create_genesis_config(500);
+
+        // Add a variety of accounts, token and not
+        let account = Account::new(42, 55, &Pubkey::new_unique());
+
+        let mint_authority = Pubkey::new_unique();
+        let mint_base = Mint {
+            mint_authority: COption::None,
+            supply: 4242,
+            decimals: 2,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        // Extended mints are padded to the length of a token account
+        let mut mint_data = vec![0; TokenAccount::LEN];
+        Mint::pack(mint_base, &mut mint_data[..Mint::LEN]).unwrap();
+        mint_data.push(ACCOUNTTYPE_MINT);
+        // MintCloseAuthority
+        mint_data.extend_from_slice(&[3, 0, 32, 0]);
+        mint_data.extend_from_slice(mint_authority.as_ref());
+
+        let mint_pubkey = Pubkey::new_unique();
+        let mint = Account {
+            lamports: 100,
+            data: mint_data.to_vec(),
+            owner: ppl_token_2022_id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+        let other_mint_pubkey = Pubkey::new_unique();
+        let other_mint = Account {
+            lamports: 100,
+            data: mint_data.to_vec(),
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let token_owner = Pubkey::new_unique();
+        let extended_account_data = |mint| {
+            let token_base = TokenAccount {
+                mint,
+                owner: token_owner,
+                amount: 42,
+                delegate: COption::None,
+                state: ppl_token::state::AccountState::Initialized,
+                is_native: COption::Some(100),
+                delegated_amount: 0,
+                close_authority: COption::None,
+            };
+            let mut account_data = vec![0; TokenAccount::LEN];
+            TokenAccount::pack(token_base, &mut account_data).unwrap();
+            account_data.push(ACCOUNTTYPE_ACCOUNT);
+            // ImmutableOwner
+            account_data.extend_from_slice(&[7, 0, 0, 0]);
+            // MemoTransfer { require_incoming_transfer_memos: true }
+            account_data.extend_from_slice(&[8, 0, 1, 0, 1]);
+            account_data
+        };
+
+        let account_data = extended_account_data(mint_pubkey);
+        let ppl_token_account = Account {
+            lamports: 100,
+            data: account_data.to_vec(),
+            owner: ppl_token_2022_id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+        let other_account = Account {
+            lamports: 100,
+            data: account_data.to_vec(),
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let other_mint_token_account = Account {
+            lamports: 100,
+            data: extended_account_data(other_mint_pubkey),
+            owner: ppl_token_2022_id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let mut accounts = BTreeMap::new();
+
+        let account_pubkey = Pubkey::new_unique();
+        accounts.insert(account_pubkey, account);
+        accounts.insert(mint_pubkey, mint);
+        accounts.insert(other_mint_pubkey, other_mint);
+        let ppl_token_account_pubkey = Pubkey::new_unique();
+        accounts.insert(ppl_token_account_pubkey, ppl_token_account);
+        let other_account_pubkey = Pubkey::new_unique();
+        accounts.insert(other_account_pubkey, other_account);
+        let other_mint_account_pubkey = Pubkey::new_unique();
+        accounts.insert(other_mint_account_pubkey, other_mint_token_account);
+
+        genesis_config.accounts = accounts;
+
+        let bank = Bank::new_for_tests(&genesis_config);
+        let mut mint_decimals = HashMap::new();
+
+        // Account is not owned by ppl_token (nor does it have TokenAccount state)
+        assert_eq!(
+            collect_token_balance_from_account(&bank, &account_pubkey, &mut mint_decimals),
+            None
+        );
+
+        // Mint does not have TokenAccount state
+        assert_eq!(
+            collect_token_balance_from_account(&bank, &mint_pubkey, &mut mint_decimals),
+            None
+        );
+
+        // TokenAccount owned by ppl_token_2022_id() works
+        assert_eq!(
+            collect_token_balance_from_account(
+                &bank,
+                &ppl_token_account_pubkey,
+                &mut mint_decimals
+            ),
+            Some(TokenBalanceData {
+                mint: mint_pubkey.to_string(),
+                owner: token_owner.to_string(),
+                ui_token_amount: UiTokenAmount {
+                    ui_amount: Some(0.42),
+                    decimals: 2,
+                    amount: "42".to_string(),
+                    ui_amount_string: "0.42".to_string(),
+                },
+                program_id: ppl_token_2022_id().to_string(),
+            })
+        );
+
+        // TokenAccount is not owned by known ppl-token program_id
+        assert_eq!(
+            collect_token_balance_from_account(&bank, &other_account_pubkey, &mut mint_decimals),
+            None
+        );
+
+        // TokenAccount's mint is not owned by known ppl-token program_id
+        assert_eq!(
+            collect_token_balance_from_account(
+                &bank,
+                &other_mint_account_pubkey,
+                &mut mint_decimals
+            ),
+            None
+        );
+    }
 }
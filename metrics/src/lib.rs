@@ -3,7 +3,10 @@ pub mod counter;
 pub mod datapoint;
 pub mod metrics;
 pub mod poh_timing_point;
-pub use crate::metrics::{flush, query, set_host_id, set_panic_hook, submit};
+pub mod prometheus;
+pub use crate::metrics::{
+    flush, query, set_host_id, set_panic_hook, set_prometheus_bind_address, submit,
+};
 use std::sync::{
     atomic::{AtomicU64, Ordering},
     Arc,
@@ -1,7 +1,8 @@
-//! The `metrics` module enables sending measurements to an `InfluxDB` instance
+//! The `metrics` module enables sending measurements to an `InfluxDB` instance, or serving
+//! them to Prometheus
 
 use {
-    crate::{counter::CounterPoint, datapoint::DataPoint},
+    crate::{counter::CounterPoint, datapoint::DataPoint, prometheus::PrometheusMetricsWriter},
     crossbeam_channel::{unbounded, Receiver, RecvTimeoutError, Sender},
     gethostname::gethostname,
     lazy_static::lazy_static,
@@ -13,6 +14,8 @@ use {
         convert::Into,
         env,
         fmt::Write,
+        io,
+        net::SocketAddr,
         sync::{Arc, Barrier, Mutex, Once, RwLock},
         thread,
         time::{Duration, Instant, UNIX_EPOCH},
@@ -45,6 +48,10 @@ pub trait MetricsWriter {
     // Write the points and empty the vector.  Called on the internal
     // MetricsAgent worker thread.
     fn write(&self, points: Vec<DataPoint>);
+
+    // Write the counters accumulated since the last write and empty the
+    // vector.  Counters left in the vector are written as points instead.
+    fn write_counters(&self, _counters: &mut Vec<CounterPoint>) {}
 }
 
 struct InfluxDbMetricsWriter {
@@ -158,11 +165,22 @@ impl Default for MetricsAgent {
             })
             .unwrap_or(4000);
 
-        Self::new(
-            Arc::new(InfluxDbMetricsWriter::new()),
-            Duration::from_secs(10),
-            max_points_per_sec,
-        )
+        let prometheus_writer = PROMETHEUS_WRITER.lock().unwrap().take();
+        let writer: Arc<dyn MetricsWriter + Send + Sync> = match prometheus_writer {
+            Some(writer) => Arc::new(writer),
+            None => match get_prometheus_bind_address() {
+                Some(bind_address) => match PrometheusMetricsWriter::new(bind_address) {
+                    Ok(writer) => Arc::new(writer),
+                    Err(err) => {
+                        warn!("prometheus metrics disabled: {}", err);
+                        Arc::new(InfluxDbMetricsWriter::new())
+                    }
+                },
+                None => Arc::new(InfluxDbMetricsWriter::new()),
+            },
+        };
+
+        Self::new(writer, Duration::from_secs(10), max_points_per_sec)
     }
 }
 
@@ -182,10 +200,15 @@ impl MetricsAgent {
         Self { sender }
     }
 
-    fn collect_points(points: &mut Vec<DataPoint>, counters: &mut CounterMap) -> Vec<DataPoint> {
+    fn collect_points(
+        writer: &Arc<dyn MetricsWriter + Send + Sync>,
+        points: &mut Vec<DataPoint>,
+        counters: &mut CounterMap,
+    ) -> Vec<DataPoint> {
         let mut ret = std::mem::take(points);
-        ret.extend(counters.values().map(|v| v.into()));
-        counters.clear();
+        let mut counters: Vec<_> = counters.drain().map(|(_, counter)| counter).collect();
+        writer.write_counters(&mut counters);
+        ret.extend(counters.iter().map(|v| v.into()));
         ret
     }
 
@@ -249,7 +272,7 @@ impl MetricsAgent {
                         debug!("metrics_thread: flush");
                         Self::write(
                             writer,
-                            Self::collect_points(&mut points, &mut counters),
+                            Self::collect_points(writer, &mut points, &mut counters),
                             max_points,
                             max_points_per_sec,
                             last_write_time,
@@ -285,7 +308,7 @@ impl MetricsAgent {
             if now.duration_since(last_write_time) >= write_frequency {
                 Self::write(
                     writer,
-                    Self::collect_points(&mut points, &mut counters),
+                    Self::collect_points(writer, &mut points, &mut counters),
                     max_points,
                     max_points_per_sec,
                     last_write_time,
@@ -335,7 +358,7 @@ fn get_singleton_agent() -> &'static MetricsAgent {
 }
 
 lazy_static! {
-    static ref HOST_ID: Arc<RwLock<String>> = {
+    pub(crate) static ref HOST_ID: Arc<RwLock<String>> = {
         Arc::new(RwLock::new({
             let hostname: String = gethostname()
                 .into_string()
@@ -350,6 +373,28 @@ pub fn set_host_id(host_id: String) {
     *HOST_ID.write().unwrap() = host_id;
 }
 
+lazy_static! {
+    static ref PROMETHEUS_WRITER: Mutex<Option<PrometheusMetricsWriter>> = Mutex::new(None);
+}
+
+/// Serves metrics to Prometheus on `bind_address` instead of sending them to `InfluxDB`.  The
+/// address is bound right away, so that an unusable one is reported to the caller.  Takes
+/// precedence over `PUT_METRICS_PROMETHEUS_ADDRESS`, and must be called before the first point
+/// is submitted.
+pub fn set_prometheus_bind_address(bind_address: SocketAddr) -> io::Result<()> {
+    let writer = PrometheusMetricsWriter::new(bind_address)?;
+    *PROMETHEUS_WRITER.lock().unwrap() = Some(writer);
+    Ok(())
+}
+
+fn get_prometheus_bind_address() -> Option<SocketAddr> {
+    let config_var = env::var("PUT_METRICS_PROMETHEUS_ADDRESS").ok()?;
+    config_var
+        .parse()
+        .map_err(|err| warn!("PUT_METRICS_PROMETHEUS_ADDRESS is invalid: {}", err))
+        .ok()
+}
+
 /// Submits a new point from any thread.  Note that points are internally queued
 /// and transmitted periodically in batches.
 pub fn submit(point: DataPoint, level: log::Level) {
@@ -663,4 +708,11 @@ mod test {
             .to_owned();
         agent.submit(point, Level::Info);
     }
+
+    #[test]
+    fn test_set_prometheus_bind_address_in_use() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        assert!(set_prometheus_bind_address(listener.local_addr().unwrap()).is_err());
+        assert!(PROMETHEUS_WRITER.lock().unwrap().is_none());
+    }
 }
@@ -0,0 +1,357 @@
+//! The `prometheus` module serves datapoints and counters in the Prometheus text exposition
+//! format, as an alternative to sending them to an `InfluxDB` instance
+//!
+//! Every numeric field of a datapoint becomes a gauge named `put_<datapoint>_<field>`, holding
+//! the last value written. Every counter becomes a counter named `put_<counter>_total`, holding
+//! the running total of its increments. Datapoint tags and the host id are exposed as labels.
+//! String fields have no Prometheus equivalent and are dropped.
+
+use {
+    crate::{
+        counter::CounterPoint,
+        datapoint::DataPoint,
+        metrics::{MetricsWriter, HOST_ID},
+    },
+    log::*,
+    std::{
+        collections::BTreeMap,
+        fmt::Write as _,
+        io::{self, BufRead, BufReader, Read, Write},
+        net::{SocketAddr, TcpListener, TcpStream},
+        sync::{Arc, Mutex},
+        thread,
+        time::Duration,
+    },
+};
+
+const METRIC_NAME_PREFIX: &str = "put_";
+const COUNTER_NAME_SUFFIX: &str = "_total";
+const METRICS_PATH: &str = "/metrics";
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+// Longest request header accepted from a scraper
+const MAX_REQUEST_LINE_LEN: usize = 8 * 1024;
+
+/// Samples of a metric, keyed by their rendered label set
+type Samples<T> = BTreeMap<String, T>;
+
+#[derive(Debug, Default)]
+struct PrometheusMetrics {
+    gauges: BTreeMap<String, Samples<f64>>,
+    counters: BTreeMap<String, Samples<i64>>,
+}
+
+impl PrometheusMetrics {
+    fn add_point(&mut self, point: &DataPoint, host_id: &str) {
+        let labels = render_labels(host_id, &point.tags);
+        for (field, value) in &point.fields {
+            if let Some(value) = parse_field_value(value) {
+                let name = metric_name(&format!("{}_{}", point.name, field), "");
+                self.gauges
+                    .entry(name)
+                    .or_default()
+                    .insert(labels.clone(), value);
+            }
+        }
+    }
+
+    fn add_counter(&mut self, counter: &CounterPoint, host_id: &str) {
+        let name = metric_name(counter.name, COUNTER_NAME_SUFFIX);
+        let total = self
+            .counters
+            .entry(name)
+            .or_default()
+            .entry(render_labels(host_id, &[]))
+            .or_default();
+        *total = total.saturating_add(counter.count);
+    }
+
+    fn render(&self) -> String {
+        let mut text = String::new();
+        for (name, samples) in &self.gauges {
+            let _ = writeln!(text, "# TYPE {name} gauge");
+            for (labels, value) in samples {
+                let _ = writeln!(text, "{name}{labels} {value}");
+            }
+        }
+        for (name, samples) in &self.counters {
+            let _ = writeln!(text, "# TYPE {name} counter");
+            for (labels, value) in samples {
+                let _ = writeln!(text, "{name}{labels} {value}");
+            }
+        }
+        text
+    }
+}
+
+/// A `MetricsWriter` that aggregates datapoints and counters in memory and serves them to
+/// Prometheus scrapes over HTTP
+pub struct PrometheusMetricsWriter {
+    metrics: Arc<Mutex<PrometheusMetrics>>,
+}
+
+impl PrometheusMetricsWriter {
+    /// Serves the aggregated metrics at `http://<bind_address>/metrics`
+    pub fn new(bind_address: SocketAddr) -> io::Result<Self> {
+        let listener = TcpListener::bind(bind_address)?;
+        info!("serving prometheus metrics on {}", listener.local_addr()?);
+
+        let metrics = Arc::new(Mutex::new(PrometheusMetrics::default()));
+        let server_metrics = metrics.clone();
+        thread::Builder::new()
+            .name("solMetricsProm".into())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    match stream {
+                        Ok(stream) => {
+                            if let Err(err) = Self::serve(stream, &server_metrics) {
+                                debug!("prometheus scrape failed: {}", err);
+                            }
+                        }
+                        Err(err) => warn!("prometheus connection failed: {}", err),
+                    }
+                }
+            })?;
+
+        Ok(Self { metrics })
+    }
+
+    /// Renders the aggregated metrics in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        self.metrics.lock().unwrap().render()
+    }
+
+    fn serve(mut stream: TcpStream, metrics: &Mutex<PrometheusMetrics>) -> io::Result<()> {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        let mut reader = BufReader::new(&stream).take(MAX_REQUEST_LINE_LEN as u64);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // Drain the request headers, up to the blank line ending them
+        let mut header = String::new();
+        while reader.read_line(&mut header)? > 0 && !header.trim_end().is_empty() {
+            header.clear();
+        }
+
+        let mut request = request_line.split_whitespace();
+        let response = match (request.next(), request.next()) {
+            (Some("GET"), Some(METRICS_PATH)) => {
+                let body = metrics.lock().unwrap().render();
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\n\
+                     Connection: close\r\n\r\n{}",
+                    CONTENT_TYPE,
+                    body.len(),
+                    body
+                )
+            }
+            (Some("GET"), Some(_)) => {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string()
+            }
+            _ => "HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                .to_string(),
+        };
+        stream.write_all(response.as_bytes())?;
+        stream.flush()
+    }
+}
+
+impl MetricsWriter for PrometheusMetricsWriter {
+    fn write(&self, points: Vec<DataPoint>) {
+        let host_id = HOST_ID.read().unwrap();
+        let mut metrics = self.metrics.lock().unwrap();
+        for point in &points {
+            metrics.add_point(point, &host_id);
+        }
+    }
+
+    fn write_counters(&self, counters: &mut Vec<CounterPoint>) {
+        let host_id = HOST_ID.read().unwrap();
+        let mut metrics = self.metrics.lock().unwrap();
+        for counter in counters.drain(..) {
+            metrics.add_counter(&counter, &host_id);
+        }
+    }
+}
+
+/// Converts a datapoint field value, as serialized for `InfluxDB`, to a sample value
+fn parse_field_value(value: &str) -> Option<f64> {
+    match value {
+        "true" => Some(1.0),
+        "false" => Some(0.0),
+        _ if value.starts_with('"') => None,
+        _ => value
+            .strip_suffix('i')
+            .unwrap_or(value)
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite()),
+    }
+}
+
+/// Maps `name` to a valid Prometheus metric name, `[a-zA-Z_:][a-zA-Z0-9_:]*`
+fn metric_name(name: &str, suffix: &str) -> String {
+    let mut metric_name =
+        String::with_capacity(METRIC_NAME_PREFIX.len() + name.len() + suffix.len());
+    metric_name.push_str(METRIC_NAME_PREFIX);
+    metric_name.extend(name.chars().map(|c| {
+        if c.is_ascii_alphanumeric() || c == '_' || c == ':' {
+            c
+        } else {
+            '_'
+        }
+    }));
+    metric_name.push_str(suffix);
+    metric_name
+}
+
+/// Maps `name` to a valid Prometheus label name, `[a-zA-Z_][a-zA-Z0-9_]*`
+fn label_name(name: &str) -> String {
+    let label_name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if label_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{label_name}")
+    } else {
+        label_name
+    }
+}
+
+fn render_labels(host_id: &str, tags: &[(&'static str, String)]) -> String {
+    let mut labels = format!("{{host_id=\"{}\"", escape_label_value(host_id));
+    for (name, value) in tags {
+        let _ = write!(
+            labels,
+            ",{}=\"{}\"",
+            label_name(name),
+            escape_label_value(value)
+        );
+    }
+    labels.push('}');
+    labels
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::metrics::MetricsAgent};
+
+    #[test]
+    fn test_parse_field_value() {
+        assert_eq!(parse_field_value("42i"), Some(42.0));
+        assert_eq!(parse_field_value("-7i"), Some(-7.0));
+        assert_eq!(parse_field_value("1.5"), Some(1.5));
+        assert_eq!(parse_field_value("true"), Some(1.0));
+        assert_eq!(parse_field_value("false"), Some(0.0));
+        assert_eq!(parse_field_value("\"a string\""), None);
+        assert_eq!(parse_field_value("NaN"), None);
+        assert_eq!(parse_field_value("inf"), None);
+    }
+
+    #[test]
+    fn test_metric_and_label_names() {
+        assert_eq!(metric_name("bank-process.txs", ""), "put_bank_process_txs");
+        assert_eq!(metric_name("votes", "_total"), "put_votes_total");
+        assert_eq!(label_name("slot-leader"), "slot_leader");
+        assert_eq!(label_name("0th"), "_0th");
+        assert_eq!(escape_label_value("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn test_render() {
+        let mut metrics = PrometheusMetrics::default();
+        metrics.add_point(
+            DataPoint::new("replay-slot")
+                .add_tag("leader", "abc")
+                .add_field_i64("slot", 7)
+                .add_field_str("version", "1.0")
+                .add_field_bool("full", true),
+            "host",
+        );
+        // Gauges hold the last value written
+        metrics.add_point(
+            DataPoint::new("replay-slot")
+                .add_tag("leader", "abc")
+                .add_field_i64("slot", 8),
+            "host",
+        );
+        for count in [3, 4] {
+            metrics.add_counter(
+                &CounterPoint {
+                    name: "tx-count",
+                    count,
+                    timestamp: std::time::UNIX_EPOCH,
+                },
+                "host",
+            );
+        }
+
+        assert_eq!(
+            metrics.render(),
+            "# TYPE put_replay_slot_full gauge\n\
+             put_replay_slot_full{host_id=\"host\",leader=\"abc\"} 1\n\
+             # TYPE put_replay_slot_slot gauge\n\
+             put_replay_slot_slot{host_id=\"host\",leader=\"abc\"} 8\n\
+             # TYPE put_tx_count_total counter\n\
+             put_tx_count_total{host_id=\"host\"} 7\n"
+        );
+    }
+
+    #[test]
+    fn test_agent_writes_counters() {
+        let writer =
+            Arc::new(PrometheusMetricsWriter::new("127.0.0.1:0".parse().unwrap()).unwrap());
+        let agent = MetricsAgent::new(writer.clone(), Duration::from_secs(10), 1000);
+        for bucket in 0..2 {
+            let counter = CounterPoint {
+                name: "counter",
+                count: 5,
+                timestamp: std::time::UNIX_EPOCH,
+            };
+            agent.submit_counter(counter, Level::Info, bucket);
+        }
+        agent.flush();
+
+        let text = writer.render();
+        assert!(text.starts_with("# TYPE put_counter_total counter\nput_counter_total{host_id="));
+        assert!(text.ends_with("} 10\n"));
+    }
+
+    #[test]
+    fn test_serve() {
+        let writer = PrometheusMetricsWriter::new("127.0.0.1:0".parse().unwrap()).unwrap();
+        writer.write(vec![DataPoint::new("point")
+            .add_field_i64("i", 1)
+            .to_owned()]);
+        assert!(writer.render().contains("put_point_i{host_id="));
+
+        // Serve a single scrape on a listener of our own, whose address is known
+        let metrics = writer.metrics.clone();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            PrometheusMetricsWriter::serve(stream, &metrics).unwrap();
+        });
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        server.join().unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with(&writer.render()));
+    }
+}
@@ -1,3 +1,4 @@
+use put_runtime::inline_ppl_memo::{self as ppl_memo_1_0, v3 as ppl_memo_3_0};
 use put_sdk::{
     account::{Account, AccountSharedData},
     bpf_loader_upgradeable::UpgradeableLoaderState,
@@ -8,12 +9,6 @@ use put_sdk::{
 mod ppl_token {
     put_sdk::declare_id!("PutToken11111111111111111111111111111111111");
 }
-mod ppl_memo_1_0 {
-    put_sdk::declare_id!("8jjXD6tToXM9zJiLG44t1P4nhrf8zrVLy3T39CvBEgaa");
-}
-mod ppl_memo_3_0 {
-    put_sdk::declare_id!("PutMemo111111111111111111111111111111111111");
-}
 mod ppl_associated_token_account {
     put_sdk::declare_id!("PutATA1111111111111111111111111111111111111");
 }
@@ -21,11 +16,11 @@ mod ppl_associated_token_account {
 static PPL_PROGRAMS: &[(Pubkey, &[u8])] = &[
     (ppl_token::ID, include_bytes!("programs/ppl_token.so")),
     (
-        ppl_memo_1_0::ID,
+        ppl_memo_3_0::ID,
         include_bytes!("programs/ppl_memo.so"),
     ),
     (
-        ppl_memo_3_0::ID,
+        ppl_memo_1_0::ID,
         include_bytes!("programs/ppl_memo.so"),
     ),
     (
@@ -59,17 +54,17 @@ static SPL_PROGRAMS: &[(Pubkey, Pubkey, &[u8])] = &[
         include_bytes!("programs/ppl_token.so"),
     ),
     (
-        ppl_memo_1_0::ID,
+        ppl_memo_3_0::ID,
         put_sdk::bpf_loader_upgradeable::ID,
         include_bytes!("programs/ppl_token.so"),
     ),
     (
-        ppl_memo_1_0::ID,
+        ppl_memo_3_0::ID,
         put_sdk::bpf_loader::ID,
         include_bytes!("programs/ppl_memo.so"),
     ),
     (
-        ppl_memo_3_0::ID,
+        ppl_memo_1_0::ID,
         put_sdk::bpf_loader::ID,
         include_bytes!("programs/ppl_memo.so"),
     ),
@@ -232,6 +232,8 @@ fn execute_transactions(
                         loaded_addresses: LoadedAddresses::default(),
                         return_data,
                         compute_units_consumed: Some(executed_units),
+                        pre_nft_holdings: None,
+                        post_nft_holdings: None,
                     };
 
                     Ok(ConfirmedTransactionWithStatusMeta {
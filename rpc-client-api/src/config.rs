@@ -144,6 +144,7 @@ pub enum RpcAccountIndex {
     PplTokenMint,
     PplTokenOwner,
     PplNftOwner,
+    PplNftMint,
     PplNameOwner,
 }
 
@@ -164,6 +165,63 @@ pub struct RpcProgramAccountsConfig {
     #[serde(flatten)]
     pub account_config: RpcAccountInfoConfig,
     pub with_context: Option<bool>,
+    #[serde(flatten)]
+    pub pagination: RpcPaginationConfig,
+}
+
+/// Pages through the accounts returned by a query, in pubkey order. Setting either field returns
+/// a page of accounts along with the cursor of the next page, if any.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcPaginationConfig {
+    /// Maximum number of accounts in the page, `MAX_ACCOUNTS_PAGE_LIMIT` if unset
+    pub limit: Option<usize>,
+    /// Cursor returned with the previous page. Every page following a cursor is read from the
+    /// slot of the first page.
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAccountsByOwnerConfig {
+    #[serde(flatten)]
+    pub account_config: RpcAccountInfoConfig,
+    #[serde(flatten)]
+    pub pagination: RpcPaginationConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcNftAccountsByMintConfig {
+    /// Only return the account holding the NFT with this token id
+    pub token_id: Option<u64>,
+    #[serde(flatten)]
+    pub account_config: RpcAccountInfoConfig,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcDomainExpiryFilter {
+    /// Return every domain, reporting its expiry state
+    Flag,
+    /// Leave out expired domains, reporting the expiry state of the others
+    ExcludeExpired,
+    /// Only return active domains, leaving out those within their grace period as well
+    ExcludeGracePeriod,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcDomainAccountsConfig {
+    /// How to treat domains past their expire time. Every domain is returned, without its expiry
+    /// state, if unset
+    pub expiry_filter: Option<RpcDomainExpiryFilter>,
+    /// Seconds after its expire time during which a domain is in its grace period, none if unset
+    pub grace_period: Option<u64>,
+    #[serde(flatten)]
+    pub account_config: RpcAccountInfoConfig,
+    #[serde(flatten)]
+    pub pagination: RpcPaginationConfig,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -24,6 +24,7 @@ pub const JSON_RPC_SERVER_ERROR_TRANSACTION_SIGNATURE_LEN_MISMATCH: i64 = -32013
 pub const JSON_RPC_SERVER_ERROR_BLOCK_STATUS_NOT_AVAILABLE_YET: i64 = -32014;
 pub const JSON_RPC_SERVER_ERROR_UNSUPPORTED_TRANSACTION_VERSION: i64 = -32015;
 pub const JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED: i64 = -32016;
+pub const JSON_RPC_SERVER_ERROR_RATE_LIMITED: i64 = -32017;
 
 #[derive(Error, Debug)]
 pub enum RpcCustomError {
@@ -65,6 +66,8 @@ pub enum RpcCustomError {
     UnsupportedTransactionVersion(u8),
     #[error("MinContextSlotNotReached")]
     MinContextSlotNotReached { context_slot: Slot },
+    #[error("RateLimited")]
+    RateLimited { method: String, retry_after_ms: u64 },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -79,6 +82,12 @@ pub struct MinContextSlotNotReachedErrorData {
     pub context_slot: Slot,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitedErrorData {
+    pub retry_after_ms: u64,
+}
+
 impl From<EncodeError> for RpcCustomError {
     fn from(err: EncodeError) -> Self {
         match err {
@@ -206,6 +215,14 @@ impl From<RpcCustomError> for Error {
                     context_slot,
                 })),
             },
+            RpcCustomError::RateLimited {
+                method,
+                retry_after_ms,
+            } => Self {
+                code: ErrorCode::ServerError(JSON_RPC_SERVER_ERROR_RATE_LIMITED),
+                message: format!("Rate limit exceeded for {method}, retry after {retry_after_ms}ms"),
+                data: Some(serde_json::json!(RateLimitedErrorData { retry_after_ms })),
+            },
         }
     }
 }
@@ -1,8 +1,8 @@
 #![allow(deprecated)]
 use {
     crate::version_req::VersionReq,
+    put_account_decoder::parse_token::is_valid_token_account_data,
     put_sdk::account::{AccountSharedData, ReadableAccount},
-    // ppl_token::{generic_token_account::GenericTokenAccount, state::Account},
     std::borrow::Cow,
     thiserror::Error,
 };
@@ -16,7 +16,7 @@ const MAX_DATA_BASE64_SIZE: usize = 172;
 pub enum RpcFilterType {
     DataSize(u64),
     Memcmp(Memcmp),
-    // TokenAccountState,
+    TokenAccountState,
 }
 
 impl RpcFilterType {
@@ -75,7 +75,7 @@ impl RpcFilterType {
                     }
                 }
             }
-            // RpcFilterType::TokenAccountState => Ok(()),
+            RpcFilterType::TokenAccountState => Ok(()),
         }
     }
 
@@ -83,7 +83,7 @@ impl RpcFilterType {
         match self {
             RpcFilterType::DataSize(size) => account.data().len() as u64 == *size,
             RpcFilterType::Memcmp(compare) => compare.bytes_match(account.data()),
-            // RpcFilterType::TokenAccountState => Account::valid_account_data(account.data()),
+            RpcFilterType::TokenAccountState => is_valid_token_account_data(account.data()),
         }
     }
 }
@@ -115,10 +115,13 @@ pub enum RpcRequest {
     SimulateTransaction,
     SignVote,
     GetNftAccountsByOwner,
+    GetNftAccountsByMint,
     GetDomainAccountsByOwner,
     GetDomainResolveAccount,
     GetDomainAccount,
     GetDomainAddressAccount,
+    ResolveDomain,
+    ReverseLookup,
 }
 
 #[allow(deprecated)]
@@ -196,10 +199,13 @@ impl fmt::Display for RpcRequest {
             RpcRequest::SimulateTransaction => "simulateTransaction",
             RpcRequest::SignVote => "signVote",
             RpcRequest::GetNftAccountsByOwner => "getNftAccountsByOwner",
+            RpcRequest::GetNftAccountsByMint => "getNftAccountsByMint",
             RpcRequest::GetDomainAccountsByOwner => "getDomainAccountsByOwner",
             RpcRequest::GetDomainResolveAccount => "getDomainResolveAccount",
             RpcRequest::GetDomainAccount => "getDomainAccount",
             RpcRequest::GetDomainAddressAccount => "getDomainAddressAccount",
+            RpcRequest::ResolveDomain => "resolveDomain",
+            RpcRequest::ReverseLookup => "reverseLookup",
         };
 
         write!(f, "{method}")
@@ -212,6 +218,7 @@ pub const MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS_SLOT_RANGE: u64 = 10_000;
 pub const MAX_GET_CONFIRMED_BLOCKS_RANGE: u64 = 500_000;
 pub const MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT: usize = 1_000;
 pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+pub const MAX_ACCOUNTS_PAGE_LIMIT: usize = 1_000;
 pub const NUM_LARGEST_ACCOUNTS: usize = 20;
 pub const MAX_GET_PROGRAM_ACCOUNT_FILTERS: usize = 4;
 pub const MAX_GET_SLOT_LEADERS: usize = 5000;
@@ -36,6 +36,33 @@ impl<T> OptionalContext<T> {
     }
 }
 
+/// Wrapper for rpc return types of methods that can return their accounts a page at a time.
+/// Accounts are only returned as a page when one is requested, so that pagination does not break
+/// backwards compatibility.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OptionalPage<T> {
+    Page(RpcAccountsPage<T>),
+    NoPage(Vec<T>),
+}
+
+impl<T> OptionalPage<T> {
+    pub fn parse_value(self) -> Vec<T> {
+        match self {
+            Self::Page(page) => page.accounts,
+            Self::NoPage(accounts) => accounts,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAccountsPage<T> {
+    pub accounts: Vec<T>,
+    /// Cursor of the next page, unless this is the last one
+    pub next_cursor: Option<String>,
+}
+
 pub type RpcResult<T> = client_error::Result<Response<T>>;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -194,6 +221,44 @@ pub struct RpcKeyedAccount {
     pub account: UiAccount,
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcDomainExpiryState {
+    Active,
+    /// Past its expire time, but still within the requested grace period
+    GracePeriod,
+    Expired,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcDomainKeyedAccount {
+    pub pubkey: String,
+    pub account: UiAccount,
+    /// Expiry state of the domain, or of the parent domain of a domain resolve account. Only
+    /// reported when an expiry filter is requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry_state: Option<RpcDomainExpiryState>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcResolvedDomain {
+    /// The resolved name, e.g. `pay.alice.put`
+    pub name: String,
+    pub top_domain_account: String,
+    pub domain_account: String,
+    /// Owner of the domain account
+    pub owner: String,
+    /// The domain resolve account holding the value, if one exists for this name
+    pub resolve_account: Option<String>,
+    /// The resolved value, base-58 encoded, if it has been set
+    pub value: Option<String>,
+    /// Domain expire time as a unix timestamp
+    pub expire_time: UnixTimestamp,
+    pub expired: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SlotInfo {
     pub slot: Slot,
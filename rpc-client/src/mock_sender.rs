@@ -234,6 +234,8 @@ impl RpcSender for MockSender {
                             loaded_addresses: OptionSerializer::Skip,
                             return_data: OptionSerializer::Skip,
                             compute_units_consumed: OptionSerializer::Skip,
+                            pre_nft_holdings: OptionSerializer::Skip,
+                            post_nft_holdings: OptionSerializer::Skip,
                         }),
                 },
                 block_time: Some(1628633791),
@@ -39,7 +39,10 @@ use {
         },
         config::{RpcAccountInfoConfig, *},
         filter::{self, RpcFilterType},
-        request::{RpcError, RpcRequest, RpcResponseErrorData, TokenAccountsFilter},
+        request::{
+            RpcError, RpcRequest, RpcResponseErrorData, TokenAccountsFilter,
+            MAX_ACCOUNTS_PAGE_LIMIT,
+        },
         response::*,
     },
     put_sdk::{
@@ -4417,6 +4420,7 @@ impl RpcClient {
     ///         min_context_slot: Some(1234),
     ///     },
     ///     with_context: Some(false),
+    ///     ..RpcProgramAccountsConfig::default()
     /// };
     /// let accounts = rpc_client.get_program_accounts_with_config(
     ///     &alice.pubkey(),
@@ -4451,6 +4455,56 @@ impl RpcClient {
         parse_keyed_accounts(accounts, RpcRequest::GetProgramAccounts)
     }
 
+    /// Returns a page of the accounts owned by the provided program pubkey, in pubkey order,
+    /// along with the cursor of the next page unless this is the last one.
+    ///
+    /// Request the next page by setting `config.pagination.cursor` to the returned cursor. Every
+    /// page following a cursor is read from the same slot as the first one. Pages hold
+    /// [`MAX_ACCOUNTS_PAGE_LIMIT`] accounts unless `config.pagination.limit` is set.
+    ///
+    /// # RPC Reference
+    ///
+    /// This method is built on the [`getProgramAccounts`] RPC method.
+    ///
+    /// [`getProgramAccounts`]: https://docs.put.com/developing/clients/jsonrpc-api#getprogramaccounts
+    pub async fn get_program_accounts_page(
+        &self,
+        pubkey: &Pubkey,
+        mut config: RpcProgramAccountsConfig,
+    ) -> ClientResult<(Vec<(Pubkey, Account)>, Option<String>)> {
+        let commitment = config
+            .account_config
+            .commitment
+            .unwrap_or_else(|| self.commitment());
+        let commitment = self.maybe_map_commitment(commitment).await?;
+        config.account_config.commitment = Some(commitment);
+        if let Some(filters) = config.filters {
+            config.filters = Some(self.maybe_map_filters(filters).await?);
+        }
+        if config.pagination.limit.is_none() && config.pagination.cursor.is_none() {
+            config.pagination.limit = Some(MAX_ACCOUNTS_PAGE_LIMIT);
+        }
+
+        let page = self
+            .send::<OptionalContext<OptionalPage<RpcKeyedAccount>>>(
+                RpcRequest::GetProgramAccounts,
+                json!([pubkey.to_string(), config]),
+            )
+            .await?
+            .parse_value();
+        let (accounts, next_cursor) = match page {
+            OptionalPage::Page(RpcAccountsPage {
+                accounts,
+                next_cursor,
+            }) => (accounts, next_cursor),
+            OptionalPage::NoPage(accounts) => (accounts, None),
+        };
+        Ok((
+            parse_keyed_accounts(accounts, RpcRequest::GetProgramAccounts)?,
+            next_cursor,
+        ))
+    }
+
     /// Returns the stake minimum delegation, in lamports.
     ///
     /// # RPC Reference
@@ -4982,6 +5036,69 @@ impl RpcClient {
             .await
     }
 
+    /// Returns all NFT accounts minted from `mint`.
+    pub async fn get_nft_accounts_by_mint(
+        &self,
+        mint: &Pubkey,
+    ) -> ClientResult<Vec<RpcKeyedAccount>> {
+        Ok(self
+            .get_nft_accounts_by_mint_with_config(
+                mint,
+                RpcNftAccountsByMintConfig {
+                    token_id: None,
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::JsonParsed),
+                        commitment: Some(self.commitment()),
+                        ..RpcAccountInfoConfig::default()
+                    },
+                },
+            )
+            .await?
+            .value)
+    }
+
+    /// Returns the account currently holding the NFT `token_id` of `mint`, if any.
+    pub async fn get_nft_account_by_token_id(
+        &self,
+        mint: &Pubkey,
+        token_id: u64,
+    ) -> ClientResult<Option<RpcKeyedAccount>> {
+        Ok(self
+            .get_nft_accounts_by_mint_with_config(
+                mint,
+                RpcNftAccountsByMintConfig {
+                    token_id: Some(token_id),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::JsonParsed),
+                        commitment: Some(self.commitment()),
+                        ..RpcAccountInfoConfig::default()
+                    },
+                },
+            )
+            .await?
+            .value
+            .pop())
+    }
+
+    pub async fn get_nft_accounts_by_mint_with_config(
+        &self,
+        mint: &Pubkey,
+        mut config: RpcNftAccountsByMintConfig,
+    ) -> RpcResult<Vec<RpcKeyedAccount>> {
+        let commitment = config
+            .account_config
+            .commitment
+            .unwrap_or_else(|| self.commitment());
+        let commitment = self.maybe_map_commitment(commitment).await?;
+        config.account_config.commitment = Some(commitment);
+
+        self.send(
+            RpcRequest::GetNftAccountsByMint,
+            json!([mint.to_string(), config]),
+        )
+        .await
+    }
+
     pub async fn get_domain_accounts_by_owner(
         &self,
         owner: &Pubkey,
@@ -5015,6 +5132,32 @@ impl RpcClient {
             .await
     }
 
+    /// Returns the domain accounts of `owner` and their domain resolve accounts.
+    ///
+    /// With `config.expiry_filter` set, every account also carries the expiry state of its
+    /// domain against the bank clock, and expired domains can be left out.
+    pub async fn get_domain_accounts_by_owner_with_config(
+        &self,
+        owner: &Pubkey,
+        mut config: RpcDomainAccountsConfig,
+    ) -> RpcResult<Vec<RpcDomainKeyedAccount>> {
+        let commitment = config
+            .account_config
+            .commitment
+            .unwrap_or_else(|| self.commitment());
+        let commitment = self.maybe_map_commitment(commitment).await?;
+        config.account_config.commitment = Some(commitment);
+        if config.account_config.encoding.is_none() {
+            config.account_config.encoding = Some(UiAccountEncoding::JsonParsed);
+        }
+
+        self.send(
+            RpcRequest::GetDomainAccountsByOwner,
+            json!([owner.to_string(), config]),
+        )
+        .await
+    }
+
     pub async fn get_domain_resolve_account(
         &self,
         domain_account: &Pubkey,
@@ -5234,6 +5377,53 @@ impl RpcClient {
             })?
     }
 
+    /// Resolves a human-readable name such as `alice.put` through its top domain, domain and
+    /// domain resolve accounts.
+    ///
+    /// Returns `None` if the domain does not exist. Expired domains are still returned, with
+    /// `expired` set.
+    pub async fn resolve_domain(&self, name: &str) -> ClientResult<Option<RpcResolvedDomain>> {
+        Ok(self
+            .resolve_domain_with_commitment(name, self.commitment())
+            .await?
+            .value)
+    }
+
+    pub async fn resolve_domain_with_commitment(
+        &self,
+        name: &str,
+        commitment_config: CommitmentConfig,
+    ) -> RpcResult<Option<RpcResolvedDomain>> {
+        self.send(
+            RpcRequest::ResolveDomain,
+            json!([name, self.maybe_map_commitment(commitment_config).await?]),
+        )
+        .await
+    }
+
+    /// Returns the unexpired names whose records resolve to `address`, sorted by name.
+    pub async fn reverse_lookup(&self, address: &Pubkey) -> ClientResult<Vec<RpcResolvedDomain>> {
+        Ok(self
+            .reverse_lookup_with_commitment(address, self.commitment())
+            .await?
+            .value)
+    }
+
+    pub async fn reverse_lookup_with_commitment(
+        &self,
+        address: &Pubkey,
+        commitment_config: CommitmentConfig,
+    ) -> RpcResult<Vec<RpcResolvedDomain>> {
+        self.send(
+            RpcRequest::ReverseLookup,
+            json!([
+                address.to_string(),
+                self.maybe_map_commitment(commitment_config).await?
+            ]),
+        )
+        .await
+    }
+
     pub async fn get_token_largest_accounts(
         &self,
         mint: &Pubkey,
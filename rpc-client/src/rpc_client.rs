@@ -3621,6 +3621,7 @@ impl RpcClient {
     ///         min_context_slot: Some(1234),
     ///     },
     ///     with_context: Some(false),
+    ///     ..RpcProgramAccountsConfig::default()
     /// };
     /// let accounts = rpc_client.get_program_accounts_with_config(
     ///     &alice.pubkey(),
@@ -3636,6 +3637,61 @@ impl RpcClient {
         self.invoke((self.rpc_client.as_ref()).get_program_accounts_with_config(pubkey, config))
     }
 
+    /// Returns a page of the accounts owned by the provided program pubkey, in pubkey order,
+    /// along with the cursor of the next page unless this is the last one.
+    ///
+    /// Request the next page by setting `config.pagination.cursor` to the returned cursor. Every
+    /// page following a cursor is read from the same slot as the first one. Pages hold
+    /// [`MAX_ACCOUNTS_PAGE_LIMIT`] accounts unless `config.pagination.limit` is set.
+    ///
+    /// [`MAX_ACCOUNTS_PAGE_LIMIT`]: put_rpc_client_api::request::MAX_ACCOUNTS_PAGE_LIMIT
+    ///
+    /// # RPC Reference
+    ///
+    /// This method is built on the [`getProgramAccounts`] RPC method.
+    ///
+    /// [`getProgramAccounts`]: https://docs.put.com/developing/clients/jsonrpc-api#getprogramaccounts
+    pub fn get_program_accounts_page(
+        &self,
+        pubkey: &Pubkey,
+        config: RpcProgramAccountsConfig,
+    ) -> ClientResult<(Vec<(Pubkey, Account)>, Option<String>)> {
+        self.invoke((self.rpc_client.as_ref()).get_program_accounts_page(pubkey, config))
+    }
+
+    /// Returns an iterator over the accounts owned by the provided program pubkey, in pubkey
+    /// order.
+    ///
+    /// Accounts are requested a page at a time as the iterator advances, following the cursor
+    /// of each page, so that all of them are read from the same slot. The iterator ends after
+    /// the last page, or after yielding the error of a failed request.
+    pub fn get_program_accounts_iter(
+        &self,
+        pubkey: &Pubkey,
+        config: RpcProgramAccountsConfig,
+    ) -> impl Iterator<Item = ClientResult<(Pubkey, Account)>> + '_ {
+        let pubkey = *pubkey;
+        // The config of the next page to request, if any
+        let mut next_config = Some(config);
+        let mut accounts = Vec::new().into_iter();
+        std::iter::from_fn(move || loop {
+            if let Some(account) = accounts.next() {
+                return Some(Ok(account));
+            }
+            let mut config = next_config.take()?;
+            match self.get_program_accounts_page(&pubkey, config.clone()) {
+                Ok((page, next_cursor)) => {
+                    accounts = page.into_iter();
+                    if next_cursor.is_some() {
+                        config.pagination.cursor = next_cursor;
+                        next_config = Some(config);
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        })
+    }
+
     /// Returns the stake minimum delegation, in lamports.
     ///
     /// # RPC Reference
@@ -3884,6 +3940,31 @@ impl RpcClient {
         )
     }
 
+    /// Returns all NFT accounts minted from `mint`.
+    pub fn get_nft_accounts_by_mint(&self, mint: &Pubkey) -> ClientResult<Vec<RpcKeyedAccount>> {
+        self.invoke(self.rpc_client.get_nft_accounts_by_mint(mint))
+    }
+
+    /// Returns the account currently holding the NFT `token_id` of `mint`, if any.
+    pub fn get_nft_account_by_token_id(
+        &self,
+        mint: &Pubkey,
+        token_id: u64,
+    ) -> ClientResult<Option<RpcKeyedAccount>> {
+        self.invoke(self.rpc_client.get_nft_account_by_token_id(mint, token_id))
+    }
+
+    pub fn get_nft_accounts_by_mint_with_config(
+        &self,
+        mint: &Pubkey,
+        config: RpcNftAccountsByMintConfig,
+    ) -> RpcResult<Vec<RpcKeyedAccount>> {
+        self.invoke(
+            self.rpc_client
+                .get_nft_accounts_by_mint_with_config(mint, config),
+        )
+    }
+
     pub fn get_domain_accounts_by_owner(
         &self,
         owner: &Pubkey,
@@ -3894,6 +3975,19 @@ impl RpcClient {
         )
     }
 
+    /// Returns the domain accounts of `owner` and their domain resolve accounts, flagged or
+    /// filtered by the expiry state of their domain as set in `config`.
+    pub fn get_domain_accounts_by_owner_with_config(
+        &self,
+        owner: &Pubkey,
+        config: RpcDomainAccountsConfig,
+    ) -> RpcResult<Vec<RpcDomainKeyedAccount>> {
+        self.invoke(
+            self.rpc_client
+                .get_domain_accounts_by_owner_with_config(owner, config),
+        )
+    }
+
     pub fn get_domain_resolve_account(
         &self,
         domain_account: &Pubkey,
@@ -3925,6 +4019,42 @@ impl RpcClient {
         )
     }
 
+    /// Resolves a human-readable name such as `alice.put` through its top domain, domain and
+    /// domain resolve accounts.
+    ///
+    /// Returns `None` if the domain does not exist. Expired domains are still returned, with
+    /// `expired` set.
+    pub fn resolve_domain(&self, name: &str) -> ClientResult<Option<RpcResolvedDomain>> {
+        self.invoke(self.rpc_client.resolve_domain(name))
+    }
+
+    pub fn resolve_domain_with_commitment(
+        &self,
+        name: &str,
+        commitment_config: CommitmentConfig,
+    ) -> RpcResult<Option<RpcResolvedDomain>> {
+        self.invoke(
+            self.rpc_client
+                .resolve_domain_with_commitment(name, commitment_config),
+        )
+    }
+
+    /// Returns the unexpired names whose records resolve to `address`, sorted by name.
+    pub fn reverse_lookup(&self, address: &Pubkey) -> ClientResult<Vec<RpcResolvedDomain>> {
+        self.invoke(self.rpc_client.reverse_lookup(address))
+    }
+
+    pub fn reverse_lookup_with_commitment(
+        &self,
+        address: &Pubkey,
+        commitment_config: CommitmentConfig,
+    ) -> RpcResult<Vec<RpcResolvedDomain>> {
+        self.invoke(
+            self.rpc_client
+                .reverse_lookup_with_commitment(address, commitment_config),
+        )
+    }
+
     pub fn get_token_largest_accounts(
         &self,
         mint: &Pubkey,
@@ -4438,6 +4568,7 @@ mod tests {
                             min_context_slot: None,
                         },
                         with_context: None,
+                        ..RpcProgramAccountsConfig::default()
                     },
                 )
                 .unwrap();
@@ -4472,6 +4603,7 @@ mod tests {
                             min_context_slot: None,
                         },
                         with_context: Some(true),
+                        ..RpcProgramAccountsConfig::default()
                     },
                 )
                 .unwrap();
@@ -3,7 +3,8 @@ use {
     put_account_decoder::{
         parse_account_data::AccountAdditionalData, 
         parse_token::{
-            get_token_account_mint,ppl_token_native_mint,ppl_token_native_min_info,ppl_token_native_mint_program_id
+            get_token_account_mint,ppl_token_native_mint,ppl_token_native_min_info,ppl_token_native_mint_program_id,
+            unpack_token_mint,
         }, UiAccount,
         UiAccountData, UiAccountEncoding,
     },
@@ -13,7 +14,7 @@ use {
         account::{AccountSharedData, ReadableAccount},
         pubkey::Pubkey,
     },
-    ppl_token::{put_program::program_pack::Pack, state::Mint,state::MintMeta},
+    ppl_token::{put_program::program_pack::Pack, state::MintMeta},
     std::{collections::HashMap, sync::Arc},
 };
 
@@ -29,6 +30,7 @@ pub fn get_parsed_token_account(
             ppl_token_symbol: Some(symbol),
             ppl_token_name: Some(name),
             ppl_token_icon: Some(icon),
+            ppl_token_account_lamports: Some(account.lamports()),
         });
 
     UiAccount::encode(
@@ -65,7 +67,8 @@ where
                     ppl_token_decimals,
                     ppl_token_symbol,
                     ppl_token_name,
-                    ppl_token_icon
+                    ppl_token_icon,
+                    ppl_token_account_lamports: Some(account.lamports()),
                 }
             }else{
                 AccountAdditionalData { 
@@ -73,6 +76,7 @@ where
                     ppl_token_symbol: None,
                     ppl_token_name: None,
                     ppl_token_icon: None, 
+                    ppl_token_account_lamports: Some(account.lamports()),
                 }
             }
         });
@@ -99,13 +103,20 @@ where
 /// program_id) and decimals
 pub fn get_mint_owner_and_decimals(bank: &Arc<Bank>, mint: &Pubkey) -> Result<(Pubkey, u8,String,String,String)> {
     if mint == &ppl_token_native_mint() {
-
-        let mint_info_account = bank.get_account( &ppl_token_native_min_info()).ok_or_else(|| {
-            Error::invalid_params("Invalid param: could not find mint".to_string())
-        })?;
-        let symbol = get_mint_symbol(mint_info_account.data())?;
-        let name = get_mint_name(mint_info_account.data())?;
-        let icon = get_mint_icon(mint_info_account.data())?;
+        // The native mint metadata is installed by the bank, fall back to the ppl-token constants
+        // on banks that have not been reconfigured yet so wrapped PUT accounts stay queryable
+        let (symbol, name, icon) = match bank.get_account(&ppl_token_native_min_info()) {
+            Some(mint_info_account) => (
+                get_mint_symbol(mint_info_account.data())?,
+                get_mint_name(mint_info_account.data())?,
+                get_mint_icon(mint_info_account.data())?,
+            ),
+            None => (
+                ppl_token::native_mint::SYMBOL.to_string(),
+                ppl_token::native_mint::NAME.to_string(),
+                ppl_token::native_mint::ICON.to_string(),
+            ),
+        };
 
         Ok((
             ppl_token_native_mint_program_id(),
@@ -138,8 +149,8 @@ pub fn get_mint_owner_and_decimals(bank: &Arc<Bank>, mint: &Pubkey) -> Result<(P
 }
 
 fn get_mint_decimals(data: &[u8]) -> Result<u8> {
-    Mint::unpack(data)
-        .map_err(|_| {
+    unpack_token_mint(data)
+        .ok_or_else(|| {
             Error::invalid_params("Invalid param: Token mint could not be unpacked".to_string())
         })
         .map(|mint| mint.decimals)
@@ -2,32 +2,41 @@
 
 use ppl_name::instruction::get_domain;
 use ppl_name::state::{get_seeds_and_key, AccountType};
-use put_account_decoder::parse_name::{is_known_name_id, NameAccountType};
-use put_account_decoder::parse_nft::is_known_nft_token_id;
-use put_account_decoder::UiAccountData;
+use put_account_decoder::parse_name::{
+    is_known_name_id, parse_domain_account, parse_domain_resolve_account, parse_name,
+    NameAccountType, UiDomainAccount,
+};
+use put_account_decoder::parse_nft::{
+    is_known_nft_token_id, parse_nft, TokenAccountType as NftAccountType,
+};
+use put_account_decoder::{parse_account_data::ParsedAccount, UiAccountData};
 use put_runtime::inline_name;
 use put_runtime::inline_name::{
     DOMAIN_ACCOUNT_OWNER_OFFSET, DOMAIN_ACCOUNT_TYPE_OFFSET, DOMAIN_RESOLVE_ACCOUNT_PARENT_OFFSET,
     DOMAIN_RESOLVE_ACCOUNT_TYPE_OFFSET,
 };
-use put_runtime::inline_nft::{NFT_ACCOUNT_LENGTH, NFT_ACCOUNT_OWNER_OFFSET};
+use put_runtime::inline_nft::{
+    NFT_ACCOUNT_LENGTH, NFT_ACCOUNT_MINT_OFFSET, NFT_ACCOUNT_OWNER_OFFSET,
+};
 use put_sdk::hash::hashv;
 use {
     crate::{
         max_slots::MaxSlots, optimistically_confirmed_bank_tracker::OptimisticallyConfirmedBank,
         parsed_token_accounts::*, rpc_cache::LargestAccountsCache, rpc_health::*,
+        rpc_rate_limiter::RpcRateLimitConfig,
     },
     base64::{prelude::BASE64_STANDARD, Engine},
     bincode::{config::Options, serialize},
     crossbeam_channel::{unbounded, Receiver, Sender},
     jsonrpc_core::{futures::future, types::error, BoxFuture, Error, Metadata, Result},
     jsonrpc_derive::rpc,
-    ppl_token::{
-        put_program::program_pack::Pack,
-        state::{Account as TokenAccount, Mint},
-    },
+    ppl_token::{put_program::program_pack::Pack, state::Account as TokenAccount},
     put_account_decoder::{
-        parse_token::{is_known_ppl_token_id, token_amount_to_ui_amount, UiTokenAmount},
+        parse_token::{
+            is_known_ppl_token_id, ppl_token_2022_id, token_account_balance,
+            token_amount_to_ui_amount, unpack_token_account, unpack_token_mint, UiTokenAmount,
+        },
+        parse_token_extension::{ACCOUNTTYPE_ACCOUNT, ACCOUNT_TYPE_OFFSET},
         UiAccount, UiAccountEncoding, UiDataSliceConfig, MAX_BASE58_BYTES,
     },
     put_client::connection_cache::{ConnectionCache, Protocol},
@@ -49,7 +58,7 @@ use {
         deprecated_config::*,
         filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
         request::{
-            TokenAccountsFilter, DELINQUENT_VALIDATOR_SLOT_DISTANCE,
+            TokenAccountsFilter, DELINQUENT_VALIDATOR_SLOT_DISTANCE, MAX_ACCOUNTS_PAGE_LIMIT,
             MAX_GET_CONFIRMED_BLOCKS_RANGE, MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT,
             MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS_SLOT_RANGE, MAX_GET_PROGRAM_ACCOUNT_FILTERS,
             MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS, MAX_GET_SLOT_LEADERS, MAX_MULTIPLE_ACCOUNTS,
@@ -59,7 +68,7 @@ use {
     },
     put_runtime::{
         accounts::AccountAddressFilter,
-        accounts_index::{AccountIndex, AccountSecondaryIndexes, IndexKey, ScanConfig},
+        accounts_index::{AccountIndex, AccountSecondaryIndexes, IndexKey, ScanConfig, ScanPage},
         bank::{Bank, TransactionSimulationResult},
         bank_forks::BankForks,
         commitment::{BlockCommitmentArray, BlockCommitmentCache, CommitmentSlots},
@@ -97,8 +106,7 @@ use {
         tpu_info::NullTpuInfo,
     },
     put_stake_program,
-    put_storage_bigtable::Error as StorageError,
-    put_storage_hbase::Error as HBaseStorageError,
+    put_storage_interface::{Error as StorageError, LongTermStorage},
     put_streamer::socket::SocketAddrSpace,
     put_transaction_status::{
         BlockEncodingOptions, ConfirmedBlock, ConfirmedTransactionStatusWithSignature,
@@ -112,7 +120,7 @@ use {
         cmp::{max, min},
         collections::{HashMap, HashSet},
         convert::TryFrom,
-        net::SocketAddr,
+        net::{IpAddr, SocketAddr},
         str::FromStr,
         sync::{
             atomic::{AtomicBool, AtomicU64, Ordering},
@@ -160,6 +168,7 @@ pub struct JsonRpcConfig {
     pub obsolete_v1_7_api: bool,
     pub rpc_scan_and_fix_roots: bool,
     pub max_request_body_size: Option<usize>,
+    pub rate_limit: Option<RpcRateLimitConfig>,
 }
 
 impl JsonRpcConfig {
@@ -197,6 +206,8 @@ pub struct RpcHBaseConfig {
     pub enable_hbase_ledger_upload: bool,
     pub enable_rpc_hbase_ledger_storage: bool,
     pub hbase_rpc_url: String,
+    pub hbase_namespace: String,
+    pub timeout: Option<Duration>,
 }
 
 impl Default for RpcHBaseConfig {
@@ -205,6 +216,8 @@ impl Default for RpcHBaseConfig {
             enable_hbase_ledger_upload: false,
             enable_rpc_hbase_ledger_storage: false,
             hbase_rpc_url: String::default(),
+            hbase_namespace: put_storage_hbase::DEFAULT_HBASE_NAMESPACE.to_string(),
+            timeout: None,
         }
     }
 }
@@ -222,8 +235,7 @@ pub struct JsonRpcRequestProcessor {
     cluster_info: Arc<ClusterInfo>,
     genesis_hash: Hash,
     transaction_sender: Arc<Mutex<Sender<TransactionInfo>>>,
-    bigtable_ledger_storage: Option<put_storage_bigtable::LedgerStorage>,
-    hbase_ledger_storage: Option<put_storage_hbase::LedgerStorage>,
+    long_term_storage: Option<Arc<dyn LongTermStorage>>,
     optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
     largest_accounts_cache: Arc<RwLock<LargestAccountsCache>>,
     max_slots: Arc<MaxSlots>,
@@ -231,10 +243,21 @@ pub struct JsonRpcRequestProcessor {
     max_complete_transaction_status_slot: Arc<AtomicU64>,
     max_complete_rewards_slot: Arc<AtomicU64>,
     prioritization_fee_cache: Arc<PrioritizationFeeCache>,
+    client_ip: Option<IpAddr>,
 }
 impl Metadata for JsonRpcRequestProcessor {}
 
 impl JsonRpcRequestProcessor {
+    /// Address of the client that sent the request being processed, when known
+    pub(crate) fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
+
+    pub(crate) fn with_client_ip(mut self, client_ip: Option<IpAddr>) -> Self {
+        self.client_ip = client_ip;
+        self
+    }
+
     fn get_bank_with_config(&self, config: RpcContextConfig) -> Result<Arc<Bank>> {
         let RpcContextConfig {
             commitment,
@@ -252,6 +275,51 @@ impl JsonRpcRequestProcessor {
         Ok(bank)
     }
 
+    /// Get the bank to read a page of accounts from, and the page itself, unless no page is
+    /// requested
+    ///
+    /// A page following a cursor is read from the slot of the previous page while that slot is
+    /// still on the fork of the requested commitment. Once it is not, e.g. because the root
+    /// advanced past it and it was pruned, the page is read from the bank at the requested
+    /// commitment instead: pages then keep following the pubkey order, but accounts created,
+    /// closed or changed between the two slots may be missing from or differ across pages.
+    fn get_bank_and_accounts_page(
+        &self,
+        config: RpcContextConfig,
+        pagination: RpcPaginationConfig,
+    ) -> Result<(Arc<Bank>, Option<AccountsPage>)> {
+        let RpcPaginationConfig { limit, cursor } = pagination;
+        if limit.is_none() && cursor.is_none() {
+            return Ok((self.get_bank_with_config(config)?, None));
+        }
+        let limit = limit.unwrap_or(MAX_ACCOUNTS_PAGE_LIMIT);
+        if limit == 0 || limit > MAX_ACCOUNTS_PAGE_LIMIT {
+            return Err(Error::invalid_params(format!(
+                "Invalid limit; max {MAX_ACCOUNTS_PAGE_LIMIT}"
+            )));
+        }
+        let (bank, after) = match cursor {
+            Some(cursor) => {
+                let (slot, after) = decode_accounts_cursor(&cursor)?;
+                let commitment_bank = self.get_bank_with_config(config)?;
+                if slot > commitment_bank.slot() {
+                    return Err(Error::invalid_params(format!(
+                        "Cursor slot {slot} has not reached the requested commitment"
+                    )));
+                }
+                let cursor_bank = if commitment_bank.ancestors.contains_key(&slot) {
+                    self.bank_forks.read().unwrap().get(slot)
+                } else {
+                    None
+                };
+                (cursor_bank.unwrap_or(commitment_bank), Some(after))
+            }
+            None => (self.get_bank_with_config(config)?, None),
+        };
+        let slot = bank.slot();
+        Ok((bank, Some(AccountsPage { slot, after, limit })))
+    }
+
     #[allow(deprecated)]
     fn bank(&self, commitment: Option<CommitmentConfig>) -> Arc<Bank> {
         debug!("RPC commitment_config: {:?}", commitment);
@@ -330,8 +398,7 @@ impl JsonRpcRequestProcessor {
         health: Arc<RpcHealth>,
         cluster_info: Arc<ClusterInfo>,
         genesis_hash: Hash,
-        bigtable_ledger_storage: Option<put_storage_bigtable::LedgerStorage>,
-        hbase_ledger_storage: Option<put_storage_hbase::LedgerStorage>,
+        long_term_storage: Option<Arc<dyn LongTermStorage>>,
         optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
         largest_accounts_cache: Arc<RwLock<LargestAccountsCache>>,
         max_slots: Arc<MaxSlots>,
@@ -353,8 +420,7 @@ impl JsonRpcRequestProcessor {
                 cluster_info,
                 genesis_hash,
                 transaction_sender: Arc::new(Mutex::new(sender)),
-                bigtable_ledger_storage,
-                hbase_ledger_storage,
+                long_term_storage,
                 optimistically_confirmed_bank,
                 largest_accounts_cache,
                 max_slots,
@@ -362,6 +428,7 @@ impl JsonRpcRequestProcessor {
                 max_complete_transaction_status_slot,
                 max_complete_rewards_slot,
                 prioritization_fee_cache,
+                client_ip: None,
             },
             receiver,
         )
@@ -425,8 +492,7 @@ impl JsonRpcRequestProcessor {
             cluster_info,
             genesis_hash,
             transaction_sender: Arc::new(Mutex::new(sender)),
-            bigtable_ledger_storage: None,
-            hbase_ledger_storage: None,
+            long_term_storage: None,
             optimistically_confirmed_bank: Arc::new(RwLock::new(OptimisticallyConfirmedBank {
                 bank: bank.clone(),
             })),
@@ -436,6 +502,7 @@ impl JsonRpcRequestProcessor {
             max_complete_transaction_status_slot: Arc::new(AtomicU64::default()),
             max_complete_rewards_slot: Arc::new(AtomicU64::default()),
             prioritization_fee_cache: Arc::new(PrioritizationFeeCache::default()),
+            client_ip: None,
         }
     }
 
@@ -499,28 +566,46 @@ impl JsonRpcRequestProcessor {
         config: Option<RpcAccountInfoConfig>,
         mut filters: Vec<RpcFilterType>,
         with_context: bool,
-    ) -> Result<OptionalContext<Vec<RpcKeyedAccount>>> {
+        pagination: RpcPaginationConfig,
+    ) -> Result<OptionalContext<OptionalPage<RpcKeyedAccount>>> {
         let RpcAccountInfoConfig {
             encoding,
             data_slice: data_slice_config,
             commitment,
             min_context_slot,
         } = config.unwrap_or_default();
-        let bank = self.get_bank_with_config(RpcContextConfig {
-            commitment,
-            min_context_slot,
-        })?;
+        let (bank, page) = self.get_bank_and_accounts_page(
+            RpcContextConfig {
+                commitment,
+                min_context_slot,
+            },
+            pagination,
+        )?;
         let encoding = encoding.unwrap_or(UiAccountEncoding::Binary);
         optimize_filters(&mut filters);
-        let keyed_accounts = {
+        let scan_config = AccountsPage::scan_config(page);
+        let mut keyed_accounts = {
             if let Some(owner) = get_ppl_token_owner_filter(program_id, &filters) {
-                self.get_filtered_ppl_token_accounts_by_owner(&bank, program_id, &owner, filters)?
+                self.get_filtered_ppl_token_accounts_by_owner(
+                    &bank,
+                    program_id,
+                    &owner,
+                    filters,
+                    &scan_config,
+                )?
             } else if let Some(mint) = get_ppl_token_mint_filter(program_id, &filters) {
-                self.get_filtered_ppl_token_accounts_by_mint(&bank, program_id, &mint, filters)?
+                self.get_filtered_ppl_token_accounts_by_mint(
+                    &bank,
+                    program_id,
+                    &mint,
+                    filters,
+                    &scan_config,
+                )?
             } else {
-                self.get_filtered_program_accounts(&bank, program_id, filters)?
+                self.get_filtered_program_accounts(&bank, program_id, filters, &scan_config)?
             }
         };
+        let next_cursor = AccountsPage::truncate(page, &mut keyed_accounts);
         let accounts = if is_known_ppl_token_id(program_id)
             && encoding == UiAccountEncoding::JsonParsed
         {
@@ -536,6 +621,7 @@ impl JsonRpcRequestProcessor {
                 })
                 .collect::<Result<Vec<_>>>()?
         };
+        let accounts = AccountsPage::wrap(page, accounts, next_cursor);
         Ok(match with_context {
             true => OptionalContext::Context(new_response(&bank, accounts)),
             false => OptionalContext::NoContext(accounts),
@@ -563,7 +649,7 @@ impl JsonRpcRequestProcessor {
         // Rewards for this epoch are found in the first confirmed block of the next epoch
         let first_slot_in_epoch = epoch_schedule.get_first_slot_in_epoch(epoch.saturating_add(1));
         if first_slot_in_epoch < first_available_block {
-            if self.bigtable_ledger_storage.is_some() || self.hbase_ledger_storage.is_some() {
+            if self.long_term_storage.is_some() {
                 return Err(RpcCustomError::LongTermStorageSlotSkipped {
                     slot: first_slot_in_epoch,
                 }
@@ -1077,21 +1163,11 @@ impl JsonRpcRequestProcessor {
         Ok(())
     }
 
-    fn check_bigtable_result<T>(
-        &self,
-        result: &std::result::Result<T, put_storage_bigtable::Error>,
-    ) -> Result<()> {
-        if let Err(put_storage_bigtable::Error::BlockNotFound(slot)) = result {
-            return Err(RpcCustomError::LongTermStorageSlotSkipped { slot: *slot }.into());
-        }
-        Ok(())
-    }
-
-    fn check_hbase_result<T>(
+    fn check_long_term_storage_result<T>(
         &self,
-        result: &std::result::Result<T, put_storage_hbase::Error>,
+        result: &std::result::Result<T, StorageError>,
     ) -> Result<()> {
-        if let Err(put_storage_hbase::Error::BlockNotFound(slot)) = result {
+        if let Err(StorageError::BlockNotFound(slot)) = result {
             return Err(RpcCustomError::LongTermStorageSlotSkipped { slot: *slot }.into());
         }
         Ok(())
@@ -1150,18 +1226,11 @@ impl JsonRpcRequestProcessor {
                     Ok(encoded_block)
                 };
                 if result.is_err() {
-                    if let Some(bigtable_ledger_storage) = &self.bigtable_ledger_storage {
-                        let bigtable_result =
-                            bigtable_ledger_storage.get_confirmed_block(slot).await;
-                        self.check_bigtable_result(&bigtable_result)?;
-                        return bigtable_result.ok().map(encode_block).transpose();
-                    }
-
-                    if let Some(hbase_ledger_storage) = &self.hbase_ledger_storage {
-                        let hbase_result =
-                            hbase_ledger_storage.get_confirmed_block(slot);
-                        self.check_hbase_result(&hbase_result)?;
-                        return hbase_result.ok().map(encode_block).transpose();
+                    if let Some(long_term_storage) = &self.long_term_storage {
+                        let long_term_storage_result =
+                            long_term_storage.get_confirmed_block(slot).await;
+                        self.check_long_term_storage_result(&long_term_storage_result)?;
+                        return long_term_storage_result.ok().map(encode_block).transpose();
                     }
                 }
                 self.check_blockstore_root(&result, slot)?;
@@ -1247,39 +1316,23 @@ impl JsonRpcRequestProcessor {
             .unwrap_or_default();
         if start_slot < lowest_blockstore_slot {
             // If the starting slot is lower than what's available in blockstore assume the entire
-            // [start_slot..end_slot] can be fetched from BigTable. This range should not ever run
-            // into unfinalized confirmed blocks due to MAX_GET_CONFIRMED_BLOCKS_RANGE
-            if let Some(bigtable_ledger_storage) = &self.bigtable_ledger_storage {
-                return bigtable_ledger_storage
+            // [start_slot..end_slot] can be fetched from long-term storage. This range should not
+            // ever run into unfinalized confirmed blocks due to MAX_GET_CONFIRMED_BLOCKS_RANGE
+            if let Some(long_term_storage) = &self.long_term_storage {
+                return long_term_storage
                     .get_confirmed_blocks(start_slot, (end_slot - start_slot) as usize + 1) // increment limit by 1 to ensure returned range is inclusive of both start_slot and end_slot
                     .await
-                    .map(|mut bigtable_blocks| {
-                        bigtable_blocks.retain(|&slot| slot <= end_slot);
-                        bigtable_blocks
+                    .map(|mut long_term_storage_blocks| {
+                        long_term_storage_blocks.retain(|&slot| slot <= end_slot);
+                        long_term_storage_blocks
                     })
                     .map_err(|_| {
-                        Error::invalid_params(
-                            "BigTable query failed (maybe timeout due to too large range?)"
-                                .to_string(),
-                        )
-                    });
-            }
-
-            if let Some(hbase_ledger_storage) = &self.hbase_ledger_storage {
-                return hbase_ledger_storage
-                    .get_confirmed_blocks(start_slot, (end_slot - start_slot) as usize + 1) // increment limit by 1 to ensure returned range is inclusive of both start_slot and end_slot
-                    .map(|mut hbase_blocks| {
-                        hbase_blocks.retain(|&slot| slot <= end_slot);
-                        hbase_blocks
-                    })
-                    .map_err(|_| {
-                        Error::invalid_params(
-                            "HBase query failed (maybe timeout due to too large range?)"
-                                .to_string(),
-                        )
+                        Error::invalid_params(format!(
+                            "{} query failed (maybe timeout due to too large range?)",
+                            long_term_storage.name()
+                        ))
                     });
             }
-
         }
 
         // Finalized blocks
@@ -1330,21 +1383,14 @@ impl JsonRpcRequestProcessor {
 
         if start_slot < lowest_blockstore_slot {
             // If the starting slot is lower than what's available in blockstore assume the entire
-            // range can be fetched from BigTable. This range should not ever run into unfinalized
-            // confirmed blocks due to MAX_GET_CONFIRMED_BLOCKS_RANGE
-            if let Some(bigtable_ledger_storage) = &self.bigtable_ledger_storage {
-                return Ok(bigtable_ledger_storage
+            // range can be fetched from long-term storage. This range should not ever run into
+            // unfinalized confirmed blocks due to MAX_GET_CONFIRMED_BLOCKS_RANGE
+            if let Some(long_term_storage) = &self.long_term_storage {
+                return Ok(long_term_storage
                     .get_confirmed_blocks(start_slot, limit)
                     .await
                     .unwrap_or_default());
             }
-
-            if let Some(hbase_ledger_storage) = &self.hbase_ledger_storage {
-                return Ok(hbase_ledger_storage
-                    .get_confirmed_blocks(start_slot, limit)
-                    .unwrap_or_default());
-            }
-
         }
 
         let highest_super_majority_root = self
@@ -1395,17 +1441,11 @@ impl JsonRpcRequestProcessor {
             let result = self.blockstore.get_block_time(slot);
             self.check_blockstore_root(&result, slot)?;
             if result.is_err() || matches!(result, Ok(None)) {
-                if let Some(bigtable_ledger_storage) = &self.bigtable_ledger_storage {
-                    let bigtable_result = bigtable_ledger_storage.get_confirmed_block(slot).await;
-                    self.check_bigtable_result(&bigtable_result)?;
-                    return Ok(bigtable_result
-                        .ok()
-                        .and_then(|confirmed_block| confirmed_block.block_time));
-                }
-                if let Some(hbase_ledger_storage) = &self.hbase_ledger_storage {
-                    let hbase_result = hbase_ledger_storage.get_confirmed_block(slot);
-                    self.check_hbase_result(&hbase_result)?;
-                    return Ok(hbase_result
+                if let Some(long_term_storage) = &self.long_term_storage {
+                    let long_term_storage_result =
+                        long_term_storage.get_confirmed_block(slot).await;
+                    self.check_long_term_storage_result(&long_term_storage_result)?;
+                    return Ok(long_term_storage_result
                         .ok()
                         .and_then(|confirmed_block| confirmed_block.block_time));
                 }
@@ -1495,17 +1535,12 @@ impl JsonRpcRequestProcessor {
                     })
                 {
                     Some(status)
-                } else if let Some(bigtable_ledger_storage) = &self.bigtable_ledger_storage {
-                    bigtable_ledger_storage
+                } else if let Some(long_term_storage) = &self.long_term_storage {
+                    long_term_storage
                         .get_signature_status(&signature)
                         .await
                         .map(Some)
                         .unwrap_or(None)
-                } else if let Some(hbase_ledger_storage) = &self.hbase_ledger_storage {
-                    hbase_ledger_storage
-                        .get_signature_status(&signature)
-                        .map(Some)
-                        .unwrap_or(None)
                 } else {
                     None
                 }
@@ -1613,21 +1648,14 @@ impl JsonRpcRequestProcessor {
                     }
                 }
                 None => {
-                    if let Some(bigtable_ledger_storage) = &self.bigtable_ledger_storage {
-                        return bigtable_ledger_storage
+                    if let Some(long_term_storage) = &self.long_term_storage {
+                        return long_term_storage
                             .get_confirmed_transaction(&signature)
                             .await
                             .unwrap_or(None)
                             .map(encode_transaction)
                             .transpose();
                     }
-                    if let Some(hbase_ledger_storage) = &self.hbase_ledger_storage {
-                        return hbase_ledger_storage
-                            .get_confirmed_transaction(&signature)
-                            .unwrap_or(None)
-                            .map(encode_transaction)
-                            .transpose();
-                    }
                 }
             }
         } else {
@@ -1716,23 +1744,23 @@ impl JsonRpcRequestProcessor {
             };
 
             if results.len() < limit {
-                if let Some(bigtable_ledger_storage) = &self.bigtable_ledger_storage {
-                    let mut bigtable_before = before;
+                if let Some(long_term_storage) = &self.long_term_storage {
+                    let mut long_term_storage_before = before;
                     if !results.is_empty() {
                         limit -= results.len();
-                        bigtable_before = results.last().map(|x| x.signature);
+                        long_term_storage_before = results.last().map(|x| x.signature);
                     }
 
                     // If the oldest address-signature found in Blockstore has not yet been
                     // uploaded to long-term storage, modify the storage query to return all latest
                     // signatures to prevent erroring on RowNotFound. This can race with upload.
-                    if found_before && bigtable_before.is_some() {
-                        match bigtable_ledger_storage
-                            .get_signature_status(&bigtable_before.unwrap())
+                    if found_before && long_term_storage_before.is_some() {
+                        match long_term_storage
+                            .get_signature_status(&long_term_storage_before.unwrap())
                             .await
                         {
                             Err(StorageError::SignatureNotFound) => {
-                                bigtable_before = None;
+                                long_term_storage_before = None;
                             }
                             Err(err) => {
                                 warn!("{:?}", err);
@@ -1742,79 +1770,26 @@ impl JsonRpcRequestProcessor {
                         }
                     }
 
-                    let bigtable_results = bigtable_ledger_storage
+                    let long_term_storage_results = long_term_storage
                         .get_confirmed_signatures_for_address(
                             &address,
-                            bigtable_before.as_ref(),
+                            long_term_storage_before.as_ref(),
                             until.as_ref(),
                             limit,
                         )
                         .await;
-                    match bigtable_results {
-                        Ok(bigtable_results) => {
+                    match long_term_storage_results {
+                        Ok(long_term_storage_results) => {
                             let results_set: HashSet<_> =
                                 results.iter().map(|result| result.signature).collect();
-                            for (bigtable_result, _) in bigtable_results {
+                            for (long_term_storage_result, _) in long_term_storage_results {
                                 // In the upload race condition, latest address-signatures in
                                 // long-term storage may include original `before` signature...
-                                if before != Some(bigtable_result.signature)
+                                if before != Some(long_term_storage_result.signature)
                                     // ...or earlier Blockstore signatures
-                                    && !results_set.contains(&bigtable_result.signature)
+                                    && !results_set.contains(&long_term_storage_result.signature)
                                 {
-                                    results.push(bigtable_result);
-                                }
-                            }
-                        }
-                        Err(err) => {
-                            warn!("{:?}", err);
-                        }
-                    }
-                }
-
-                if let Some(hbase_ledger_storage) = &self.hbase_ledger_storage {
-                    let mut hbase_before = before;
-                    if !results.is_empty() {
-                        limit -= results.len();
-                        hbase_before = results.last().map(|x| x.signature);
-                    }
-
-                    // If the oldest address-signature found in Blockstore has not yet been
-                    // uploaded to long-term storage, modify the storage query to return all latest
-                    // signatures to prevent erroring on RowNotFound. This can race with upload.
-                    if found_before && hbase_before.is_some() {
-                        match hbase_ledger_storage
-                            .get_signature_status(&hbase_before.unwrap())
-                        {
-                            Err(HBaseStorageError::SignatureNotFound) => {
-                                hbase_before = None;
-                            }
-                            Err(err) => {
-                                warn!("{:?}", err);
-                                return Ok(map_results(results));
-                            }
-                            Ok(_) => {}
-                        }
-                    }
-
-                    let hbase_results = hbase_ledger_storage
-                        .get_confirmed_signatures_for_address(
-                            &address,
-                            hbase_before.as_ref(),
-                            until.as_ref(),
-                            limit,
-                        );
-                    match hbase_results {
-                        Ok(hbase_results) => {
-                            let results_set: HashSet<_> =
-                                results.iter().map(|result| result.signature).collect();
-                            for (hbase_result, _) in hbase_results {
-                                // In the upload race condition, latest address-signatures in
-                                // long-term storage may include original `before` signature...
-                                if before != Some(hbase_result.signature)
-                                    // ...or earlier Blockstore signatures
-                                    && !results_set.contains(&hbase_result.signature)
-                                {
-                                    results.push(hbase_result);
+                                    results.push(long_term_storage_result);
                                 }
                             }
                         }
@@ -1837,26 +1812,15 @@ impl JsonRpcRequestProcessor {
             .get_first_available_block()
             .unwrap_or_default();
 
-        if let Some(bigtable_ledger_storage) = &self.bigtable_ledger_storage {
-            let bigtable_slot = bigtable_ledger_storage
+        if let Some(long_term_storage) = &self.long_term_storage {
+            let long_term_storage_slot = long_term_storage
                 .get_first_available_block()
                 .await
                 .unwrap_or(None)
                 .unwrap_or(slot);
 
-            if bigtable_slot < slot {
-                return bigtable_slot;
-            }
-        }
-
-        if let Some(hbase_ledger_storage) = &self.hbase_ledger_storage {
-            let hbase_slot = hbase_ledger_storage
-                .get_first_available_block()
-                .unwrap_or(None)
-                .unwrap_or(slot);
-
-            if hbase_slot < slot {
-                return hbase_slot;
+            if long_term_storage_slot < slot {
+                return long_term_storage_slot;
             }
         }
 
@@ -1970,12 +1934,16 @@ impl JsonRpcRequestProcessor {
                 "Invalid param: not a Token account".to_string(),
             ));
         }
-        let token_account = TokenAccount::unpack(account.data())
-            .map_err(|_| Error::invalid_params("Invalid param: not a Token account".to_string()))?;
+        let token_account = unpack_token_account(account.data()).ok_or_else(|| {
+            Error::invalid_params("Invalid param: not a Token account".to_string())
+        })?;
         let mint = &Pubkey::from_str(&token_account.mint.to_string())
             .expect("Token account mint should be convertible to Pubkey");
         let (_, decimals, _, _, _) = get_mint_owner_and_decimals(&bank, mint)?;
-        let balance = token_amount_to_ui_amount(token_account.amount, decimals);
+        let balance = token_amount_to_ui_amount(
+            token_account_balance(&token_account, Some(account.lamports())),
+            decimals,
+        );
         Ok(new_response(&bank, balance))
     }
 
@@ -1993,7 +1961,7 @@ impl JsonRpcRequestProcessor {
                 "Invalid param: not a Token mint".to_string(),
             ));
         }
-        let mint = Mint::unpack(mint_account.data()).map_err(|_| {
+        let mint = unpack_token_mint(mint_account.data()).ok_or_else(|| {
             Error::invalid_params("Invalid param: mint could not be unpacked".to_string())
         })?;
 
@@ -2014,11 +1982,19 @@ impl JsonRpcRequestProcessor {
             ));
         }
         let mut token_balances: Vec<RpcTokenAccountBalance> = self
-            .get_filtered_ppl_token_accounts_by_mint(&bank, &mint_owner, mint, vec![])?
+            .get_filtered_ppl_token_accounts_by_mint(
+                &bank,
+                &mint_owner,
+                mint,
+                vec![],
+                &ScanConfig::default(),
+            )?
             .into_iter()
             .map(|(address, account)| {
-                let amount = TokenAccount::unpack(account.data())
-                    .map(|account| account.amount)
+                let amount = unpack_token_account(account.data())
+                    .map(|token_account| {
+                        token_account_balance(&token_account, Some(account.lamports()))
+                    })
                     .unwrap_or(0);
                 let amount = token_amount_to_ui_amount(amount, decimals);
                 RpcTokenAccountBalance {
@@ -2043,18 +2019,25 @@ impl JsonRpcRequestProcessor {
         &self,
         owner: &Pubkey,
         token_account_filter: TokenAccountsFilter,
-        config: Option<RpcAccountInfoConfig>,
-    ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>> {
-        let RpcAccountInfoConfig {
-            encoding,
-            data_slice: data_slice_config,
-            commitment,
-            min_context_slot,
+        config: Option<RpcAccountsByOwnerConfig>,
+    ) -> Result<RpcResponse<OptionalPage<RpcKeyedAccount>>> {
+        let RpcAccountsByOwnerConfig {
+            account_config:
+                RpcAccountInfoConfig {
+                    encoding,
+                    data_slice: data_slice_config,
+                    commitment,
+                    min_context_slot,
+                },
+            pagination,
         } = config.unwrap_or_default();
-        let bank = self.get_bank_with_config(RpcContextConfig {
-            commitment,
-            min_context_slot,
-        })?;
+        let (bank, page) = self.get_bank_and_accounts_page(
+            RpcContextConfig {
+                commitment,
+                min_context_slot,
+            },
+            pagination,
+        )?;
         let encoding = encoding.unwrap_or(UiAccountEncoding::Binary);
         let (token_program_id, mint) = get_token_program_id_and_mint(&bank, token_account_filter)?;
 
@@ -2067,12 +2050,14 @@ impl JsonRpcRequestProcessor {
             )));
         }
 
-        let keyed_accounts = self.get_filtered_ppl_token_accounts_by_owner(
+        let mut keyed_accounts = self.get_filtered_ppl_token_accounts_by_owner(
             &bank,
             &token_program_id,
             owner,
             filters,
+            &AccountsPage::scan_config(page),
         )?;
+        let next_cursor = AccountsPage::truncate(page, &mut keyed_accounts);
         let accounts = if encoding == UiAccountEncoding::JsonParsed {
             get_parsed_token_accounts(bank.clone(), keyed_accounts.into_iter()).collect()
         } else {
@@ -2086,17 +2071,29 @@ impl JsonRpcRequestProcessor {
                 })
                 .collect::<Result<Vec<_>>>()?
         };
-        Ok(new_response(&bank, accounts))
+        Ok(new_response(
+            &bank,
+            AccountsPage::wrap(page, accounts, next_cursor),
+        ))
     }
 
     pub fn get_nft_accounts_by_owner(
         &self,
         owner: &Pubkey,
         token_account_filter: TokenAccountsFilter,
-        config: Option<RpcAccountInfoConfig>,
-    ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>> {
-        let config = config.unwrap_or_default();
-        let bank = self.bank(config.commitment);
+        config: Option<RpcAccountsByOwnerConfig>,
+    ) -> Result<RpcResponse<OptionalPage<RpcKeyedAccount>>> {
+        let RpcAccountsByOwnerConfig {
+            account_config: config,
+            pagination,
+        } = config.unwrap_or_default();
+        let (bank, page) = self.get_bank_and_accounts_page(
+            RpcContextConfig {
+                commitment: config.commitment,
+                min_context_slot: config.min_context_slot,
+            },
+            pagination,
+        )?;
         let encoding = config.encoding.unwrap_or(UiAccountEncoding::Binary);
         let data_slice_config = config.data_slice;
         check_slice_and_encoding(&encoding, data_slice_config.is_some())?;
@@ -2110,8 +2107,60 @@ impl JsonRpcRequestProcessor {
                 mint.to_bytes().into(),
             )));
         }
-        let keyed_accounts =
-            self.get_filtered_nft_accounts_by_owner(&bank, &nft_program_id, owner, filters)?;
+        let mut keyed_accounts = self.get_filtered_nft_accounts_by_owner(
+            &bank,
+            &nft_program_id,
+            owner,
+            filters,
+            &AccountsPage::scan_config(page),
+        )?;
+        let next_cursor = AccountsPage::truncate(page, &mut keyed_accounts);
+
+        let accounts = if encoding == UiAccountEncoding::JsonParsed {
+            get_parsed_ui_accounts(keyed_accounts.into_iter()).collect()
+        } else {
+            keyed_accounts
+                .into_iter()
+                .map(|(pubkey, account)| {
+                    Ok(RpcKeyedAccount {
+                        pubkey: pubkey.to_string(),
+                        account: encode_account(&account, &pubkey, encoding, data_slice_config)?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?
+        };
+        Ok(new_response(
+            &bank,
+            AccountsPage::wrap(page, accounts, next_cursor),
+        ))
+    }
+
+    pub fn get_nft_accounts_by_mint(
+        &self,
+        mint: &Pubkey,
+        config: Option<RpcNftAccountsByMintConfig>,
+    ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>> {
+        let RpcNftAccountsByMintConfig {
+            token_id,
+            account_config,
+        } = config.unwrap_or_default();
+        let bank = self.bank(account_config.commitment);
+        let encoding = account_config.encoding.unwrap_or(UiAccountEncoding::Binary);
+        let data_slice_config = account_config.data_slice;
+        check_slice_and_encoding(&encoding, data_slice_config.is_some())?;
+        let (nft_program_id, _) =
+            get_nft_program_id_and_mint(&bank, TokenAccountsFilter::Mint(*mint))?;
+
+        let mut keyed_accounts =
+            self.get_filtered_nft_accounts_by_mint(&bank, &nft_program_id, mint, vec![])?;
+        if let Some(token_id) = token_id {
+            keyed_accounts.retain(|(_, account)| {
+                matches!(
+                    parse_nft(account.data()),
+                    Ok(NftAccountType::Account(nft_account)) if nft_account.token_id == token_id
+                )
+            });
+        }
 
         let accounts = if encoding == UiAccountEncoding::JsonParsed {
             get_parsed_ui_accounts(keyed_accounts.into_iter()).collect()
@@ -2132,43 +2181,89 @@ impl JsonRpcRequestProcessor {
     pub fn get_domain_accounts_by_owner(
         &self,
         owner: &Pubkey,
-        config: Option<RpcAccountInfoConfig>,
-    ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>> {
-        let config = config.unwrap_or_default();
-        let bank = self.bank(config.commitment);
-        let encoding = config.encoding.unwrap_or(UiAccountEncoding::Binary);
-        let data_slice_config = config.data_slice;
-        check_slice_and_encoding(&encoding, data_slice_config.is_some())?;
+        config: Option<RpcDomainAccountsConfig>,
+    ) -> Result<RpcResponse<OptionalPage<RpcDomainKeyedAccount>>> {
+        let RpcDomainAccountsConfig {
+            expiry_filter,
+            grace_period,
+            account_config,
+            pagination,
+        } = config.unwrap_or_default();
+        let (bank, page) = self.get_bank_and_accounts_page(
+            RpcContextConfig {
+                commitment: account_config.commitment,
+                min_context_slot: account_config.min_context_slot,
+            },
+            pagination,
+        )?;
+        let encoding = account_config.encoding.unwrap_or(UiAccountEncoding::Binary);
+        check_slice_and_encoding(&encoding, account_config.data_slice.is_some())?;
         let name_program_id = inline_name::id();
-        let filters = vec![];
-
-        // Get domain accounts by owner
-        let own_domain_accounts =
-            self.get_filtered_domain_accounts_by_owner(&bank, &name_program_id, owner, filters)?;
-        // Filter expired domain
-        let domaint_accounts: Vec<RpcKeyedAccount> =
-            get_parsed_ui_accounts(own_domain_accounts.into_iter()).collect();
-        let mut ret = domaint_accounts.clone();
-        for account in domaint_accounts {
-            let domain_puk = account.pubkey.parse::<Pubkey>().unwrap();
-            let filters = vec![];
-            let own_domain_resolve_accounts = self.get_filtered_domain_resolve_accounts_by_parent(
-                &bank,
-                &name_program_id,
-                &domain_puk,
-                filters,
-            );
-            if own_domain_resolve_accounts.is_err() {
-                info!("get_filtered_domain_resolve_accounts_by_parent is err");
+        let now = bank.clock().unix_timestamp;
+        let grace_period = grace_period.unwrap_or_default();
+
+        // Get a page of domain accounts by owner, with their expiry state against the bank clock
+        let mut domain_accounts = self.get_filtered_domain_accounts_by_owner(
+            &bank,
+            &name_program_id,
+            owner,
+            vec![],
+            &AccountsPage::scan_config(page),
+        )?;
+        let next_cursor = AccountsPage::truncate(page, &mut domain_accounts);
+        let mut domain_expiry_states = HashMap::new();
+        let mut ret = vec![];
+        for (pubkey, account) in domain_accounts {
+            let Ok((domain, expire_time)) = parse_domain_account(account.data()) else {
+                continue;
+            };
+            let expiry_state = domain_expiry_state(expire_time, grace_period, now);
+            if !domain_expiry_filter_allows(expiry_filter, expiry_state) {
                 continue;
             }
-            let own_domain_resolve_accounts = own_domain_resolve_accounts.unwrap();
-            let mut domain_resolve_accounts: Vec<RpcKeyedAccount> =
-                get_parsed_ui_accounts(own_domain_resolve_accounts.into_iter()).collect();
-            ret.append(&mut domain_resolve_accounts);
+            domain_expiry_states.insert(pubkey, expiry_state);
+            // Domain accounts are returned parsed unless another encoding is requested
+            let ui_account = match account_config.encoding {
+                None | Some(UiAccountEncoding::JsonParsed) => {
+                    encode_parsed_domain_account(&pubkey, &account, domain)
+                }
+                Some(_) => encode_account(&account, &pubkey, encoding, account_config.data_slice)?,
+            };
+            ret.push(RpcDomainKeyedAccount {
+                pubkey: pubkey.to_string(),
+                account: ui_account,
+                expiry_state: expiry_filter.map(|_| expiry_state),
+            });
         }
 
-        Ok(new_response(&bank, ret))
+        // Get the domain resolve accounts of all remaining domains at once
+        let parent_keys = domain_expiry_states.keys().cloned().collect();
+        let resolve_accounts = self.get_filtered_domain_resolve_accounts_by_parents(
+            &bank,
+            &name_program_id,
+            &parent_keys,
+        )?;
+        for (pubkey, account) in resolve_accounts {
+            let ui_account =
+                UiAccount::encode(&pubkey, &account, UiAccountEncoding::JsonParsed, None, None);
+            if !matches!(ui_account.data, UiAccountData::Json(_)) {
+                continue;
+            }
+            let expiry_state = expiry_filter.and_then(|_| {
+                domain_resolve_account_parent(account.data())
+                    .and_then(|parent_key| domain_expiry_states.get(&parent_key).copied())
+            });
+            ret.push(RpcDomainKeyedAccount {
+                pubkey: pubkey.to_string(),
+                account: ui_account,
+                expiry_state,
+            });
+        }
+
+        Ok(new_response(
+            &bank,
+            AccountsPage::wrap(page, ret, next_cursor),
+        ))
     }
 
     pub fn get_domain_resolve_account(
@@ -2222,6 +2317,156 @@ impl JsonRpcRequestProcessor {
         Ok(new_response(&bank, response))
     }
 
+    /// Resolve a human-readable name such as `pay.alice.put` by walking its top domain, domain
+    /// and domain resolve accounts
+    pub fn resolve_domain(
+        &self,
+        name: &str,
+        config: RpcContextConfig,
+    ) -> Result<RpcResponse<Option<RpcResolvedDomain>>> {
+        let bank = self.get_bank_with_config(config)?;
+        let name = name.trim_end_matches('.');
+        if name.split('.').count() < 2 || name.split('.').any(|label| label.is_empty()) {
+            return Err(Error::invalid_params(format!(
+                "Invalid domain name: {name}"
+            )));
+        }
+        let resolved = self.resolve_domain_in_bank(&bank, name)?;
+        Ok(new_response(&bank, resolved))
+    }
+
+    fn resolve_domain_in_bank(
+        &self,
+        bank: &Arc<Bank>,
+        name: &str,
+    ) -> Result<Option<RpcResolvedDomain>> {
+        let name_program_id = inline_name::id();
+
+        let domain_name = get_domain(name);
+        let domain_name_hash = hashv(&[domain_name.as_bytes()]);
+        let (domain_account_pk, _) = get_seeds_and_key(
+            &ppl_name::id(),
+            Some(domain_name_hash.to_bytes().to_vec()),
+            AccountType::Domain,
+            None,
+        )
+        .map_err(|_| Error::invalid_params(format!("Invalid domain name: {name}")))?;
+        let Some((domain_account, expire_time)) =
+            get_domain_account_ignoring_expiry(bank, &domain_account_pk)
+        else {
+            return Ok(None);
+        };
+        let Ok(top_domain_account_pk) = domain_account.parent_key.parse::<Pubkey>() else {
+            return Ok(None);
+        };
+        let Some((_, top_domain_expire_time)) =
+            get_domain_account_ignoring_expiry(bank, &top_domain_account_pk)
+        else {
+            return Ok(None);
+        };
+
+        let resolve_account = self
+            .get_filtered_domain_resolve_accounts_by_parent(
+                bank,
+                &name_program_id,
+                &domain_account_pk,
+                vec![],
+            )?
+            .into_iter()
+            .find_map(
+                |(pubkey, account)| match parse_domain_resolve_account(account.data()) {
+                    Ok((resolve, value)) if resolve.domain_name == name => Some((pubkey, value)),
+                    _ => None,
+                },
+            );
+
+        let now = bank.clock().unix_timestamp;
+        Ok(Some(RpcResolvedDomain {
+            name: name.to_string(),
+            top_domain_account: top_domain_account_pk.to_string(),
+            domain_account: domain_account_pk.to_string(),
+            owner: domain_account.owner,
+            resolve_account: resolve_account
+                .as_ref()
+                .map(|(pubkey, _)| pubkey.to_string()),
+            value: resolve_account.and_then(|(_, value)| value),
+            expire_time,
+            expired: now > expire_time || now > top_domain_expire_time,
+        }))
+    }
+
+    /// Find the names resolving to `address`. Candidates are the records of the domains owned by
+    /// `address`, collected through the `PplNameOwner` index, and the name kept in the address
+    /// resolve account of `address`. A name is only returned once its forward lookup still
+    /// resolves to `address` and has not expired
+    pub fn reverse_lookup(
+        &self,
+        address: &Pubkey,
+        config: RpcContextConfig,
+    ) -> Result<RpcResponse<Vec<RpcResolvedDomain>>> {
+        let bank = self.get_bank_with_config(config)?;
+        let name_program_id = inline_name::id();
+        let address_str = address.to_string();
+        let mut names = HashSet::new();
+
+        // Records of the domains owned by the address
+        let domain_keys = self
+            .get_filtered_domain_accounts_by_owner(
+                &bank,
+                &name_program_id,
+                address,
+                vec![],
+                &ScanConfig::default(),
+            )?
+            .into_iter()
+            .map(|(pubkey, _)| pubkey)
+            .collect();
+        let resolve_accounts = self.get_filtered_domain_resolve_accounts_by_parents(
+            &bank,
+            &name_program_id,
+            &domain_keys,
+        )?;
+        for (_, account) in resolve_accounts {
+            if let Ok((resolve, Some(value))) = parse_domain_resolve_account(account.data()) {
+                if value == address_str {
+                    names.insert(resolve.domain_name);
+                }
+            }
+        }
+
+        // The name the address resolve account points at, for domains owned by someone else
+        let (address_resolve_account_pk, _) = get_seeds_and_key(
+            &ppl_name::id(),
+            Some(hashv(&[address.as_ref()]).to_bytes().to_vec()),
+            AccountType::AddressResolve,
+            None,
+        )
+        .map_err(|_| Error::invalid_params(format!("Invalid address: {address}")))?;
+        if let Some(name) = bank
+            .get_account(&address_resolve_account_pk)
+            .filter(|account| is_known_name_id(account.owner()) && !account.data().is_empty())
+            .and_then(|account| match parse_name(account.data()) {
+                Ok(NameAccountType::AddressResolve(address_resolve)) => {
+                    Some(address_resolve.domain)
+                }
+                _ => None,
+            })
+        {
+            names.insert(name.trim_end_matches('.').to_string());
+        }
+
+        let mut resolved: Vec<_> = names
+            .iter()
+            // Names read from accounts may not be valid domain names
+            .filter_map(|name| self.resolve_domain_in_bank(&bank, name).ok().flatten())
+            .filter(|resolved| {
+                !resolved.expired && resolved.value.as_deref() == Some(address_str.as_str())
+            })
+            .collect();
+        resolved.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(new_response(&bank, resolved))
+    }
+
     pub fn get_token_accounts_by_delegate(
         &self,
         delegate: &Pubkey,
@@ -2246,13 +2491,22 @@ impl JsonRpcRequestProcessor {
         ];
         // Optional filter on Mint address, uses mint account index for scan
         let keyed_accounts = if let Some(mint) = mint {
-            self.get_filtered_ppl_token_accounts_by_mint(&bank, &token_program_id, &mint, filters)?
+            self.get_filtered_ppl_token_accounts_by_mint(
+                &bank,
+                &token_program_id,
+                &mint,
+                filters,
+                &ScanConfig::default(),
+            )?
         } else {
             // Filter on Token Account state
-            filters.push(RpcFilterType::DataSize(
-                TokenAccount::get_packed_len() as u64
-            ));
-            self.get_filtered_program_accounts(&bank, &token_program_id, filters)?
+            filters.push(RpcFilterType::TokenAccountState);
+            self.get_filtered_program_accounts(
+                &bank,
+                &token_program_id,
+                filters,
+                &ScanConfig::default(),
+            )?
         };
         let accounts = if encoding == UiAccountEncoding::JsonParsed {
             get_parsed_token_accounts(bank.clone(), keyed_accounts.into_iter()).collect()
@@ -2276,6 +2530,7 @@ impl JsonRpcRequestProcessor {
         bank: &Arc<Bank>,
         program_id: &Pubkey,
         mut filters: Vec<RpcFilterType>,
+        scan_config: &ScanConfig,
     ) -> RpcCustomResult<Vec<(Pubkey, AccountSharedData)>> {
         optimize_filters(&mut filters);
         let filter_closure = |account: &AccountSharedData| {
@@ -2304,7 +2559,7 @@ impl JsonRpcRequestProcessor {
                         // accounts.
                         account.owner() == program_id && filter_closure(account)
                     },
-                    &ScanConfig::default(),
+                    scan_config,
                     bank.byte_limit_for_scans(),
                 )
                 .map_err(|e| RpcCustomError::ScanError {
@@ -2313,7 +2568,7 @@ impl JsonRpcRequestProcessor {
         } else {
             // this path does not need to provide a mb limit because we only want to support secondary indexes
             Ok(bank
-                .get_filtered_program_accounts(program_id, filter_closure, &ScanConfig::default())
+                .get_filtered_program_accounts(program_id, filter_closure, scan_config)
                 .map_err(|e| RpcCustomError::ScanError {
                     message: e.to_string(),
                 })?)
@@ -2327,6 +2582,7 @@ impl JsonRpcRequestProcessor {
         program_id: &Pubkey,
         owner_key: &Pubkey,
         mut filters: Vec<RpcFilterType>,
+        scan_config: &ScanConfig,
     ) -> RpcCustomResult<Vec<(Pubkey, AccountSharedData)>> {
         // The by-owner accounts index checks for Token Account state and Owner address on
         // inclusion. However, due to the current AccountsDb implementation, an account may remain
@@ -2334,9 +2590,7 @@ impl JsonRpcRequestProcessor {
         // later updates. We include the redundant filters here to avoid returning these accounts.
         //
         // Filter on Token Account state
-        filters.push(RpcFilterType::DataSize(
-            TokenAccount::get_packed_len() as u64
-        ));
+        filters.push(RpcFilterType::TokenAccountState);
         // Filter on Owner address
         filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
             PPL_TOKEN_ACCOUNT_OWNER_OFFSET,
@@ -2358,23 +2612,18 @@ impl JsonRpcRequestProcessor {
                     &IndexKey::PplTokenOwner(*owner_key),
                     |account| {
                         account.owner() == program_id
-                            && filters.iter().all(|filter_type| match filter_type {
-                                RpcFilterType::DataSize(size) => {
-                                    account.data().len() as u64 == *size
-                                }
-                                RpcFilterType::Memcmp(compare) => {
-                                    compare.bytes_match(account.data())
-                                }
-                            })
+                            && filters
+                                .iter()
+                                .all(|filter_type| filter_type.allows(account))
                     },
-                    &ScanConfig::default(),
+                    scan_config,
                     bank.byte_limit_for_scans(),
                 )
                 .map_err(|e| RpcCustomError::ScanError {
                     message: e.to_string(),
                 })?)
         } else {
-            self.get_filtered_program_accounts(bank, program_id, filters)
+            self.get_filtered_program_accounts(bank, program_id, filters, scan_config)
         }
     }
 
@@ -2385,6 +2634,7 @@ impl JsonRpcRequestProcessor {
         program_id: &Pubkey,
         owner_key: &Pubkey,
         mut filters: Vec<RpcFilterType>,
+        scan_config: &ScanConfig,
     ) -> RpcCustomResult<Vec<(Pubkey, AccountSharedData)>> {
         // The by-owner accounts index checks for Token Account state and Owner address on
         // inclusion. However, due to the current AccountsDb implementation, an account may remain
@@ -2414,14 +2664,58 @@ impl JsonRpcRequestProcessor {
                     &IndexKey::PplNftOwner(*owner_key),
                     |account| {
                         account.owner() == program_id
-                            && filters.iter().all(|filter_type| match filter_type {
-                                RpcFilterType::DataSize(size) => {
-                                    account.data().len() as u64 == *size
-                                }
-                                RpcFilterType::Memcmp(compare) => {
-                                    compare.bytes_match(account.data())
-                                }
-                            })
+                            && filters
+                                .iter()
+                                .all(|filter_type| filter_type.allows(account))
+                    },
+                    scan_config,
+                    bank.byte_limit_for_scans(),
+                )
+                .map_err(|e| RpcCustomError::ScanError {
+                    message: e.to_string(),
+                })?)
+        } else {
+            self.get_filtered_program_accounts(bank, program_id, filters, scan_config)
+        }
+    }
+
+    /// Get an iterator of nft accounts by mint address
+    fn get_filtered_nft_accounts_by_mint(
+        &self,
+        bank: &Arc<Bank>,
+        program_id: &Pubkey,
+        mint_key: &Pubkey,
+        mut filters: Vec<RpcFilterType>,
+    ) -> RpcCustomResult<Vec<(Pubkey, AccountSharedData)>> {
+        // As in `get_filtered_nft_accounts_by_owner`, the redundant filters keep wiped
+        // zero-lamport accounts out of the result
+        //
+        // Filter on NFT Account state
+        filters.push(RpcFilterType::DataSize(NFT_ACCOUNT_LENGTH as u64));
+        // Filter on Mint address
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            NFT_ACCOUNT_MINT_OFFSET,
+            mint_key.to_bytes().into(),
+        )));
+
+        if self
+            .config
+            .account_indexes
+            .contains(&AccountIndex::PplNftMint)
+        {
+            if !self.config.account_indexes.include_key(mint_key) {
+                return Err(RpcCustomError::KeyExcludedFromSecondaryIndex {
+                    index_key: mint_key.to_string(),
+                });
+            }
+            Ok(bank
+                .get_filtered_indexed_accounts(
+                    &IndexKey::PplNftMint(*mint_key),
+                    |account| {
+                        account.owner() == program_id
+                            && filters
+                                .iter()
+                                .all(|filter_type| filter_type.allows(account))
                     },
                     &ScanConfig::default(),
                     bank.byte_limit_for_scans(),
@@ -2430,7 +2724,7 @@ impl JsonRpcRequestProcessor {
                     message: e.to_string(),
                 })?)
         } else {
-            self.get_filtered_program_accounts(bank, program_id, filters)
+            self.get_filtered_program_accounts(bank, program_id, filters, &ScanConfig::default())
         }
     }
 
@@ -2441,6 +2735,7 @@ impl JsonRpcRequestProcessor {
         program_id: &Pubkey,
         owner_key: &Pubkey,
         mut filters: Vec<RpcFilterType>,
+        scan_config: &ScanConfig,
     ) -> RpcCustomResult<Vec<(Pubkey, AccountSharedData)>> {
         // The by-owner accounts index checks for Token Account state and Owner address on
         // inclusion. However, due to the current AccountsDb implementation, an account may remain
@@ -2477,23 +2772,18 @@ impl JsonRpcRequestProcessor {
                     &IndexKey::PplNameOwner(*owner_key),
                     |account| {
                         account.owner() == program_id
-                            && filters.iter().all(|filter_type| match filter_type {
-                                RpcFilterType::DataSize(size) => {
-                                    account.data().len() as u64 == *size
-                                }
-                                RpcFilterType::Memcmp(compare) => {
-                                    compare.bytes_match(account.data())
-                                }
-                            })
+                            && filters
+                                .iter()
+                                .all(|filter_type| filter_type.allows(account))
                     },
-                    &ScanConfig::default(),
+                    scan_config,
                     bank.byte_limit_for_scans(),
                 )
                 .map_err(|e| RpcCustomError::ScanError {
                     message: e.to_string(),
                 })?)
         } else {
-            self.get_filtered_program_accounts(bank, program_id, filters)
+            self.get_filtered_program_accounts(bank, program_id, filters, scan_config)
         }
     }
 
@@ -2540,14 +2830,9 @@ impl JsonRpcRequestProcessor {
                     &IndexKey::PplNameOwner(*parent_key),
                     |account| {
                         account.owner() == program_id
-                            && filters.iter().all(|filter_type| match filter_type {
-                                RpcFilterType::DataSize(size) => {
-                                    account.data().len() as u64 == *size
-                                }
-                                RpcFilterType::Memcmp(compare) => {
-                                    compare.bytes_match(account.data())
-                                }
-                            })
+                            && filters
+                                .iter()
+                                .all(|filter_type| filter_type.allows(account))
                     },
                     &ScanConfig::default(),
                     bank.byte_limit_for_scans(),
@@ -2556,10 +2841,50 @@ impl JsonRpcRequestProcessor {
                     message: e.to_string(),
                 })?)
         } else {
-            self.get_filtered_program_accounts(bank, program_id, filters)
+            self.get_filtered_program_accounts(bank, program_id, filters, &ScanConfig::default())
         }
     }
 
+    /// Get the domain resolve accounts of all `parent_keys` with a single scan
+    fn get_filtered_domain_resolve_accounts_by_parents(
+        &self,
+        bank: &Arc<Bank>,
+        program_id: &Pubkey,
+        parent_keys: &HashSet<Pubkey>,
+    ) -> RpcCustomResult<Vec<(Pubkey, AccountSharedData)>> {
+        if parent_keys.is_empty() {
+            return Ok(vec![]);
+        }
+        let filter = |account: &AccountSharedData| {
+            account.owner() == program_id
+                && domain_resolve_account_parent(account.data())
+                    .map(|parent_key| parent_keys.contains(&parent_key))
+                    .unwrap_or(false)
+        };
+        let account_indexes = &self.config.account_indexes;
+        let result = if account_indexes.contains(&AccountIndex::PplNameOwner)
+            && parent_keys
+                .iter()
+                .all(|key| account_indexes.include_key(key))
+        {
+            let index_keys: Vec<_> = parent_keys
+                .iter()
+                .map(|parent_key| IndexKey::PplNameOwner(*parent_key))
+                .collect();
+            bank.get_filtered_indexed_accounts_by_keys(
+                &index_keys,
+                filter,
+                &ScanConfig::default(),
+                bank.byte_limit_for_scans(),
+            )
+        } else {
+            bank.get_filtered_program_accounts(program_id, filter, &ScanConfig::default())
+        };
+        result.map_err(|e| RpcCustomError::ScanError {
+            message: e.to_string(),
+        })
+    }
+
     /// Get an iterator of ppl-token accounts by mint address
     fn get_filtered_ppl_token_accounts_by_mint(
         &self,
@@ -2567,6 +2892,7 @@ impl JsonRpcRequestProcessor {
         program_id: &Pubkey,
         mint_key: &Pubkey,
         mut filters: Vec<RpcFilterType>,
+        scan_config: &ScanConfig,
     ) -> RpcCustomResult<Vec<(Pubkey, AccountSharedData)>> {
         // The by-mint accounts index checks for Token Account state and Mint address on inclusion.
         // However, due to the current AccountsDb implementation, an account may remain in storage
@@ -2574,9 +2900,7 @@ impl JsonRpcRequestProcessor {
         // updates. We include the redundant filters here to avoid returning these accounts.
         //
         // Filter on Token Account state
-        filters.push(RpcFilterType::DataSize(
-            TokenAccount::get_packed_len() as u64
-        ));
+        filters.push(RpcFilterType::TokenAccountState);
         // Filter on Mint address
         #[allow(deprecated)]
         filters.push(RpcFilterType::Memcmp(Memcmp {
@@ -2599,23 +2923,18 @@ impl JsonRpcRequestProcessor {
                     &IndexKey::PplTokenMint(*mint_key),
                     |account| {
                         account.owner() == program_id
-                            && filters.iter().all(|filter_type| match filter_type {
-                                RpcFilterType::DataSize(size) => {
-                                    account.data().len() as u64 == *size
-                                }
-                                RpcFilterType::Memcmp(compare) => {
-                                    compare.bytes_match(account.data())
-                                }
-                            })
+                            && filters
+                                .iter()
+                                .all(|filter_type| filter_type.allows(account))
                     },
-                    &ScanConfig::default(),
+                    scan_config,
                     bank.byte_limit_for_scans(),
                 )
                 .map_err(|e| RpcCustomError::ScanError {
                     message: e.to_string(),
                 })?)
         } else {
-            self.get_filtered_program_accounts(bank, program_id, filters)
+            self.get_filtered_program_accounts(bank, program_id, filters, scan_config)
         }
     }
 
@@ -2953,6 +3272,146 @@ fn check_address_domain_expired(
     false
 }
 
+/// Load a domain account owned by the name program along with its expire time, without
+/// rejecting it once expired
+fn get_domain_account_ignoring_expiry(
+    bank: &Arc<Bank>,
+    domain_account: &Pubkey,
+) -> Option<(UiDomainAccount, UnixTimestamp)> {
+    let account = bank.get_account(domain_account)?;
+    if !is_known_name_id(account.owner()) {
+        return None;
+    }
+    parse_domain_account(account.data()).ok()
+}
+
+/// A page of accounts requested through `RpcPaginationConfig`, read from the bank at `slot`
+#[derive(Debug, Clone, Copy)]
+struct AccountsPage {
+    slot: Slot,
+    after: Option<Pubkey>,
+    limit: usize,
+}
+
+impl AccountsPage {
+    fn scan_config(page: Option<Self>) -> ScanConfig {
+        ScanConfig {
+            // Scan for one more account than requested, to tell whether there is a next page
+            page: page.map(|page| ScanPage {
+                after: page.after,
+                limit: page.limit.saturating_add(1),
+            }),
+            ..ScanConfig::default()
+        }
+    }
+
+    /// Truncates the scanned `keyed_accounts` to the page, returning the cursor of the next page
+    /// if there is one
+    fn truncate(
+        page: Option<Self>,
+        keyed_accounts: &mut Vec<(Pubkey, AccountSharedData)>,
+    ) -> Option<String> {
+        let page = page?;
+        if keyed_accounts.len() <= page.limit {
+            return None;
+        }
+        keyed_accounts.truncate(page.limit);
+        keyed_accounts
+            .last()
+            .map(|(pubkey, _)| encode_accounts_cursor(page.slot, pubkey))
+    }
+
+    fn wrap<T>(
+        page: Option<Self>,
+        accounts: Vec<T>,
+        next_cursor: Option<String>,
+    ) -> OptionalPage<T> {
+        match page {
+            Some(_) => OptionalPage::Page(RpcAccountsPage {
+                accounts,
+                next_cursor,
+            }),
+            None => OptionalPage::NoPage(accounts),
+        }
+    }
+}
+
+fn encode_accounts_cursor(slot: Slot, last_pubkey: &Pubkey) -> String {
+    let mut cursor = slot.to_le_bytes().to_vec();
+    cursor.extend_from_slice(last_pubkey.as_ref());
+    bs58::encode(cursor).into_string()
+}
+
+fn decode_accounts_cursor(cursor: &str) -> Result<(Slot, Pubkey)> {
+    let invalid_cursor = || Error::invalid_params(format!("Invalid cursor: {cursor}"));
+    let bytes = bs58::decode(cursor)
+        .into_vec()
+        .map_err(|_| invalid_cursor())?;
+    if bytes.len() != std::mem::size_of::<Slot>() + PUBKEY_BYTES {
+        return Err(invalid_cursor());
+    }
+    let (slot, last_pubkey) = bytes.split_at(std::mem::size_of::<Slot>());
+    let slot = Slot::from_le_bytes(slot.try_into().map_err(|_| invalid_cursor())?);
+    let last_pubkey = Pubkey::try_from(last_pubkey).map_err(|_| invalid_cursor())?;
+    Ok((slot, last_pubkey))
+}
+
+/// The parent domain of a domain resolve account
+fn domain_resolve_account_parent(data: &[u8]) -> Option<Pubkey> {
+    if data.get(DOMAIN_RESOLVE_ACCOUNT_TYPE_OFFSET) != Some(&2) {
+        return None;
+    }
+    let parent_end = DOMAIN_RESOLVE_ACCOUNT_PARENT_OFFSET + PUBKEY_BYTES;
+    data.get(DOMAIN_RESOLVE_ACCOUNT_PARENT_OFFSET..parent_end)
+        .and_then(|parent_key| Pubkey::try_from(parent_key).ok())
+}
+
+fn domain_expiry_state(
+    expire_time: UnixTimestamp,
+    grace_period: u64,
+    now: UnixTimestamp,
+) -> RpcDomainExpiryState {
+    if now <= expire_time {
+        RpcDomainExpiryState::Active
+    } else if now <= expire_time.saturating_add(grace_period as UnixTimestamp) {
+        RpcDomainExpiryState::GracePeriod
+    } else {
+        RpcDomainExpiryState::Expired
+    }
+}
+
+fn domain_expiry_filter_allows(
+    expiry_filter: Option<RpcDomainExpiryFilter>,
+    expiry_state: RpcDomainExpiryState,
+) -> bool {
+    match expiry_filter {
+        None | Some(RpcDomainExpiryFilter::Flag) => true,
+        Some(RpcDomainExpiryFilter::ExcludeExpired) => {
+            expiry_state != RpcDomainExpiryState::Expired
+        }
+        Some(RpcDomainExpiryFilter::ExcludeGracePeriod) => {
+            expiry_state == RpcDomainExpiryState::Active
+        }
+    }
+}
+
+/// Encode a domain account as jsonParsed whether or not it has expired, as `parse_name` only
+/// parses domains that are unexpired by the system time
+fn encode_parsed_domain_account(
+    pubkey: &Pubkey,
+    account: &AccountSharedData,
+    domain: UiDomainAccount,
+) -> UiAccount {
+    let mut ui_account = UiAccount::encode(pubkey, account, UiAccountEncoding::Base64, None, None);
+    ui_account.data = UiAccountData::Json(ParsedAccount {
+        program: "ppl-name".to_string(),
+        parsed: serde_json::to_value(NameAccountType::Domain(domain))
+            .expect("domain account should serialize"),
+        space: account.data().len() as u64,
+    });
+    ui_account
+}
+
 fn check_domain_expired(domain_ui_account: &UiAccountData) -> bool {
     if let UiAccountData::Json(_) = domain_ui_account {
         true
@@ -2994,9 +3453,14 @@ fn get_ppl_token_owner_filter(program_id: &Pubkey, filters: &[RpcFilterType]) ->
     let mut data_size_filter: Option<u64> = None;
     let mut owner_key: Option<Pubkey> = None;
     let mut incorrect_owner_len: Option<usize> = None;
+    let mut token_account_state_filter = false;
     for filter in filters {
         match filter {
             RpcFilterType::DataSize(size) => data_size_filter = Some(*size),
+            RpcFilterType::TokenAccountState => token_account_state_filter = true,
+            filter if is_extended_token_account_filter(program_id, filter) => {
+                token_account_state_filter = true
+            }
             #[allow(deprecated)]
             RpcFilterType::Memcmp(Memcmp {
                 offset: PPL_TOKEN_ACCOUNT_OWNER_OFFSET,
@@ -3012,7 +3476,8 @@ fn get_ppl_token_owner_filter(program_id: &Pubkey, filters: &[RpcFilterType]) ->
             _ => {}
         }
     }
-    if data_size_filter == Some(TokenAccount::get_packed_len() as u64) {
+    if data_size_filter == Some(TokenAccount::get_packed_len() as u64) || token_account_state_filter
+    {
         if let Some(incorrect_owner_len) = incorrect_owner_len {
             info!(
                 "Incorrect num bytes ({:?}) provided for ppl_token_owner_filter",
@@ -3026,6 +3491,21 @@ fn get_ppl_token_owner_filter(program_id: &Pubkey, filters: &[RpcFilterType]) ->
     }
 }
 
+/// Extended ppl-token-2022 token accounts are marked by their account type, which selects them as
+/// reliably as the data size of a base token account
+#[allow(deprecated)]
+fn is_extended_token_account_filter(program_id: &Pubkey, filter: &RpcFilterType) -> bool {
+    *program_id == ppl_token_2022_id()
+        && matches!(
+            filter,
+            RpcFilterType::Memcmp(Memcmp {
+                offset: ACCOUNT_TYPE_OFFSET,
+                bytes: MemcmpEncodedBytes::Bytes(bytes),
+                ..
+            }) if bytes[..] == [ACCOUNTTYPE_ACCOUNT]
+        )
+}
+
 /// Analyze custom filters to determine if the result will be a subset of ppl-token accounts by
 /// mint.
 /// NOTE: `optimize_filters()` should almost always be called before using this method because of
@@ -3037,9 +3517,14 @@ fn get_ppl_token_mint_filter(program_id: &Pubkey, filters: &[RpcFilterType]) ->
     let mut data_size_filter: Option<u64> = None;
     let mut mint: Option<Pubkey> = None;
     let mut incorrect_mint_len: Option<usize> = None;
+    let mut token_account_state_filter = false;
     for filter in filters {
         match filter {
             RpcFilterType::DataSize(size) => data_size_filter = Some(*size),
+            RpcFilterType::TokenAccountState => token_account_state_filter = true,
+            filter if is_extended_token_account_filter(program_id, filter) => {
+                token_account_state_filter = true
+            }
             #[allow(deprecated)]
             RpcFilterType::Memcmp(Memcmp {
                 offset: PPL_TOKEN_ACCOUNT_MINT_OFFSET,
@@ -3055,7 +3540,8 @@ fn get_ppl_token_mint_filter(program_id: &Pubkey, filters: &[RpcFilterType]) ->
             _ => {}
         }
     }
-    if data_size_filter == Some(TokenAccount::get_packed_len() as u64) {
+    if data_size_filter == Some(TokenAccount::get_packed_len() as u64) || token_account_state_filter
+    {
         if let Some(incorrect_mint_len) = incorrect_mint_len {
             info!(
                 "Incorrect num bytes ({:?}) provided for ppl_token_mint_filter",
@@ -3676,7 +4162,15 @@ pub mod rpc_accounts {
             meta: Self::Metadata,
             owner_str: String,
             token_account_filter: RpcTokenAccountsFilter,
-            config: Option<RpcAccountInfoConfig>,
+            config: Option<RpcAccountsByOwnerConfig>,
+        ) -> Result<RpcResponse<OptionalPage<RpcKeyedAccount>>>;
+
+        #[rpc(meta, name = "getNftAccountsByMint")]
+        fn get_nft_accounts_by_mint(
+            &self,
+            meta: Self::Metadata,
+            mint_str: String,
+            config: Option<RpcNftAccountsByMintConfig>,
         ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>>;
 
         #[rpc(meta, name = "getDomainAccountsByOwner")]
@@ -3684,8 +4178,8 @@ pub mod rpc_accounts {
             &self,
             meta: Self::Metadata,
             owner_str: String,
-            config: Option<RpcAccountInfoConfig>,
-        ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>>;
+            config: Option<RpcDomainAccountsConfig>,
+        ) -> Result<RpcResponse<OptionalPage<RpcDomainKeyedAccount>>>;
 
         #[rpc(meta, name = "getDomainResolveAccount")]
         fn get_domain_resolve_account(
@@ -3711,6 +4205,22 @@ pub mod rpc_accounts {
             address_pubkey_str: String,
             config: Option<RpcAccountInfoConfig>,
         ) -> Result<RpcResponse<Option<UiAccount>>>;
+
+        #[rpc(meta, name = "resolveDomain")]
+        fn resolve_domain(
+            &self,
+            meta: Self::Metadata,
+            name: String,
+            config: Option<RpcContextConfig>,
+        ) -> Result<RpcResponse<Option<RpcResolvedDomain>>>;
+
+        #[rpc(meta, name = "reverseLookup")]
+        fn reverse_lookup(
+            &self,
+            meta: Self::Metadata,
+            address_str: String,
+            config: Option<RpcContextConfig>,
+        ) -> Result<RpcResponse<Vec<RpcResolvedDomain>>>;
     }
 
     pub struct AccountsDataImpl;
@@ -3847,13 +4357,34 @@ pub mod rpc_accounts {
             meta.get_domain_address_account(&domain_address_pubkey, config)
         }
 
+        fn resolve_domain(
+            &self,
+            meta: Self::Metadata,
+            name: String,
+            config: Option<RpcContextConfig>,
+        ) -> Result<RpcResponse<Option<RpcResolvedDomain>>> {
+            debug!("resolveDomain rpc request received: {:?}", name);
+            meta.resolve_domain(&name, config.unwrap_or_default())
+        }
+
+        fn reverse_lookup(
+            &self,
+            meta: Self::Metadata,
+            address_str: String,
+            config: Option<RpcContextConfig>,
+        ) -> Result<RpcResponse<Vec<RpcResolvedDomain>>> {
+            debug!("reverseLookup rpc request received: {:?}", address_str);
+            let address = verify_pubkey(&address_str)?;
+            meta.reverse_lookup(&address, config.unwrap_or_default())
+        }
+
         fn get_nft_accounts_by_owner(
             &self,
             meta: Self::Metadata,
             owner_str: String,
             token_account_filter: RpcTokenAccountsFilter,
-            config: Option<RpcAccountInfoConfig>,
-        ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>> {
+            config: Option<RpcAccountsByOwnerConfig>,
+        ) -> Result<RpcResponse<OptionalPage<RpcKeyedAccount>>> {
             debug!(
                 "get_token_accounts_by_owner rpc request received: {:?}",
                 owner_str
@@ -3863,12 +4394,26 @@ pub mod rpc_accounts {
             meta.get_nft_accounts_by_owner(&owner, token_account_filter, config)
         }
 
+        fn get_nft_accounts_by_mint(
+            &self,
+            meta: Self::Metadata,
+            mint_str: String,
+            config: Option<RpcNftAccountsByMintConfig>,
+        ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>> {
+            debug!(
+                "get_nft_accounts_by_mint rpc request received: {:?}",
+                mint_str
+            );
+            let mint = verify_pubkey(&mint_str)?;
+            meta.get_nft_accounts_by_mint(&mint, config)
+        }
+
         fn get_domain_accounts_by_owner(
             &self,
             meta: Self::Metadata,
             owner_str: String,
-            config: Option<RpcAccountInfoConfig>,
-        ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>> {
+            config: Option<RpcDomainAccountsConfig>,
+        ) -> Result<RpcResponse<OptionalPage<RpcDomainKeyedAccount>>> {
             debug!(
                 "get_domain_accounts_by_owner rpc request received: {:?}",
                 owner_str
@@ -3894,7 +4439,7 @@ pub mod rpc_accounts_scan {
             meta: Self::Metadata,
             program_id_str: String,
             config: Option<RpcProgramAccountsConfig>,
-        ) -> Result<OptionalContext<Vec<RpcKeyedAccount>>>;
+        ) -> Result<OptionalContext<OptionalPage<RpcKeyedAccount>>>;
 
         #[rpc(meta, name = "getLargestAccounts")]
         fn get_largest_accounts(
@@ -3928,8 +4473,8 @@ pub mod rpc_accounts_scan {
             meta: Self::Metadata,
             owner_str: String,
             token_account_filter: RpcTokenAccountsFilter,
-            config: Option<RpcAccountInfoConfig>,
-        ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>>;
+            config: Option<RpcAccountsByOwnerConfig>,
+        ) -> Result<RpcResponse<OptionalPage<RpcKeyedAccount>>>;
 
         #[rpc(meta, name = "getTokenAccountsByDelegate")]
         fn get_token_accounts_by_delegate(
@@ -3950,20 +4495,21 @@ pub mod rpc_accounts_scan {
             meta: Self::Metadata,
             program_id_str: String,
             config: Option<RpcProgramAccountsConfig>,
-        ) -> Result<OptionalContext<Vec<RpcKeyedAccount>>> {
+        ) -> Result<OptionalContext<OptionalPage<RpcKeyedAccount>>> {
             debug!(
                 "get_program_accounts rpc request received: {:?}",
                 program_id_str
             );
             let program_id = verify_pubkey(&program_id_str)?;
-            let (config, filters, with_context) = if let Some(config) = config {
+            let (config, filters, with_context, pagination) = if let Some(config) = config {
                 (
                     Some(config.account_config),
                     config.filters.unwrap_or_default(),
                     config.with_context.unwrap_or_default(),
+                    config.pagination,
                 )
             } else {
-                (None, vec![], false)
+                (None, vec![], false, RpcPaginationConfig::default())
             };
             if filters.len() > MAX_GET_PROGRAM_ACCOUNT_FILTERS {
                 return Err(Error::invalid_params(format!(
@@ -3973,7 +4519,7 @@ pub mod rpc_accounts_scan {
             for filter in &filters {
                 verify_filter(filter)?;
             }
-            meta.get_program_accounts(&program_id, config, filters, with_context)
+            meta.get_program_accounts(&program_id, config, filters, with_context, pagination)
         }
 
         fn get_largest_accounts(
@@ -4013,8 +4559,8 @@ pub mod rpc_accounts_scan {
             meta: Self::Metadata,
             owner_str: String,
             token_account_filter: RpcTokenAccountsFilter,
-            config: Option<RpcAccountInfoConfig>,
-        ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>> {
+            config: Option<RpcAccountsByOwnerConfig>,
+        ) -> Result<RpcResponse<OptionalPage<RpcKeyedAccount>>> {
             debug!(
                 "get_token_accounts_by_owner rpc request received: {:?}",
                 owner_str
@@ -5441,6 +5987,10 @@ pub mod tests {
         bincode::deserialize,
         jsonrpc_core::{futures, ErrorCode, MetaIoHandler, Output, Response, Value},
         jsonrpc_core_client::transports::local,
+        ppl_nft::{
+            put_program::pubkey::Pubkey as PplNftPubkey,
+            state::{AccountState as NftAccountState, MetaAccount},
+        },
         ppl_token::{
             put_program::{program_option::COption, pubkey::Pubkey as PplTokenPubkey},
             state::{AccountState as TokenAccountState, Mint},
@@ -5608,7 +6158,6 @@ pub mod tests {
                 cluster_info,
                 Hash::default(),
                 None,
-                None,
                 OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
                 Arc::new(RwLock::new(LargestAccountsCache::new(30))),
                 max_slots.clone(),
@@ -6630,25 +7179,127 @@ pub mod tests {
                 }]}, // Filter on Initialized and Nonce authority
             ])),
         );
-        let result: Vec<RpcKeyedAccount> = parse_success_result(rpc.handle_request_sync(request));
-        assert_eq!(result.len(), 1);
+        let result: Vec<RpcKeyedAccount> = parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(result.len(), 1);
+
+        let request = create_test_request(
+            "getProgramAccounts",
+            Some(json!([
+                system_program::id().to_string(),
+                {"filters": [{
+                    "memcmp": {
+                        "offset": 4,
+                        "bytes": bs58::encode(vec![1, 0, 0, 0]).into_string(),
+                    },
+                }, {
+                    "dataSize": 1,
+                }]}, // Filter on Initialized and non-matching data size
+            ])),
+        );
+        let result: Vec<RpcKeyedAccount> = parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn test_rpc_get_program_accounts_paginated() {
+        let rpc = RpcHandler::start();
+        let bank = rpc.working_bank();
+
+        let program_id = Pubkey::new_unique();
+        let mut pubkeys: Vec<_> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        for pubkey in &pubkeys {
+            bank.store_account(pubkey, &AccountSharedData::new(42, 0, &program_id));
+        }
+        pubkeys.sort();
+
+        // Follow cursors until the last page
+        let mut cursor = None;
+        let mut page_sizes = vec![];
+        let mut paged_pubkeys = vec![];
+        loop {
+            let request = create_test_request(
+                "getProgramAccounts",
+                Some(json!([program_id.to_string(), {"limit": 2, "cursor": cursor}])),
+            );
+            let page: RpcAccountsPage<RpcKeyedAccount> =
+                parse_success_result(rpc.handle_request_sync(request));
+            page_sizes.push(page.accounts.len());
+            paged_pubkeys.extend(page.accounts.into_iter().map(|account| account.pubkey));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(page_sizes, vec![2, 2, 1]);
+        assert_eq!(
+            paged_pubkeys,
+            pubkeys.iter().map(Pubkey::to_string).collect::<Vec<_>>()
+        );
+
+        // Test that invalid limit returns an error
+        let limit = MAX_ACCOUNTS_PAGE_LIMIT + 1;
+        let request = create_test_request(
+            "getProgramAccounts",
+            Some(json!([program_id.to_string(), { "limit": limit }])),
+        );
+        let response = parse_failure_response(rpc.handle_request_sync(request));
+        let expected = (
+            ErrorCode::InvalidParams.code(),
+            format!("Invalid limit; max {MAX_ACCOUNTS_PAGE_LIMIT}"),
+        );
+        assert_eq!(response, expected);
+
+        // Test that a cursor of a slot past the requested commitment returns an error
+        let cursor = encode_accounts_cursor(42, &pubkeys[0]);
+        assert_eq!(decode_accounts_cursor(&cursor).unwrap(), (42, pubkeys[0]));
+        let request = create_test_request(
+            "getProgramAccounts",
+            Some(json!([program_id.to_string(), { "cursor": cursor }])),
+        );
+        let response = parse_failure_response(rpc.handle_request_sync(request));
+        let expected = (
+            ErrorCode::InvalidParams.code(),
+            String::from("Cursor slot 42 has not reached the requested commitment"),
+        );
+        assert_eq!(response, expected);
 
-        let request = create_test_request(
-            "getProgramAccounts",
-            Some(json!([
-                system_program::id().to_string(),
-                {"filters": [{
-                    "memcmp": {
-                        "offset": 4,
-                        "bytes": bs58::encode(vec![1, 0, 0, 0]).into_string(),
-                    },
-                }, {
-                    "dataSize": 1,
-                }]}, // Filter on Initialized and non-matching data size
-            ])),
+        // Test that a cursor keeps reading from its slot while it is on the fork of the requested
+        // commitment, and falls back to the bank at the requested commitment once it is not
+        let bank = rpc.advance_bank_to_confirmed_slot(3);
+        let new_pubkey = Pubkey::from([u8::MAX; 32]);
+        bank.store_account(&new_pubkey, &AccountSharedData::new(42, 0, &program_id));
+        let get_page = |slot, commitment| {
+            let cursor = encode_accounts_cursor(slot, &pubkeys[3]);
+            let request = create_test_request(
+                "getProgramAccounts",
+                Some(json!([
+                    program_id.to_string(),
+                    {"limit": 1, "cursor": cursor, "commitment": commitment},
+                ])),
+            );
+            rpc.handle_request_sync(request)
+        };
+
+        let page: RpcAccountsPage<RpcKeyedAccount> = parse_success_result(get_page(0, "processed"));
+        assert_eq!(page.accounts.len(), 1);
+        assert_eq!(page.accounts[0].pubkey, pubkeys[4].to_string());
+        assert_eq!(page.next_cursor, None);
+
+        let page: RpcAccountsPage<RpcKeyedAccount> = parse_success_result(get_page(2, "processed"));
+        assert_eq!(page.accounts.len(), 1);
+        assert_eq!(page.accounts[0].pubkey, pubkeys[4].to_string());
+        assert_eq!(
+            decode_accounts_cursor(&page.next_cursor.unwrap()).unwrap(),
+            (3, pubkeys[4])
         );
-        let result: Vec<RpcKeyedAccount> = parse_success_result(rpc.handle_request_sync(request));
-        assert_eq!(result.len(), 0);
+
+        // Test that a cursor of a slot past the root is rejected at finalized commitment
+        let response = parse_failure_response(get_page(3, "finalized"));
+        let expected = (
+            ErrorCode::InvalidParams.code(),
+            String::from("Cursor slot 3 has not reached the requested commitment"),
+        );
+        assert_eq!(response, expected);
     }
 
     #[test]
@@ -7246,7 +7897,6 @@ pub mod tests {
             cluster_info,
             Hash::default(),
             None,
-            None,
             OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             Arc::new(MaxSlots::default()),
@@ -7519,7 +8169,6 @@ pub mod tests {
             cluster_info,
             Hash::default(),
             None,
-            None,
             OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             Arc::new(MaxSlots::default()),
@@ -8631,6 +9280,245 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_get_nft_accounts_by_mint() {
+        let nft_program_id = Pubkey::new_from_array(ppl_nft::id().to_bytes());
+        let mint = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+        let holders = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let nft_account = |mint: &Pubkey, owner: &Pubkey, token_id: u64| {
+            let mut data = vec![0; MetaAccount::get_packed_len()];
+            let mut account = MetaAccount::unpack_unchecked(&data).unwrap();
+            account.mint = PplNftPubkey::from(mint.to_bytes());
+            account.owner = PplNftPubkey::from(owner.to_bytes());
+            account.state = NftAccountState::Initialized;
+            account.token_id = token_id;
+            account.token_uri = format!("https://example.com/{token_id}");
+            MetaAccount::pack(account, &mut data).unwrap();
+            AccountSharedData::from(Account {
+                lamports: 111,
+                data,
+                owner: nft_program_id,
+                ..Account::default()
+            })
+        };
+
+        let mut indexed = JsonRpcConfig::default();
+        indexed.account_indexes.indexes.insert(AccountIndex::PplNftMint);
+        for config in [JsonRpcConfig::default(), indexed] {
+            let rpc = RpcHandler::start_with_config(config);
+            let bank = rpc.working_bank();
+            let RpcHandler { io, meta, .. } = rpc;
+
+            let mint_account = AccountSharedData::new(111, 0, &nft_program_id);
+            bank.store_account(&mint, &mint_account);
+            bank.store_account(&other_mint, &mint_account);
+            let mut nft_pubkeys = vec![];
+            for (token_id, holder) in holders.iter().enumerate() {
+                let nft_pubkey = Pubkey::new_unique();
+                bank.store_account(&nft_pubkey, &nft_account(&mint, holder, token_id as u64));
+                nft_pubkeys.push(nft_pubkey);
+            }
+            bank.store_account(
+                &Pubkey::new_unique(),
+                &nft_account(&other_mint, &holders[0], 0),
+            );
+
+            let get_nft_accounts_by_mint = |mint: &Pubkey, config: Value| {
+                let req = json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "getNftAccountsByMint",
+                    "params": [mint.to_string(), config],
+                });
+                let res = io.handle_request_sync(&req.to_string(), meta.clone());
+                serde_json::from_str::<Value>(&res.expect("actual response"))
+                    .expect("actual response deserialization")
+            };
+
+            // All NFTs minted from `mint`
+            let result = get_nft_accounts_by_mint(&mint, json!({"encoding": "base64"}));
+            let mut accounts: Vec<String> =
+                serde_json::from_value::<Vec<RpcKeyedAccount>>(result["result"]["value"].clone())
+                    .unwrap()
+                    .into_iter()
+                    .map(|keyed_account| keyed_account.pubkey)
+                    .collect();
+            accounts.sort();
+            let mut expected: Vec<String> = nft_pubkeys.iter().map(|p| p.to_string()).collect();
+            expected.sort();
+            assert_eq!(accounts, expected);
+
+            // Current holder of a single NFT, parsed through parse_nft
+            let result =
+                get_nft_accounts_by_mint(&mint, json!({"encoding": "jsonParsed", "tokenId": 1}));
+            let accounts: Vec<RpcKeyedAccount> =
+                serde_json::from_value(result["result"]["value"].clone()).unwrap();
+            assert_eq!(accounts.len(), 1);
+            assert_eq!(accounts[0].pubkey, nft_pubkeys[1].to_string());
+            match &accounts[0].account.data {
+                UiAccountData::Json(parsed_account) => {
+                    assert_eq!(parsed_account.program, "ppl-nft");
+                    assert_eq!(
+                        parsed_account.parsed["info"]["owner"],
+                        json!(holders[1].to_string())
+                    );
+                    assert_eq!(parsed_account.parsed["info"]["tokenId"], json!(1));
+                }
+                data => panic!("expected jsonParsed account data, got {data:?}"),
+            }
+
+            // Unknown token id
+            let result = get_nft_accounts_by_mint(&mint, json!({"tokenId": 7}));
+            assert_eq!(result["result"]["value"], json!([]));
+
+            // Not an NFT mint
+            let result = get_nft_accounts_by_mint(&Pubkey::new_unique(), json!({}));
+            assert!(result.get("error").is_some());
+        }
+    }
+
+    #[test]
+    fn test_rpc_get_token_accounts_by_owner_paginated() {
+        let owner = Pubkey::new_unique();
+        let token_account = |owner: &Pubkey| {
+            let mut account_data = vec![0; TokenAccount::get_packed_len()];
+            let token_account = TokenAccount {
+                mint: PplTokenPubkey::from([2; 32]),
+                owner: PplTokenPubkey::from(owner.to_bytes()),
+                delegate: COption::None,
+                amount: 42,
+                state: TokenAccountState::Initialized,
+                is_native: COption::None,
+                delegated_amount: 0,
+                close_authority: COption::None,
+            };
+            TokenAccount::pack(token_account, &mut account_data).unwrap();
+            AccountSharedData::from(Account {
+                lamports: 111,
+                data: account_data,
+                owner: ppl_token_id(),
+                ..Account::default()
+            })
+        };
+
+        let mut indexed = JsonRpcConfig::default();
+        indexed
+            .account_indexes
+            .indexes
+            .insert(AccountIndex::PplTokenOwner);
+        for config in [JsonRpcConfig::default(), indexed] {
+            let rpc = RpcHandler::start_with_config(config);
+            let bank = rpc.working_bank();
+
+            let mut pubkeys: Vec<_> = (0..5).map(|_| Pubkey::new_unique()).collect();
+            for pubkey in &pubkeys {
+                bank.store_account(pubkey, &token_account(&owner));
+            }
+            bank.store_account(&Pubkey::new_unique(), &token_account(&Pubkey::new_unique()));
+            pubkeys.sort();
+
+            // Follow cursors until the last page
+            let mut cursor = None;
+            let mut page_sizes = vec![];
+            let mut paged_pubkeys = vec![];
+            loop {
+                let request = create_test_request(
+                    "getTokenAccountsByOwner",
+                    Some(json!([
+                        owner.to_string(),
+                        {"programId": ppl_token_id().to_string()},
+                        {"encoding": "base64", "limit": 2, "cursor": cursor},
+                    ])),
+                );
+                let response: RpcResponse<RpcAccountsPage<RpcKeyedAccount>> =
+                    parse_success_result(rpc.handle_request_sync(request));
+                let page = response.value;
+                page_sizes.push(page.accounts.len());
+                paged_pubkeys.extend(page.accounts.into_iter().map(|account| account.pubkey));
+                cursor = page.next_cursor;
+                if cursor.is_none() {
+                    break;
+                }
+            }
+            assert_eq!(page_sizes, vec![2, 2, 1]);
+            assert_eq!(
+                paged_pubkeys,
+                pubkeys.iter().map(Pubkey::to_string).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_token_rpcs_native_mint() {
+        let rpc = RpcHandler::start();
+        let bank = rpc.working_bank();
+        let RpcHandler { io, meta, .. } = rpc;
+
+        // A wrapped PUT account that received lamports since it was last synced
+        let owner = PplTokenPubkey::from([3; 32]);
+        let rent_exempt_reserve = 2_039_280;
+        let mut account_data = vec![0; TokenAccount::get_packed_len()];
+        let token_account = TokenAccount {
+            mint: ppl_token::native_mint::id(),
+            owner,
+            delegate: COption::None,
+            amount: 1_000_000_000,
+            state: TokenAccountState::Initialized,
+            is_native: COption::Some(rent_exempt_reserve),
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        TokenAccount::pack(token_account, &mut account_data).unwrap();
+        let token_account = AccountSharedData::from(Account {
+            lamports: 1_500_000_000 + rent_exempt_reserve as u128,
+            data: account_data.to_vec(),
+            owner: ppl_token_id(),
+            ..Account::default()
+        });
+        let token_account_pubkey = put_sdk::pubkey::new_rand();
+        bank.store_account(&token_account_pubkey, &token_account);
+
+        let req = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"getTokenAccountBalance","params":["{token_account_pubkey}"]}}"#,
+        );
+        let res = io.handle_request_sync(&req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let balance: UiTokenAmount =
+            serde_json::from_value(result["result"]["value"].clone()).unwrap();
+        assert_eq!(balance.amount, "1500000000");
+        assert_eq!(balance.decimals, 9);
+
+        // Filtering by the native mint
+        let req = format!(
+            r#"{{
+                "jsonrpc":"2.0",
+                "id":1,
+                "method":"getTokenAccountsByOwner",
+                "params":["{}", {{"mint": "{}"}}, {{"encoding": "jsonParsed"}}]
+            }}"#,
+            owner,
+            ppl_token::native_mint::id(),
+        );
+        let res = io.handle_request_sync(&req, meta);
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let accounts: Vec<RpcKeyedAccount> =
+            serde_json::from_value(result["result"]["value"].clone()).unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].pubkey, token_account_pubkey.to_string());
+        match &accounts[0].account.data {
+            UiAccountData::Json(parsed_account) => {
+                let info = &parsed_account.parsed["info"];
+                assert_eq!(info["isNative"], json!(true));
+                assert_eq!(info["tokenAmount"]["amount"], json!("1500000000"));
+                assert_eq!(info["tokenAmount"]["uiAmountString"], json!("1.5"));
+            }
+            data => panic!("expected jsonParsed account data, got {data:?}"),
+        }
+    }
+
     #[test]
     fn test_token_parsing() {
         let rpc = RpcHandler::start();
@@ -8756,18 +9644,39 @@ pub mod tests {
     #[test]
     fn test_get_ppl_token_owner_filter() {
         let owner = Pubkey::new_unique();
+        assert_eq!(
+            get_ppl_token_owner_filter(
+                &ppl_token_2022_id(),
+                &[
+                    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(32, owner.to_bytes().to_vec())),
+                    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(189, vec![2])),
+                ],
+            )
+            .unwrap(),
+            owner
+        );
         assert_eq!(
             get_ppl_token_owner_filter(
                 &Pubkey::from_str("PutToken11111111111111111111111111111111111").unwrap(),
                 &[
                     RpcFilterType::Memcmp(Memcmp::new_raw_bytes(32, owner.to_bytes().to_vec())),
-                    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(165, vec![2])),
+                    RpcFilterType::TokenAccountState,
                 ],
             )
             .unwrap(),
             owner
         );
 
+        // The account type only marks token accounts of the token-2022 program
+        assert!(get_ppl_token_owner_filter(
+            &Pubkey::from_str("PutToken11111111111111111111111111111111111").unwrap(),
+            &[
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(32, owner.to_bytes().to_vec())),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(189, vec![2])),
+            ],
+        )
+        .is_none());
+
         // Filtering on mint instead of owner
         assert!(get_ppl_token_owner_filter(
             &Pubkey::from_str("PutToken11111111111111111111111111111111111").unwrap(),
@@ -8789,6 +9698,384 @@ pub mod tests {
         .is_none());
     }
 
+    #[test]
+    fn test_domain_expiry_state() {
+        let expire_time = 1_000;
+        assert_eq!(
+            domain_expiry_state(expire_time, 100, expire_time),
+            RpcDomainExpiryState::Active
+        );
+        assert_eq!(
+            domain_expiry_state(expire_time, 100, expire_time + 100),
+            RpcDomainExpiryState::GracePeriod
+        );
+        assert_eq!(
+            domain_expiry_state(expire_time, 100, expire_time + 101),
+            RpcDomainExpiryState::Expired
+        );
+        assert_eq!(
+            domain_expiry_state(expire_time, 0, expire_time + 1),
+            RpcDomainExpiryState::Expired
+        );
+        assert_eq!(
+            domain_expiry_state(UnixTimestamp::MAX, u64::MAX, UnixTimestamp::MAX),
+            RpcDomainExpiryState::Active
+        );
+
+        for expiry_state in [
+            RpcDomainExpiryState::Active,
+            RpcDomainExpiryState::GracePeriod,
+            RpcDomainExpiryState::Expired,
+        ] {
+            assert!(domain_expiry_filter_allows(None, expiry_state));
+            assert!(domain_expiry_filter_allows(
+                Some(RpcDomainExpiryFilter::Flag),
+                expiry_state
+            ));
+            assert_eq!(
+                domain_expiry_filter_allows(
+                    Some(RpcDomainExpiryFilter::ExcludeExpired),
+                    expiry_state
+                ),
+                expiry_state != RpcDomainExpiryState::Expired
+            );
+            assert_eq!(
+                domain_expiry_filter_allows(
+                    Some(RpcDomainExpiryFilter::ExcludeGracePeriod),
+                    expiry_state
+                ),
+                expiry_state == RpcDomainExpiryState::Active
+            );
+        }
+    }
+
+    fn name_program_address(seed: &[u8], account_type: AccountType) -> Pubkey {
+        get_seeds_and_key(
+            &ppl_name::id(),
+            Some(hashv(&[seed]).to_bytes().to_vec()),
+            account_type,
+            None,
+        )
+        .unwrap()
+        .0
+    }
+
+    fn borsh_string(data: &mut Vec<u8>, value: &str) {
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+
+    fn store_domain_account(
+        bank: &Bank,
+        name: &str,
+        parent_key: &Pubkey,
+        owner: &Pubkey,
+        expire_time: UnixTimestamp,
+    ) -> Pubkey {
+        let mut data = vec![1, 1];
+        data.extend_from_slice(parent_key.as_ref());
+        data.extend_from_slice(owner.as_ref());
+        data.extend_from_slice(&expire_time.to_le_bytes());
+        data.extend_from_slice(&32u16.to_le_bytes());
+        borsh_string(&mut data, name);
+        let pubkey = name_program_address(get_domain(name).as_bytes(), AccountType::Domain);
+        let account = AccountSharedData::create(1, data, inline_name::id(), false, 0);
+        bank.store_account(&pubkey, &account);
+        pubkey
+    }
+
+    fn store_domain_resolve_account(
+        bank: &Bank,
+        name: &str,
+        domain_account: &Pubkey,
+        value: Option<&Pubkey>,
+    ) -> Pubkey {
+        let mut data = vec![2, 1];
+        data.extend_from_slice(domain_account.as_ref());
+        match value {
+            Some(value) => {
+                data.push(1);
+                data.extend_from_slice(&(PUBKEY_BYTES as u32).to_le_bytes());
+                data.extend_from_slice(value.as_ref());
+            }
+            None => data.push(0),
+        }
+        borsh_string(&mut data, name);
+        let pubkey = name_program_address(name.as_bytes(), AccountType::DomainResolve);
+        let account = AccountSharedData::create(1, data, inline_name::id(), false, 0);
+        bank.store_account(&pubkey, &account);
+        pubkey
+    }
+
+    fn store_address_resolve_account(bank: &Bank, address: &Pubkey, name: &str) {
+        let mut data = vec![3, 1];
+        borsh_string(&mut data, name);
+        let pubkey = name_program_address(address.as_ref(), AccountType::AddressResolve);
+        let account = AccountSharedData::create(1, data, inline_name::id(), false, 0);
+        bank.store_account(&pubkey, &account);
+    }
+
+    #[test]
+    fn test_rpc_resolve_domain() {
+        let rpc = RpcHandler::start();
+        let bank = rpc.working_bank();
+        let now = bank.clock().unix_timestamp;
+
+        let owner = Pubkey::new_unique();
+        let address = Pubkey::new_unique();
+        let top_domain = store_domain_account(&bank, "put", &Pubkey::default(), &owner, now + 100);
+        let domain = store_domain_account(&bank, "alice.put", &top_domain, &owner, now + 100);
+        let resolve_account =
+            store_domain_resolve_account(&bank, "alice.put", &domain, Some(&address));
+        store_domain_resolve_account(&bank, "pay.alice.put", &domain, None);
+
+        let request = create_test_request("resolveDomain", Some(json!(["alice.put."])));
+        let result: RpcResponse<Option<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(
+            result.value,
+            Some(RpcResolvedDomain {
+                name: "alice.put".to_string(),
+                top_domain_account: top_domain.to_string(),
+                domain_account: domain.to_string(),
+                owner: owner.to_string(),
+                resolve_account: Some(resolve_account.to_string()),
+                value: Some(address.to_string()),
+                expire_time: now + 100,
+                expired: false,
+            })
+        );
+
+        // A record without a value resolves to no value
+        let request = create_test_request("resolveDomain", Some(json!(["pay.alice.put"])));
+        let result: RpcResponse<Option<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        let resolved = result.value.unwrap();
+        assert!(resolved.resolve_account.is_some());
+        assert_eq!(resolved.value, None);
+
+        // A name without a domain account does not resolve
+        let request = create_test_request("resolveDomain", Some(json!(["bob.put"])));
+        let result: RpcResponse<Option<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(result.value, None);
+
+        // Expired domains still resolve, flagged as expired
+        let expired_domain = store_domain_account(&bank, "carol.put", &top_domain, &owner, now - 1);
+        store_domain_resolve_account(&bank, "carol.put", &expired_domain, Some(&address));
+        let request = create_test_request("resolveDomain", Some(json!(["carol.put"])));
+        let result: RpcResponse<Option<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        let resolved = result.value.unwrap();
+        assert_eq!(resolved.value, Some(address.to_string()));
+        assert!(resolved.expired);
+
+        let request = create_test_request("resolveDomain", Some(json!(["put"])));
+        let (code, message) = parse_failure_response(rpc.handle_request_sync(request));
+        assert_eq!(code, ErrorCode::InvalidParams.code());
+        assert_eq!(message, "Invalid domain name: put");
+    }
+
+    #[test]
+    fn test_rpc_reverse_lookup() {
+        let rpc = RpcHandler::start();
+        let bank = rpc.working_bank();
+        let now = bank.clock().unix_timestamp;
+
+        let owner = Pubkey::new_unique();
+        let address = Pubkey::new_unique();
+        let top_domain = store_domain_account(&bank, "put", &Pubkey::default(), &owner, now + 100);
+        // The domain is owned by someone other than the address it resolves to
+        let domain = store_domain_account(&bank, "alice.put", &top_domain, &owner, now + 100);
+        let resolve_account =
+            store_domain_resolve_account(&bank, "alice.put", &domain, Some(&address));
+        store_address_resolve_account(&bank, &address, "alice.put");
+
+        let request = create_test_request("reverseLookup", Some(json!([address.to_string()])));
+        let result: RpcResponse<Vec<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(result.value.len(), 1);
+        let resolved = &result.value[0];
+        assert_eq!(resolved.name, "alice.put");
+        assert_eq!(resolved.owner, owner.to_string());
+        assert_eq!(resolved.resolve_account, Some(resolve_account.to_string()));
+        assert_eq!(resolved.value, Some(address.to_string()));
+
+        // Records of the domains owned by the address are found without an address resolve
+        // account, unless they resolve elsewhere
+        let domain_owner = Pubkey::new_unique();
+        let bob_domain =
+            store_domain_account(&bank, "bob.put", &top_domain, &domain_owner, now + 100);
+        store_domain_resolve_account(&bank, "bob.put", &bob_domain, Some(&domain_owner));
+        store_domain_resolve_account(&bank, "pay.bob.put", &bob_domain, Some(&domain_owner));
+        store_domain_resolve_account(&bank, "mail.bob.put", &bob_domain, Some(&address));
+        let request = create_test_request("reverseLookup", Some(json!([domain_owner.to_string()])));
+        let result: RpcResponse<Vec<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        let names: Vec<_> = result.value.iter().map(|resolved| &resolved.name).collect();
+        assert_eq!(names, vec!["bob.put", "pay.bob.put"]);
+
+        // An address without any name
+        let request = create_test_request(
+            "reverseLookup",
+            Some(json!([Pubkey::new_unique().to_string()])),
+        );
+        let result: RpcResponse<Vec<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert!(result.value.is_empty());
+
+        // A stale address resolve account whose name now resolves elsewhere is ignored
+        let stale_address = Pubkey::new_unique();
+        store_address_resolve_account(&bank, &stale_address, "alice.put");
+        let request =
+            create_test_request("reverseLookup", Some(json!([stale_address.to_string()])));
+        let result: RpcResponse<Vec<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert!(result.value.is_empty());
+
+        // Names of expired domains are ignored
+        let expired_address = Pubkey::new_unique();
+        let expired_domain =
+            store_domain_account(&bank, "carol.put", &top_domain, &expired_address, now - 1);
+        store_domain_resolve_account(&bank, "carol.put", &expired_domain, Some(&expired_address));
+        store_address_resolve_account(&bank, &expired_address, "carol.put");
+        let request =
+            create_test_request("reverseLookup", Some(json!([expired_address.to_string()])));
+        let result: RpcResponse<Vec<RpcResolvedDomain>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert!(result.value.is_empty());
+    }
+
+    #[test]
+    fn test_rpc_get_domain_accounts_by_owner() {
+        let rpc = RpcHandler::start();
+        let bank = rpc.working_bank();
+        let now = bank.clock().unix_timestamp;
+
+        let owner = Pubkey::new_unique();
+        let address = Pubkey::new_unique();
+        let top_domain = store_domain_account(
+            &bank,
+            "put",
+            &Pubkey::default(),
+            &Pubkey::new_unique(),
+            now + 1_000,
+        );
+        let mut domains = HashMap::new();
+        let mut resolve_accounts = HashMap::new();
+        for (name, expire_time, expiry_state) in [
+            ("alice.put", now + 100, RpcDomainExpiryState::Active),
+            ("bob.put", now - 10, RpcDomainExpiryState::GracePeriod),
+            ("carol.put", now - 1_000, RpcDomainExpiryState::Expired),
+        ] {
+            let domain = store_domain_account(&bank, name, &top_domain, &owner, expire_time);
+            let resolve_account =
+                store_domain_resolve_account(&bank, name, &domain, Some(&address));
+            domains.insert(domain.to_string(), expiry_state);
+            resolve_accounts.insert(resolve_account.to_string(), expiry_state);
+        }
+        // Another owner's domain is never returned
+        store_domain_account(&bank, "dave.put", &top_domain, &address, now + 100);
+
+        let get_domain_accounts_by_owner = |config: Value| {
+            let request = create_test_request(
+                "getDomainAccountsByOwner",
+                Some(json!([owner.to_string(), config])),
+            );
+            let result: RpcResponse<OptionalPage<RpcDomainKeyedAccount>> =
+                parse_success_result(rpc.handle_request_sync(request));
+            result.value.parse_value()
+        };
+        let expiry_states = |accounts: &[RpcDomainKeyedAccount]| {
+            let mut states: Vec<_> = accounts
+                .iter()
+                .map(|account| (account.pubkey.clone(), account.expiry_state))
+                .collect();
+            states.sort_by(|a, b| a.0.cmp(&b.0));
+            states
+        };
+        let expected_states = |allowed: &[RpcDomainExpiryState]| {
+            let mut states: Vec<_> = domains
+                .iter()
+                .chain(resolve_accounts.iter())
+                .filter(|(_, state)| allowed.contains(state))
+                .map(|(pubkey, state)| (pubkey.clone(), Some(*state)))
+                .collect();
+            states.sort_by(|a, b| a.0.cmp(&b.0));
+            states
+        };
+
+        // Every domain and its resolve account are returned, flagged with the domain's state
+        let accounts = get_domain_accounts_by_owner(json!({
+            "expiryFilter": "flag",
+            "gracePeriod": 100,
+        }));
+        assert_eq!(
+            expiry_states(&accounts),
+            expected_states(&[
+                RpcDomainExpiryState::Active,
+                RpcDomainExpiryState::GracePeriod,
+                RpcDomainExpiryState::Expired,
+            ])
+        );
+        for account in &accounts {
+            let UiAccountData::Json(parsed) = &account.account.data else {
+                panic!("expected a jsonParsed account");
+            };
+            assert_eq!(parsed.program, "ppl-name");
+        }
+
+        // Without a grace period, bob.put has expired as well
+        let accounts = get_domain_accounts_by_owner(json!({"expiryFilter": "excludeExpired"}));
+        assert_eq!(
+            expiry_states(&accounts),
+            expected_states(&[RpcDomainExpiryState::Active])
+        );
+        let accounts = get_domain_accounts_by_owner(json!({
+            "expiryFilter": "excludeExpired",
+            "gracePeriod": 100,
+        }));
+        assert_eq!(
+            expiry_states(&accounts),
+            expected_states(&[
+                RpcDomainExpiryState::Active,
+                RpcDomainExpiryState::GracePeriod,
+            ])
+        );
+        let accounts = get_domain_accounts_by_owner(json!({
+            "expiryFilter": "excludeGracePeriod",
+            "gracePeriod": 100,
+        }));
+        assert_eq!(
+            expiry_states(&accounts),
+            expected_states(&[RpcDomainExpiryState::Active])
+        );
+
+        // Without an expiry filter every account is returned, without an expiry state
+        let accounts = get_domain_accounts_by_owner(json!({}));
+        assert_eq!(accounts.len(), 6);
+        assert!(accounts
+            .iter()
+            .all(|account| account.expiry_state.is_none()));
+
+        // Domain accounts honour a requested binary encoding, resolve accounts stay parsed
+        let accounts = get_domain_accounts_by_owner(json!({
+            "expiryFilter": "flag",
+            "encoding": "base64",
+        }));
+        assert_eq!(accounts.len(), 6);
+        for account in &accounts {
+            let pubkey = Pubkey::from_str(&account.pubkey).unwrap();
+            if domains.contains_key(&account.pubkey) {
+                let decoded: AccountSharedData = account.account.decode().unwrap();
+                assert_eq!(decoded.data(), bank.get_account(&pubkey).unwrap().data());
+            } else {
+                assert!(resolve_accounts.contains_key(&account.pubkey));
+                assert!(matches!(account.account.data, UiAccountData::Json(_)));
+            }
+        }
+    }
+
     #[test]
     fn test_rpc_single_gossip() {
         let exit = Arc::new(AtomicBool::new(false));
@@ -8836,7 +10123,6 @@ pub mod tests {
             cluster_info,
             Hash::default(),
             None,
-            None,
             optimistically_confirmed_bank.clone(),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             Arc::new(MaxSlots::default()),
@@ -6,29 +6,34 @@ use {
         rpc::check_is_at_least_confirmed,
         rpc_pubsub_service::PubSubConfig,
         rpc_subscription_tracker::{
-            AccountSubscriptionParams, BlockSubscriptionKind, BlockSubscriptionParams,
-            LogsSubscriptionKind, LogsSubscriptionParams, ProgramSubscriptionParams,
-            SignatureSubscriptionParams, SubscriptionControl, SubscriptionId, SubscriptionParams,
-            SubscriptionToken,
+            AccountSubscriptionParams, AccountsByOwnerSubscriptionParams, BlockSubscriptionKind,
+            BlockSubscriptionParams, LogsSubscriptionKind, LogsSubscriptionParams,
+            ProgramSubscriptionParams, SignatureSubscriptionParams, SubscriptionControl,
+            SubscriptionId, SubscriptionParams, SubscriptionToken,
         },
     },
     dashmap::DashMap,
     jsonrpc_core::{Error, ErrorCode, Result},
     jsonrpc_derive::rpc,
     jsonrpc_pubsub::{typed::Subscriber, SubscriptionId as PubSubSubscriptionId},
-    put_account_decoder::{UiAccount, UiAccountEncoding},
+    put_account_decoder::{
+        parse_nft::is_known_nft_token_id, parse_token::is_known_ppl_token_id, UiAccount,
+        UiAccountEncoding,
+    },
     put_rpc_client_api::{
         config::{
             RpcAccountInfoConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter,
-            RpcProgramAccountsConfig, RpcSignatureSubscribeConfig, RpcTransactionLogsConfig,
-            RpcTransactionLogsFilter,
+            RpcProgramAccountsConfig, RpcSignatureSubscribeConfig, RpcTokenAccountsFilter,
+            RpcTransactionLogsConfig, RpcTransactionLogsFilter,
         },
         response::{
             Response as RpcResponse, RpcBlockUpdate, RpcKeyedAccount, RpcLogsResponse,
             RpcSignatureResult, RpcVersionInfo, RpcVote, SlotInfo, SlotUpdate,
         },
     },
-    put_sdk::{clock::Slot, pubkey::Pubkey, signature::Signature},
+    put_sdk::{
+        clock::Slot, commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature,
+    },
     put_transaction_status::UiTransactionEncoding,
     std::{str::FromStr, sync::Arc},
 };
@@ -103,6 +108,89 @@ pub trait RpcPutPubSub {
         id: PubSubSubscriptionId,
     ) -> Result<bool>;
 
+    // Get notification every time a token account of a particular owner is changed
+    // Accepts owner parameter as base-58 encoded string
+    #[pubsub(
+        subscription = "tokenAccountsByOwnerNotification",
+        subscribe,
+        name = "tokenAccountsByOwnerSubscribe"
+    )]
+    fn token_accounts_by_owner_subscribe(
+        &self,
+        meta: Self::Metadata,
+        subscriber: Subscriber<RpcResponse<RpcKeyedAccount>>,
+        owner_str: String,
+        token_account_filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    );
+
+    // Unsubscribe from token accounts by owner notification subscription.
+    #[pubsub(
+        subscription = "tokenAccountsByOwnerNotification",
+        unsubscribe,
+        name = "tokenAccountsByOwnerUnsubscribe"
+    )]
+    fn token_accounts_by_owner_unsubscribe(
+        &self,
+        meta: Option<Self::Metadata>,
+        id: PubSubSubscriptionId,
+    ) -> Result<bool>;
+
+    // Get notification every time an nft account of a particular owner is changed
+    // Accepts owner parameter as base-58 encoded string
+    #[pubsub(
+        subscription = "nftAccountsByOwnerNotification",
+        subscribe,
+        name = "nftAccountsByOwnerSubscribe"
+    )]
+    fn nft_accounts_by_owner_subscribe(
+        &self,
+        meta: Self::Metadata,
+        subscriber: Subscriber<RpcResponse<RpcKeyedAccount>>,
+        owner_str: String,
+        token_account_filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    );
+
+    // Unsubscribe from nft accounts by owner notification subscription.
+    #[pubsub(
+        subscription = "nftAccountsByOwnerNotification",
+        unsubscribe,
+        name = "nftAccountsByOwnerUnsubscribe"
+    )]
+    fn nft_accounts_by_owner_unsubscribe(
+        &self,
+        meta: Option<Self::Metadata>,
+        id: PubSubSubscriptionId,
+    ) -> Result<bool>;
+
+    // Get notification every time a domain account of a particular owner is changed
+    // Accepts owner parameter as base-58 encoded string
+    #[pubsub(
+        subscription = "domainNotification",
+        subscribe,
+        name = "domainSubscribe"
+    )]
+    fn domain_subscribe(
+        &self,
+        meta: Self::Metadata,
+        subscriber: Subscriber<RpcResponse<RpcKeyedAccount>>,
+        owner_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    );
+
+    // Unsubscribe from domain notification subscription.
+    #[pubsub(
+        subscription = "domainNotification",
+        unsubscribe,
+        name = "domainUnsubscribe"
+    )]
+    fn domain_unsubscribe(
+        &self,
+        meta: Option<Self::Metadata>,
+        id: PubSubSubscriptionId,
+    ) -> Result<bool>;
+
     // Get logs for all transactions that reference the specified address
     #[pubsub(subscription = "logsNotification", subscribe, name = "logsSubscribe")]
     fn logs_subscribe(
@@ -281,6 +369,47 @@ mod internal {
         #[rpc(name = "programUnsubscribe")]
         fn program_unsubscribe(&self, id: SubscriptionId) -> Result<bool>;
 
+        // Get notification every time a token account of a particular owner is changed
+        // Accepts owner parameter as base-58 encoded string
+        #[rpc(name = "tokenAccountsByOwnerSubscribe")]
+        fn token_accounts_by_owner_subscribe(
+            &self,
+            owner_str: String,
+            token_account_filter: RpcTokenAccountsFilter,
+            config: Option<RpcAccountInfoConfig>,
+        ) -> Result<SubscriptionId>;
+
+        // Unsubscribe from token accounts by owner notification subscription.
+        #[rpc(name = "tokenAccountsByOwnerUnsubscribe")]
+        fn token_accounts_by_owner_unsubscribe(&self, id: SubscriptionId) -> Result<bool>;
+
+        // Get notification every time an nft account of a particular owner is changed
+        // Accepts owner parameter as base-58 encoded string
+        #[rpc(name = "nftAccountsByOwnerSubscribe")]
+        fn nft_accounts_by_owner_subscribe(
+            &self,
+            owner_str: String,
+            token_account_filter: RpcTokenAccountsFilter,
+            config: Option<RpcAccountInfoConfig>,
+        ) -> Result<SubscriptionId>;
+
+        // Unsubscribe from nft accounts by owner notification subscription.
+        #[rpc(name = "nftAccountsByOwnerUnsubscribe")]
+        fn nft_accounts_by_owner_unsubscribe(&self, id: SubscriptionId) -> Result<bool>;
+
+        // Get notification every time a domain account of a particular owner is changed
+        // Accepts owner parameter as base-58 encoded string
+        #[rpc(name = "domainSubscribe")]
+        fn domain_subscribe(
+            &self,
+            owner_str: String,
+            config: Option<RpcAccountInfoConfig>,
+        ) -> Result<SubscriptionId>;
+
+        // Unsubscribe from domain notification subscription.
+        #[rpc(name = "domainUnsubscribe")]
+        fn domain_unsubscribe(&self, id: SubscriptionId) -> Result<bool>;
+
         // Get logs for all transactions that reference the specified address
         #[rpc(name = "logsSubscribe")]
         fn logs_subscribe(
@@ -419,6 +548,46 @@ fn param<T: FromStr>(param_str: &str, thing: &str) -> Result<T> {
     })
 }
 
+// Accounts by owner subscriptions are always notified in `jsonParsed` encoding
+fn accounts_by_owner_commitment(config: Option<RpcAccountInfoConfig>) -> Result<CommitmentConfig> {
+    let RpcAccountInfoConfig {
+        encoding,
+        data_slice,
+        commitment,
+        min_context_slot: _, // ignored
+    } = config.unwrap_or_default();
+    if encoding.unwrap_or(UiAccountEncoding::JsonParsed) != UiAccountEncoding::JsonParsed
+        || data_slice.is_some()
+    {
+        return Err(Error {
+            code: ErrorCode::InvalidParams,
+            message: "Invalid Request: only jsonParsed encoding is supported".into(),
+            data: None,
+        });
+    }
+    Ok(commitment.unwrap_or_default())
+}
+
+fn token_accounts_filter_mint(
+    token_account_filter: RpcTokenAccountsFilter,
+    is_known_program_id: fn(&Pubkey) -> bool,
+) -> Result<Option<Pubkey>> {
+    match token_account_filter {
+        RpcTokenAccountsFilter::Mint(mint_str) => Ok(Some(param::<Pubkey>(&mint_str, "mint")?)),
+        RpcTokenAccountsFilter::ProgramId(program_id_str) => {
+            let program_id = param::<Pubkey>(&program_id_str, "programId")?;
+            if !is_known_program_id(&program_id) {
+                return Err(Error {
+                    code: ErrorCode::InvalidParams,
+                    message: "Invalid Request: unrecognized Token program id".into(),
+                    data: None,
+                });
+            }
+            Ok(None)
+        }
+    }
+}
+
 impl RpcPutPubSubInternal for RpcPutPubSubImpl {
     fn account_subscribe(
         &self,
@@ -468,6 +637,59 @@ impl RpcPutPubSubInternal for RpcPutPubSubImpl {
         self.unsubscribe(id)
     }
 
+    fn token_accounts_by_owner_subscribe(
+        &self,
+        owner_str: String,
+        token_account_filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<SubscriptionId> {
+        let params = AccountsByOwnerSubscriptionParams {
+            owner: param::<Pubkey>(&owner_str, "owner")?,
+            mint: token_accounts_filter_mint(token_account_filter, is_known_ppl_token_id)?,
+            commitment: accounts_by_owner_commitment(config)?,
+        };
+        self.subscribe(SubscriptionParams::TokenAccountsByOwner(params))
+    }
+
+    fn token_accounts_by_owner_unsubscribe(&self, id: SubscriptionId) -> Result<bool> {
+        self.unsubscribe(id)
+    }
+
+    fn nft_accounts_by_owner_subscribe(
+        &self,
+        owner_str: String,
+        token_account_filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<SubscriptionId> {
+        let params = AccountsByOwnerSubscriptionParams {
+            owner: param::<Pubkey>(&owner_str, "owner")?,
+            mint: token_accounts_filter_mint(token_account_filter, is_known_nft_token_id)?,
+            commitment: accounts_by_owner_commitment(config)?,
+        };
+        self.subscribe(SubscriptionParams::NftAccountsByOwner(params))
+    }
+
+    fn nft_accounts_by_owner_unsubscribe(&self, id: SubscriptionId) -> Result<bool> {
+        self.unsubscribe(id)
+    }
+
+    fn domain_subscribe(
+        &self,
+        owner_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<SubscriptionId> {
+        let params = AccountsByOwnerSubscriptionParams {
+            owner: param::<Pubkey>(&owner_str, "owner")?,
+            mint: None,
+            commitment: accounts_by_owner_commitment(config)?,
+        };
+        self.subscribe(SubscriptionParams::Domain(params))
+    }
+
+    fn domain_unsubscribe(&self, id: SubscriptionId) -> Result<bool> {
+        self.unsubscribe(id)
+    }
+
     fn logs_subscribe(
         &self,
         filter: RpcTransactionLogsFilter,
@@ -127,6 +127,9 @@ struct SentNotificationStats {
     num_logs: AtomicUsize,
     num_program: AtomicUsize,
     num_signature: AtomicUsize,
+    num_token_accounts_by_owner: AtomicUsize,
+    num_nft_accounts_by_owner: AtomicUsize,
+    num_domain: AtomicUsize,
     num_slot: AtomicUsize,
     num_slots_updates: AtomicUsize,
     num_root: AtomicUsize,
@@ -160,6 +163,21 @@ impl SentNotificationStats {
                     self.num_signature.swap(0, Ordering::Relaxed) as i64,
                     i64
                 ),
+                (
+                    "num_token_accounts_by_owner",
+                    self.num_token_accounts_by_owner.swap(0, Ordering::Relaxed) as i64,
+                    i64
+                ),
+                (
+                    "num_nft_accounts_by_owner",
+                    self.num_nft_accounts_by_owner.swap(0, Ordering::Relaxed) as i64,
+                    i64
+                ),
+                (
+                    "num_domain",
+                    self.num_domain.swap(0, Ordering::Relaxed) as i64,
+                    i64
+                ),
                 (
                     "num_slot",
                     self.num_slot.swap(0, Ordering::Relaxed) as i64,
@@ -212,6 +230,19 @@ fn increment_sent_notification_stats(
         SubscriptionParams::Signature(_) => {
             stats.num_signature.fetch_add(1, Ordering::Relaxed);
         }
+        SubscriptionParams::TokenAccountsByOwner(_) => {
+            stats
+                .num_token_accounts_by_owner
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        SubscriptionParams::NftAccountsByOwner(_) => {
+            stats
+                .num_nft_accounts_by_owner
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        SubscriptionParams::Domain(_) => {
+            stats.num_domain.fetch_add(1, Ordering::Relaxed);
+        }
         SubscriptionParams::Slot => {
             stats.num_slot.fetch_add(1, Ordering::Relaxed);
         }
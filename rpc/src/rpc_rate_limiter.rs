@@ -0,0 +1,501 @@
+//! The `rpc_rate_limiter` module implements per-client rate limiting of JSON RPC method calls.
+//!
+//! Every client, identified by its IP address as reported by the trusted reverse proxies in front
+//! of the RPC service, holds a token bucket of credits that refills at a constant rate. Each call
+//! is charged the cost of its method, so that expensive methods such as `getProgramAccounts`
+//! exhaust the bucket sooner than cheap ones. Methods may additionally be limited to a number of
+//! calls per second for each client.
+//!
+//! The number of clients tracked at once is bounded. Once it is reached, the calls of further
+//! clients share a single set of buckets until idle clients are removed.
+
+use {
+    crate::rpc::JsonRpcRequestProcessor,
+    jsonrpc_core::{
+        futures::future::{self, Either},
+        Call, Error, Middleware, Output, Response,
+    },
+    put_rpc_client_api::custom_error::RpcCustomError,
+    std::{
+        collections::{HashMap, HashSet},
+        future::Future,
+        net::{IpAddr, Ipv4Addr},
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    },
+};
+
+/// Number of seconds of credits a client may accumulate unless configured otherwise
+pub const DEFAULT_RATE_LIMIT_BURST_SECONDS: u64 = 10;
+
+/// Number of clients tracked at once unless configured otherwise
+pub const DEFAULT_RATE_LIMIT_MAX_CLIENTS: usize = 100_000;
+
+/// Cost of the methods that are more expensive to serve than the default of 1 credit
+pub const DEFAULT_RATE_LIMIT_METHOD_COSTS: &[(&str, u64)] = &[
+    ("getProgramAccounts", 100),
+    ("getLargestAccounts", 100),
+    ("getSupply", 50),
+    ("getTokenLargestAccounts", 20),
+    ("getTokenAccountsByOwner", 20),
+    ("getTokenAccountsByDelegate", 20),
+    ("getNftAccountsByOwner", 20),
+    ("getDomainAccountsByOwner", 20),
+    ("getSignaturesForAddress", 20),
+    ("getConfirmedSignaturesForAddress2", 20),
+    ("getBlock", 10),
+    ("getConfirmedBlock", 10),
+    ("getBlocks", 5),
+    ("getBlocksWithLimit", 5),
+    ("getMultipleAccounts", 5),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcRateLimitConfig {
+    /// Credits earned by each client per second
+    pub credits_per_second: u64,
+    /// Credits a client can accumulate, which bounds the size of its bursts
+    pub burst_credits: u64,
+    /// Credits charged per call, by method name. Other methods cost 1 credit
+    pub method_costs: HashMap<String, u64>,
+    /// Calls per second allowed to each client, by method name, on top of its credits
+    pub method_calls_per_second: HashMap<String, u64>,
+    /// Addresses of the reverse proxies in front of the RPC service, which are skipped when
+    /// identifying the client of a request from its X-Forwarded-For header
+    pub trusted_proxies: HashSet<IpAddr>,
+    /// Number of clients tracked at once
+    pub max_clients: usize,
+}
+
+impl RpcRateLimitConfig {
+    pub fn new(credits_per_second: u64) -> Self {
+        Self {
+            credits_per_second,
+            burst_credits: credits_per_second.saturating_mul(DEFAULT_RATE_LIMIT_BURST_SECONDS),
+            method_costs: DEFAULT_RATE_LIMIT_METHOD_COSTS
+                .iter()
+                .map(|(method, cost)| (method.to_string(), *cost))
+                .collect(),
+            method_calls_per_second: HashMap::new(),
+            trusted_proxies: HashSet::new(),
+            max_clients: DEFAULT_RATE_LIMIT_MAX_CLIENTS,
+        }
+    }
+
+    /// Clients are identified by the forwarding headers of the reverse proxies in front of the
+    /// RPC service, which anyone could forge if the proxies were not known
+    pub fn verify(&self) -> Result<(), String> {
+        if self.trusted_proxies.is_empty() {
+            return Err("RPC rate limiting requires trusted reverse proxies".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn method_cost(&self, method: &str) -> u64 {
+        self.method_costs.get(method).copied().unwrap_or(1)
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    capacity: f64,
+    refill_per_second: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u64, refill_per_second: u64, now: Instant) -> Self {
+        let capacity = capacity.max(1) as f64;
+        Self {
+            tokens: capacity,
+            capacity,
+            refill_per_second: refill_per_second.max(1) as f64,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = self
+            .capacity
+            .min(self.tokens + elapsed.as_secs_f64() * self.refill_per_second);
+        self.last_refill = now;
+    }
+
+    /// Time until `cost` tokens are available. Costs above the capacity are charged the whole
+    /// bucket, so that every call can eventually succeed.
+    fn wait_time(&self, cost: u64) -> Duration {
+        let cost = self.capacity.min(cost as f64);
+        if self.tokens >= cost {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((cost - self.tokens) / self.refill_per_second)
+        }
+    }
+
+    fn take(&mut self, cost: u64) {
+        self.tokens = (self.tokens - self.capacity.min(cost as f64)).max(0.0);
+    }
+
+    fn is_full_at(&self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens + elapsed.as_secs_f64() * self.refill_per_second >= self.capacity
+    }
+}
+
+#[derive(Debug)]
+struct ClientBuckets {
+    credits: TokenBucket,
+    methods: HashMap<String, TokenBucket>,
+}
+
+#[derive(Debug)]
+struct RateLimiterState {
+    clients: HashMap<IpAddr, ClientBuckets>,
+    last_prune: Instant,
+}
+
+#[derive(Debug)]
+pub struct RpcRateLimiter {
+    config: RpcRateLimitConfig,
+    state: Mutex<RateLimiterState>,
+}
+
+impl RpcRateLimiter {
+    /// Interval between the removals of idle clients, whose buckets are full again
+    const PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+    /// Client charged for the calls of requests that don't identify their client, and of clients
+    /// beyond the maximum number tracked
+    pub const SHARED_CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+
+    pub fn new(config: RpcRateLimitConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(RateLimiterState {
+                clients: HashMap::new(),
+                last_prune: Instant::now(),
+            }),
+        }
+    }
+
+    /// Charges a call of `method` to `client`. Returns how long the client must wait before the
+    /// call can be accepted if it is over its limits, in which case nothing is charged.
+    pub fn check(&self, client: IpAddr, method: &str) -> Result<(), Duration> {
+        self.check_at(client, method, Instant::now())
+    }
+
+    fn check_at(&self, client: IpAddr, method: &str, now: Instant) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+        if now.saturating_duration_since(state.last_prune) >= Self::PRUNE_INTERVAL {
+            state.clients.retain(|_, buckets| {
+                !buckets.credits.is_full_at(now)
+                    || buckets
+                        .methods
+                        .values()
+                        .any(|bucket| !bucket.is_full_at(now))
+            });
+            state.last_prune = now;
+        }
+
+        let config = &self.config;
+        let client = if client != Self::SHARED_CLIENT
+            && state.clients.len() >= config.max_clients
+            && !state.clients.contains_key(&client)
+        {
+            inc_new_counter_info!("rpc-rate-limit-clients-overflow", 1);
+            Self::SHARED_CLIENT
+        } else {
+            client
+        };
+        let buckets = state
+            .clients
+            .entry(client)
+            .or_insert_with(|| ClientBuckets {
+                credits: TokenBucket::new(config.burst_credits, config.credits_per_second, now),
+                methods: HashMap::new(),
+            });
+        let method_bucket = config
+            .method_calls_per_second
+            .get(method)
+            .map(|calls_per_second| {
+                buckets
+                    .methods
+                    .entry(method.to_string())
+                    .or_insert_with(|| TokenBucket::new(*calls_per_second, *calls_per_second, now))
+            });
+
+        let cost = config.method_cost(method);
+        buckets.credits.refill(now);
+        let mut wait_time = buckets.credits.wait_time(cost);
+        if let Some(method_bucket) = method_bucket {
+            method_bucket.refill(now);
+            wait_time = wait_time.max(method_bucket.wait_time(1));
+            if wait_time.is_zero() {
+                method_bucket.take(1);
+            }
+        }
+        if !wait_time.is_zero() {
+            return Err(wait_time);
+        }
+        buckets.credits.take(cost);
+        inc_new_counter_info!("rpc-rate-limit-credits-charged", cost as usize);
+        Ok(())
+    }
+}
+
+/// Rejects the calls of clients that are over their rate limits, with
+/// [`RpcCustomError::RateLimited`]. Calls are accepted unconditionally when no limits are
+/// configured.
+#[derive(Debug, Default, Clone)]
+pub struct RpcRateLimitMiddleware {
+    limiter: Option<Arc<RpcRateLimiter>>,
+}
+
+impl RpcRateLimitMiddleware {
+    pub fn new(config: Option<RpcRateLimitConfig>) -> Self {
+        Self {
+            limiter: config.map(|config| Arc::new(RpcRateLimiter::new(config))),
+        }
+    }
+}
+
+impl Middleware<JsonRpcRequestProcessor> for RpcRateLimitMiddleware {
+    type Future = future::Ready<Option<Response>>;
+    type CallFuture = future::Ready<Option<Output>>;
+
+    fn on_call<F, X>(
+        &self,
+        call: Call,
+        meta: JsonRpcRequestProcessor,
+        next: F,
+    ) -> Either<Self::CallFuture, X>
+    where
+        F: Fn(Call, JsonRpcRequestProcessor) -> X + Send + Sync,
+        X: Future<Output = Option<Output>> + Send + 'static,
+    {
+        let Some(limiter) = self.limiter.as_ref() else {
+            return Either::Right(next(call, meta));
+        };
+        let (method, id, jsonrpc) = match &call {
+            Call::MethodCall(method_call) => (
+                &method_call.method,
+                Some(method_call.id.clone()),
+                method_call.jsonrpc,
+            ),
+            Call::Notification(notification) => (&notification.method, None, notification.jsonrpc),
+            Call::Invalid { .. } => return Either::Right(next(call, meta)),
+        };
+
+        let client = meta.client_ip().unwrap_or(RpcRateLimiter::SHARED_CLIENT);
+        match limiter.check(client, method) {
+            Ok(()) => Either::Right(next(call, meta)),
+            Err(retry_after) => {
+                inc_new_counter_info!("rpc-rate-limit-rejected", 1);
+                debug!("rate limited {method} call from {client}, retry after {retry_after:?}");
+                let error = Error::from(RpcCustomError::RateLimited {
+                    method: method.clone(),
+                    retry_after_ms: retry_after.as_millis() as u64,
+                });
+                Either::Left(future::ready(
+                    id.map(|id| Output::from(Err(error), id, jsonrpc)),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::rpc::rpc_minimal::*,
+        jsonrpc_core::{ErrorCode, MetaIoHandler},
+        put_client::connection_cache::ConnectionCache,
+        put_ledger::genesis_utils::create_genesis_config,
+        put_rpc_client_api::custom_error::JSON_RPC_SERVER_ERROR_RATE_LIMITED,
+        put_runtime::bank::Bank,
+        put_streamer::socket::SocketAddrSpace,
+    };
+
+    fn config_for_test() -> RpcRateLimitConfig {
+        RpcRateLimitConfig {
+            credits_per_second: 10,
+            burst_credits: 20,
+            method_costs: HashMap::from([("getProgramAccounts".to_string(), 15)]),
+            method_calls_per_second: HashMap::from([("getBlock".to_string(), 2)]),
+            trusted_proxies: HashSet::from([IpAddr::V4(Ipv4Addr::LOCALHOST)]),
+            max_clients: DEFAULT_RATE_LIMIT_MAX_CLIENTS,
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_config_verify() {
+        assert_eq!(config_for_test().verify(), Ok(()));
+        let config = RpcRateLimitConfig::new(10);
+        assert!(config.verify().is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_method_costs() {
+        let limiter = RpcRateLimiter::new(config_for_test());
+        let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let other_client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let now = Instant::now();
+
+        assert_eq!(limiter.check_at(client, "getProgramAccounts", now), Ok(()));
+        for _ in 0..5 {
+            assert_eq!(limiter.check_at(client, "getSlot", now), Ok(()));
+        }
+        assert_eq!(
+            limiter.check_at(client, "getSlot", now),
+            Err(Duration::from_millis(100))
+        );
+        assert_eq!(
+            limiter.check_at(client, "getProgramAccounts", now),
+            Err(Duration::from_millis(1_500))
+        );
+
+        // Clients are limited independently
+        assert_eq!(
+            limiter.check_at(other_client, "getProgramAccounts", now),
+            Ok(())
+        );
+
+        // Credits refill over time, up to the burst
+        let later = now + Duration::from_millis(1_500);
+        assert_eq!(
+            limiter.check_at(client, "getProgramAccounts", later),
+            Ok(())
+        );
+        let much_later = later + Duration::from_secs(60);
+        for _ in 0..20 {
+            assert_eq!(limiter.check_at(client, "getSlot", much_later), Ok(()));
+        }
+        assert!(limiter.check_at(client, "getSlot", much_later).is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_method_calls_per_second() {
+        let limiter = RpcRateLimiter::new(config_for_test());
+        let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let now = Instant::now();
+
+        assert_eq!(limiter.check_at(client, "getBlock", now), Ok(()));
+        assert_eq!(limiter.check_at(client, "getBlock", now), Ok(()));
+        assert_eq!(
+            limiter.check_at(client, "getBlock", now),
+            Err(Duration::from_millis(500))
+        );
+        // Rejected calls are not charged
+        for _ in 0..18 {
+            assert_eq!(limiter.check_at(client, "getSlot", now), Ok(()));
+        }
+        assert_eq!(
+            limiter.check_at(client, "getBlock", now + Duration::from_millis(500)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_rate_limiter_prunes_idle_clients() {
+        let limiter = RpcRateLimiter::new(config_for_test());
+        let now = Instant::now();
+        for i in 0..10 {
+            let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, i));
+            assert_eq!(limiter.check_at(client, "getBlock", now), Ok(()));
+        }
+        assert_eq!(limiter.state.lock().unwrap().clients.len(), 10);
+
+        let later = now + RpcRateLimiter::PRUNE_INTERVAL;
+        let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0));
+        assert_eq!(limiter.check_at(client, "getSlot", later), Ok(()));
+        assert_eq!(limiter.state.lock().unwrap().clients.len(), 1);
+    }
+
+    #[test]
+    fn test_rate_limiter_max_clients() {
+        let limiter = RpcRateLimiter::new(RpcRateLimitConfig {
+            max_clients: 2,
+            ..config_for_test()
+        });
+        let clients: Vec<_> = (0..4)
+            .map(|i| IpAddr::V4(Ipv4Addr::new(10, 0, 0, i)))
+            .collect();
+        let now = Instant::now();
+
+        assert_eq!(limiter.check_at(clients[0], "getSlot", now), Ok(()));
+        assert_eq!(limiter.check_at(clients[1], "getSlot", now), Ok(()));
+        // Further clients share a single set of buckets
+        assert_eq!(
+            limiter.check_at(clients[2], "getProgramAccounts", now),
+            Ok(())
+        );
+        assert_eq!(
+            limiter.check_at(clients[3], "getProgramAccounts", now),
+            Err(Duration::from_secs(1))
+        );
+        assert_eq!(limiter.check_at(clients[0], "getSlot", now), Ok(()));
+        assert_eq!(limiter.state.lock().unwrap().clients.len(), 3);
+
+        // Clients are tracked again once idle clients are removed
+        let later = now + RpcRateLimiter::PRUNE_INTERVAL;
+        assert_eq!(
+            limiter.check_at(clients[3], "getProgramAccounts", later),
+            Ok(())
+        );
+        assert_eq!(
+            limiter.check_at(clients[2], "getProgramAccounts", later),
+            Ok(())
+        );
+        assert!(limiter
+            .state
+            .lock()
+            .unwrap()
+            .clients
+            .contains_key(&clients[3]));
+    }
+
+    #[test]
+    fn test_rate_limit_middleware() {
+        let genesis = create_genesis_config(100);
+        let bank = Arc::new(Bank::new_for_tests(&genesis.genesis_config));
+        let connection_cache = Arc::new(ConnectionCache::new("connection_cache_test"));
+        let meta = JsonRpcRequestProcessor::new_from_bank(
+            &bank,
+            SocketAddrSpace::Unspecified,
+            connection_cache,
+        );
+        let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let other_client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+
+        let config = RpcRateLimitConfig {
+            credits_per_second: 1,
+            burst_credits: 2,
+            ..config_for_test()
+        };
+        let mut io = MetaIoHandler::with_middleware(RpcRateLimitMiddleware::new(Some(config)));
+        io.extend_with(MinimalImpl.to_delegate());
+        let get_slot = |client_ip| {
+            let req = r#"{"jsonrpc":"2.0","id":1,"method":"getSlot"}"#;
+            let res = io.handle_request_sync(req, meta.clone().with_client_ip(client_ip));
+            serde_json::from_str::<serde_json::Value>(&res.expect("actual response"))
+                .expect("actual response deserialization")
+        };
+
+        for _ in 0..2 {
+            assert_eq!(get_slot(Some(client))["result"], 0);
+        }
+        let result = get_slot(Some(client));
+        assert_eq!(
+            result["error"]["code"],
+            ErrorCode::ServerError(JSON_RPC_SERVER_ERROR_RATE_LIMITED).code()
+        );
+        assert!(result["error"]["data"]["retryAfterMs"].as_u64().unwrap() > 0);
+
+        // Other clients, and requests that don't identify their client, are limited separately
+        assert_eq!(get_slot(Some(other_client))["result"], 0);
+        assert_eq!(get_slot(None)["result"], 0);
+    }
+}
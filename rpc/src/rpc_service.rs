@@ -11,6 +11,7 @@ use {
         },
         rpc_cache::LargestAccountsCache,
         rpc_health::*,
+        rpc_rate_limiter::RpcRateLimitMiddleware,
     },
     crossbeam_channel::unbounded,
     jsonrpc_core::{futures::prelude::*, MetaIoHandler},
@@ -24,7 +25,7 @@ use {
     put_ledger::{
         bigtable_upload::ConfirmedBlockUploadConfig,
         bigtable_upload_service::BigTableUploadService, blockstore::Blockstore,
-        leader_schedule_cache::LeaderScheduleCache,
+        hbase_upload_service::HBaseUploadService, leader_schedule_cache::LeaderScheduleCache,
     },
     put_metrics::inc_new_counter_info,
     put_perf::thread::renice_this_thread,
@@ -41,9 +42,10 @@ use {
     },
     put_send_transaction_service::send_transaction_service::{self, SendTransactionService},
     put_storage_bigtable::CredentialType,
+    put_storage_interface::LongTermStorage,
     std::{
         collections::HashSet,
-        net::SocketAddr,
+        net::{IpAddr, SocketAddr},
         path::{Path, PathBuf},
         sync::{
             atomic::{AtomicBool, AtomicU64, Ordering},
@@ -337,6 +339,36 @@ fn process_rest(bank_forks: &Arc<RwLock<BankForks>>, path: &str) -> Option<Strin
     }
 }
 
+/// Address of the client that sent `request`, as reported by the reverse proxies in front of the
+/// RPC service: the rightmost X-Forwarded-For entry that is not one of `trusted_proxies`, since
+/// the entries to its left are supplied by the client itself, or else the X-Real-IP header.
+/// Requests that were not forwarded don't identify their client.
+///
+/// The HTTP server does not expose the TCP peer address of a request, so these headers can only
+/// be trusted when the RPC service is reachable through the trusted proxies alone. Rate limiting
+/// therefore requires `trusted_proxies` to be configured.
+fn forwarded_client_ip(
+    request: &hyper::Request<hyper::Body>,
+    trusted_proxies: &HashSet<IpAddr>,
+) -> Option<IpAddr> {
+    let header = |name: &str| {
+        request
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    };
+    match header("x-forwarded-for") {
+        Some(forwarded_for) => forwarded_for
+            .rsplit(',')
+            .map(|client_ip| client_ip.trim().parse::<IpAddr>().ok())
+            .find(|client_ip| {
+                client_ip.map_or(true, |client_ip| !trusted_proxies.contains(&client_ip))
+            })
+            .flatten(),
+        None => header("x-real-ip").and_then(|client_ip| client_ip.trim().parse().ok()),
+    }
+}
+
 impl JsonRpcService {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -366,6 +398,9 @@ impl JsonRpcService {
     ) -> Result<Self, String> {
         info!("rpc bound to {:?}", rpc_addr);
         info!("rpc configuration: {:?}", config);
+        if let Some(rate_limit) = &config.rate_limit {
+            rate_limit.verify()?;
+        }
         let rpc_threads = 1.max(config.rpc_threads);
         let rpc_niceness_adj = config.rpc_niceness_adj;
 
@@ -454,29 +489,71 @@ impl JsonRpcService {
                 (None, None)
             };
 
-        let hbase_ledger_storage =
-        if config.rpc_hbase_config.is_some() {
-            put_storage_hbase::LedgerStorage::new(
-                    config.rpc_hbase_config.clone().unwrap().hbase_rpc_url,
-                    None,
-                )
-                .map(|hbase_ledger_storage| {
-                    info!("Hbase edger storage initialized");
-                    Some(hbase_ledger_storage)
-                })
-                .unwrap_or_else(|err| {
-                    error!("Failed to initialize Hbase ledger storage: {:?}", err);
-                    None
-                })
-        } else {
-            None
-        };
-        
+        let exit_hbase_ledger_upload_service = Arc::new(AtomicBool::new(false));
+
+        let (hbase_ledger_storage, _hbase_ledger_upload_service) =
+            if let Some(RpcHBaseConfig {
+                enable_hbase_ledger_upload,
+                ref hbase_rpc_url,
+                ref hbase_namespace,
+                timeout,
+                ..
+            }) = config.rpc_hbase_config
+            {
+                put_storage_hbase::LedgerStorage::new(hbase_rpc_url.clone(), hbase_namespace, timeout)
+                    .map(|hbase_ledger_storage| {
+                        info!("HBase ledger storage initialized");
+
+                        let hbase_ledger_upload_service = if enable_hbase_ledger_upload {
+                            Some(Arc::new(HBaseUploadService::new(
+                                runtime.clone(),
+                                hbase_ledger_storage.clone(),
+                                blockstore.clone(),
+                                block_commitment_cache.clone(),
+                                max_complete_transaction_status_slot.clone(),
+                                max_complete_rewards_slot.clone(),
+                                exit_hbase_ledger_upload_service.clone(),
+                            )))
+                        } else {
+                            None
+                        };
+
+                        (Some(hbase_ledger_storage), hbase_ledger_upload_service)
+                    })
+                    .unwrap_or_else(|err| {
+                        error!("Failed to initialize HBase ledger storage: {:?}", err);
+                        (None, None)
+                    })
+            } else {
+                (None, None)
+            };
+
+        // BigTable takes precedence over HBase when both are configured
+        let long_term_storage: Option<Arc<dyn LongTermStorage>> =
+            match (bigtable_ledger_storage, hbase_ledger_storage) {
+                (Some(bigtable_ledger_storage), hbase_ledger_storage) => {
+                    if hbase_ledger_storage.is_some() {
+                        warn!(
+                            "Both BigTable and HBase ledger storage are configured, using BigTable"
+                        );
+                    }
+                    Some(Arc::new(bigtable_ledger_storage))
+                }
+                (None, Some(hbase_ledger_storage)) => Some(Arc::new(hbase_ledger_storage)),
+                (None, None) => None,
+            };
+
         let full_api = config.full_api;
         let obsolete_v1_7_api = config.obsolete_v1_7_api;
         let max_request_body_size = config
             .max_request_body_size
             .unwrap_or(MAX_REQUEST_BODY_SIZE);
+        let rate_limit_middleware = RpcRateLimitMiddleware::new(config.rate_limit.clone());
+        let trusted_proxies = config
+            .rate_limit
+            .as_ref()
+            .map(|rate_limit| rate_limit.trusted_proxies.clone())
+            .unwrap_or_default();
         let (request_processor, receiver) = JsonRpcRequestProcessor::new(
             config,
             snapshot_config.clone(),
@@ -487,8 +564,7 @@ impl JsonRpcService {
             health.clone(),
             cluster_info.clone(),
             genesis_hash,
-            bigtable_ledger_storage,
-            hbase_ledger_storage,
+            long_term_storage,
             optimistically_confirmed_bank,
             largest_accounts_cache,
             max_slots,
@@ -521,7 +597,7 @@ impl JsonRpcService {
             .spawn(move || {
                 renice_this_thread(rpc_niceness_adj).unwrap();
 
-                let mut io = MetaIoHandler::default();
+                let mut io = MetaIoHandler::with_middleware(rate_limit_middleware);
 
                 io.extend_with(rpc_minimal::MinimalImpl.to_delegate());
                 if full_api {
@@ -544,7 +620,11 @@ impl JsonRpcService {
                 );
                 let server = ServerBuilder::with_meta_extractor(
                     io,
-                    move |_req: &hyper::Request<hyper::Body>| request_processor.clone(),
+                    move |req: &hyper::Request<hyper::Body>| {
+                        request_processor
+                            .clone()
+                            .with_client_ip(forwarded_client_ip(req, &trusted_proxies))
+                    },
                 )
                 .event_loop_executor(runtime.handle().clone())
                 .threads(1)
@@ -571,6 +651,7 @@ impl JsonRpcService {
                 close_handle_sender.send(Ok(server.close_handle())).unwrap();
                 server.wait();
                 exit_bigtable_ledger_upload_service.store(true, Ordering::Relaxed);
+                exit_hbase_ledger_upload_service.store(true, Ordering::Relaxed);
             })
             .unwrap();
 
@@ -745,6 +826,50 @@ mod tests {
         assert_eq!(RpcRequestMiddleware::strip_leading_slash("abc"), None);
     }
 
+    #[test]
+    fn test_forwarded_client_ip() {
+        let request = |headers: &[(&str, &str)]| {
+            let mut request = hyper::Request::builder().uri("/");
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            request.body(hyper::Body::empty()).unwrap()
+        };
+
+        let no_proxies = HashSet::new();
+        assert_eq!(forwarded_client_ip(&request(&[]), &no_proxies), None);
+        assert_eq!(
+            forwarded_client_ip(
+                &request(&[("X-Forwarded-For", "10.0.0.1, 10.0.0.2")]),
+                &no_proxies
+            ),
+            Some("10.0.0.2".parse().unwrap())
+        );
+        assert_eq!(
+            forwarded_client_ip(&request(&[("X-Real-IP", "::1")]), &no_proxies),
+            Some("::1".parse().unwrap())
+        );
+        assert_eq!(
+            forwarded_client_ip(&request(&[("X-Forwarded-For", "unknown")]), &no_proxies),
+            None
+        );
+
+        // Entries added by trusted proxies are skipped, while those supplied by the client are not
+        let trusted_proxies = HashSet::from(["10.0.0.2".parse().unwrap()]);
+        let spoofed = request(&[("X-Forwarded-For", "1.1.1.1, 10.0.0.1, 10.0.0.2")]);
+        assert_eq!(
+            forwarded_client_ip(&spoofed, &trusted_proxies),
+            Some("10.0.0.1".parse().unwrap())
+        );
+        assert_eq!(
+            forwarded_client_ip(
+                &request(&[("X-Forwarded-For", "unknown, 10.0.0.2")]),
+                &trusted_proxies
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_is_file_get_path() {
         let bank_forks = create_bank_forks();
@@ -46,6 +46,9 @@ pub enum SubscriptionParams {
     Logs(LogsSubscriptionParams),
     Program(ProgramSubscriptionParams),
     Signature(SignatureSubscriptionParams),
+    TokenAccountsByOwner(AccountsByOwnerSubscriptionParams),
+    NftAccountsByOwner(AccountsByOwnerSubscriptionParams),
+    Domain(AccountsByOwnerSubscriptionParams),
     Slot,
     SlotsUpdates,
     Root,
@@ -59,6 +62,9 @@ impl SubscriptionParams {
             SubscriptionParams::Logs(_) => "logsNotification",
             SubscriptionParams::Program(_) => "programNotification",
             SubscriptionParams::Signature(_) => "signatureNotification",
+            SubscriptionParams::TokenAccountsByOwner(_) => "tokenAccountsByOwnerNotification",
+            SubscriptionParams::NftAccountsByOwner(_) => "nftAccountsByOwnerNotification",
+            SubscriptionParams::Domain(_) => "domainNotification",
             SubscriptionParams::Slot => "slotNotification",
             SubscriptionParams::SlotsUpdates => "slotsUpdatesNotification",
             SubscriptionParams::Block(_) => "blockNotification",
@@ -74,6 +80,9 @@ impl SubscriptionParams {
             SubscriptionParams::Program(params) => Some(params.commitment),
             SubscriptionParams::Signature(params) => Some(params.commitment),
             SubscriptionParams::Block(params) => Some(params.commitment),
+            SubscriptionParams::TokenAccountsByOwner(params)
+            | SubscriptionParams::NftAccountsByOwner(params)
+            | SubscriptionParams::Domain(params) => Some(params.commitment),
             SubscriptionParams::Slot
             | SubscriptionParams::SlotsUpdates
             | SubscriptionParams::Root
@@ -88,6 +97,9 @@ impl SubscriptionParams {
             SubscriptionParams::Logs(params) => &params.commitment,
             SubscriptionParams::Program(params) => &params.commitment,
             SubscriptionParams::Signature(params) => &params.commitment,
+            SubscriptionParams::TokenAccountsByOwner(params)
+            | SubscriptionParams::NftAccountsByOwner(params)
+            | SubscriptionParams::Domain(params) => &params.commitment,
             SubscriptionParams::Root
             | SubscriptionParams::Slot
             | SubscriptionParams::SlotsUpdates
@@ -103,6 +115,9 @@ impl SubscriptionParams {
             SubscriptionParams::Logs(params) => &params.commitment,
             SubscriptionParams::Program(params) => &params.commitment,
             SubscriptionParams::Signature(params) => &params.commitment,
+            SubscriptionParams::TokenAccountsByOwner(params)
+            | SubscriptionParams::NftAccountsByOwner(params)
+            | SubscriptionParams::Domain(params) => &params.commitment,
             SubscriptionParams::Root
             | SubscriptionParams::Slot
             | SubscriptionParams::SlotsUpdates
@@ -169,6 +184,13 @@ pub struct ProgramSubscriptionParams {
     pub with_context: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccountsByOwnerSubscriptionParams {
+    pub owner: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub commitment: CommitmentConfig,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SignatureSubscriptionParams {
     pub signature: Signature,
@@ -417,9 +439,9 @@ impl LogsSubscriptionsIndex {
 pub struct SubscriptionsTracker {
     logs_subscriptions_index: LogsSubscriptionsIndex,
     by_signature: HashMap<Signature, HashMap<SubscriptionId, Arc<SubscriptionInfo>>>,
-    // Accounts, logs, programs, signatures (not gossip)
+    // Accounts, logs, programs, signatures, accounts by owner (not gossip)
     commitment_watchers: HashMap<SubscriptionId, Arc<SubscriptionInfo>>,
-    // Accounts, logs, programs, signatures (gossip)
+    // Accounts, logs, programs, signatures, accounts by owner (gossip)
     gossip_watchers: HashMap<SubscriptionId, Arc<SubscriptionInfo>>,
     // Slots, slots updates, roots, votes.
     node_progress_watchers: HashMap<SubscriptionParams, Arc<SubscriptionInfo>>,
@@ -2,32 +2,44 @@
 use {
     crate::{
         optimistically_confirmed_bank_tracker::OptimisticallyConfirmedBank,
-        parsed_token_accounts::{get_parsed_token_account, get_parsed_token_accounts},
+        parsed_token_accounts::{
+            get_parsed_token_account, get_parsed_token_accounts, get_parsed_ui_accounts,
+        },
         rpc_pubsub_service::PubSubConfig,
         rpc_subscription_tracker::{
-            AccountSubscriptionParams, BlockSubscriptionKind, BlockSubscriptionParams,
-            LogsSubscriptionKind, LogsSubscriptionParams, ProgramSubscriptionParams,
-            SignatureSubscriptionParams, SubscriptionControl, SubscriptionId, SubscriptionInfo,
-            SubscriptionParams, SubscriptionsTracker,
+            AccountSubscriptionParams, AccountsByOwnerSubscriptionParams, BlockSubscriptionKind,
+            BlockSubscriptionParams, LogsSubscriptionKind, LogsSubscriptionParams,
+            ProgramSubscriptionParams, SignatureSubscriptionParams, SubscriptionControl,
+            SubscriptionId, SubscriptionInfo, SubscriptionParams, SubscriptionsTracker,
         },
     },
     crossbeam_channel::{Receiver, RecvTimeoutError, SendError, Sender},
     itertools::Either,
     rayon::prelude::*,
     serde::Serialize,
-    put_account_decoder::{parse_token::is_known_ppl_token_id, UiAccount, UiAccountEncoding},
+    put_account_decoder::{
+        parse_token::{is_known_ppl_token_id, ppl_token_ids},
+        UiAccount, UiAccountEncoding,
+    },
     put_ledger::{blockstore::Blockstore, get_tmp_ledger_path},
     put_measure::measure::Measure,
     put_rayon_threadlimit::get_thread_count,
-    put_rpc_client_api::response::{
-        ProcessedSignatureResult, ReceivedSignatureResult, Response as RpcResponse, RpcBlockUpdate,
-        RpcBlockUpdateError, RpcKeyedAccount, RpcLogsResponse, RpcResponseContext,
-        RpcSignatureResult, RpcVote, SlotInfo, SlotUpdate,
+    put_rpc_client_api::{
+        filter::{Memcmp, RpcFilterType},
+        response::{
+            ProcessedSignatureResult, ReceivedSignatureResult, Response as RpcResponse,
+            RpcBlockUpdate, RpcBlockUpdateError, RpcKeyedAccount, RpcLogsResponse,
+            RpcResponseContext, RpcSignatureResult, RpcVote, SlotInfo, SlotUpdate,
+        },
     },
     put_runtime::{
+        accounts_index::{AccountIndex, IndexKey},
         bank::{Bank, TransactionLogInfo},
         bank_forks::BankForks,
         commitment::{BlockCommitmentCache, CommitmentSlots},
+        inline_name::{self, DOMAIN_ACCOUNT_OWNER_OFFSET, DOMAIN_ACCOUNT_TYPE_OFFSET},
+        inline_nft::{self, NFT_ACCOUNT_LENGTH, NFT_ACCOUNT_MINT_OFFSET, NFT_ACCOUNT_OWNER_OFFSET},
+        inline_ppl_token::{PPL_TOKEN_ACCOUNT_MINT_OFFSET, PPL_TOKEN_ACCOUNT_OWNER_OFFSET},
         vote_transaction::VoteTransaction,
     },
     put_sdk::{
@@ -434,6 +446,141 @@ fn filter_program_results(
     (accounts, last_notified_slot)
 }
 
+/// Returns the accounts of `program_id` modified in `bank`'s slot that pass `filters`, looked up
+/// through the `account_index` secondary index when the node maintains it for `owner`
+fn get_accounts_by_owner_modified_since_parent(
+    bank: &Bank,
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    account_index: AccountIndex,
+    index_key: IndexKey,
+    filters: Vec<RpcFilterType>,
+) -> Vec<(Pubkey, AccountSharedData)> {
+    let filter = |account: &AccountSharedData| {
+        account.owner() == program_id
+            && filters
+                .iter()
+                .all(|filter_type| filter_type.allows(account))
+    };
+    if bank.account_indexes_contain(&account_index) && bank.account_indexes_include_key(owner) {
+        bank.get_indexed_accounts_modified_since_parent(&index_key, filter)
+            .unwrap_or_else(|err| {
+                warn!("{:?} scan failed: {}", account_index, err);
+                vec![]
+            })
+    } else {
+        bank.get_program_accounts_modified_since_parent(program_id)
+            .into_iter()
+            .filter(|(_, account)| filter(account))
+            .collect()
+    }
+}
+
+fn get_token_accounts_by_owner_modified_since_parent(
+    bank: &Bank,
+    params: &AccountsByOwnerSubscriptionParams,
+) -> Vec<(Pubkey, AccountSharedData)> {
+    let mut filters = vec![
+        RpcFilterType::TokenAccountState,
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            PPL_TOKEN_ACCOUNT_OWNER_OFFSET,
+            params.owner.to_bytes().into(),
+        )),
+    ];
+    if let Some(mint) = params.mint {
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            PPL_TOKEN_ACCOUNT_MINT_OFFSET,
+            mint.to_bytes().into(),
+        )));
+    }
+    // Token accounts of every known token program, extended ones included
+    ppl_token_ids()
+        .iter()
+        .flat_map(|program_id| {
+            get_accounts_by_owner_modified_since_parent(
+                bank,
+                program_id,
+                &params.owner,
+                AccountIndex::PplTokenOwner,
+                IndexKey::PplTokenOwner(params.owner),
+                filters.clone(),
+            )
+        })
+        .collect()
+}
+
+fn get_nft_accounts_by_owner_modified_since_parent(
+    bank: &Bank,
+    params: &AccountsByOwnerSubscriptionParams,
+) -> Vec<(Pubkey, AccountSharedData)> {
+    let mut filters = vec![
+        RpcFilterType::DataSize(NFT_ACCOUNT_LENGTH as u64),
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            NFT_ACCOUNT_OWNER_OFFSET,
+            params.owner.to_bytes().into(),
+        )),
+    ];
+    if let Some(mint) = params.mint {
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            NFT_ACCOUNT_MINT_OFFSET,
+            mint.to_bytes().into(),
+        )));
+    }
+    get_accounts_by_owner_modified_since_parent(
+        bank,
+        &inline_nft::id(),
+        &params.owner,
+        AccountIndex::PplNftOwner,
+        IndexKey::PplNftOwner(params.owner),
+        filters,
+    )
+}
+
+fn get_domain_accounts_modified_since_parent(
+    bank: &Bank,
+    params: &AccountsByOwnerSubscriptionParams,
+) -> Vec<(Pubkey, AccountSharedData)> {
+    let filters = vec![
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(DOMAIN_ACCOUNT_TYPE_OFFSET, vec![1])),
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            DOMAIN_ACCOUNT_OWNER_OFFSET,
+            params.owner.to_bytes().into(),
+        )),
+    ];
+    get_accounts_by_owner_modified_since_parent(
+        bank,
+        &inline_name::id(),
+        &params.owner,
+        AccountIndex::PplNameOwner,
+        IndexKey::PplNameOwner(params.owner),
+        filters,
+    )
+}
+
+fn filter_token_accounts_by_owner_results(
+    accounts: Vec<(Pubkey, AccountSharedData)>,
+    _params: &AccountsByOwnerSubscriptionParams,
+    last_notified_slot: Slot,
+    bank: Arc<Bank>,
+) -> (impl Iterator<Item = RpcKeyedAccount>, Slot) {
+    (
+        get_parsed_token_accounts(bank, accounts.into_iter()),
+        last_notified_slot,
+    )
+}
+
+fn filter_parsed_accounts_results(
+    accounts: Vec<(Pubkey, AccountSharedData)>,
+    _params: &AccountsByOwnerSubscriptionParams,
+    last_notified_slot: Slot,
+    _bank: Arc<Bank>,
+) -> (impl Iterator<Item = RpcKeyedAccount>, Slot) {
+    (
+        get_parsed_ui_accounts(accounts.into_iter()),
+        last_notified_slot,
+    )
+}
+
 fn filter_logs_results(
     logs: Option<Vec<TransactionLogInfo>>,
     _params: &LogsSubscriptionParams,
@@ -955,6 +1102,9 @@ impl RpcSubscriptions {
         let num_signatures_found = AtomicUsize::new(0);
         let num_signatures_notified = AtomicUsize::new(0);
 
+        let num_accounts_by_owner_found = AtomicUsize::new(0);
+        let num_accounts_by_owner_notified = AtomicUsize::new(0);
+
         let subscriptions = subscriptions.into_par_iter();
         subscriptions.for_each(|(_id, subscription)| {
             let slot = if let Some(commitment) = subscription.commitment() {
@@ -1137,6 +1287,63 @@ impl RpcSubscriptions {
                         }
                     }
                 }
+                SubscriptionParams::TokenAccountsByOwner(params) => {
+                    num_accounts_by_owner_found.fetch_add(1, Ordering::Relaxed);
+                    if let Some(slot) = slot {
+                        let notified = check_commitment_and_notify(
+                            params,
+                            subscription,
+                            bank_forks,
+                            slot,
+                            get_token_accounts_by_owner_modified_since_parent,
+                            filter_token_accounts_by_owner_results,
+                            notifier,
+                            false,
+                        );
+
+                        if notified {
+                            num_accounts_by_owner_notified.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                }
+                SubscriptionParams::NftAccountsByOwner(params) => {
+                    num_accounts_by_owner_found.fetch_add(1, Ordering::Relaxed);
+                    if let Some(slot) = slot {
+                        let notified = check_commitment_and_notify(
+                            params,
+                            subscription,
+                            bank_forks,
+                            slot,
+                            get_nft_accounts_by_owner_modified_since_parent,
+                            filter_parsed_accounts_results,
+                            notifier,
+                            false,
+                        );
+
+                        if notified {
+                            num_accounts_by_owner_notified.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                }
+                SubscriptionParams::Domain(params) => {
+                    num_accounts_by_owner_found.fetch_add(1, Ordering::Relaxed);
+                    if let Some(slot) = slot {
+                        let notified = check_commitment_and_notify(
+                            params,
+                            subscription,
+                            bank_forks,
+                            slot,
+                            get_domain_accounts_modified_since_parent,
+                            filter_parsed_accounts_results,
+                            notifier,
+                            false,
+                        );
+
+                        if notified {
+                            num_accounts_by_owner_notified.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                }
                 _ => error!("wrong subscription type in alps map"),
             }
         });
@@ -1146,11 +1353,13 @@ impl RpcSubscriptions {
         let total_notified = num_accounts_notified.load(Ordering::Relaxed)
             + num_logs_notified.load(Ordering::Relaxed)
             + num_programs_notified.load(Ordering::Relaxed)
-            + num_signatures_notified.load(Ordering::Relaxed);
+            + num_signatures_notified.load(Ordering::Relaxed)
+            + num_accounts_by_owner_notified.load(Ordering::Relaxed);
         let total_ms = total_time.as_ms();
         if total_notified > 0 || total_ms > 10 {
             debug!(
-                "notified({}): accounts: {} / {} logs: {} / {} programs: {} / {} signatures: {} / {}",
+                "notified({}): accounts: {} / {} logs: {} / {} programs: {} / {} signatures: {} / {} \
+                 accounts by owner: {} / {}",
                 source,
                 num_accounts_found.load(Ordering::Relaxed),
                 num_accounts_notified.load(Ordering::Relaxed),
@@ -1160,6 +1369,8 @@ impl RpcSubscriptions {
                 num_programs_notified.load(Ordering::Relaxed),
                 num_signatures_found.load(Ordering::Relaxed),
                 num_signatures_notified.load(Ordering::Relaxed),
+                num_accounts_by_owner_found.load(Ordering::Relaxed),
+                num_accounts_by_owner_notified.load(Ordering::Relaxed),
             );
             inc_new_counter_info!("rpc-subscription-notify-bank-or-gossip", total_notified);
             datapoint_info!(
@@ -1205,6 +1416,16 @@ impl RpcSubscriptions {
                     num_signatures_notified.load(Ordering::Relaxed),
                     i64
                 ),
+                (
+                    "num_accounts_by_owner_subscriptions",
+                    num_accounts_by_owner_found.load(Ordering::Relaxed),
+                    i64
+                ),
+                (
+                    "num_accounts_by_owner_notified",
+                    num_accounts_by_owner_notified.load(Ordering::Relaxed),
+                    i64
+                ),
                 ("notifications_time", total_time.as_us() as i64, i64),
             );
             inc_new_counter_info!(
@@ -1257,11 +1478,20 @@ pub(crate) mod tests {
             rpc_pubsub::RpcPutPubSubInternal,
             rpc_pubsub_service,
         },
+        ppl_nft::{
+            put_program::pubkey::Pubkey as PplNftPubkey,
+            state::{AccountState as NftAccountState, MetaAccount},
+        },
+        ppl_token::{
+            put_program::{program_option::COption, program_pack::Pack},
+            state::{Account as TokenAccount, AccountState as TokenAccountState},
+        },
         serial_test::serial,
+        put_account_decoder::parse_token::{ppl_token_id, ppl_token_native_mint, ppl_token_pubkey},
         put_rpc_client_api::config::{
             RpcAccountInfoConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter,
-            RpcProgramAccountsConfig, RpcSignatureSubscribeConfig, RpcTransactionLogsConfig,
-            RpcTransactionLogsFilter,
+            RpcProgramAccountsConfig, RpcSignatureSubscribeConfig, RpcTokenAccountsFilter,
+            RpcTransactionLogsConfig, RpcTransactionLogsFilter,
         },
         put_runtime::{
             commitment::BlockCommitment,
@@ -1269,6 +1499,7 @@ pub(crate) mod tests {
             prioritization_fee_cache::PrioritizationFeeCache,
         },
         put_sdk::{
+            account::Account,
             commitment_config::CommitmentConfig,
             message::Message,
             signature::{Keypair, Signer},
@@ -1922,6 +2153,255 @@ pub(crate) mod tests {
             }));
     }
 
+    #[test]
+    #[serial]
+    fn test_check_token_accounts_by_owner_subscribe() {
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(100);
+        let bank = Bank::new_for_tests(&genesis_config);
+        let owner = Pubkey::new_unique();
+        let token_account_pubkey = Pubkey::new_unique();
+        let store_wrapped_put_account = |pubkey: &Pubkey, owner: &Pubkey| {
+            let mut account_data = vec![0; TokenAccount::get_packed_len()];
+            let token_account = TokenAccount {
+                mint: ppl_token::native_mint::id(),
+                owner: ppl_token_pubkey(owner),
+                delegate: COption::None,
+                amount: 1_000_000_000,
+                state: TokenAccountState::Initialized,
+                is_native: COption::Some(2_039_280),
+                delegated_amount: 0,
+                close_authority: COption::None,
+            };
+            TokenAccount::pack(token_account, &mut account_data).unwrap();
+            let account = AccountSharedData::from(Account {
+                lamports: 1_002_039_280,
+                data: account_data,
+                owner: ppl_token_id(),
+                ..Account::default()
+            });
+            bank.store_account(pubkey, &account);
+        };
+        store_wrapped_put_account(&token_account_pubkey, &owner);
+        store_wrapped_put_account(&Pubkey::new_unique(), &Pubkey::new_unique());
+        let bank_forks = Arc::new(RwLock::new(BankForks::new(bank)));
+
+        let exit = Arc::new(AtomicBool::new(false));
+        let optimistically_confirmed_bank =
+            OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks);
+        let max_complete_transaction_status_slot = Arc::new(AtomicU64::default());
+        let max_complete_rewards_slot = Arc::new(AtomicU64::default());
+        let subscriptions = Arc::new(RpcSubscriptions::new_for_tests(
+            &exit,
+            max_complete_transaction_status_slot,
+            max_complete_rewards_slot,
+            bank_forks,
+            Arc::new(RwLock::new(BlockCommitmentCache::new_for_tests())),
+            optimistically_confirmed_bank,
+        ));
+        let (rpc, mut receiver) = rpc_pubsub_service::test_connection(&subscriptions);
+        let mint_filter = RpcTokenAccountsFilter::Mint(ppl_token_native_mint().to_string());
+        assert!(rpc
+            .token_accounts_by_owner_subscribe(
+                owner.to_string(),
+                mint_filter.clone(),
+                Some(RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..RpcAccountInfoConfig::default()
+                }),
+            )
+            .is_err());
+        let sub_id = rpc
+            .token_accounts_by_owner_subscribe(
+                owner.to_string(),
+                mint_filter,
+                Some(RpcAccountInfoConfig {
+                    commitment: Some(CommitmentConfig::processed()),
+                    ..RpcAccountInfoConfig::default()
+                }),
+            )
+            .unwrap();
+
+        let params = SubscriptionParams::TokenAccountsByOwner(AccountsByOwnerSubscriptionParams {
+            owner,
+            mint: Some(ppl_token_native_mint()),
+            commitment: CommitmentConfig::processed(),
+        });
+        subscriptions.control.assert_subscribed(&params);
+
+        subscriptions.notify_subscribers(CommitmentSlots::default());
+        let response: serde_json::Value = serde_json::from_str(&receiver.recv()).unwrap();
+        assert_eq!(response["method"], "tokenAccountsByOwnerNotification");
+        assert_eq!(response["params"]["subscription"], 0);
+        let value = &response["params"]["result"]["value"];
+        assert_eq!(value["pubkey"], token_account_pubkey.to_string());
+        let info = &value["account"]["data"]["parsed"]["info"];
+        assert_eq!(info["owner"], owner.to_string());
+        assert_eq!(info["isNative"], true);
+
+        rpc.token_accounts_by_owner_unsubscribe(sub_id).unwrap();
+        subscriptions.control.assert_unsubscribed(&params);
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_nft_accounts_by_owner_subscribe() {
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(100);
+        let bank = Bank::new_for_tests(&genesis_config);
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let nft_account_pubkey = Pubkey::new_unique();
+        let store_nft_account = |pubkey: &Pubkey, mint: &Pubkey, owner: &Pubkey| {
+            let mut account_data = vec![0; MetaAccount::get_packed_len()];
+            let mut nft_account = MetaAccount::unpack_unchecked(&account_data).unwrap();
+            nft_account.mint = PplNftPubkey::from(mint.to_bytes());
+            nft_account.owner = PplNftPubkey::from(owner.to_bytes());
+            nft_account.state = NftAccountState::Initialized;
+            nft_account.token_id = 7;
+            nft_account.token_uri = "https://example.com/7".to_string();
+            MetaAccount::pack(nft_account, &mut account_data).unwrap();
+            let account = AccountSharedData::from(Account {
+                lamports: 111,
+                data: account_data,
+                owner: inline_nft::id(),
+                ..Account::default()
+            });
+            bank.store_account(pubkey, &account);
+        };
+        store_nft_account(&nft_account_pubkey, &mint, &owner);
+        store_nft_account(&Pubkey::new_unique(), &Pubkey::new_unique(), &owner);
+        store_nft_account(&Pubkey::new_unique(), &mint, &Pubkey::new_unique());
+        let bank_forks = Arc::new(RwLock::new(BankForks::new(bank)));
+
+        let exit = Arc::new(AtomicBool::new(false));
+        let optimistically_confirmed_bank =
+            OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks);
+        let max_complete_transaction_status_slot = Arc::new(AtomicU64::default());
+        let max_complete_rewards_slot = Arc::new(AtomicU64::default());
+        let subscriptions = Arc::new(RpcSubscriptions::new_for_tests(
+            &exit,
+            max_complete_transaction_status_slot,
+            max_complete_rewards_slot,
+            bank_forks,
+            Arc::new(RwLock::new(BlockCommitmentCache::new_for_tests())),
+            optimistically_confirmed_bank,
+        ));
+        let (rpc, mut receiver) = rpc_pubsub_service::test_connection(&subscriptions);
+        let sub_id = rpc
+            .nft_accounts_by_owner_subscribe(
+                owner.to_string(),
+                RpcTokenAccountsFilter::Mint(mint.to_string()),
+                Some(RpcAccountInfoConfig {
+                    commitment: Some(CommitmentConfig::processed()),
+                    ..RpcAccountInfoConfig::default()
+                }),
+            )
+            .unwrap();
+
+        let params = SubscriptionParams::NftAccountsByOwner(AccountsByOwnerSubscriptionParams {
+            owner,
+            mint: Some(mint),
+            commitment: CommitmentConfig::processed(),
+        });
+        subscriptions.control.assert_subscribed(&params);
+
+        subscriptions.notify_subscribers(CommitmentSlots::default());
+        let response: serde_json::Value = serde_json::from_str(&receiver.recv()).unwrap();
+        assert_eq!(response["method"], "nftAccountsByOwnerNotification");
+        assert_eq!(response["params"]["subscription"], 0);
+        let value = &response["params"]["result"]["value"];
+        assert_eq!(value["pubkey"], nft_account_pubkey.to_string());
+        let info = &value["account"]["data"]["parsed"]["info"];
+        assert_eq!(info["owner"], owner.to_string());
+        assert_eq!(info["mint"], mint.to_string());
+        assert_eq!(info["tokenId"], 7);
+        // Neither the owner's other NFT nor another owner's NFT of the same mint is notified
+        assert!(receiver.recv_timeout(Duration::from_millis(300)).is_err());
+
+        rpc.nft_accounts_by_owner_unsubscribe(sub_id).unwrap();
+        subscriptions.control.assert_unsubscribed(&params);
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_domain_subscribe() {
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(100);
+        let bank = Bank::new_for_tests(&genesis_config);
+        let owner = Pubkey::new_unique();
+        let domain_pubkey = Pubkey::new_unique();
+        let store_domain_account =
+            |pubkey: &Pubkey, name: &str, owner: &Pubkey, expire_time: i64| {
+                let mut account_data = vec![1, 1];
+                account_data.extend_from_slice(Pubkey::new_unique().as_ref());
+                account_data.extend_from_slice(owner.as_ref());
+                account_data.extend_from_slice(&expire_time.to_le_bytes());
+                account_data.extend_from_slice(&32u16.to_le_bytes());
+                account_data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+                account_data.extend_from_slice(name.as_bytes());
+                let account = AccountSharedData::from(Account {
+                    lamports: 111,
+                    data: account_data,
+                    owner: inline_name::id(),
+                    ..Account::default()
+                });
+                bank.store_account(pubkey, &account);
+            };
+        store_domain_account(&domain_pubkey, "live.put", &owner, i64::MAX);
+        store_domain_account(&Pubkey::new_unique(), "expired.put", &owner, 1);
+        store_domain_account(
+            &Pubkey::new_unique(),
+            "other.put",
+            &Pubkey::new_unique(),
+            i64::MAX,
+        );
+        let bank_forks = Arc::new(RwLock::new(BankForks::new(bank)));
+
+        let exit = Arc::new(AtomicBool::new(false));
+        let optimistically_confirmed_bank =
+            OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks);
+        let max_complete_transaction_status_slot = Arc::new(AtomicU64::default());
+        let max_complete_rewards_slot = Arc::new(AtomicU64::default());
+        let subscriptions = Arc::new(RpcSubscriptions::new_for_tests(
+            &exit,
+            max_complete_transaction_status_slot,
+            max_complete_rewards_slot,
+            bank_forks,
+            Arc::new(RwLock::new(BlockCommitmentCache::new_for_tests())),
+            optimistically_confirmed_bank,
+        ));
+        let (rpc, mut receiver) = rpc_pubsub_service::test_connection(&subscriptions);
+        let sub_id = rpc
+            .domain_subscribe(
+                owner.to_string(),
+                Some(RpcAccountInfoConfig {
+                    commitment: Some(CommitmentConfig::processed()),
+                    ..RpcAccountInfoConfig::default()
+                }),
+            )
+            .unwrap();
+
+        let params = SubscriptionParams::Domain(AccountsByOwnerSubscriptionParams {
+            owner,
+            mint: None,
+            commitment: CommitmentConfig::processed(),
+        });
+        subscriptions.control.assert_subscribed(&params);
+
+        subscriptions.notify_subscribers(CommitmentSlots::default());
+        let response: serde_json::Value = serde_json::from_str(&receiver.recv()).unwrap();
+        assert_eq!(response["method"], "domainNotification");
+        assert_eq!(response["params"]["subscription"], 0);
+        let value = &response["params"]["result"]["value"];
+        assert_eq!(value["pubkey"], domain_pubkey.to_string());
+        let info = &value["account"]["data"]["parsed"]["info"];
+        assert_eq!(info["owner"], owner.to_string());
+        assert_eq!(info["domainName"], "live.put");
+        // The owner's expired domain no longer parses and another owner's domain never matches
+        assert!(receiver.recv_timeout(Duration::from_millis(300)).is_err());
+
+        rpc.domain_unsubscribe(sub_id).unwrap();
+        subscriptions.control.assert_unsubscribed(&params);
+    }
+
     #[test]
     #[serial]
     fn test_check_program_subscribe_for_missing_optimistically_confirmed_slot() {
@@ -83,6 +83,8 @@ impl TransactionStatusService {
                     post_balances,
                     pre_token_balances,
                     post_token_balances,
+                    pre_nft_holdings,
+                    post_nft_holdings,
                     rent_debits,
                     transaction_index,
                 ) in izip!(
@@ -92,6 +94,8 @@ impl TransactionStatusService {
                     balances.post_balances,
                     token_balances.pre_token_balances,
                     token_balances.post_token_balances,
+                    token_balances.pre_nft_holdings,
+                    token_balances.post_nft_holdings,
                     rent_debits,
                     transaction_indexes,
                 ) {
@@ -141,6 +145,8 @@ impl TransactionStatusService {
 
                         let pre_token_balances = Some(pre_token_balances);
                         let post_token_balances = Some(post_token_balances);
+                        let pre_nft_holdings = Some(pre_nft_holdings);
+                        let post_nft_holdings = Some(post_nft_holdings);
                         let rewards = Some(
                             rent_debits
                                 .into_unordered_rewards_iter()
@@ -167,6 +173,8 @@ impl TransactionStatusService {
                             loaded_addresses,
                             return_data,
                             compute_units_consumed: Some(executed_units),
+                            pre_nft_holdings,
+                            post_nft_holdings,
                         };
 
                         if let Some(transaction_notifier) = transaction_notifier.as_ref() {
@@ -248,8 +256,8 @@ pub(crate) mod tests {
             },
         },
         put_transaction_status::{
-            token_balances::TransactionTokenBalancesSet, TransactionStatusMeta,
-            TransactionTokenBalance,
+            token_balances::TransactionTokenBalancesSet, TransactionNftHolding,
+            TransactionStatusMeta, TransactionTokenBalance,
         },
         std::{
             sync::{
@@ -269,7 +277,7 @@ pub(crate) mod tests {
     }
 
     struct TestNotification {
-        _meta: TransactionStatusMeta,
+        meta: TransactionStatusMeta,
         transaction: SanitizedTransaction,
     }
 
@@ -301,7 +309,7 @@ pub(crate) mod tests {
                     signature: *signature,
                 },
                 TestNotification {
-                    _meta: transaction_status_meta.clone(),
+                    meta: transaction_status_meta.clone(),
                     transaction: transaction.clone(),
                 },
             );
@@ -414,9 +422,25 @@ pub(crate) mod tests {
             program_id: token_program_id,
         };
 
+        let nft_mint = Pubkey::new_unique().to_string();
+        let pre_nft_holding = TransactionNftHolding {
+            account_index: 0,
+            mint: nft_mint.clone(),
+            token_id: 1,
+            owner: Pubkey::new_unique().to_string(),
+        };
+        let post_nft_holding = TransactionNftHolding {
+            account_index: 0,
+            mint: nft_mint,
+            token_id: 1,
+            owner: Pubkey::new_unique().to_string(),
+        };
+
         let token_balances = TransactionTokenBalancesSet {
             pre_token_balances: vec![vec![pre_token_balance]],
             post_token_balances: vec![vec![post_token_balance]],
+            pre_nft_holdings: vec![vec![pre_nft_holding.clone()]],
+            post_nft_holdings: vec![vec![post_nft_holding.clone()]],
         };
 
         let slot = bank.slot();
@@ -466,5 +490,7 @@ pub(crate) mod tests {
             expected_transaction.signature(),
             result.transaction.signature()
         );
+        assert_eq!(result.meta.pre_nft_holdings, Some(vec![pre_nft_holding]));
+        assert_eq!(result.meta.post_nft_holdings, Some(vec![post_nft_holding]));
     }
 }
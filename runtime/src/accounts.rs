@@ -9,7 +9,8 @@ use {
             ACCOUNTS_DB_CONFIG_FOR_TESTING,
         },
         accounts_index::{
-            AccountSecondaryIndexes, IndexKey, ScanConfig, ScanError, ScanResult, ZeroLamport,
+            AccountIndex, AccountSecondaryIndexes, IndexKey, ScanConfig, ScanError, ScanPage,
+            ScanResult, ZeroLamport,
         },
         accounts_update_notifier_interface::AccountsUpdateNotifier,
         ancestors::Ancestors,
@@ -63,7 +64,7 @@ use {
         cmp::Reverse,
         collections::{
             hash_map::{self, Entry},
-            BinaryHeap, HashMap, HashSet,
+            BTreeMap, BinaryHeap, HashMap, HashSet,
         },
         num::NonZeroUsize,
         ops::RangeBounds,
@@ -960,6 +961,37 @@ impl Accounts {
         }
     }
 
+    /// Keeps the accounts of `page` among the accounts scanned so far
+    fn load_page_while_filtering<F: Fn(&AccountSharedData) -> bool>(
+        collector: &mut BTreeMap<Pubkey, AccountSharedData>,
+        page: &ScanPage,
+        some_account_tuple: Option<(&Pubkey, AccountSharedData, Slot)>,
+        filter: F,
+    ) {
+        let Some((pubkey, account, _slot)) = some_account_tuple else {
+            return;
+        };
+        if page.after.map(|after| *pubkey <= after).unwrap_or(false) {
+            return;
+        }
+        // Once the page is full, only accounts sorting before its last one can be part of it
+        if collector.len() >= page.limit
+            && collector
+                .keys()
+                .next_back()
+                .map(|last| pubkey >= last)
+                .unwrap_or(true)
+        {
+            return;
+        }
+        if Self::is_loadable(account.lamports()) && filter(&account) {
+            collector.insert(*pubkey, account);
+            if collector.len() > page.limit {
+                collector.pop_last();
+            }
+        }
+    }
+
     fn load_with_slot(
         collector: &mut Vec<PubkeyAccountSlot>,
         some_account_tuple: Option<(&Pubkey, AccountSharedData, Slot)>,
@@ -1002,6 +1034,26 @@ impl Accounts {
         filter: F,
         config: &ScanConfig,
     ) -> ScanResult<Vec<TransactionAccount>> {
+        if let Some(page) = config.page {
+            let mut collector = BTreeMap::new();
+            return self
+                .accounts_db
+                .scan_accounts(
+                    ancestors,
+                    bank_id,
+                    |some_account_tuple| {
+                        Self::load_page_while_filtering(
+                            &mut collector,
+                            &page,
+                            some_account_tuple,
+                            |account| account.owner() == program_id && filter(account),
+                        )
+                    },
+                    config,
+                )
+                .map(|_| collector.into_iter().collect());
+        }
+
         let mut collector = Vec::new();
         self.accounts_db
             .scan_accounts(
@@ -1062,41 +1114,121 @@ impl Accounts {
         config: &ScanConfig,
         byte_limit_for_scan: Option<usize>,
     ) -> ScanResult<Vec<TransactionAccount>> {
+        self.load_by_index_keys_with_filter(
+            ancestors,
+            bank_id,
+            std::slice::from_ref(index_key),
+            filter,
+            config,
+            byte_limit_for_scan,
+        )
+    }
+
+    /// Loads the accounts found under any of `index_keys` into one collection, with the scan
+    /// size limit applying to the accumulated results of all keys. A page of results is bounded
+    /// by its limit instead.
+    pub fn load_by_index_keys_with_filter<F: Fn(&AccountSharedData) -> bool>(
+        &self,
+        ancestors: &Ancestors,
+        bank_id: BankId,
+        index_keys: &[IndexKey],
+        filter: F,
+        config: &ScanConfig,
+        byte_limit_for_scan: Option<usize>,
+    ) -> ScanResult<Vec<TransactionAccount>> {
+        if let Some(page) = config.page {
+            let mut collector = BTreeMap::new();
+            for index_key in index_keys {
+                self.accounts_db.index_scan_accounts(
+                    ancestors,
+                    bank_id,
+                    *index_key,
+                    |some_account_tuple| {
+                        Self::load_page_while_filtering(
+                            &mut collector,
+                            &page,
+                            some_account_tuple,
+                            &filter,
+                        )
+                    },
+                    config,
+                )?;
+            }
+            return Ok(collector.into_iter().collect());
+        }
+
         let sum = AtomicUsize::default();
         let config = config.recreate_with_abort();
         let mut collector = Vec::new();
-        let result = self
-            .accounts_db
+        let mut result = Ok(());
+        for index_key in index_keys {
+            if result.is_err() || config.is_aborted() {
+                break;
+            }
+            result = self
+                .accounts_db
+                .index_scan_accounts(
+                    ancestors,
+                    bank_id,
+                    *index_key,
+                    |some_account_tuple| {
+                        Self::load_while_filtering(&mut collector, some_account_tuple, |account| {
+                            let use_account = filter(account);
+                            if use_account
+                                && Self::accumulate_and_check_scan_result_size(
+                                    &sum,
+                                    account,
+                                    &byte_limit_for_scan,
+                                )
+                            {
+                                // total size of results exceeds size limit, so abort scan
+                                config.abort();
+                            }
+                            use_account
+                        });
+                    },
+                    &config,
+                )
+                .map(|_| ());
+        }
+        Self::maybe_abort_scan(result.map(|_| collector), &config)
+    }
+
+    /// Loads the accounts found under `index_key` whose latest version was stored in `slot`
+    pub fn load_by_index_key_in_slot<F: Fn(&AccountSharedData) -> bool>(
+        &self,
+        ancestors: &Ancestors,
+        bank_id: BankId,
+        slot: Slot,
+        index_key: &IndexKey,
+        filter: F,
+    ) -> ScanResult<Vec<TransactionAccount>> {
+        let mut collector = Vec::new();
+        self.accounts_db
             .index_scan_accounts(
                 ancestors,
                 bank_id,
                 *index_key,
                 |some_account_tuple| {
-                    Self::load_while_filtering(&mut collector, some_account_tuple, |account| {
-                        let use_account = filter(account);
-                        if use_account
-                            && Self::accumulate_and_check_scan_result_size(
-                                &sum,
-                                account,
-                                &byte_limit_for_scan,
-                            )
-                        {
-                            // total size of results exceeds size limit, so abort scan
-                            config.abort();
-                        }
-                        use_account
-                    });
+                    Self::load_while_filtering(
+                        &mut collector,
+                        some_account_tuple.filter(|(_, _, account_slot)| *account_slot == slot),
+                        &filter,
+                    )
                 },
-                &config,
+                &ScanConfig::default(),
             )
-            .map(|_| collector);
-        Self::maybe_abort_scan(result, &config)
+            .map(|_| collector)
     }
 
     pub fn account_indexes_include_key(&self, key: &Pubkey) -> bool {
         self.accounts_db.account_indexes.include_key(key)
     }
 
+    pub fn account_indexes_contain(&self, index: &AccountIndex) -> bool {
+        self.accounts_db.account_indexes.contains(index)
+    }
+
     pub fn load_all(
         &self,
         ancestors: &Ancestors,
@@ -2440,6 +2572,175 @@ mod tests {
         assert_eq!(loaded, vec![]);
     }
 
+    #[test]
+    fn test_load_by_program_with_filter_page() {
+        let accounts = Accounts::new_with_config_for_tests(
+            Vec::new(),
+            &ClusterType::Development,
+            AccountSecondaryIndexes::default(),
+            AccountShrinkThreshold::default(),
+        );
+
+        let program_id = Pubkey::from([2; 32]);
+        let mut pubkeys: Vec<_> = (0..5).map(|_| put_sdk::pubkey::new_rand()).collect();
+        for pubkey in &pubkeys {
+            let account = AccountSharedData::new(1, 0, &program_id);
+            accounts.store_slow_uncached(0, pubkey, &account);
+        }
+        let other_account = AccountSharedData::new(1, 0, &Pubkey::from([3; 32]));
+        accounts.store_slow_uncached(0, &put_sdk::pubkey::new_rand(), &other_account);
+        pubkeys.sort();
+
+        let ancestors = Ancestors::from(vec![0]);
+        let load_page = |after: Option<Pubkey>, limit: usize| {
+            let config = ScanConfig {
+                page: Some(ScanPage { after, limit }),
+                ..ScanConfig::default()
+            };
+            accounts
+                .load_by_program_with_filter(&ancestors, 0, &program_id, |_| true, &config)
+                .unwrap()
+                .into_iter()
+                .map(|(pubkey, _)| pubkey)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(load_page(None, 2), pubkeys[..2]);
+        assert_eq!(load_page(Some(pubkeys[1]), 2), pubkeys[2..4]);
+        assert_eq!(load_page(Some(pubkeys[3]), 2), pubkeys[4..]);
+        assert_eq!(load_page(Some(pubkeys[4]), 2), Vec::<Pubkey>::new());
+        assert_eq!(load_page(None, 10), pubkeys);
+        assert_eq!(load_page(None, 0), Vec::<Pubkey>::new());
+    }
+
+    #[test]
+    fn test_load_by_index_keys_with_filter() {
+        let accounts = Accounts::new_with_config_for_tests(
+            Vec::new(),
+            &ClusterType::Development,
+            AccountSecondaryIndexes {
+                keys: None,
+                indexes: HashSet::from([AccountIndex::ProgramId]),
+            },
+            AccountShrinkThreshold::default(),
+        );
+
+        let program_ids = [Pubkey::from([2; 32]), Pubkey::from([3; 32])];
+        let mut pubkeys = vec![];
+        for program_id in &program_ids {
+            for _ in 0..2 {
+                let pubkey = put_sdk::pubkey::new_rand();
+                let account = AccountSharedData::new(1, 10, program_id);
+                accounts.store_slow_uncached(0, &pubkey, &account);
+                pubkeys.push(pubkey);
+            }
+        }
+        let other_account = AccountSharedData::new(1, 10, &Pubkey::from([4; 32]));
+        accounts.store_slow_uncached(0, &put_sdk::pubkey::new_rand(), &other_account);
+        pubkeys.sort();
+
+        let ancestors = Ancestors::from(vec![0]);
+        let index_keys = program_ids.map(IndexKey::ProgramId);
+        let load = |index_keys: &[IndexKey], byte_limit_for_scan: Option<usize>| {
+            accounts
+                .load_by_index_keys_with_filter(
+                    &ancestors,
+                    0,
+                    index_keys,
+                    |_| true,
+                    &ScanConfig::default(),
+                    byte_limit_for_scan,
+                )
+                .map(|loaded| {
+                    let mut loaded: Vec<_> = loaded.into_iter().map(|(pubkey, _)| pubkey).collect();
+                    loaded.sort();
+                    loaded
+                })
+        };
+
+        assert_eq!(load(&index_keys, None).unwrap(), pubkeys);
+        assert_eq!(load(&index_keys[..1], None).unwrap().len(), 2);
+        assert_eq!(load(&[], None).unwrap(), Vec::<Pubkey>::new());
+
+        // The byte limit applies to the results of all keys together: each key alone fits in it,
+        // both do not
+        let account_size =
+            Accounts::calc_scan_result_size(&AccountSharedData::new(1, 10, &program_ids[0]));
+        let byte_limit_for_scan = Some(3 * account_size);
+        assert_eq!(
+            load(&index_keys[..1], byte_limit_for_scan).unwrap().len(),
+            2
+        );
+        assert_eq!(
+            load(&index_keys[1..], byte_limit_for_scan).unwrap().len(),
+            2
+        );
+        assert_matches!(
+            load(&index_keys, byte_limit_for_scan),
+            Err(ScanError::Aborted(_))
+        );
+        assert_eq!(load(&index_keys, Some(4 * account_size)).unwrap(), pubkeys);
+    }
+
+    #[test]
+    fn test_load_by_index_keys_with_filter_page() {
+        let accounts = Accounts::new_with_config_for_tests(
+            Vec::new(),
+            &ClusterType::Development,
+            AccountSecondaryIndexes {
+                keys: None,
+                indexes: HashSet::from([AccountIndex::ProgramId]),
+            },
+            AccountShrinkThreshold::default(),
+        );
+
+        let program_ids = [Pubkey::from([2; 32]), Pubkey::from([3; 32])];
+        let mut pubkeys = vec![];
+        for program_id in &program_ids {
+            for lamports in 1..4 {
+                let pubkey = put_sdk::pubkey::new_rand();
+                let account = AccountSharedData::new(lamports, 0, program_id);
+                accounts.store_slow_uncached(0, &pubkey, &account);
+                // Accounts with 2 lamports are filtered out
+                if lamports != 2 {
+                    pubkeys.push(pubkey);
+                }
+            }
+        }
+        let other_account = AccountSharedData::new(1, 0, &Pubkey::from([4; 32]));
+        accounts.store_slow_uncached(0, &put_sdk::pubkey::new_rand(), &other_account);
+        pubkeys.sort();
+
+        let ancestors = Ancestors::from(vec![0]);
+        let index_keys = program_ids.map(IndexKey::ProgramId);
+        let load_page = |after: Option<Pubkey>, limit: usize| {
+            let config = ScanConfig {
+                page: Some(ScanPage { after, limit }),
+                ..ScanConfig::default()
+            };
+            accounts
+                .load_by_index_keys_with_filter(
+                    &ancestors,
+                    0,
+                    &index_keys,
+                    |account| account.lamports() != 2,
+                    &config,
+                    None,
+                )
+                .unwrap()
+                .into_iter()
+                .map(|(pubkey, _)| pubkey)
+                .collect::<Vec<_>>()
+        };
+
+        // Pages are ordered by pubkey across the results of all keys
+        assert_eq!(load_page(None, 3), pubkeys[..3]);
+        assert_eq!(load_page(Some(pubkeys[2]), 3), pubkeys[3..]);
+        assert_eq!(load_page(Some(pubkeys[3]), 3), Vec::<Pubkey>::new());
+        assert_eq!(load_page(None, 10), pubkeys);
+        assert_eq!(load_page(None, 0), Vec::<Pubkey>::new());
+    }
+
     #[test]
     fn test_load_accounts_executable_with_write_lock() {
         let mut accounts: Vec<TransactionAccount> = Vec::new();
@@ -4858,6 +4858,7 @@ impl AccountsDb {
             IndexKey::PplTokenMint(key) => key,
             IndexKey::PplTokenOwner(key) => key,
             IndexKey::PplNftOwner(key) => key,
+            IndexKey::PplNftMint(key) => key,
             IndexKey::PplNameOwner(key) => key,
         };
         if !self.account_indexes.include_key(key) {
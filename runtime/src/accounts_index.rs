@@ -86,6 +86,14 @@ pub enum UpsertReclaim {
     IgnoreReclaims,
 }
 
+/// A page of the accounts matched by a scan: the `limit` accounts with the lowest pubkeys
+/// greater than `after`, in pubkey order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanPage {
+    pub after: Option<Pubkey>,
+    pub limit: usize,
+}
+
 #[derive(Debug, Default)]
 pub struct ScanConfig {
     /// checked by the scan. When true, abort scan.
@@ -94,6 +102,10 @@ pub struct ScanConfig {
     /// true to allow return of all matching items and allow them to be unsorted.
     /// This is more efficient.
     pub collect_all_unsorted: bool,
+
+    /// when set, only the accounts of this page are collected. Supported by the filtered
+    /// program and index scans of `Accounts`.
+    pub page: Option<ScanPage>,
 }
 
 impl ScanConfig {
@@ -116,6 +128,7 @@ impl ScanConfig {
         ScanConfig {
             abort: Some(self.abort.as_ref().map(Arc::clone).unwrap_or_default()),
             collect_all_unsorted: self.collect_all_unsorted,
+            page: self.page,
         }
     }
 
@@ -161,6 +174,7 @@ pub enum IndexKey {
     PplTokenMint(Pubkey),
     PplTokenOwner(Pubkey),
     PplNftOwner(Pubkey),
+    PplNftMint(Pubkey),
     PplNameOwner(Pubkey),
 }
 
@@ -170,6 +184,7 @@ pub enum AccountIndex {
     PplTokenMint,
     PplTokenOwner,
     PplNftOwner,
+    PplNftMint,
     PplNameOwner,
 }
 
@@ -684,6 +699,7 @@ pub struct AccountsIndex<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> {
     ppl_token_mint_index: SecondaryIndex<DashMapSecondaryIndexEntry>,
     ppl_token_owner_index: SecondaryIndex<RwLockSecondaryIndexEntry>,
     ppl_nft_owner_index: SecondaryIndex<RwLockSecondaryIndexEntry>,
+    ppl_nft_mint_index: SecondaryIndex<DashMapSecondaryIndexEntry>,
     ppl_name_owner_index: SecondaryIndex<RwLockSecondaryIndexEntry>,
     pub(crate) roots_tracker: RwLock<RootsTracker>,
     ongoing_scan_roots: RwLock<BTreeMap<Slot, u64>>,
@@ -742,6 +758,9 @@ impl<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> AccountsIndex<T, U> {
             ppl_nft_owner_index: SecondaryIndex::<RwLockSecondaryIndexEntry>::new(
                 "ppl_nft_owner_index_stats",
             ),
+            ppl_nft_mint_index: SecondaryIndex::<DashMapSecondaryIndexEntry>::new(
+                "ppl_nft_mint_index_stats",
+            ),
             ppl_name_owner_index: SecondaryIndex::<RwLockSecondaryIndexEntry>::new(
                 "ppl_name_owner_index_stats",
             ),
@@ -1007,6 +1026,16 @@ impl<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> AccountsIndex<T, U> {
                     config,
                 );
             }
+            ScanTypes::Indexed(IndexKey::PplNftMint(mint_key)) => {
+                self.do_scan_secondary_index(
+                    ancestors,
+                    func,
+                    &self.ppl_nft_mint_index,
+                    &mint_key,
+                    Some(max_root),
+                    config,
+                );
+            }
             ScanTypes::Indexed(IndexKey::PplNameOwner(owner_key)) => {
                 self.do_scan_secondary_index(
                     ancestors,
@@ -1539,6 +1568,14 @@ impl<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> AccountsIndex<T, U> {
                     }
                 }
             }
+
+            if account_indexes.contains(&AccountIndex::PplNftMint) {
+                if let Some(mint_key) = G::unpack_account_mint(account_data) {
+                    if account_indexes.include_key(mint_key) {
+                        self.ppl_nft_mint_index.insert(mint_key, pubkey);
+                    }
+                }
+            }
         }
     }
 
@@ -1584,8 +1621,21 @@ impl<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> AccountsIndex<T, U> {
                 .index
                 .get(index_key)
                 .map(|x| x.len()),
-            AccountIndex::PplNftOwner => todo!(),
-            AccountIndex::PplNameOwner => todo!(),
+            AccountIndex::PplNftOwner => self
+                .ppl_nft_owner_index
+                .index
+                .get(index_key)
+                .map(|x| x.len()),
+            AccountIndex::PplNftMint => self
+                .ppl_nft_mint_index
+                .index
+                .get(index_key)
+                .map(|x| x.len()),
+            AccountIndex::PplNameOwner => self
+                .ppl_name_owner_index
+                .index
+                .get(index_key)
+                .map(|x| x.len()),
         }
     }
 
@@ -1607,8 +1657,18 @@ impl<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> AccountsIndex<T, U> {
                 .ppl_token_mint_index
                 .key_size_index
                 .get_largest_keys(max_entries),
-            AccountIndex::PplNftOwner => todo!(),
-            AccountIndex::PplNameOwner => todo!(),
+            AccountIndex::PplNftOwner => self
+                .ppl_nft_owner_index
+                .key_size_index
+                .get_largest_keys(max_entries),
+            AccountIndex::PplNftMint => self
+                .ppl_nft_mint_index
+                .key_size_index
+                .get_largest_keys(max_entries),
+            AccountIndex::PplNameOwner => self
+                .ppl_name_owner_index
+                .key_size_index
+                .get_largest_keys(max_entries),
         }
     }
 
@@ -1626,6 +1686,18 @@ impl<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> AccountsIndex<T, U> {
             info!("secondary index: {:?}", AccountIndex::PplTokenOwner);
             self.ppl_token_owner_index.log_contents();
         }
+        if !self.ppl_nft_owner_index.index.is_empty() {
+            info!("secondary index: {:?}", AccountIndex::PplNftOwner);
+            self.ppl_nft_owner_index.log_contents();
+        }
+        if !self.ppl_nft_mint_index.index.is_empty() {
+            info!("secondary index: {:?}", AccountIndex::PplNftMint);
+            self.ppl_nft_mint_index.log_contents();
+        }
+        if !self.ppl_name_owner_index.index.is_empty() {
+            info!("secondary index: {:?}", AccountIndex::PplNameOwner);
+            self.ppl_name_owner_index.log_contents();
+        }
     }
 
     pub(crate) fn update_secondary_indexes(
@@ -1858,6 +1930,18 @@ impl<T: IndexValue, U: DiskIndexValue + From<T> + Into<T>> AccountsIndex<T, U> {
         if account_indexes.contains(&AccountIndex::PplTokenMint) {
             self.ppl_token_mint_index.remove_by_inner_key(inner_key);
         }
+
+        if account_indexes.contains(&AccountIndex::PplNftOwner) {
+            self.ppl_nft_owner_index.remove_by_inner_key(inner_key);
+        }
+
+        if account_indexes.contains(&AccountIndex::PplNftMint) {
+            self.ppl_nft_mint_index.remove_by_inner_key(inner_key);
+        }
+
+        if account_indexes.contains(&AccountIndex::PplNameOwner) {
+            self.ppl_name_owner_index.remove_by_inner_key(inner_key);
+        }
     }
 
     fn purge_older_root_entries(
@@ -2144,6 +2228,13 @@ pub mod tests {
         }
     }
 
+    fn secondary_index_enabled(account_index: &AccountIndex) -> AccountSecondaryIndexes {
+        AccountSecondaryIndexes {
+            indexes: [account_index.clone()].into_iter().collect(),
+            keys: None,
+        }
+    }
+
     impl<T: IndexValue> AccountIndexGetResult<T> {
         pub fn unwrap(self) -> (ReadAccountMapEntry<T>, usize) {
             match self {
@@ -3604,6 +3695,10 @@ pub mod tests {
         let index_key = Pubkey::new_unique();
         let mut account_data = vec![0; inline_ppl_token::Account::get_packed_len()];
         account_data[key_start..key_end].clone_from_slice(&(index_key.to_bytes()));
+        if *token_id == inline_ppl_token_2022::id() {
+            // Extended account: account type, then an empty ImmutableOwner extension
+            account_data.extend_from_slice(&[2, 7, 0, 0, 0]);
+        }
 
         // Wrong program id
         index.upsert(
@@ -3722,6 +3817,167 @@ pub mod tests {
         }
     }
 
+    fn run_test_secondary_index_include_exclude<
+        SecondaryIndexEntryType: SecondaryIndexEntry + Default + Sync + Send,
+    >(
+        program_id: &Pubkey,
+        account_index: AccountIndex,
+        get_secondary_index: fn(
+            &AccountsIndex<bool, bool>,
+        ) -> &SecondaryIndex<SecondaryIndexEntryType>,
+        account_data: &[u8],
+        index_key: &Pubkey,
+    ) {
+        let account_key = Pubkey::new_unique();
+        let account = AccountSharedData::create(0, account_data.to_vec(), *program_id, false, 0);
+        let include_exclude = |keys: &[Pubkey], exclude: bool| AccountSecondaryIndexes {
+            keys: Some(AccountSecondaryIndexesIncludeExclude {
+                keys: keys.iter().cloned().collect(),
+                exclude,
+            }),
+            ..secondary_index_enabled(&account_index)
+        };
+
+        // Index disabled
+        let index = AccountsIndex::<bool, bool>::default_for_tests();
+        index.update_secondary_indexes(
+            &account_key,
+            &account,
+            &secondary_index_enabled(&AccountIndex::ProgramId),
+        );
+        assert!(get_secondary_index(&index).index.is_empty());
+        assert_eq!(index.get_index_key_size(&account_index, index_key), None);
+
+        // Wrong program id
+        let index = AccountsIndex::<bool, bool>::default_for_tests();
+        index.update_secondary_indexes(
+            &account_key,
+            &AccountSharedData::create(0, account_data.to_vec(), Pubkey::default(), false, 0),
+            &secondary_index_enabled(&account_index),
+        );
+        assert!(get_secondary_index(&index).index.is_empty());
+
+        // Empty account data is skipped
+        index.update_secondary_indexes(
+            &account_key,
+            &AccountSharedData::create(0, vec![], *program_id, false, 0),
+            &secondary_index_enabled(&account_index),
+        );
+        assert!(get_secondary_index(&index).index.is_empty());
+
+        // Excluded, or not among the included keys
+        for secondary_indexes in [
+            include_exclude(&[*index_key], true),
+            include_exclude(&[Pubkey::new_unique()], false),
+        ] {
+            let index = AccountsIndex::<bool, bool>::default_for_tests();
+            index.update_secondary_indexes(&account_key, &account, &secondary_indexes);
+            assert!(get_secondary_index(&index).index.is_empty());
+            assert_eq!(index.get_index_key_size(&account_index, index_key), None);
+            assert!(index.get_largest_keys(&account_index, 10).is_empty());
+        }
+
+        // Included, not excluded, or no include/exclude keys at all
+        for secondary_indexes in [
+            include_exclude(&[*index_key], false),
+            include_exclude(&[Pubkey::new_unique()], true),
+            secondary_index_enabled(&account_index),
+        ] {
+            let index = AccountsIndex::<bool, bool>::default_for_tests();
+            index.upsert(
+                0,
+                0,
+                &account_key,
+                &account,
+                &secondary_indexes,
+                true,
+                &mut vec![],
+                UPSERT_POPULATE_RECLAIMS,
+            );
+            check_secondary_index_mapping_correct(
+                get_secondary_index(&index),
+                &[*index_key],
+                &account_key,
+            );
+            assert_eq!(index.get_index_key_size(&account_index, index_key), Some(1));
+            assert_eq!(
+                index.get_index_key_size(&account_index, &Pubkey::new_unique()),
+                None
+            );
+            assert_eq!(
+                index.get_largest_keys(&account_index, 10),
+                vec![(1, *index_key)]
+            );
+            index.log_secondary_indexes();
+
+            // Everything should be deleted
+            index.slot_list_mut(&account_key, |slot_list| slot_list.clear());
+            let _ = index.handle_dead_keys(&[&account_key], &secondary_indexes);
+            assert!(get_secondary_index(&index).index.is_empty());
+            assert!(get_secondary_index(&index).reverse_index.is_empty());
+            assert_eq!(index.get_index_key_size(&account_index, index_key), None);
+        }
+    }
+
+    #[test]
+    fn test_ppl_nft_secondary_indexes() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut account_data = vec![0; inline_nft::NFT_ACCOUNT_LENGTH];
+        account_data[inline_nft::NFT_ACCOUNT_MINT_OFFSET
+            ..inline_nft::NFT_ACCOUNT_MINT_OFFSET + PUBKEY_BYTES]
+            .copy_from_slice(mint.as_ref());
+        account_data[inline_nft::NFT_ACCOUNT_OWNER_OFFSET
+            ..inline_nft::NFT_ACCOUNT_OWNER_OFFSET + PUBKEY_BYTES]
+            .copy_from_slice(owner.as_ref());
+        run_test_secondary_index_include_exclude(
+            &inline_nft::id(),
+            AccountIndex::PplNftOwner,
+            |index| &index.ppl_nft_owner_index,
+            &account_data,
+            &owner,
+        );
+        run_test_secondary_index_include_exclude(
+            &inline_nft::id(),
+            AccountIndex::PplNftMint,
+            |index| &index.ppl_nft_mint_index,
+            &account_data,
+            &mint,
+        );
+    }
+
+    #[test]
+    fn test_ppl_name_owner_secondary_index() {
+        let owner = Pubkey::new_unique();
+        let mut domain_data = vec![0; inline_name::DOMAIN_ACCOUNT_LENGTH];
+        domain_data[inline_name::DOMAIN_ACCOUNT_TYPE_OFFSET] = 1;
+        domain_data[inline_name::DOMAIN_ACCOUNT_OWNER_OFFSET
+            ..inline_name::DOMAIN_ACCOUNT_OWNER_OFFSET + PUBKEY_BYTES]
+            .copy_from_slice(owner.as_ref());
+        run_test_secondary_index_include_exclude(
+            &inline_name::id(),
+            AccountIndex::PplNameOwner,
+            |index| &index.ppl_name_owner_index,
+            &domain_data,
+            &owner,
+        );
+
+        // Domain resolve accounts are indexed by their parent domain
+        let parent = Pubkey::new_unique();
+        let mut resolve_data = vec![0; inline_name::DOMAIN_ACCOUNT_LENGTH];
+        resolve_data[inline_name::DOMAIN_RESOLVE_ACCOUNT_TYPE_OFFSET] = 2;
+        resolve_data[inline_name::DOMAIN_RESOLVE_ACCOUNT_PARENT_OFFSET
+            ..inline_name::DOMAIN_RESOLVE_ACCOUNT_PARENT_OFFSET + PUBKEY_BYTES]
+            .copy_from_slice(parent.as_ref());
+        run_test_secondary_index_include_exclude(
+            &inline_name::id(),
+            AccountIndex::PplNameOwner,
+            |index| &index.ppl_name_owner_index,
+            &resolve_data,
+            &parent,
+        );
+    }
+
     fn run_test_secondary_indexes_same_slot_and_forks<
         SecondaryIndexEntryType: SecondaryIndexEntry + Default + Sync + Send,
     >(
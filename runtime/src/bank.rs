@@ -52,7 +52,9 @@ use {
             ACCOUNTS_DB_CONFIG_FOR_BENCHMARKS, ACCOUNTS_DB_CONFIG_FOR_TESTING,
         },
         accounts_hash::{AccountsHash, CalcAccountsHashConfig, HashStats, IncrementalAccountsHash},
-        accounts_index::{AccountSecondaryIndexes, IndexKey, ScanConfig, ScanResult, ZeroLamport},
+        accounts_index::{
+            AccountIndex, AccountSecondaryIndexes, IndexKey, ScanConfig, ScanResult, ZeroLamport,
+        },
         accounts_partition::{self, Partition, PartitionIndex},
         accounts_update_notifier_interface::AccountsUpdateNotifier,
         ancestors::{Ancestors, AncestorsForSerialization},
@@ -62,7 +64,6 @@ use {
         cost_tracker::CostTracker,
         epoch_accounts_hash::{self, EpochAccountsHash},
         epoch_stakes::{EpochStakes, NodeVoteAccounts},
-        // inline_ppl_associated_token_account, 
         inline_ppl_token,
         message_processor::MessageProcessor,
         partitioned_rewards::PartitionedEpochRewardsConfig,
@@ -6200,10 +6201,49 @@ impl Bank {
         )
     }
 
+    /// Returns the accounts found under any of `index_keys` in the secondary index, looked up one
+    /// key after another with a single scan size limit shared by all of their results
+    pub fn get_filtered_indexed_accounts_by_keys<F: Fn(&AccountSharedData) -> bool>(
+        &self,
+        index_keys: &[IndexKey],
+        filter: F,
+        config: &ScanConfig,
+        byte_limit_for_scan: Option<usize>,
+    ) -> ScanResult<Vec<TransactionAccount>> {
+        self.rc.accounts.load_by_index_keys_with_filter(
+            &self.ancestors,
+            self.bank_id,
+            index_keys,
+            filter,
+            config,
+            byte_limit_for_scan,
+        )
+    }
+
+    /// Returns the accounts found under `index_key` in the secondary index that were modified in
+    /// this bank's slot
+    pub fn get_indexed_accounts_modified_since_parent<F: Fn(&AccountSharedData) -> bool>(
+        &self,
+        index_key: &IndexKey,
+        filter: F,
+    ) -> ScanResult<Vec<TransactionAccount>> {
+        self.rc.accounts.load_by_index_key_in_slot(
+            &self.ancestors,
+            self.bank_id,
+            self.slot(),
+            index_key,
+            filter,
+        )
+    }
+
     pub fn account_indexes_include_key(&self, key: &Pubkey) -> bool {
         self.rc.accounts.account_indexes_include_key(key)
     }
 
+    pub fn account_indexes_contain(&self, index: &AccountIndex) -> bool {
+        self.rc.accounts.account_indexes_contain(index)
+    }
+
     pub fn get_all_accounts_with_modified_slots(&self) -> ScanResult<Vec<PubkeyAccountSlot>> {
         self.rc.accounts.load_all(&self.ancestors, self.bank_id)
     }
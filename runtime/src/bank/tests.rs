@@ -7357,6 +7357,59 @@ fn test_reconfigure_token2_native_mint() {
     assert_eq!(native_mint_account.owner(), &inline_ppl_token::id());
 }
 
+#[test]
+fn test_reconfigure_token2_native_mint_capitalization() {
+    put_logger::setup();
+
+    let genesis_config =
+        create_genesis_config_with_leader(5, &put_sdk::pubkey::new_rand(), 0).genesis_config;
+    let bank = Arc::new(Bank::new_for_tests(&genesis_config));
+
+    // Both native mint accounts are created out of thin air and counted in the capitalization
+    assert_eq!(
+        bank.get_balance(&inline_ppl_token::native_mint::id()),
+        put_to_lamports("1")
+    );
+    assert_eq!(
+        bank.get_balance(&inline_ppl_token::native_mint_info::id()),
+        put_to_lamports("1")
+    );
+    add_root_and_flush_write_cache(&bank);
+    assert_eq!(bank.capitalization(), bank.calculate_capitalization(true));
+
+    // Reconfiguring over the program owned accounts leaves them and the capitalization alone
+    let mut bank = Bank::new_from_parent(&bank, &Pubkey::default(), 1);
+    let capitalization = bank.capitalization();
+    bank.reconfigure_token2_native_mint();
+    assert_eq!(bank.capitalization(), capitalization);
+    assert_eq!(
+        bank.get_balance(&inline_ppl_token::native_mint::id()),
+        put_to_lamports("1")
+    );
+
+    // A system owned account squatting on the native mint address is taken over with its
+    // lamports, which are already part of the capitalization
+    let squatter_lamports = 42 * LAMPORTS_PER_PUT;
+    bank.store_account_and_update_capitalization(
+        &inline_ppl_token::native_mint::id(),
+        &AccountSharedData::new(squatter_lamports, 0, &system_program::id()),
+    );
+    let capitalization = bank.capitalization();
+    bank.reconfigure_token2_native_mint();
+    assert_eq!(bank.capitalization(), capitalization);
+    let native_mint_account = bank
+        .get_account(&inline_ppl_token::native_mint::id())
+        .unwrap();
+    assert_eq!(native_mint_account.owner(), &inline_ppl_token::id());
+    assert_eq!(native_mint_account.lamports(), squatter_lamports);
+    assert_eq!(
+        native_mint_account.data(),
+        &inline_ppl_token::native_mint::ACCOUNT_DATA[..]
+    );
+    add_root_and_flush_write_cache(&bank);
+    assert_eq!(bank.capitalization(), bank.calculate_capitalization(true));
+}
+
 #[test]
 fn test_bank_load_program() {
     put_logger::setup();
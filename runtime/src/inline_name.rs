@@ -55,10 +55,12 @@ pub struct Account;
 impl GenericNameAccount for Account {
 
     fn valid_domain_account_data(account_data: &[u8]) -> bool {
-        account_data[0] == 1
+        account_data.len() >= DOMAIN_ACCOUNT_OWNER_OFFSET + PUBKEY_BYTES
+            && account_data[DOMAIN_ACCOUNT_TYPE_OFFSET] == 1
     }
 
     fn valid_domain_resolve_account_data(account_data: &[u8]) -> bool {
-        account_data[0] == 2
+        account_data.len() >= DOMAIN_RESOLVE_ACCOUNT_PARENT_OFFSET + PUBKEY_BYTES
+            && account_data[DOMAIN_RESOLVE_ACCOUNT_TYPE_OFFSET] == 2
     }
 }
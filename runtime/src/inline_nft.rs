@@ -3,6 +3,7 @@ use put_sdk::pubkey::{Pubkey, PUBKEY_BYTES};
 put_sdk::declare_id!("An2DRyUtGBKYioLhHJEQ3nPcGgzzRJQ8vgdhyjdtC14H");
 
 pub const NFT_ACCOUNT_LENGTH: usize = 306;
+pub const NFT_ACCOUNT_MINT_OFFSET: usize = 0;
 pub const NFT_ACCOUNT_OWNER_OFFSET: usize = 32;
 
 pub(crate) trait GenericNFTAccount {
@@ -13,6 +14,11 @@ pub(crate) trait GenericNFTAccount {
         Self::unpack_pubkey_unchecked(account_data, NFT_ACCOUNT_OWNER_OFFSET)
     }
 
+    // Call after account length has already been verified
+    fn unpack_account_mint_unchecked(account_data: &[u8]) -> &Pubkey {
+        Self::unpack_pubkey_unchecked(account_data, NFT_ACCOUNT_MINT_OFFSET)
+    }
+
     // Call after account length has already been verified
     fn unpack_pubkey_unchecked(account_data: &[u8], offset: usize) -> &Pubkey {
         bytemuck::from_bytes(&account_data[offset..offset + PUBKEY_BYTES])
@@ -25,6 +31,14 @@ pub(crate) trait GenericNFTAccount {
             None
         }
     }
+
+    fn unpack_account_mint(account_data: &[u8]) -> Option<&Pubkey> {
+        if Self::valid_account_data(account_data) {
+            Some(Self::unpack_account_mint_unchecked(account_data))
+        } else {
+            None
+        }
+    }
 }
 
 pub struct Account;
@@ -1,6 +1,7 @@
 // Partial PPL Associated Token Account declarations inlined to avoid an external dependency on the ppl-associated-token-account crate
 put_sdk::declare_id!("PutATA1111111111111111111111111111111111111");
 
-pub(crate) mod program_v1_0_4 {
+// The ppl-associated-token-account 1.0.4 deployment, still referenced by older transactions
+pub mod program_v1_0_4 {
     put_sdk::declare_id!("7p41UXia9fFLQCZ1MNVhYT3jFAsg1kePE8zDBgnPuvTP");
 }
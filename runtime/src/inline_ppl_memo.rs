@@ -0,0 +1,9 @@
+// Partial PPL Memo declarations inlined to avoid an external dependency on the ppl-memo crate
+
+// Memo v1, the id of the ppl-memo crate
+put_sdk::declare_id!("PutMemo111111111111111111111111111111111111");
+
+// Memo v3, the second deployment of the memo program
+pub mod v3 {
+    put_sdk::declare_id!("8jjXD6tToXM9zJiLG44t1P4nhrf8zrVLy3T39CvBEgaa");
+}
@@ -21,7 +21,7 @@ put_sdk::declare_id!("PutToken11111111111111111111111111111111111");
 */
 pub const PPL_TOKEN_ACCOUNT_MINT_OFFSET: usize = 0;
 pub const PPL_TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
-const PPL_TOKEN_ACCOUNT_LENGTH: usize = 165+16+8;
+pub const PPL_TOKEN_ACCOUNT_LENGTH: usize = 165+16+8;
 
 pub(crate) trait GenericTokenAccount {
     fn valid_account_data(account_data: &[u8]) -> bool;
@@ -3,16 +3,60 @@ use crate::inline_ppl_token::{self, GenericTokenAccount};
 
 put_sdk::declare_id!("CgK4eAi5GLCzdvF7f9JEgDqBUkWgYf3bASeZ759mJpbi");
 
+// Extended accounts hold the account type byte right after the base account
+pub const ACCOUNT_TYPE_OFFSET: usize = inline_ppl_token::PPL_TOKEN_ACCOUNT_LENGTH;
+
 // `ppl_token_program_2022::extension::AccountType::Account` ordinal value
-const ACCOUNTTYPE_ACCOUNT: u8 = 2;
+pub const ACCOUNTTYPE_ACCOUNT: u8 = 2;
+
+/*
+    ppl_token::state::Multisig {
+        m: u8,
+        n: u8,
+        is_initialized: bool,
+        signers: [Pubkey; MAX_SIGNERS],
+    }
+*/
+pub const PPL_TOKEN_MULTISIG_LENGTH: usize = 3 + 32 * 11;
 
 pub struct Account;
 impl GenericTokenAccount for Account {
+    // Extended accounts hold the base account, then the account type byte and their extensions.
+    // Multisig accounts are longer than the base account but never carry extensions.
     fn valid_account_data(account_data: &[u8]) -> bool {
         inline_ppl_token::Account::valid_account_data(account_data)
-            || ACCOUNTTYPE_ACCOUNT
-                == *account_data
-                    .get(inline_ppl_token::Account::get_packed_len())
-                    .unwrap_or(&0)
+            || (account_data.len() > ACCOUNT_TYPE_OFFSET
+                && account_data.len() != PPL_TOKEN_MULTISIG_LENGTH
+                && account_data[ACCOUNT_TYPE_OFFSET] == ACCOUNTTYPE_ACCOUNT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_account_data() {
+        let base_len = inline_ppl_token::Account::get_packed_len();
+        assert!(Account::valid_account_data(&vec![0; base_len]));
+        assert!(!Account::valid_account_data(&vec![0; base_len - 1]));
+
+        // Extended token account, with an ImmutableOwner extension
+        let mut account_data = vec![0; base_len];
+        account_data.push(ACCOUNTTYPE_ACCOUNT);
+        account_data.extend_from_slice(&[7, 0, 0, 0]);
+        assert!(Account::valid_account_data(&account_data));
+        assert_eq!(
+            Account::unpack_account_owner(&account_data),
+            Some(&put_sdk::pubkey::Pubkey::default())
+        );
+
+        // Extended mint
+        account_data[base_len] = ACCOUNTTYPE_ACCOUNT - 1;
+        assert!(!Account::valid_account_data(&account_data));
+
+        let mut multisig_data = vec![0; PPL_TOKEN_MULTISIG_LENGTH];
+        multisig_data[base_len] = ACCOUNTTYPE_ACCOUNT;
+        assert!(!Account::valid_account_data(&multisig_data));
     }
 }
@@ -44,6 +44,7 @@ pub mod genesis_utils;
 pub mod hardened_unpack;
 pub mod in_mem_accounts_index;
 pub mod inline_ppl_associated_token_account;
+pub mod inline_ppl_memo;
 pub mod inline_ppl_token;
 pub mod inline_ppl_token_2022;
 pub mod loader_utils;
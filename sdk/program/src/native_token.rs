@@ -1,24 +1,82 @@
 //! Definitions for the native PUT token and its fractional lamports.
 
 #![allow(clippy::integer_arithmetic)]
+
+use thiserror::Error;
+
 /// There are 10^9 lamports in one PUT
 pub const LAMPORTS_PER_PUT: u128 = 1_000_000_000;
 
+/// Number of decimal places used to display PUT amounts
+pub const PUT_DECIMALS: u8 = 9;
+
+/// Reasons a decimal amount string can be rejected by [`parse_amount`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseAmountError {
+    #[error("amount is empty")]
+    Empty,
+
+    #[error("invalid character {0:?} in amount")]
+    InvalidCharacter(char),
+
+    #[error("scientific notation is not supported")]
+    ScientificNotation,
+
+    #[error("amount contains more than one decimal point")]
+    MultipleDecimalPoints,
+
+    #[error("amount has more than {0} significant fractional digits")]
+    TooManyFractionalDigits(u8),
+
+    #[error("amount is too large")]
+    Overflow,
+}
+
+fn push_digit(value: u128, c: char) -> Result<u128, ParseAmountError> {
+    let digit = match c {
+        '0'..='9' => c as u128 - '0' as u128,
+        '.' => return Err(ParseAmountError::MultipleDecimalPoints),
+        'e' | 'E' => return Err(ParseAmountError::ScientificNotation),
+        _ => return Err(ParseAmountError::InvalidCharacter(c)),
+    };
+    value
+        .checked_mul(10)
+        .and_then(|value| value.checked_add(digit))
+        .ok_or(ParseAmountError::Overflow)
+}
+
+/// Exactly convert a decimal `amount` into base units, where one whole unit is `10^decimals`
+/// base units
+///
+/// Only digits and a single optional decimal point are accepted; either side of the point may be
+/// empty, but not both. Fractional digits beyond `decimals` are allowed only if they are zero.
+/// No floating point arithmetic is involved, so the result is never rounded.
+pub fn parse_amount(amount: &str, decimals: u8) -> Result<u128, ParseAmountError> {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(ParseAmountError::Empty);
+    }
+
+    let mut base_units = 0u128;
+    for c in whole.chars() {
+        base_units = push_digit(base_units, c)?;
+    }
 
-pub fn parse_amount(amount:&str) -> (u128, f64){
-    let num:Vec<&str> = amount.split('.').collect();
-    if num.len() == 1 {
-        return (num[0].parse::<u128>().unwrap(),0_f64)
-    }else if num.len() == 2  {
-        if num[1] != ""{
-            let frac  = "0.".to_owned() + num[1];
-            return (num[0].parse::<u128>().unwrap(),frac.parse::<f64>().unwrap())
-        }else{
-            return (num[0].parse::<u128>().unwrap(),0_f64)
+    let mut fraction_digits = 0u8;
+    for c in fraction.chars() {
+        if fraction_digits < decimals {
+            base_units = push_digit(base_units, c)?;
+            fraction_digits += 1;
+        } else if push_digit(0, c)? != 0 {
+            return Err(ParseAmountError::TooManyFractionalDigits(decimals));
         }
-    }else {
-        return (0_u128,0_f64)
     }
+    for _ in fraction_digits..decimals {
+        base_units = base_units
+            .checked_mul(10)
+            .ok_or(ParseAmountError::Overflow)?;
+    }
+    Ok(base_units)
 }
 
 /// Approximately convert fractional native tokens (lamports) into native tokens (PUT)
@@ -32,17 +90,25 @@ pub fn lamports_to_put(lamports: u128) -> String {
     s
 }
 
-/// Approximately convert native tokens (PUT) into fractional native tokens (lamports)
+/// Exactly convert native tokens (PUT) into fractional native tokens (lamports)
+pub fn try_put_to_lamports(put: &str) -> Result<u128, ParseAmountError> {
+    parse_amount(put, PUT_DECIMALS)
+}
+
+/// Convert native tokens (PUT) into fractional native tokens (lamports)
+///
+/// # Panics
+///
+/// Panics if `put` is not a valid amount; use [`try_put_to_lamports`] for untrusted input.
 pub fn put_to_lamports(put: &str) -> u128 {
-    let (inte,frac) = parse_amount(put);
-    inte * LAMPORTS_PER_PUT + (frac * LAMPORTS_PER_PUT as f64) as u128
+    try_put_to_lamports(put).unwrap_or_else(|err| panic!("invalid PUT amount {put:?}: {err}"))
 }
 
-use std::fmt::{Debug, Display, Formatter, Result};
+use std::fmt::{self, Debug, Display, Formatter};
 pub struct PUT(pub u128);
 
 impl PUT {
-    fn write_in_put(&self, f: &mut Formatter) -> Result {
+    fn write_in_put(&self, f: &mut Formatter) -> fmt::Result {
         write!(
             f,
             "◎{}.{:09}",
@@ -53,13 +119,81 @@ impl PUT {
 }
 
 impl Display for PUT {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         self.write_in_put(f)
     }
 }
 
 impl Debug for PUT {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         self.write_in_put(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_put_to_lamports() {
+        assert_eq!(put_to_lamports("0"), 0);
+        assert_eq!(put_to_lamports("1"), LAMPORTS_PER_PUT);
+        assert_eq!(put_to_lamports("1."), LAMPORTS_PER_PUT);
+        assert_eq!(put_to_lamports(".5"), LAMPORTS_PER_PUT / 2);
+        assert_eq!(put_to_lamports("0.000000001"), 1);
+        assert_eq!(
+            put_to_lamports("123456789.123456789"),
+            123_456_789_123_456_789
+        );
+        assert_eq!(put_to_lamports("1.5000000000000"), 1_500_000_000);
+        assert_eq!(put_to_lamports(&lamports_to_put(u128::MAX)), u128::MAX);
+        let leading_zeros = format!("{}1", "0".repeat(5000));
+        assert_eq!(put_to_lamports(&leading_zeros), LAMPORTS_PER_PUT);
+    }
+
+    #[test]
+    fn test_try_put_to_lamports_errors() {
+        assert_eq!(try_put_to_lamports(""), Err(ParseAmountError::Empty));
+        assert_eq!(try_put_to_lamports("."), Err(ParseAmountError::Empty));
+        assert_eq!(
+            try_put_to_lamports("1.2.3"),
+            Err(ParseAmountError::MultipleDecimalPoints)
+        );
+        assert_eq!(
+            try_put_to_lamports("1e9"),
+            Err(ParseAmountError::ScientificNotation)
+        );
+        assert_eq!(
+            try_put_to_lamports("-1"),
+            Err(ParseAmountError::InvalidCharacter('-'))
+        );
+        assert_eq!(
+            try_put_to_lamports(" 1"),
+            Err(ParseAmountError::InvalidCharacter(' '))
+        );
+        assert_eq!(
+            try_put_to_lamports("0.0000000001"),
+            Err(ParseAmountError::TooManyFractionalDigits(PUT_DECIMALS))
+        );
+        assert_eq!(
+            try_put_to_lamports(&"9".repeat(5000)),
+            Err(ParseAmountError::Overflow)
+        );
+        let one_past_max = (u128::MAX / LAMPORTS_PER_PUT + 1).to_string();
+        assert_eq!(
+            try_put_to_lamports(&one_past_max),
+            Err(ParseAmountError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_amount_decimals() {
+        assert_eq!(parse_amount("1.5", 2), Ok(150));
+        assert_eq!(parse_amount("42", 0), Ok(42));
+        assert_eq!(parse_amount("42.000", 0), Ok(42));
+        assert_eq!(
+            parse_amount("42.1", 0),
+            Err(ParseAmountError::TooManyFractionalDigits(0))
+        );
+    }
+}
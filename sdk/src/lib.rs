@@ -99,6 +99,7 @@ pub mod transaction;
 pub mod transaction_context;
 pub mod transport;
 pub mod wasm;
+pub mod wrapped_put;
 
 /// Same as `declare_id` except report that this id has been deprecated.
 pub use put_sdk_macro::declare_deprecated_id;
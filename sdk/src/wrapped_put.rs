@@ -0,0 +1,129 @@
+//! Instruction builders for wrapped PUT, the native mint balance held in a ppl-token account
+//! whose lamports back its token amount
+
+#![cfg(feature = "full")]
+
+use {
+    crate::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        system_instruction,
+    },
+    ppl_associated_token_account::{create_associated_token_account, get_associated_token_address},
+    ppl_token::put_program::{
+        instruction::Instruction as PplTokenInstruction, pubkey::Pubkey as PplTokenPubkey,
+    },
+};
+
+fn ppl_token_pubkey(pubkey: &Pubkey) -> PplTokenPubkey {
+    PplTokenPubkey::new_from_array(pubkey.to_bytes())
+}
+
+fn pubkey_from_ppl_token(pubkey: &PplTokenPubkey) -> Pubkey {
+    Pubkey::new_from_array(pubkey.to_bytes())
+}
+
+fn ppl_token_instruction(instruction: PplTokenInstruction) -> Instruction {
+    Instruction {
+        program_id: pubkey_from_ppl_token(&instruction.program_id),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: pubkey_from_ppl_token(&meta.pubkey),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: instruction.data,
+    }
+}
+
+/// Address of the associated wrapped PUT token account of `owner`
+pub fn wrapped_put_address(owner: &Pubkey) -> Pubkey {
+    pubkey_from_ppl_token(&get_associated_token_address(
+        &ppl_token_pubkey(owner),
+        &ppl_token::native_mint::id(),
+    ))
+}
+
+/// Creates the associated wrapped PUT token account of `owner`, funded by `payer`
+pub fn create_wrapped_put_account(payer: &Pubkey, owner: &Pubkey) -> Instruction {
+    ppl_token_instruction(create_associated_token_account(
+        &ppl_token_pubkey(payer),
+        &ppl_token_pubkey(owner),
+        &ppl_token::native_mint::id(),
+    ))
+}
+
+/// Moves `lamports` from `owner` into its associated wrapped PUT token account and syncs the
+/// token amount with the new balance
+pub fn wrap_put(owner: &Pubkey, lamports: u128) -> Result<Vec<Instruction>, ProgramError> {
+    let account = wrapped_put_address(owner);
+    let sync_native =
+        ppl_token::instruction::sync_native(&ppl_token::id(), &ppl_token_pubkey(&account))
+            .map_err(|err| ProgramError::from(u64::from(err)))?;
+    Ok(vec![
+        system_instruction::transfer(owner, &account, lamports),
+        ppl_token_instruction(sync_native),
+    ])
+}
+
+/// Closes the associated wrapped PUT token account of `owner`, returning all of its lamports,
+/// rent exempt reserve included, to `destination`
+pub fn unwrap_put(owner: &Pubkey, destination: &Pubkey) -> Result<Instruction, ProgramError> {
+    let close_account = ppl_token::instruction::close_account(
+        &ppl_token::id(),
+        &ppl_token_pubkey(&wrapped_put_address(owner)),
+        &ppl_token_pubkey(destination),
+        &ppl_token_pubkey(owner),
+        &[],
+    )
+    .map_err(|err| ProgramError::from(u64::from(err)))?;
+    Ok(ppl_token_instruction(close_account))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, ppl_token::instruction::TokenInstruction};
+
+    #[test]
+    fn test_wrap_put() {
+        let owner = Pubkey::new_unique();
+        let account = wrapped_put_address(&owner);
+        assert_ne!(account, owner);
+        assert_eq!(account, wrapped_put_address(&owner));
+
+        let ixs = wrap_put(&owner, 42).unwrap();
+        assert_eq!(ixs.len(), 2);
+        assert_eq!(ixs[0], system_instruction::transfer(&owner, &account, 42));
+        assert_eq!(ixs[1].program_id, pubkey_from_ppl_token(&ppl_token::id()));
+        assert_eq!(ixs[1].accounts, vec![AccountMeta::new(account, false)]);
+        assert_eq!(
+            TokenInstruction::unpack(&ixs[1].data).unwrap(),
+            TokenInstruction::SyncNative
+        );
+    }
+
+    #[test]
+    fn test_unwrap_put() {
+        let owner = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let ix = unwrap_put(&owner, &destination).unwrap();
+        assert_eq!(ix.program_id, pubkey_from_ppl_token(&ppl_token::id()));
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(wrapped_put_address(&owner), false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(owner, true),
+            ]
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&ix.data).unwrap(),
+            TokenInstruction::CloseAccount
+        );
+    }
+}
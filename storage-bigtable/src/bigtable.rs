@@ -937,6 +937,8 @@ mod tests {
                 loaded_addresses: LoadedAddresses::default(),
                 return_data: Some(TransactionReturnData::default()),
                 compute_units_consumed: Some(1234),
+                pre_nft_holdings: Some(vec![]),
+                post_nft_holdings: Some(vec![]),
             },
         });
         let expected_block = ConfirmedBlock {
@@ -996,6 +998,8 @@ mod tests {
                 meta.rewards = None; // Legacy bincode implementation does not support rewards
                 meta.return_data = None; // Legacy bincode implementation does not support return data
                 meta.compute_units_consumed = None; // Legacy bincode implementation does not support CU consumed
+                meta.pre_nft_holdings = None; // Legacy bincode implementation does not support nft holdings
+                meta.post_nft_holdings = None; // Legacy bincode implementation does not support nft holdings
             }
             assert_eq!(block, bincode_block.into());
         } else {
@@ -1,6 +1,7 @@
 #![allow(clippy::integer_arithmetic)]
 
 use {
+    async_trait::async_trait,
     crate::bigtable::RowKey,
     log::*,
     serde::{Deserialize, Serialize},
@@ -15,6 +16,7 @@ use {
         timing::AtomicInterval,
         transaction::{TransactionError, VersionedTransaction},
     },
+    put_storage_interface::{Error as StorageError, LongTermStorage, Result as StorageResult},
     put_storage_proto::convert::{generated, tx_by_addr},
     put_transaction_status::{
         extract_and_fmt_memos, ConfirmedBlock, ConfirmedTransactionStatusWithSignature,
@@ -81,6 +83,16 @@ impl std::convert::From<std::io::Error> for Error {
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+impl From<Error> for StorageError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::BlockNotFound(slot) => Self::BlockNotFound(slot),
+            Error::SignatureNotFound => Self::SignatureNotFound,
+            _ => Self::Storage(err.to_string()),
+        }
+    }
+}
+
 // Convert a slot to its bucket representation whereby lower slots are always lexically ordered
 // before higher slots
 fn slot_to_key(slot: Slot) -> String {
@@ -251,6 +263,8 @@ impl From<StoredConfirmedBlockTransactionStatusMeta> for TransactionStatusMeta {
             loaded_addresses: LoadedAddresses::default(),
             return_data: None,
             compute_units_consumed: None,
+            pre_nft_holdings: None,
+            post_nft_holdings: None,
         }
     }
 }
@@ -1118,6 +1132,83 @@ impl LedgerStorage {
     }
 }
 
+#[async_trait]
+impl LongTermStorage for LedgerStorage {
+    fn name(&self) -> &'static str {
+        "BigTable"
+    }
+
+    async fn get_first_available_block(&self) -> StorageResult<Option<Slot>> {
+        Ok(LedgerStorage::get_first_available_block(self).await?)
+    }
+
+    async fn get_confirmed_blocks(
+        &self,
+        start_slot: Slot,
+        limit: usize,
+    ) -> StorageResult<Vec<Slot>> {
+        Ok(LedgerStorage::get_confirmed_blocks(self, start_slot, limit).await?)
+    }
+
+    async fn get_confirmed_block(&self, slot: Slot) -> StorageResult<ConfirmedBlock> {
+        Ok(LedgerStorage::get_confirmed_block(self, slot).await?)
+    }
+
+    async fn confirmed_block_exists(&self, slot: Slot) -> StorageResult<bool> {
+        Ok(LedgerStorage::confirmed_block_exists(self, slot).await?)
+    }
+
+    async fn get_signature_status(
+        &self,
+        signature: &Signature,
+    ) -> StorageResult<TransactionStatus> {
+        Ok(LedgerStorage::get_signature_status(self, signature).await?)
+    }
+
+    async fn get_confirmed_transactions(
+        &self,
+        signatures: &[Signature],
+    ) -> StorageResult<Vec<ConfirmedTransactionWithStatusMeta>> {
+        Ok(LedgerStorage::get_confirmed_transactions(self, signatures).await?)
+    }
+
+    async fn get_confirmed_transaction(
+        &self,
+        signature: &Signature,
+    ) -> StorageResult<Option<ConfirmedTransactionWithStatusMeta>> {
+        Ok(LedgerStorage::get_confirmed_transaction(self, signature).await?)
+    }
+
+    async fn get_confirmed_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before_signature: Option<&Signature>,
+        until_signature: Option<&Signature>,
+        limit: usize,
+    ) -> StorageResult<Vec<(ConfirmedTransactionStatusWithSignature, u32)>> {
+        Ok(LedgerStorage::get_confirmed_signatures_for_address(
+            self,
+            address,
+            before_signature,
+            until_signature,
+            limit,
+        )
+        .await?)
+    }
+
+    async fn upload_confirmed_block(
+        &self,
+        slot: Slot,
+        confirmed_block: VersionedConfirmedBlock,
+    ) -> StorageResult<()> {
+        Ok(LedgerStorage::upload_confirmed_block(self, slot, confirmed_block).await?)
+    }
+
+    async fn delete_confirmed_block(&self, slot: Slot, dry_run: bool) -> StorageResult<()> {
+        Ok(LedgerStorage::delete_confirmed_block(self, slot, dry_run).await?)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,29 +1,34 @@
 // Primitives for reading/writing HBase tables
 
-use std::{sync::{Arc, Mutex}};
-use thrift::{
-    protocol::{TBinaryInputProtocol, TBinaryOutputProtocol},
-    transport::{TBufferedReadTransport, TBufferedWriteTransport, TIoChannel, TTcpChannel},
-};
-
-use crate::hbase_thrift2::{TResult, TDelete};
-
 use {
     crate::{
         compression::{compress_best, decompress},
-        hbase_thrift2::{THBaseServiceSyncClient,TTHBaseServiceSyncClient, TPut, TColumnValue, TGet, TColumn,TScan},
+        hbase_thrift2::{
+            TColumn, TColumnFamilyDescriptor, TColumnValue, TDelete, TGet, TIOError,
+            TNamespaceDescriptor, TPut, TResult, TScan, TTableDescriptor, TTableName,
+            THBaseServiceSyncClient, TTHBaseServiceSyncClient,
+        },
     },
-    // backoff::{future::retry, ExponentialBackoff},
+    backoff::{retry_notify, ExponentialBackoff},
     log::*,
-    std::time::{Duration},
+    std::{
+        net::{TcpStream, ToSocketAddrs},
+        sync::{Arc, Mutex},
+        time::Duration,
+    },
     thiserror::Error,
-    // tonic::{
-    //     codegen::InterceptedService, metadata::MetadataValue, transport::ClientTlsConfig, Request,
-    //     Status,
-    // },
-    // std::convert::TryFrom,
+    thrift::{
+        protocol::{TBinaryInputProtocol, TBinaryOutputProtocol},
+        transport::{TBufferedReadTransport, TBufferedWriteTransport, TIoChannel, TTcpChannel},
+    },
 };
 
+// Maximum number of idle Thrift clients kept open for reuse
+const MAX_IDLE_CLIENTS: usize = 16;
+
+// Give up retrying a failed operation after this long
+const MAX_RETRY_ELAPSED_TIME: Duration = Duration::from_secs(30);
+
 pub type RowKey = String;
 pub type RowData = Vec<(CellName, CellValue)>;
 pub type RowDataSlice<'a> = &'a [(CellName, CellValue)];
@@ -60,53 +65,165 @@ impl std::convert::From<thrift::Error> for Error {
     }
 }
 
+impl Error {
+    /// Whether the operation that produced this error may succeed if attempted again
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::Io(err) => matches!(
+                err.kind(),
+                std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::Interrupted
+            ),
+            Self::Thrift(thrift::Error::Transport(_)) => true,
+            Self::Thrift(thrift::Error::User(err)) => err
+                .downcast_ref::<TIOError>()
+                .and_then(|err| err.can_retry)
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
-// type InterceptedRequestResult = std::result::Result<Request<()>, Status>;
 
+type ThriftClient = Box<dyn TTHBaseServiceSyncClient + Send>;
 
-#[derive(Clone)]
-pub struct HBaseConnection {
+// A set of open Thrift clients to the same gateway that are handed out to one `HBase` at a time
+struct ClientPool {
     thrift2_url: String,
     timeout: Option<Duration>,
+    idle: Mutex<Vec<ThriftClient>>,
+}
+
+impl ClientPool {
+    fn connect(&self) -> Result<ThriftClient> {
+        let addr = self
+            .thrift2_url
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("Unable to resolve {}", self.thrift2_url),
+                )
+            })?;
+        let stream = match self.timeout {
+            Some(timeout) => TcpStream::connect_timeout(&addr, timeout),
+            None => TcpStream::connect(addr),
+        }
+        .map_err(|err| {
+            inc_new_counter_info!("storage-hbase-connection-error", 1);
+            err
+        })?;
+        // The socket timeouts bound every request and response of a call
+        stream.set_read_timeout(self.timeout)?;
+        stream.set_write_timeout(self.timeout)?;
+        stream.set_nodelay(true)?;
+
+        let (i_chan, o_chan) = TTcpChannel::with_stream(stream).split()?;
+        let i_prot = TBinaryInputProtocol::new(TBufferedReadTransport::new(i_chan), true);
+        let o_prot = TBinaryOutputProtocol::new(TBufferedWriteTransport::new(o_chan), true);
+
+        inc_new_counter_info!("storage-hbase-connection-opened", 1);
+        Ok(Box::new(THBaseServiceSyncClient::new(i_prot, o_prot)))
+    }
+
+    fn checkout(&self) -> Result<ThriftClient> {
+        let client = self.idle.lock().unwrap().pop();
+        match client {
+            Some(client) => {
+                inc_new_counter_debug!("storage-hbase-connection-reused", 1);
+                Ok(client)
+            }
+            None => self.connect(),
+        }
+    }
+
+    fn checkin(&self, client: ThriftClient) {
+        let mut idle = self.idle.lock().unwrap();
+        if idle.len() < MAX_IDLE_CLIENTS {
+            idle.push(client);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct HBaseConnection {
+    pool: Arc<ClientPool>,
+    namespace: String,
 }
 
 impl HBaseConnection {
-    /// Establish a connection to the HBase instance named `instance_name`.  If read-only access
-    /// is required, the `read_only` flag should be used to reduce the requested OAuth2 scope.
-    ///
-    /// The GOOGLE_APPLICATION_CREDENTIALS environment variable will be used to determine the
-    /// program name that contains the HBase instance in addition to access credentials.
+    /// Establish a connection to the HBase Thrift2 gateway at `thrift2_url`. All tables are
+    /// accessed within `namespace`.
     ///
-    /// The BIGTABLE_EMULATOR_HOST environment variable is also respected.
-    /// 
+    /// Connections are opened lazily and reused across calls. If provided, `timeout` bounds
+    /// connection establishment as well as every read and write on the socket.
     pub fn new(
         thrift2_url: String,
+        namespace: &str,
         timeout: Option<Duration>,
     ) -> Result<Self> {
         Ok(Self {
-            thrift2_url : thrift2_url.to_string(),
-            timeout,
+            pool: Arc::new(ClientPool {
+                thrift2_url,
+                timeout,
+                idle: Mutex::new(vec![]),
+            }),
+            namespace: namespace.to_string(),
         })
     }
 
     /// Create a new HBase client.
     ///
-    /// Clients require `&mut self`, due to `Tonic::transport::Channel` limitations, however
-    /// creating new clients is cheap and thus can be used as a work around for ease of use.
+    /// The client is backed by an idle connection from the pool if one is available, and its
+    /// connection is returned to the pool when the client is dropped.
     pub fn client(&self) -> Result<HBase> {
-        let mut channel = TTcpChannel::new();
-        channel.open(self.thrift2_url.clone())?;
-        let (i_chan, o_chan) = channel.split()?;
-    
-        let i_prot = TBinaryInputProtocol::new(TBufferedReadTransport::new(i_chan), true);
-        let o_prot = TBinaryOutputProtocol::new(TBufferedWriteTransport::new(o_chan), true);
-    
-        let client = THBaseServiceSyncClient::new(i_prot, o_prot);
-
         Ok(HBase {
-            client: Arc::new(Mutex::new(client)),
-            table_prefix: "".to_string(),
-            timeout: self.timeout,
+            client: Some(self.pool.checkout()?),
+            pool: self.pool.clone(),
+            namespace: self.namespace.clone(),
+        })
+    }
+
+    /// Run `op` against a pooled client, retrying with exponential backoff on errors that are
+    /// likely to be transient, such as a dropped connection or a retryable `TIOError`.
+    ///
+    /// Both the Thrift calls and the waits between retries block the calling thread, for up to
+    /// `MAX_RETRY_ELAPSED_TIME`, so async callers must run this on the blocking thread pool. The
+    /// client is returned to the pool before each wait.
+    pub fn with_retry<T, F>(&self, mut op: F) -> Result<T>
+    where
+        F: FnMut(&mut HBase) -> Result<T>,
+    {
+        let backoff = ExponentialBackoff {
+            max_elapsed_time: Some(MAX_RETRY_ELAPSED_TIME),
+            ..ExponentialBackoff::default()
+        };
+        retry_notify(
+            backoff,
+            || {
+                let classify = |err: Error| {
+                    if err.is_retryable() {
+                        backoff::Error::transient(err)
+                    } else {
+                        backoff::Error::permanent(err)
+                    }
+                };
+                let mut client = self.client().map_err(classify)?;
+                op(&mut client).map_err(classify)
+            },
+            |err: Error, duration: Duration| {
+                inc_new_counter_info!("storage-hbase-retry", 1);
+                warn!("HBase operation failed, retrying in {:?}: {}", duration, err);
+            },
+        )
+        .map_err(|err| match err {
+            backoff::Error::Permanent(err) => err,
+            backoff::Error::Transient { err, .. } => err,
         })
     }
 
@@ -118,13 +235,11 @@ impl HBaseConnection {
     where
         T: serde::ser::Serialize,
     {
-        let mut client = self.client()?;
-            client.put_bincode_cells(table, cells)
+        self.with_retry(|client| client.put_bincode_cells(table, cells))
     }
 
     pub fn delete_rows_with_retry(&self, table: &str, row_keys: &[RowKey], qualifier :&str) -> Result<()> {
-        let mut client = self.client()?;
-        client.delete_rows(table, row_keys, qualifier)
+        self.with_retry(|client| client.delete_rows(table, row_keys, qualifier))
     }
 
     pub fn get_bincode_cells_with_retry<T>(
@@ -135,8 +250,7 @@ impl HBaseConnection {
     where
         T: serde::de::DeserializeOwned,
     {
-        let mut client = self.client()?;
-        client.get_bincode_cells(table, row_keys)
+        self.with_retry(|client| client.get_bincode_cells(table, row_keys))
     }
 
     pub fn put_protobuf_cells_with_retry<T>(
@@ -147,18 +261,112 @@ impl HBaseConnection {
     where
         T: prost::Message,
     {
-        let mut client = self.client()?;
-        client.put_protobuf_cells(table, cells)
+        self.with_retry(|client| client.put_protobuf_cells(table, cells))
     }
 }
 
-    pub struct HBase {
-        client: Arc<Mutex<dyn TTHBaseServiceSyncClient>>,
-        table_prefix: String,
-        timeout: Option<Duration>,
+pub struct HBase {
+    client: Option<ThriftClient>,
+    pool: Arc<ClientPool>,
+    namespace: String,
+}
+
+impl Drop for HBase {
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take() {
+            self.pool.checkin(client);
+        }
     }
+}
 
 impl HBase {
+    // Issue a single Thrift call. A connection that fails below the application layer is in an
+    // unknown state, so it is closed rather than returned to the pool.
+    fn call<T>(
+        &mut self,
+        f: impl FnOnce(&mut dyn TTHBaseServiceSyncClient) -> thrift::Result<T>,
+    ) -> Result<T> {
+        let client = self.client.as_mut().expect("client is present until drop");
+        let result = f(client.as_mut());
+        if let Err(thrift::Error::Transport(_) | thrift::Error::Protocol(_)) = &result {
+            inc_new_counter_info!("storage-hbase-connection-dropped", 1);
+            self.client = None;
+        }
+        result.map_err(Error::from)
+    }
+
+    // Fully qualified name of `table_name` within the namespace of this client
+    fn table(&self, table_name: &str) -> Vec<u8> {
+        format!("{}:{}", self.namespace, table_name).into_bytes()
+    }
+
+    /// Create the namespace of this client if it does not exist yet. Returns whether it was
+    /// created.
+    pub fn create_namespace_if_missing(&mut self) -> Result<bool> {
+        let namespace = self.namespace.clone();
+        let namespaces = self.call(|client| client.list_namespaces())?;
+        if namespaces.contains(&namespace) {
+            return Ok(false);
+        }
+        self.call(|client| {
+            client.create_namespace(TNamespaceDescriptor {
+                name: namespace,
+                configuration: None,
+            })
+        })?;
+        Ok(true)
+    }
+
+    /// Create `table_name` with the column families `family_names` if it does not exist yet,
+    /// keeping only the latest version of each cell. Returns whether the table was created.
+    pub fn create_table_if_missing(
+        &mut self,
+        table_name: &str,
+        family_names: &[&str],
+    ) -> Result<bool> {
+        let table_name = TTableName {
+            ns: Some(self.namespace.clone().into_bytes()),
+            qualifier: table_name.to_string().into_bytes(),
+        };
+        if self.call(|client| client.table_exists(table_name.clone()))? {
+            return Ok(false);
+        }
+
+        let columns = family_names
+            .iter()
+            .map(|family_name| TColumnFamilyDescriptor {
+                name: family_name.to_string().into_bytes(),
+                attributes: None,
+                configuration: None,
+                block_size: None,
+                bloomn_filter_type: None,
+                compression_type: None,
+                dfs_replication: None,
+                data_block_encoding: None,
+                keep_deleted_cells: None,
+                max_versions: Some(1),
+                min_versions: None,
+                scope: None,
+                time_to_live: None,
+                block_cache_enabled: None,
+                cache_blooms_on_write: None,
+                cache_data_on_write: None,
+                cache_indexes_on_write: None,
+                compress_tags: None,
+                evict_blocks_on_close: None,
+                in_memory: None,
+            })
+            .collect();
+        let desc = TTableDescriptor {
+            table_name,
+            columns: Some(columns),
+            attributes: None,
+            durability: None,
+        };
+        self.call(|client| client.create_table(desc, vec![]))?;
+        Ok(true)
+    }
+
     fn decode_read_rows_response(
         &self,
         rrr: Vec<TResult>,
@@ -223,13 +431,56 @@ impl HBase {
         };
 
         // let scan_id = self.client.open_scanner(table_name.to_string().as_bytes().to_vec(),scan)?;
-        let result = self.client.lock().unwrap().get_scanner_results(table_name.to_string().as_bytes().to_vec(),scan, rows_limit)?;
+        let table = self.table(table_name);
+        let result = self.call(|client| client.get_scanner_results(table, scan, rows_limit))?;
         //result.into_iter().map(|r| String::from_utf8(r.row.unwrap()).unwrap_or("None".to_string())).collect::<Vec<_>>()
 
         let rows = self.decode_read_rows_response(result)?;
         Ok(rows.into_iter().map(|r| r.0).collect())
     }
 
+    /// Get the last row key of `table` in lexical order, if the table is not empty.
+    pub fn get_last_row_key(&mut self, table_name: &str) -> Result<Option<RowKey>> {
+        let scan = TScan {
+            reversed: Some(true),
+            ..Default::default()
+        };
+
+        let table = self.table(table_name);
+        let result = self.call(|client| client.get_scanner_results(table, scan, 1))?;
+
+        let rows = self.decode_read_rows_response(result)?;
+        Ok(rows.into_iter().next().map(|r| r.0))
+    }
+
+    /// Check whether a row key exists in a `table`
+    pub fn row_key_exists(&mut self, table_name: &str, row_key: RowKey) -> Result<bool> {
+        let get = TGet {
+            row: row_key.into_bytes(),
+            columns: Some(vec![TColumn {
+                family: "x".to_string().as_bytes().to_vec(),
+                qualifier: None,
+                timestamp: None,
+            }]),
+            timestamp: None,
+            time_range: None,
+            max_versions: None,
+            filter_string: None,
+            attributes: None,
+            authorizations: None,
+            consistency: None,
+            target_replica_id: None,
+            cache_blocks: None,
+            store_limit: None,
+            store_offset: None,
+            existence_only: None,
+            filter_bytes: None,
+        };
+
+        let table = self.table(table_name);
+        self.call(|client| client.exists(table, get))
+    }
+
     /// Get latest data from `table`.
     ///
     /// All column families are accepted, and only the latest version of each column cell will be
@@ -268,7 +519,8 @@ impl HBase {
         };
 
         // let scan_id = self.client.open_scanner(table_name.to_string().as_bytes().to_vec(),scan)?;
-        let result = self.client.lock().unwrap().get_scanner_results(table_name.to_string().as_bytes().to_vec(),scan, rows_limit)?;
+        let table = self.table(table_name);
+        let result = self.call(|client| client.get_scanner_results(table, scan, rows_limit))?;
         self.decode_read_rows_response(result)
     }
 
@@ -306,7 +558,8 @@ impl HBase {
         
 
         // let scan_id = self.client.open_scanner(table_name.to_string().as_bytes().to_vec(),scan)?;
-        let result = self.client.lock().unwrap().get_multiple(table_name.to_string().as_bytes().to_vec(),gets)?;
+        let table = self.table(table_name);
+        let result = self.call(|client| client.get_multiple(table, gets))?;
         self.decode_read_rows_response(result)
     }
 
@@ -345,7 +598,8 @@ impl HBase {
             filter_bytes:None,
         };
 
-        let result = self.client.lock().unwrap().get(table_name.to_string().as_bytes().to_vec(),get)?;
+        let table = self.table(table_name);
+        let result = self.call(|client| client.get(table, get))?;
 
         let rows = self.decode_read_rows_response(vec![result])?;
         rows.into_iter()
@@ -372,7 +626,8 @@ impl HBase {
             }
         }).collect::<Vec<_>>();
         
-        let _ = self.client.lock().unwrap().delete_multiple(table_name.to_string().as_bytes().to_vec(),dels)?;
+        let table = self.table(table_name);
+        let _ = self.call(|client| client.delete_multiple(table, dels))?;
         Ok(())
     }
 
@@ -410,7 +665,8 @@ impl HBase {
             }
         }).collect::<Vec<_>>();
 
-        let result = self.client.lock().unwrap().put_multiple(table_name.to_string().as_bytes().to_vec(),puts);
+        let table = self.table(table_name);
+        let result = self.call(|client| client.put_multiple(table, puts));
         // Ok(())
         match result {
             Ok(o) => {
@@ -617,4 +873,18 @@ where
         Error::ObjectCorrupt(format!("{}/{}", table, key))
     })
 
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_io_error_is_retryable() {
+        let io_error = |kind| Error::Io(std::io::Error::from(kind));
+        assert!(io_error(std::io::ErrorKind::TimedOut).is_retryable());
+        assert!(io_error(std::io::ErrorKind::ConnectionReset).is_retryable());
+        assert!(!io_error(std::io::ErrorKind::ConnectionRefused).is_retryable());
+        assert!(!io_error(std::io::ErrorKind::InvalidData).is_retryable());
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::integer_arithmetic)]
 use {
+    async_trait::async_trait,
     crate::hbase::RowKey,
     log::*,
     serde::{Deserialize, Serialize},
@@ -13,6 +14,7 @@ use {
         sysvar::is_sysvar_id,
         transaction::{TransactionError, VersionedTransaction},
     },
+    put_storage_interface::{Error as StorageError, LongTermStorage, Result as StorageResult},
     put_storage_proto::convert::{generated, tx_by_addr},
     put_transaction_status::{
         extract_and_fmt_memos, ConfirmedBlock, ConfirmedTransactionStatusWithSignature,
@@ -25,6 +27,7 @@ use {
         convert::TryInto,
     },
     thiserror::Error,
+    tokio::task::JoinError,
 };
 
 #[macro_use]
@@ -36,10 +39,15 @@ extern crate serde_derive;
 mod hbase;
 mod compression;
 mod hbase_thrift2;
+#[cfg(any(test, feature = "dev-context-only-utils"))]
+pub mod test_server;
 
 pub const DEFAULT_LEDGER_NAME: &str = "put-ledger";
 pub const DEFAULT_HBASE_NAMESPACE: &str = "default";
 
+// Tables holding the ledger, each with the single column family `x`
+const TABLE_NAMES: [&str; 3] = ["blocks", "tx", "tx-by-addr"];
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("HBase: {0}")]
@@ -56,6 +64,9 @@ pub enum Error {
 
     #[error("Signature not found")]
     SignatureNotFound,
+
+    #[error("tokio error")]
+    TokioJoinError(JoinError),
 }
 
 impl std::convert::From<hbase::Error> for Error {
@@ -72,6 +83,16 @@ impl std::convert::From<std::io::Error> for Error {
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+impl From<Error> for StorageError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::BlockNotFound(slot) => Self::BlockNotFound(slot),
+            Error::SignatureNotFound => Self::SignatureNotFound,
+            _ => Self::Storage(err.to_string()),
+        }
+    }
+}
+
 // Convert a slot to its bucket representation whereby lower slots are always lexically ordered
 // before higher slots
 fn slot_to_key(slot: Slot) -> String {
@@ -242,6 +263,8 @@ impl From<StoredConfirmedBlockTransactionStatusMeta> for TransactionStatusMeta {
             loaded_addresses: LoadedAddresses::default(),
             return_data: None,
             compute_units_consumed: None,
+            pre_nft_holdings: None,
+            post_nft_holdings: None,
         }
     }
 }
@@ -371,27 +394,58 @@ pub struct LedgerStorage {
 }
 
 impl LedgerStorage {
+    /// Connect to the HBase Thrift2 gateway at `thrift2_url`, storing the ledger in the tables of
+    /// `namespace`. Separate namespaces keep several ledgers isolated on one cluster.
     pub fn new(
         thrift2_url : String,
+        namespace: &str,
         timeout: Option<std::time::Duration>,
     ) -> Result<Self> {
         let connection =
-            hbase::HBaseConnection::new(thrift2_url, timeout)?;
+            hbase::HBaseConnection::new(thrift2_url, namespace, timeout)?;
         Ok(Self { connection })
     }
 
+    /// Create the namespace and the ledger tables, with their column families, if they are
+    /// missing. Returns the names of the tables that were created.
+    pub fn create_tables(&self) -> Result<Vec<&'static str>> {
+        let mut created = vec![];
+        self.connection.with_retry(|hbase| hbase.create_namespace_if_missing())?;
+        for table_name in TABLE_NAMES {
+            if self
+                .connection
+                .with_retry(|hbase| hbase.create_table_if_missing(table_name, &["x"]))?
+            {
+                info!("created table {}", table_name);
+                created.push(table_name);
+            }
+        }
+        Ok(created)
+    }
+
     /// Return the available slot that contains a block
     pub fn get_first_available_block(&self) -> Result<Option<Slot>> {
         debug!("LedgerStorage::get_first_available_block request received");
         inc_new_counter_debug!("storage-hbase-query", 1);
-        let mut hbase = self.connection.client()?;
-        let blocks = hbase.get_row_keys("blocks", None, None, 1)?;
+        let blocks = self
+            .connection
+            .with_retry(|hbase| hbase.get_row_keys("blocks", None, None, 1))?;
         if blocks.is_empty() {
             return Ok(None);
         }
         Ok(key_to_slot(&blocks[0]))
     }
 
+    /// Return the highest slot that contains a block
+    pub fn get_last_available_block(&self) -> Result<Option<Slot>> {
+        debug!("LedgerStorage::get_last_available_block request received");
+        inc_new_counter_debug!("storage-hbase-query", 1);
+        let block = self
+            .connection
+            .with_retry(|hbase| hbase.get_last_row_key("blocks"))?;
+        Ok(block.and_then(|key| key_to_slot(&key)))
+    }
+
     /// Fetch the next slots after the provided slot that contains a block
     ///
     /// start_slot: slot to start the search from (inclusive)
@@ -403,14 +457,14 @@ impl LedgerStorage {
             start_slot, limit
         );
         inc_new_counter_debug!("storage-hbase-query", 1);
-        let mut hbase = self.connection.client()?;
-        let blocks = hbase
-            .get_row_keys(
+        let blocks = self.connection.with_retry(|hbase| {
+            hbase.get_row_keys(
                 "blocks",
                 Some(slot_to_blocks_key(start_slot)),
                 None,
                 limit as i32,
-            )?;
+            )
+        })?;
         Ok(blocks.into_iter().filter_map(|s| key_to_slot(&s)).collect())
     }
 
@@ -424,10 +478,12 @@ impl LedgerStorage {
             slots
         );
         inc_new_counter_debug!("storage-hbase-query", 1);
-        let mut hbase = self.connection.client()?;
         let row_keys = slots.iter().copied().map(slot_to_blocks_key);
-        let data = hbase
-            .get_protobuf_or_bincode_cells("blocks", row_keys)?
+        let data = self
+            .connection
+            .with_retry(|hbase| {
+                hbase.get_protobuf_or_bincode_cells("blocks", row_keys.clone())
+            })?
             .filter_map(
                 |(row_key, block_cell_data): (
                     RowKey,
@@ -450,12 +506,14 @@ impl LedgerStorage {
             slot
         );
         inc_new_counter_debug!("storage-hbase-query", 1);
-        let mut hbase = self.connection.client()?;
-        let block_cell_data = hbase
-            .get_protobuf_or_bincode_cell::<StoredConfirmedBlock, generated::ConfirmedBlock>(
-                "blocks",
-                slot_to_blocks_key(slot),
-            )
+        let block_cell_data = self
+            .connection
+            .with_retry(|hbase| {
+                hbase.get_protobuf_or_bincode_cell::<StoredConfirmedBlock, generated::ConfirmedBlock>(
+                    "blocks",
+                    slot_to_blocks_key(slot),
+                )
+            })
             .map_err(|err| match err {
                 hbase::Error::RowNotFound => Error::BlockNotFound(slot),
                 _ => err.into(),
@@ -468,15 +526,30 @@ impl LedgerStorage {
         })
     }
 
+    /// Does the confirmed block for the desired slot exist
+    pub fn confirmed_block_exists(&self, slot: Slot) -> Result<bool> {
+        debug!(
+            "LedgerStorage::confirmed_block_exists request received: {:?}",
+            slot
+        );
+        inc_new_counter_debug!("storage-hbase-query", 1);
+        let block_exists = self
+            .connection
+            .with_retry(|hbase| hbase.row_key_exists("blocks", slot_to_blocks_key(slot)))?;
+        Ok(block_exists)
+    }
+
     pub fn get_signature_status(&self, signature: &Signature) -> Result<TransactionStatus> {
         debug!(
             "LedgerStorage::get_signature_status request received: {:?}",
             signature
         );
         inc_new_counter_debug!("storage-hbase-query", 1);
-        let mut hbase = self.connection.client()?;
-        let transaction_info = hbase
-            .get_bincode_cell::<TransactionInfo>("tx", signature.to_string())
+        let transaction_info = self
+            .connection
+            .with_retry(|hbase| {
+                hbase.get_bincode_cell::<TransactionInfo>("tx", signature.to_string())
+            })
             .map_err(|err| match err {
                 hbase::Error::RowNotFound => Error::SignatureNotFound,
                 _ => err.into(),
@@ -484,6 +557,67 @@ impl LedgerStorage {
         Ok(transaction_info.into())
     }
 
+    // Fetches and gets a vector of confirmed transactions via a multirow fetch
+    pub fn get_confirmed_transactions(
+        &self,
+        signatures: &[Signature],
+    ) -> Result<Vec<ConfirmedTransactionWithStatusMeta>> {
+        debug!(
+            "LedgerStorage::get_confirmed_transactions request received: {:?}",
+            signatures
+        );
+        inc_new_counter_debug!("storage-hbase-query", 1);
+
+        // Fetch transactions info
+        let keys = signatures.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let cells = self
+            .connection
+            .get_bincode_cells_with_retry::<TransactionInfo>("tx", &keys)?;
+
+        // Collect by slot
+        let mut order: Vec<(Slot, u32, String)> = Vec::new();
+        let mut slots: HashSet<Slot> = HashSet::new();
+        for cell in cells {
+            if let (signature, Ok(TransactionInfo { slot, index, .. })) = cell {
+                order.push((slot, index, signature));
+                slots.insert(slot);
+            }
+        }
+
+        // Fetch blocks
+        let slots = slots.into_iter().collect::<Vec<_>>();
+        let blocks = self
+            .get_confirmed_blocks_with_data(&slots)?
+            .collect::<HashMap<_, _>>();
+
+        // Extract transactions
+        Ok(order
+            .into_iter()
+            .filter_map(|(slot, index, signature)| {
+                blocks.get(&slot).and_then(|block| {
+                    block
+                        .transactions
+                        .get(index as usize)
+                        .and_then(|tx_with_meta| {
+                            if tx_with_meta.transaction_signature().to_string() != *signature {
+                                warn!(
+                                    "Transaction info or confirmed block for {} is corrupt",
+                                    signature
+                                );
+                                None
+                            } else {
+                                Some(ConfirmedTransactionWithStatusMeta {
+                                    slot,
+                                    tx_with_meta: tx_with_meta.clone(),
+                                    block_time: block.block_time,
+                                })
+                            }
+                        })
+                })
+            })
+            .collect::<Vec<_>>())
+    }
+
     /// Fetch a confirmed transaction
     pub fn get_confirmed_transaction(
         &self,
@@ -494,11 +628,12 @@ impl LedgerStorage {
             signature
         );
         inc_new_counter_debug!("storage-hbase-query", 1);
-        let mut hbase = self.connection.client()?;
-
         // Figure out which block the transaction is located in
-        let TransactionInfo { slot, index, .. } = hbase
-            .get_bincode_cell("tx", signature.to_string())
+        let TransactionInfo { slot, index, .. } = self
+            .connection
+            .with_retry(|hbase| {
+                hbase.get_bincode_cell::<TransactionInfo>("tx", signature.to_string())
+            })
             .map_err(|err| match err {
                 hbase::Error::RowNotFound => Error::SignatureNotFound,
                 _ => err.into(),
@@ -553,15 +688,17 @@ impl LedgerStorage {
             address
         );
         inc_new_counter_debug!("storage-hbase-query", 1);
-        let mut hbase = self.connection.client()?;
         let address_prefix = format!("{}/", address);
 
         // Figure out where to start listing from based on `before_signature`
         let (first_slot, before_transaction_index) = match before_signature {
             None => (Slot::MAX, 0),
             Some(before_signature) => {
-                let TransactionInfo { slot, index, .. } = hbase
-                    .get_bincode_cell("tx", before_signature.to_string())?;
+                let TransactionInfo { slot, index, .. } = self
+                    .connection
+                    .with_retry(|hbase| {
+                        hbase.get_bincode_cell::<TransactionInfo>("tx", before_signature.to_string())
+                    })?;
                 (slot, index)
             }
         };
@@ -570,19 +707,25 @@ impl LedgerStorage {
         let (last_slot, until_transaction_index) = match until_signature {
             None => (0, u32::MAX),
             Some(until_signature) => {
-                let TransactionInfo { slot, index, .. } = hbase
-                    .get_bincode_cell("tx", until_signature.to_string())?;
+                let TransactionInfo { slot, index, .. } = self
+                    .connection
+                    .with_retry(|hbase| {
+                        hbase.get_bincode_cell::<TransactionInfo>("tx", until_signature.to_string())
+                    })?;
                 (slot, index)
             }
         };
 
         let mut infos = vec![];
 
-        let starting_slot_tx_len = hbase
-            .get_protobuf_or_bincode_cell::<Vec<LegacyTransactionByAddrInfo>, tx_by_addr::TransactionByAddr>(
-                "tx-by-addr",
-                format!("{}{}", address_prefix, slot_to_tx_by_addr_key(first_slot)),
-            )
+        let starting_slot_tx_len = self
+            .connection
+            .with_retry(|hbase| {
+                hbase.get_protobuf_or_bincode_cell::<Vec<LegacyTransactionByAddrInfo>, tx_by_addr::TransactionByAddr>(
+                    "tx-by-addr",
+                    format!("{}{}", address_prefix, slot_to_tx_by_addr_key(first_slot)),
+                )
+            })
             .map(|cell_data| {
                 match cell_data {
                     hbase::CellData::Bincode(tx_by_addr) => tx_by_addr.len(),
@@ -593,8 +736,8 @@ impl LedgerStorage {
 
         // Return the next tx-by-addr data of amount `limit` plus extra to account for the largest
         // number that might be flitered out
-        let tx_by_addr_data = hbase
-            .get_row_data(
+        let tx_by_addr_data = self.connection.with_retry(|hbase| {
+            hbase.get_row_data(
                 "tx-by-addr",
                 Some(format!(
                     "{}{}",
@@ -607,7 +750,8 @@ impl LedgerStorage {
                     slot_to_tx_by_addr_key(last_slot),
                 )),
                 limit as i32 + starting_slot_tx_len as i32,
-            )?;
+            )
+        })?;
 
         'outer: for (row_key, data) in tx_by_addr_data {
             let slot = !key_to_slot(&row_key[address_prefix.len()..]).ok_or_else(|| {
@@ -666,11 +810,35 @@ impl LedgerStorage {
         Ok(infos)
     }
 
+    /// Run `op`, whose Thrift calls and retries block the calling thread, on the blocking thread
+    /// pool so that it doesn't stall the async runtime
+    async fn run_blocking<T, F>(&self, op: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(LedgerStorage) -> Result<T> + Send + 'static,
+    {
+        let ledger_storage = self.clone();
+        tokio::task::spawn_blocking(move || op(ledger_storage))
+            .await
+            .map_err(Error::TokioJoinError)?
+    }
+
     // Upload a new confirmed block and associated meta data.
     pub async fn upload_confirmed_block(
         &self,
         slot: Slot,
         confirmed_block: VersionedConfirmedBlock,
+    ) -> Result<()> {
+        self.run_blocking(move |ledger_storage| {
+            ledger_storage.put_confirmed_block(slot, confirmed_block)
+        })
+        .await
+    }
+
+    fn put_confirmed_block(
+        &self,
+        slot: Slot,
+        confirmed_block: VersionedConfirmedBlock,
     ) -> Result<()> {
         let mut bytes_written = 0;
 
@@ -879,6 +1047,115 @@ impl LedgerStorage {
     }
 }
 
+#[async_trait]
+impl LongTermStorage for LedgerStorage {
+    fn name(&self) -> &'static str {
+        "HBase"
+    }
+
+    async fn get_first_available_block(&self) -> StorageResult<Option<Slot>> {
+        Ok(self
+            .run_blocking(|ledger_storage| ledger_storage.get_first_available_block())
+            .await?)
+    }
+
+    async fn get_confirmed_blocks(
+        &self,
+        start_slot: Slot,
+        limit: usize,
+    ) -> StorageResult<Vec<Slot>> {
+        Ok(self
+            .run_blocking(move |ledger_storage| {
+                ledger_storage.get_confirmed_blocks(start_slot, limit)
+            })
+            .await?)
+    }
+
+    async fn get_confirmed_block(&self, slot: Slot) -> StorageResult<ConfirmedBlock> {
+        Ok(self
+            .run_blocking(move |ledger_storage| ledger_storage.get_confirmed_block(slot))
+            .await?)
+    }
+
+    async fn confirmed_block_exists(&self, slot: Slot) -> StorageResult<bool> {
+        Ok(self
+            .run_blocking(move |ledger_storage| ledger_storage.confirmed_block_exists(slot))
+            .await?)
+    }
+
+    async fn get_signature_status(
+        &self,
+        signature: &Signature,
+    ) -> StorageResult<TransactionStatus> {
+        let signature = *signature;
+        Ok(self
+            .run_blocking(move |ledger_storage| ledger_storage.get_signature_status(&signature))
+            .await?)
+    }
+
+    async fn get_confirmed_transactions(
+        &self,
+        signatures: &[Signature],
+    ) -> StorageResult<Vec<ConfirmedTransactionWithStatusMeta>> {
+        let signatures = signatures.to_vec();
+        Ok(self
+            .run_blocking(move |ledger_storage| {
+                ledger_storage.get_confirmed_transactions(&signatures)
+            })
+            .await?)
+    }
+
+    async fn get_confirmed_transaction(
+        &self,
+        signature: &Signature,
+    ) -> StorageResult<Option<ConfirmedTransactionWithStatusMeta>> {
+        let signature = *signature;
+        Ok(self
+            .run_blocking(move |ledger_storage| {
+                ledger_storage.get_confirmed_transaction(&signature)
+            })
+            .await?)
+    }
+
+    async fn get_confirmed_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before_signature: Option<&Signature>,
+        until_signature: Option<&Signature>,
+        limit: usize,
+    ) -> StorageResult<Vec<(ConfirmedTransactionStatusWithSignature, u32)>> {
+        let address = *address;
+        let before_signature = before_signature.copied();
+        let until_signature = until_signature.copied();
+        Ok(self
+            .run_blocking(move |ledger_storage| {
+                ledger_storage.get_confirmed_signatures_for_address(
+                    &address,
+                    before_signature.as_ref(),
+                    until_signature.as_ref(),
+                    limit,
+                )
+            })
+            .await?)
+    }
+
+    async fn upload_confirmed_block(
+        &self,
+        slot: Slot,
+        confirmed_block: VersionedConfirmedBlock,
+    ) -> StorageResult<()> {
+        Ok(LedgerStorage::upload_confirmed_block(self, slot, confirmed_block).await?)
+    }
+
+    async fn delete_confirmed_block(&self, slot: Slot, dry_run: bool) -> StorageResult<()> {
+        Ok(self
+            .run_blocking(move |ledger_storage| {
+                ledger_storage.delete_confirmed_block(slot, dry_run)
+            })
+            .await?)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
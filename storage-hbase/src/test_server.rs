@@ -0,0 +1,664 @@
+//! An in-process stand-in for an HBase Thrift2 gateway, for tests.
+//!
+//! `TestHBaseServer` serves an in-memory implementation of the Thrift2 `THBaseService` over a
+//! localhost TCP socket, so `LedgerStorage` can be exercised end to end without an HBase cluster.
+//! Only the calls `LedgerStorage` relies on are implemented: get, getMultiple, exists, put,
+//! putMultiple, deleteSingle, deleteMultiple, scanners, tableExists, createTable, listNamespaces
+//! and createNamespace. Every other call fails with an `UnknownMethod` application error.
+//!
+//! Row ranges follow HBase semantics: scans include `start_row` and exclude `stop_row`, and a
+//! reversed scan walks down from `start_row`. Besides being created explicitly, tables are
+//! created implicitly on first write.
+
+use {
+    crate::hbase_thrift2::*,
+    log::*,
+    std::{
+        collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+        net::{SocketAddr, TcpListener, TcpStream},
+        ops::Bound,
+        sync::{
+            atomic::{AtomicBool, AtomicI32, Ordering},
+            Arc, Mutex,
+        },
+        thread::{self, Builder, JoinHandle},
+    },
+    thrift::{
+        protocol::{TBinaryInputProtocol, TBinaryOutputProtocol},
+        server::TProcessor,
+        transport::{TBufferedReadTransport, TBufferedWriteTransport, TIoChannel, TTcpChannel},
+        ApplicationError, ApplicationErrorKind,
+    },
+};
+
+// (family, qualifier) -> value
+type Cells = BTreeMap<(Vec<u8>, Vec<u8>), Vec<u8>>;
+type Rows = BTreeMap<Vec<u8>, Cells>;
+
+fn unsupported<T>(method: &str) -> thrift::Result<T> {
+    Err(thrift::Error::Application(ApplicationError::new(
+        ApplicationErrorKind::UnknownMethod,
+        format!("{} is not supported by the test server", method),
+    )))
+}
+
+fn io_error<T>(message: String) -> thrift::Result<T> {
+    Err(thrift::Error::User(Box::new(TIOError {
+        message: Some(message),
+        can_retry: Some(false),
+    })))
+}
+
+// Whether a cell is selected by `columns`; no columns selects every cell
+fn column_selected(columns: Option<&[TColumn]>, family: &[u8], qualifier: &[u8]) -> bool {
+    columns.map_or(true, |columns| {
+        columns.iter().any(|column| {
+            column.family == family
+                && column
+                    .qualifier
+                    .as_ref()
+                    .map_or(true, |column_qualifier| column_qualifier == qualifier)
+        })
+    })
+}
+
+// Build the result for `row`, or `None` if none of its cells are selected by `columns`
+fn row_result(row: &[u8], cells: &Cells, columns: Option<&[TColumn]>) -> Option<TResult> {
+    let column_values = cells
+        .iter()
+        .filter(|((family, qualifier), _)| column_selected(columns, family, qualifier))
+        .map(|((family, qualifier), value)| TColumnValue {
+            family: family.clone(),
+            qualifier: qualifier.clone(),
+            value: value.clone(),
+            timestamp: None,
+            tags: None,
+            type_: None,
+        })
+        .collect::<Vec<_>>();
+    if column_values.is_empty() {
+        return None;
+    }
+    Some(TResult {
+        row: Some(row.to_vec()),
+        column_values,
+        stale: None,
+        partial: None,
+    })
+}
+
+// Namespaces that exist in every HBase cluster
+const BUILTIN_NAMESPACES: [&str; 2] = ["default", "hbase"];
+
+// The `namespace:qualifier` form of a table name used by the data calls
+fn qualified_table_name(table_name: &TTableName) -> Vec<u8> {
+    let mut name = table_name.ns.clone().unwrap_or_else(|| b"default".to_vec());
+    name.push(b':');
+    name.extend_from_slice(&table_name.qualifier);
+    name
+}
+
+// HBase returns a result without a row for a get that matched nothing
+fn empty_result() -> TResult {
+    TResult {
+        row: None,
+        column_values: vec![],
+        stale: None,
+        partial: None,
+    }
+}
+
+#[derive(Default)]
+struct InMemoryHBase {
+    tables: Mutex<HashMap<Vec<u8>, Rows>>,
+    namespaces: Mutex<BTreeSet<String>>,
+    scanners: Mutex<HashMap<i32, VecDeque<TResult>>>,
+    next_scanner_id: AtomicI32,
+}
+
+impl InMemoryHBase {
+    fn get_row(&self, table: &[u8], tget: &TGet) -> TResult {
+        let tables = self.tables.lock().unwrap();
+        tables
+            .get(table)
+            .and_then(|rows| rows.get(&tget.row))
+            .and_then(|cells| row_result(&tget.row, cells, tget.columns.as_deref()))
+            .unwrap_or_else(empty_result)
+    }
+
+    fn put_row(rows: &mut Rows, tput: TPut) {
+        let cells = rows.entry(tput.row).or_default();
+        for column_value in tput.column_values {
+            cells.insert(
+                (column_value.family, column_value.qualifier),
+                column_value.value,
+            );
+        }
+    }
+
+    fn delete_row(rows: &mut Rows, tdelete: TDelete) {
+        match tdelete.columns {
+            None => {
+                rows.remove(&tdelete.row);
+            }
+            Some(columns) => {
+                if let Some(cells) = rows.get_mut(&tdelete.row) {
+                    cells.retain(|(family, qualifier), _| {
+                        !column_selected(Some(columns.as_slice()), family, qualifier)
+                    });
+                    if cells.is_empty() {
+                        rows.remove(&tdelete.row);
+                    }
+                }
+            }
+        }
+    }
+
+    fn scan(&self, table: &[u8], tscan: &TScan) -> Vec<TResult> {
+        let tables = self.tables.lock().unwrap();
+        let rows = match tables.get(table) {
+            Some(rows) => rows,
+            None => return vec![],
+        };
+        let reversed = tscan.reversed.unwrap_or(false);
+
+        // A reversed scan runs from `start_row` down to `stop_row`
+        let (low, high) = if reversed {
+            (&tscan.stop_row, &tscan.start_row)
+        } else {
+            (&tscan.start_row, &tscan.stop_row)
+        };
+        if let (Some(low), Some(high)) = (low, high) {
+            if low >= high {
+                return vec![];
+            }
+        }
+        let (low, high) = if reversed {
+            (
+                low.clone().map_or(Bound::Unbounded, Bound::Excluded),
+                high.clone().map_or(Bound::Unbounded, Bound::Included),
+            )
+        } else {
+            (
+                low.clone().map_or(Bound::Unbounded, Bound::Included),
+                high.clone().map_or(Bound::Unbounded, Bound::Excluded),
+            )
+        };
+
+        let range = rows.range((low, high));
+        let in_range: Box<dyn Iterator<Item = (&Vec<u8>, &Cells)>> = if reversed {
+            Box::new(range.rev())
+        } else {
+            Box::new(range)
+        };
+        let limit = match tscan.limit {
+            Some(limit) if limit > 0 => limit as usize,
+            _ => usize::MAX,
+        };
+        in_range
+            .filter_map(|(row, cells)| row_result(row, cells, tscan.columns.as_deref()))
+            .take(limit)
+            .collect()
+    }
+}
+
+impl THBaseServiceSyncHandler for InMemoryHBase {
+    fn handle_exists(&self, table: Vec<u8>, tget: TGet) -> thrift::Result<bool> {
+        Ok(self.get_row(&table, &tget).row.is_some())
+    }
+
+    fn handle_exists_all(&self, table: Vec<u8>, tgets: Vec<TGet>) -> thrift::Result<Vec<bool>> {
+        Ok(tgets
+            .iter()
+            .map(|tget| self.get_row(&table, tget).row.is_some())
+            .collect())
+    }
+
+    fn handle_get(&self, table: Vec<u8>, tget: TGet) -> thrift::Result<TResult> {
+        Ok(self.get_row(&table, &tget))
+    }
+
+    fn handle_get_multiple(
+        &self,
+        table: Vec<u8>,
+        tgets: Vec<TGet>,
+    ) -> thrift::Result<Vec<TResult>> {
+        Ok(tgets
+            .iter()
+            .map(|tget| self.get_row(&table, tget))
+            .collect())
+    }
+
+    fn handle_put(&self, table: Vec<u8>, tput: TPut) -> thrift::Result<()> {
+        self.handle_put_multiple(table, vec![tput])
+    }
+
+    fn handle_check_and_put(
+        &self,
+        _table: Vec<u8>,
+        _row: Vec<u8>,
+        _family: Vec<u8>,
+        _qualifier: Vec<u8>,
+        _value: Vec<u8>,
+        _tput: TPut,
+    ) -> thrift::Result<bool> {
+        unsupported("checkAndPut")
+    }
+
+    fn handle_put_multiple(&self, table: Vec<u8>, tputs: Vec<TPut>) -> thrift::Result<()> {
+        let mut tables = self.tables.lock().unwrap();
+        let rows = tables.entry(table).or_default();
+        for tput in tputs {
+            Self::put_row(rows, tput);
+        }
+        Ok(())
+    }
+
+    fn handle_delete_single(&self, table: Vec<u8>, tdelete: TDelete) -> thrift::Result<()> {
+        self.handle_delete_multiple(table, vec![tdelete])
+            .map(|_| ())
+    }
+
+    fn handle_delete_multiple(
+        &self,
+        table: Vec<u8>,
+        tdeletes: Vec<TDelete>,
+    ) -> thrift::Result<Vec<TDelete>> {
+        let mut tables = self.tables.lock().unwrap();
+        if let Some(rows) = tables.get_mut(&table) {
+            for tdelete in tdeletes {
+                Self::delete_row(rows, tdelete);
+            }
+        }
+        // Every delete is applied, so there are none to hand back as failed
+        Ok(vec![])
+    }
+
+    fn handle_check_and_delete(
+        &self,
+        _table: Vec<u8>,
+        _row: Vec<u8>,
+        _family: Vec<u8>,
+        _qualifier: Vec<u8>,
+        _value: Vec<u8>,
+        _tdelete: TDelete,
+    ) -> thrift::Result<bool> {
+        unsupported("checkAndDelete")
+    }
+
+    fn handle_increment(
+        &self,
+        _table: Vec<u8>,
+        _tincrement: TIncrement,
+    ) -> thrift::Result<TResult> {
+        unsupported("increment")
+    }
+
+    fn handle_append(&self, _table: Vec<u8>, _tappend: TAppend) -> thrift::Result<TResult> {
+        unsupported("append")
+    }
+
+    fn handle_open_scanner(&self, table: Vec<u8>, tscan: TScan) -> thrift::Result<i32> {
+        let results = self.scan(&table, &tscan);
+        let scanner_id = self.next_scanner_id.fetch_add(1, Ordering::Relaxed);
+        self.scanners
+            .lock()
+            .unwrap()
+            .insert(scanner_id, results.into());
+        Ok(scanner_id)
+    }
+
+    fn handle_get_scanner_rows(
+        &self,
+        scanner_id: i32,
+        num_rows: i32,
+    ) -> thrift::Result<Vec<TResult>> {
+        let mut scanners = self.scanners.lock().unwrap();
+        match scanners.get_mut(&scanner_id) {
+            Some(results) => {
+                let num_rows = (num_rows.max(0) as usize).min(results.len());
+                Ok(results.drain(..num_rows).collect())
+            }
+            None => io_error(format!("Invalid scanner Id: {}", scanner_id)),
+        }
+    }
+
+    fn handle_close_scanner(&self, scanner_id: i32) -> thrift::Result<()> {
+        match self.scanners.lock().unwrap().remove(&scanner_id) {
+            Some(_) => Ok(()),
+            None => io_error(format!("Invalid scanner Id: {}", scanner_id)),
+        }
+    }
+
+    fn handle_mutate_row(
+        &self,
+        _table: Vec<u8>,
+        _trow_mutations: TRowMutations,
+    ) -> thrift::Result<()> {
+        unsupported("mutateRow")
+    }
+
+    fn handle_get_scanner_results(
+        &self,
+        table: Vec<u8>,
+        tscan: TScan,
+        num_rows: i32,
+    ) -> thrift::Result<Vec<TResult>> {
+        let mut results = self.scan(&table, &tscan);
+        results.truncate(num_rows.max(0) as usize);
+        Ok(results)
+    }
+
+    fn handle_get_region_location(
+        &self,
+        _table: Vec<u8>,
+        _row: Vec<u8>,
+        _reload: bool,
+    ) -> thrift::Result<THRegionLocation> {
+        unsupported("getRegionLocation")
+    }
+
+    fn handle_get_all_region_locations(
+        &self,
+        _table: Vec<u8>,
+    ) -> thrift::Result<Vec<THRegionLocation>> {
+        unsupported("getAllRegionLocations")
+    }
+
+    fn handle_check_and_mutate(
+        &self,
+        _table: Vec<u8>,
+        _row: Vec<u8>,
+        _family: Vec<u8>,
+        _qualifier: Vec<u8>,
+        _compare_operator: TCompareOperator,
+        _value: Vec<u8>,
+        _row_mutations: TRowMutations,
+    ) -> thrift::Result<bool> {
+        unsupported("checkAndMutate")
+    }
+
+    fn handle_get_table_descriptor(&self, _table: TTableName) -> thrift::Result<TTableDescriptor> {
+        unsupported("getTableDescriptor")
+    }
+
+    fn handle_get_table_descriptors(
+        &self,
+        _tables: Vec<TTableName>,
+    ) -> thrift::Result<Vec<TTableDescriptor>> {
+        unsupported("getTableDescriptors")
+    }
+
+    fn handle_table_exists(&self, table_name: TTableName) -> thrift::Result<bool> {
+        let tables = self.tables.lock().unwrap();
+        Ok(tables.contains_key(&qualified_table_name(&table_name)))
+    }
+
+    fn handle_get_table_descriptors_by_pattern(
+        &self,
+        _regex: String,
+        _include_sys_tables: bool,
+    ) -> thrift::Result<Vec<TTableDescriptor>> {
+        unsupported("getTableDescriptorsByPattern")
+    }
+
+    fn handle_get_table_descriptors_by_namespace(
+        &self,
+        _name: String,
+    ) -> thrift::Result<Vec<TTableDescriptor>> {
+        unsupported("getTableDescriptorsByNamespace")
+    }
+
+    fn handle_get_table_names_by_pattern(
+        &self,
+        _regex: String,
+        _include_sys_tables: bool,
+    ) -> thrift::Result<Vec<TTableName>> {
+        unsupported("getTableNamesByPattern")
+    }
+
+    fn handle_get_table_names_by_namespace(
+        &self,
+        _name: String,
+    ) -> thrift::Result<Vec<TTableName>> {
+        unsupported("getTableNamesByNamespace")
+    }
+
+    fn handle_create_table(
+        &self,
+        desc: TTableDescriptor,
+        _split_keys: Vec<Vec<u8>>,
+    ) -> thrift::Result<()> {
+        let namespace =
+            String::from_utf8_lossy(desc.table_name.ns.as_deref().unwrap_or(b"default"))
+                .into_owned();
+        if !self.handle_list_namespaces()?.contains(&namespace) {
+            return io_error(format!("Namespace not found: {}", namespace));
+        }
+        let mut tables = self.tables.lock().unwrap();
+        let table = qualified_table_name(&desc.table_name);
+        if tables.contains_key(&table) {
+            return io_error(format!(
+                "Table already exists: {}",
+                String::from_utf8_lossy(&table)
+            ));
+        }
+        tables.insert(table, Rows::default());
+        Ok(())
+    }
+
+    fn handle_delete_table(&self, _table_name: TTableName) -> thrift::Result<()> {
+        unsupported("deleteTable")
+    }
+
+    fn handle_truncate_table(
+        &self,
+        _table_name: TTableName,
+        _preserve_splits: bool,
+    ) -> thrift::Result<()> {
+        unsupported("truncateTable")
+    }
+
+    fn handle_enable_table(&self, _table_name: TTableName) -> thrift::Result<()> {
+        unsupported("enableTable")
+    }
+
+    fn handle_disable_table(&self, _table_name: TTableName) -> thrift::Result<()> {
+        unsupported("disableTable")
+    }
+
+    fn handle_is_table_enabled(&self, _table_name: TTableName) -> thrift::Result<bool> {
+        unsupported("isTableEnabled")
+    }
+
+    fn handle_is_table_disabled(&self, _table_name: TTableName) -> thrift::Result<bool> {
+        unsupported("isTableDisabled")
+    }
+
+    fn handle_is_table_available(&self, _table_name: TTableName) -> thrift::Result<bool> {
+        unsupported("isTableAvailable")
+    }
+
+    fn handle_is_table_available_with_split(
+        &self,
+        _table_name: TTableName,
+        _split_keys: Vec<Vec<u8>>,
+    ) -> thrift::Result<bool> {
+        unsupported("isTableAvailableWithSplit")
+    }
+
+    fn handle_add_column_family(
+        &self,
+        _table_name: TTableName,
+        _column: TColumnFamilyDescriptor,
+    ) -> thrift::Result<()> {
+        unsupported("addColumnFamily")
+    }
+
+    fn handle_delete_column_family(
+        &self,
+        _table_name: TTableName,
+        _column: Vec<u8>,
+    ) -> thrift::Result<()> {
+        unsupported("deleteColumnFamily")
+    }
+
+    fn handle_modify_column_family(
+        &self,
+        _table_name: TTableName,
+        _column: TColumnFamilyDescriptor,
+    ) -> thrift::Result<()> {
+        unsupported("modifyColumnFamily")
+    }
+
+    fn handle_modify_table(&self, _desc: TTableDescriptor) -> thrift::Result<()> {
+        unsupported("modifyTable")
+    }
+
+    fn handle_create_namespace(&self, namespace_desc: TNamespaceDescriptor) -> thrift::Result<()> {
+        if BUILTIN_NAMESPACES.contains(&namespace_desc.name.as_str())
+            || !self
+                .namespaces
+                .lock()
+                .unwrap()
+                .insert(namespace_desc.name.clone())
+        {
+            return io_error(format!("Namespace already exists: {}", namespace_desc.name));
+        }
+        Ok(())
+    }
+
+    fn handle_modify_namespace(&self, _namespace_desc: TNamespaceDescriptor) -> thrift::Result<()> {
+        unsupported("modifyNamespace")
+    }
+
+    fn handle_delete_namespace(&self, _name: String) -> thrift::Result<()> {
+        unsupported("deleteNamespace")
+    }
+
+    fn handle_get_namespace_descriptor(
+        &self,
+        _name: String,
+    ) -> thrift::Result<TNamespaceDescriptor> {
+        unsupported("getNamespaceDescriptor")
+    }
+
+    fn handle_list_namespace_descriptors(&self) -> thrift::Result<Vec<TNamespaceDescriptor>> {
+        unsupported("listNamespaceDescriptors")
+    }
+
+    fn handle_list_namespaces(&self) -> thrift::Result<Vec<String>> {
+        let namespaces = self.namespaces.lock().unwrap();
+        Ok(BUILTIN_NAMESPACES
+            .iter()
+            .map(|namespace| namespace.to_string())
+            .chain(namespaces.iter().cloned())
+            .collect())
+    }
+
+    fn handle_get_thrift_server_type(&self) -> thrift::Result<TThriftServerType> {
+        Ok(TThriftServerType::TWO)
+    }
+
+    fn handle_get_cluster_id(&self) -> thrift::Result<String> {
+        unsupported("getClusterId")
+    }
+
+    fn handle_get_slow_log_responses(
+        &self,
+        _server_names: BTreeSet<TServerName>,
+        _log_query_filter: TLogQueryFilter,
+    ) -> thrift::Result<Vec<TOnlineLogRecord>> {
+        unsupported("getSlowLogResponses")
+    }
+
+    fn handle_clear_slow_log_responses(
+        &self,
+        _server_names: BTreeSet<TServerName>,
+    ) -> thrift::Result<Vec<bool>> {
+        unsupported("clearSlowLogResponses")
+    }
+
+    fn handle_grant(&self, _info: TAccessControlEntity) -> thrift::Result<bool> {
+        unsupported("grant")
+    }
+
+    fn handle_revoke(&self, _info: TAccessControlEntity) -> thrift::Result<bool> {
+        unsupported("revoke")
+    }
+}
+
+type Processor = THBaseServiceSyncProcessor<InMemoryHBase>;
+
+// Answer requests on one connection until the client hangs up
+fn serve_connection(processor: Arc<Processor>, stream: TcpStream) {
+    let (i_chan, o_chan) = match TTcpChannel::with_stream(stream).split() {
+        Ok(channels) => channels,
+        Err(err) => {
+            warn!("test server: failed to split channel: {}", err);
+            return;
+        }
+    };
+    let mut i_prot = TBinaryInputProtocol::new(TBufferedReadTransport::new(i_chan), true);
+    let mut o_prot = TBinaryOutputProtocol::new(TBufferedWriteTransport::new(o_chan), true);
+    while processor.process(&mut i_prot, &mut o_prot).is_ok() {}
+}
+
+/// An in-memory HBase Thrift2 gateway listening on a localhost port.
+///
+/// The server stops accepting connections when dropped; all data is discarded with it.
+pub struct TestHBaseServer {
+    addr: SocketAddr,
+    exit: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TestHBaseServer {
+    /// Start a server with empty tables on an ephemeral localhost port
+    pub fn start() -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let exit = Arc::new(AtomicBool::new(false));
+        let processor = Arc::new(Processor::new(InMemoryHBase::default()));
+
+        let thread = Builder::new().name("putHBaseTestSrv".to_string()).spawn({
+            let exit = exit.clone();
+            move || {
+                for stream in listener.incoming() {
+                    if exit.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    match stream {
+                        Ok(stream) => {
+                            let processor = processor.clone();
+                            thread::spawn(move || serve_connection(processor, stream));
+                        }
+                        Err(err) => warn!("test server: failed to accept connection: {}", err),
+                    }
+                }
+            }
+        })?;
+
+        Ok(Self {
+            addr,
+            exit,
+            thread: Some(thread),
+        })
+    }
+
+    /// The address to pass to `LedgerStorage::new` as `thrift2_url`
+    pub fn thrift2_url(&self) -> String {
+        self.addr.to_string()
+    }
+}
+
+impl Drop for TestHBaseServer {
+    fn drop(&mut self) {
+        self.exit.store(true, Ordering::Relaxed);
+        // Wake up the accept loop so that it observes `exit`
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
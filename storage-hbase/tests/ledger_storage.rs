@@ -0,0 +1,293 @@
+use {
+    put_sdk::{
+        hash::Hash,
+        pubkey::Pubkey,
+        signature::{Keypair, Signature, Signer},
+        system_transaction,
+        transaction::VersionedTransaction,
+    },
+    put_storage_hbase::{
+        test_server::TestHBaseServer, Error, LedgerStorage, DEFAULT_HBASE_NAMESPACE,
+    },
+    put_transaction_status::{
+        ConfirmedBlock, TransactionStatusMeta, VersionedConfirmedBlock,
+        VersionedTransactionWithStatusMeta,
+    },
+    std::time::Duration,
+};
+
+fn connect(server: &TestHBaseServer, namespace: &str) -> LedgerStorage {
+    LedgerStorage::new(
+        server.thrift2_url(),
+        namespace,
+        Some(Duration::from_secs(5)),
+    )
+    .unwrap()
+}
+
+fn start_storage() -> (TestHBaseServer, LedgerStorage) {
+    let server = TestHBaseServer::start().unwrap();
+    let storage = connect(&server, DEFAULT_HBASE_NAMESPACE);
+    (server, storage)
+}
+
+fn transfer_with_meta(from: &Keypair, to: &Pubkey) -> VersionedTransactionWithStatusMeta {
+    let transaction = system_transaction::transfer(from, to, 1, Hash::new_unique());
+    VersionedTransactionWithStatusMeta {
+        transaction: VersionedTransaction::from(transaction),
+        meta: TransactionStatusMeta {
+            fee: 5000,
+            pre_balances: vec![10_000, 0, 1],
+            post_balances: vec![4_999, 1, 1],
+            pre_token_balances: Some(vec![]),
+            post_token_balances: Some(vec![]),
+            rewards: Some(vec![]),
+            pre_nft_holdings: Some(vec![]),
+            post_nft_holdings: Some(vec![]),
+            ..TransactionStatusMeta::default()
+        },
+    }
+}
+
+fn new_block(
+    parent_slot: u64,
+    transactions: Vec<VersionedTransactionWithStatusMeta>,
+) -> VersionedConfirmedBlock {
+    VersionedConfirmedBlock {
+        previous_blockhash: Hash::new_unique().to_string(),
+        blockhash: Hash::new_unique().to_string(),
+        parent_slot,
+        transactions,
+        rewards: vec![],
+        block_time: Some(1_600_000_000),
+        block_height: Some(parent_slot + 1),
+    }
+}
+
+fn signature_of(transaction_with_meta: &VersionedTransactionWithStatusMeta) -> Signature {
+    transaction_with_meta.transaction.signatures[0]
+}
+
+fn signatures_for_address(
+    storage: &LedgerStorage,
+    address: &Pubkey,
+    before: Option<&Signature>,
+) -> Vec<(Signature, u64, u32)> {
+    storage
+        .get_confirmed_signatures_for_address(address, before, None, 10)
+        .unwrap()
+        .into_iter()
+        .map(|(status, index)| (status.signature, status.slot, index))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_upload_and_get_confirmed_block() {
+    let (_server, storage) = start_storage();
+    let payer = Keypair::new();
+
+    assert_eq!(storage.get_first_available_block().unwrap(), None);
+
+    let block = new_block(
+        9,
+        vec![
+            transfer_with_meta(&payer, &Pubkey::new_unique()),
+            transfer_with_meta(&payer, &Pubkey::new_unique()),
+        ],
+    );
+    storage
+        .upload_confirmed_block(10, block.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        storage.get_confirmed_block(10).unwrap(),
+        ConfirmedBlock::from(block.clone())
+    );
+    assert!(matches!(
+        storage.get_confirmed_block(11),
+        Err(Error::BlockNotFound(11))
+    ));
+    assert_eq!(storage.get_first_available_block().unwrap(), Some(10));
+    assert_eq!(storage.get_last_available_block().unwrap(), Some(10));
+    assert_eq!(storage.get_confirmed_blocks(0, 10).unwrap(), vec![10]);
+
+    let status = storage
+        .get_signature_status(&signature_of(&block.transactions[1]))
+        .unwrap();
+    assert_eq!(status.slot, 10);
+}
+
+#[tokio::test]
+async fn test_get_confirmed_signatures_for_address() {
+    let (_server, storage) = start_storage();
+    let payer = Keypair::new();
+    let recipient = Pubkey::new_unique();
+
+    let block10 = new_block(
+        9,
+        vec![
+            transfer_with_meta(&payer, &recipient),
+            transfer_with_meta(&payer, &Pubkey::new_unique()),
+        ],
+    );
+    let block11 = new_block(10, vec![transfer_with_meta(&payer, &recipient)]);
+    storage
+        .upload_confirmed_block(10, block10.clone())
+        .await
+        .unwrap();
+    storage
+        .upload_confirmed_block(11, block11.clone())
+        .await
+        .unwrap();
+
+    let tx0 = signature_of(&block10.transactions[0]);
+    let tx1 = signature_of(&block10.transactions[1]);
+    let tx2 = signature_of(&block11.transactions[0]);
+
+    // Newest first, and in descending transaction order within a slot
+    assert_eq!(
+        signatures_for_address(&storage, &payer.pubkey(), None),
+        vec![(tx2, 11, 0), (tx1, 10, 1), (tx0, 10, 0)]
+    );
+    assert_eq!(
+        signatures_for_address(&storage, &recipient, None),
+        vec![(tx2, 11, 0), (tx0, 10, 0)]
+    );
+    assert_eq!(
+        signatures_for_address(&storage, &payer.pubkey(), Some(&tx2)),
+        vec![(tx1, 10, 1), (tx0, 10, 0)]
+    );
+    assert_eq!(
+        signatures_for_address(&storage, &payer.pubkey(), Some(&tx1)),
+        vec![(tx0, 10, 0)]
+    );
+    assert!(signatures_for_address(&storage, &Pubkey::new_unique(), None).is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_confirmed_block() {
+    let (_server, storage) = start_storage();
+    let payer = Keypair::new();
+
+    let block10 = new_block(9, vec![transfer_with_meta(&payer, &Pubkey::new_unique())]);
+    let block11 = new_block(10, vec![transfer_with_meta(&payer, &Pubkey::new_unique())]);
+    storage
+        .upload_confirmed_block(10, block10.clone())
+        .await
+        .unwrap();
+    storage
+        .upload_confirmed_block(11, block11.clone())
+        .await
+        .unwrap();
+
+    let tx0 = signature_of(&block10.transactions[0]);
+    let tx1 = signature_of(&block11.transactions[0]);
+
+    // A dry run leaves everything in place
+    storage.delete_confirmed_block(10, true).unwrap();
+    assert!(storage.get_confirmed_block(10).is_ok());
+    assert_eq!(
+        signatures_for_address(&storage, &payer.pubkey(), None),
+        vec![(tx1, 11, 0), (tx0, 10, 0)]
+    );
+
+    storage.delete_confirmed_block(10, false).unwrap();
+    assert!(matches!(
+        storage.get_confirmed_block(10),
+        Err(Error::BlockNotFound(10))
+    ));
+    assert!(matches!(
+        storage.get_signature_status(&tx0),
+        Err(Error::SignatureNotFound)
+    ));
+    assert_eq!(
+        signatures_for_address(&storage, &payer.pubkey(), None),
+        vec![(tx1, 11, 0)]
+    );
+    assert_eq!(
+        storage.get_confirmed_block(11).unwrap(),
+        ConfirmedBlock::from(block11)
+    );
+    assert_eq!(storage.get_confirmed_blocks(0, 10).unwrap(), vec![11]);
+}
+
+#[tokio::test]
+async fn test_confirmed_block_exists_and_get_confirmed_transactions() {
+    let (_server, storage) = start_storage();
+    let payer = Keypair::new();
+
+    let block10 = new_block(
+        9,
+        vec![
+            transfer_with_meta(&payer, &Pubkey::new_unique()),
+            transfer_with_meta(&payer, &Pubkey::new_unique()),
+        ],
+    );
+    let block11 = new_block(10, vec![transfer_with_meta(&payer, &Pubkey::new_unique())]);
+    storage
+        .upload_confirmed_block(10, block10.clone())
+        .await
+        .unwrap();
+    storage
+        .upload_confirmed_block(11, block11.clone())
+        .await
+        .unwrap();
+
+    assert!(storage.confirmed_block_exists(10).unwrap());
+    assert!(storage.confirmed_block_exists(11).unwrap());
+    assert!(!storage.confirmed_block_exists(12).unwrap());
+
+    let signatures = [
+        signature_of(&block11.transactions[0]),
+        Signature::new_unique(),
+        signature_of(&block10.transactions[1]),
+    ];
+    let transactions = storage.get_confirmed_transactions(&signatures).unwrap();
+    assert_eq!(
+        transactions
+            .iter()
+            .map(|tx| (*tx.tx_with_meta.transaction_signature(), tx.slot))
+            .collect::<Vec<_>>(),
+        vec![(signatures[0], 11), (signatures[2], 10)]
+    );
+    assert_eq!(
+        transactions[1],
+        storage
+            .get_confirmed_transaction(&signatures[2])
+            .unwrap()
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_namespaces_are_isolated() {
+    let server = TestHBaseServer::start().unwrap();
+    let devnet = connect(&server, "devnet");
+    let testnet = connect(&server, "testnet");
+
+    assert_eq!(
+        devnet.create_tables().unwrap(),
+        vec!["blocks", "tx", "tx-by-addr"]
+    );
+    assert!(devnet.create_tables().unwrap().is_empty());
+    assert_eq!(testnet.create_tables().unwrap().len(), 3);
+
+    let payer = Keypair::new();
+    let block = new_block(9, vec![transfer_with_meta(&payer, &Pubkey::new_unique())]);
+    devnet
+        .upload_confirmed_block(10, block.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        devnet.get_confirmed_block(10).unwrap(),
+        ConfirmedBlock::from(block)
+    );
+    assert!(matches!(
+        testnet.get_confirmed_block(10),
+        Err(Error::BlockNotFound(10))
+    ));
+    assert_eq!(testnet.get_first_available_block().unwrap(), None);
+    assert!(signatures_for_address(&testnet, &payer.pubkey(), None).is_empty());
+}
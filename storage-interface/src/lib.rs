@@ -0,0 +1,87 @@
+//! The `put-storage-interface` crate defines the operations shared by the long-term ledger
+//! storage backends (BigTable, HBase), so that consumers such as the RPC service, the upload
+//! services and ledger-tool can be written once against any of them.
+
+use {
+    async_trait::async_trait,
+    put_sdk::{clock::Slot, pubkey::Pubkey, signature::Signature},
+    put_transaction_status::{
+        ConfirmedBlock, ConfirmedTransactionStatusWithSignature,
+        ConfirmedTransactionWithStatusMeta, TransactionStatus, VersionedConfirmedBlock,
+    },
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Block not found: {0}")]
+    BlockNotFound(Slot),
+
+    #[error("Signature not found")]
+    SignatureNotFound,
+
+    #[error("{0}")]
+    Storage(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[async_trait]
+pub trait LongTermStorage: Send + Sync {
+    /// Human readable name of the backend, used in logs and error messages
+    fn name(&self) -> &'static str;
+
+    /// Return the available slot that contains a block
+    async fn get_first_available_block(&self) -> Result<Option<Slot>>;
+
+    /// Fetch the next slots after the provided slot that contains a block
+    ///
+    /// start_slot: slot to start the search from (inclusive)
+    /// limit: stop after this many slots have been found
+    async fn get_confirmed_blocks(&self, start_slot: Slot, limit: usize) -> Result<Vec<Slot>>;
+
+    /// Fetch the confirmed block from the desired slot
+    async fn get_confirmed_block(&self, slot: Slot) -> Result<ConfirmedBlock>;
+
+    /// Does the confirmed block for the desired slot exist
+    async fn confirmed_block_exists(&self, slot: Slot) -> Result<bool>;
+
+    async fn get_signature_status(&self, signature: &Signature) -> Result<TransactionStatus>;
+
+    /// Fetch multiple confirmed transactions with a single query per table. Signatures that are
+    /// not found are omitted from the result.
+    async fn get_confirmed_transactions(
+        &self,
+        signatures: &[Signature],
+    ) -> Result<Vec<ConfirmedTransactionWithStatusMeta>>;
+
+    /// Fetch a confirmed transaction
+    async fn get_confirmed_transaction(
+        &self,
+        signature: &Signature,
+    ) -> Result<Option<ConfirmedTransactionWithStatusMeta>>;
+
+    /// Get confirmed signatures for the provided address, in descending ledger order
+    ///
+    /// address: address to search for
+    /// before_signature: start with the first signature older than this one
+    /// until_signature: end with the last signature more recent than this one
+    /// limit: stop after this many signatures
+    async fn get_confirmed_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before_signature: Option<&Signature>,
+        until_signature: Option<&Signature>,
+        limit: usize,
+    ) -> Result<Vec<(ConfirmedTransactionStatusWithSignature, u32)>>;
+
+    /// Upload a new confirmed block and associated meta data
+    async fn upload_confirmed_block(
+        &self,
+        slot: Slot,
+        confirmed_block: VersionedConfirmedBlock,
+    ) -> Result<()>;
+
+    /// Delete a confirmed block and associated meta data
+    async fn delete_confirmed_block(&self, slot: Slot, dry_run: bool) -> Result<()>;
+}
@@ -16,8 +16,9 @@ use {
     },
     put_transaction_status::{
         ConfirmedBlock, InnerInstruction, InnerInstructions, Reward, RewardType,
-        TransactionByAddrInfo, TransactionStatusMeta, TransactionTokenBalance,
-        TransactionWithStatusMeta, VersionedConfirmedBlock, VersionedTransactionWithStatusMeta,
+        TransactionByAddrInfo, TransactionNftHolding, TransactionStatusMeta,
+        TransactionTokenBalance, TransactionWithStatusMeta, VersionedConfirmedBlock,
+        VersionedTransactionWithStatusMeta,
     },
     std::{
         convert::{TryFrom, TryInto},
@@ -369,6 +370,8 @@ impl From<TransactionStatusMeta> for generated::TransactionStatusMeta {
             loaded_addresses,
             return_data,
             compute_units_consumed,
+            pre_nft_holdings,
+            post_nft_holdings,
         } = value;
         let err = match status {
             Ok(()) => None,
@@ -411,6 +414,16 @@ impl From<TransactionStatusMeta> for generated::TransactionStatusMeta {
             .collect();
         let return_data_none = return_data.is_none();
         let return_data = return_data.map(|return_data| return_data.into());
+        let pre_nft_holdings = pre_nft_holdings
+            .unwrap_or_default()
+            .into_iter()
+            .map(|holding| holding.into())
+            .collect();
+        let post_nft_holdings = post_nft_holdings
+            .unwrap_or_default()
+            .into_iter()
+            .map(|holding| holding.into())
+            .collect();
 
         Self {
             err,
@@ -429,6 +442,8 @@ impl From<TransactionStatusMeta> for generated::TransactionStatusMeta {
             return_data,
             return_data_none,
             compute_units_consumed,
+            pre_nft_holdings,
+            post_nft_holdings,
         }
     }
 }
@@ -461,6 +476,8 @@ impl TryFrom<generated::TransactionStatusMeta> for TransactionStatusMeta {
             return_data,
             return_data_none,
             compute_units_consumed,
+            pre_nft_holdings,
+            post_nft_holdings,
         } = value;
         let status = match &err {
             None => Ok(()),
@@ -493,6 +510,18 @@ impl TryFrom<generated::TransactionStatusMeta> for TransactionStatusMeta {
                 .map(|balance| balance.into())
                 .collect(),
         );
+        let pre_nft_holdings = Some(
+            pre_nft_holdings
+                .into_iter()
+                .map(|holding| holding.into())
+                .collect(),
+        );
+        let post_nft_holdings = Some(
+            post_nft_holdings
+                .into_iter()
+                .map(|holding| holding.into())
+                .collect(),
+        );
         let rewards = Some(rewards.into_iter().map(|reward| reward.into()).collect());
         let loaded_addresses = LoadedAddresses {
             writable: loaded_writable_addresses
@@ -530,6 +559,8 @@ impl TryFrom<generated::TransactionStatusMeta> for TransactionStatusMeta {
             loaded_addresses,
             return_data,
             compute_units_consumed,
+            pre_nft_holdings,
+            post_nft_holdings,
         })
     }
 }
@@ -569,6 +600,28 @@ impl From<TransactionTokenBalance> for generated::TokenBalance {
     }
 }
 
+impl From<TransactionNftHolding> for generated::NftHolding {
+    fn from(value: TransactionNftHolding) -> Self {
+        Self {
+            account_index: value.account_index as u32,
+            mint: value.mint,
+            token_id: value.token_id,
+            owner: value.owner,
+        }
+    }
+}
+
+impl From<generated::NftHolding> for TransactionNftHolding {
+    fn from(value: generated::NftHolding) -> Self {
+        Self {
+            account_index: value.account_index as u8,
+            mint: value.mint,
+            token_id: value.token_id,
+            owner: value.owner,
+        }
+    }
+}
+
 impl From<generated::TokenBalance> for TransactionTokenBalance {
     fn from(value: generated::TokenBalance) -> Self {
         let ui_token_amount = value.ui_token_amount.unwrap_or_default();
@@ -1203,6 +1256,40 @@ mod test {
         assert_eq!(reward, gen_reward.into());
     }
 
+    #[test]
+    fn test_transaction_status_meta_nft_holdings_encode() {
+        let holding = TransactionNftHolding {
+            account_index: 2,
+            mint: Pubkey::new_unique().to_string(),
+            token_id: 42,
+            owner: Pubkey::new_unique().to_string(),
+        };
+        let meta = TransactionStatusMeta {
+            pre_token_balances: Some(vec![]),
+            post_token_balances: Some(vec![]),
+            rewards: Some(vec![]),
+            pre_nft_holdings: Some(vec![holding.clone()]),
+            post_nft_holdings: Some(vec![TransactionNftHolding {
+                owner: Pubkey::new_unique().to_string(),
+                ..holding
+            }]),
+            ..TransactionStatusMeta::default()
+        };
+        let gen_meta: generated::TransactionStatusMeta = meta.clone().into();
+        assert_eq!(meta, gen_meta.try_into().unwrap());
+
+        // Metadata written before nft holdings were recorded decodes to empty holdings
+        let gen_meta: generated::TransactionStatusMeta = TransactionStatusMeta {
+            pre_nft_holdings: None,
+            post_nft_holdings: None,
+            ..meta.clone()
+        }
+        .into();
+        let decoded: TransactionStatusMeta = gen_meta.try_into().unwrap();
+        assert_eq!(decoded.pre_nft_holdings, Some(vec![]));
+        assert_eq!(decoded.post_nft_holdings, Some(vec![]));
+    }
+
     #[test]
     fn test_transaction_by_addr_encode() {
         let info = TransactionByAddrInfo {
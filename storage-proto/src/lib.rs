@@ -9,7 +9,8 @@ use {
         transaction_context::TransactionReturnData,
     },
     put_transaction_status::{
-        InnerInstructions, Reward, RewardType, TransactionStatusMeta, TransactionTokenBalance,
+        InnerInstructions, Reward, RewardType, TransactionNftHolding, TransactionStatusMeta,
+        TransactionTokenBalance,
     },
     std::str::FromStr,
 };
@@ -158,6 +159,48 @@ impl From<TransactionTokenBalance> for StoredTransactionTokenBalance {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct StoredTransactionNftHolding {
+    pub account_index: u8,
+    pub mint: String,
+    pub token_id: u64,
+    pub owner: String,
+}
+
+impl From<StoredTransactionNftHolding> for TransactionNftHolding {
+    fn from(value: StoredTransactionNftHolding) -> Self {
+        let StoredTransactionNftHolding {
+            account_index,
+            mint,
+            token_id,
+            owner,
+        } = value;
+        Self {
+            account_index,
+            mint,
+            token_id,
+            owner,
+        }
+    }
+}
+
+impl From<TransactionNftHolding> for StoredTransactionNftHolding {
+    fn from(value: TransactionNftHolding) -> Self {
+        let TransactionNftHolding {
+            account_index,
+            mint,
+            token_id,
+            owner,
+        } = value;
+        Self {
+            account_index,
+            mint,
+            token_id,
+            owner,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct StoredTransactionStatusMeta {
     pub status: Result<()>,
@@ -178,6 +221,10 @@ pub struct StoredTransactionStatusMeta {
     pub return_data: Option<TransactionReturnData>,
     #[serde(deserialize_with = "default_on_eof")]
     pub compute_units_consumed: Option<u64>,
+    #[serde(deserialize_with = "default_on_eof")]
+    pub pre_nft_holdings: Option<Vec<StoredTransactionNftHolding>>,
+    #[serde(deserialize_with = "default_on_eof")]
+    pub post_nft_holdings: Option<Vec<StoredTransactionNftHolding>>,
 }
 
 impl From<StoredTransactionStatusMeta> for TransactionStatusMeta {
@@ -194,6 +241,8 @@ impl From<StoredTransactionStatusMeta> for TransactionStatusMeta {
             rewards,
             return_data,
             compute_units_consumed,
+            pre_nft_holdings,
+            post_nft_holdings,
         } = value;
         Self {
             status,
@@ -211,6 +260,10 @@ impl From<StoredTransactionStatusMeta> for TransactionStatusMeta {
             loaded_addresses: LoadedAddresses::default(),
             return_data,
             compute_units_consumed,
+            pre_nft_holdings: pre_nft_holdings
+                .map(|holdings| holdings.into_iter().map(|holding| holding.into()).collect()),
+            post_nft_holdings: post_nft_holdings
+                .map(|holdings| holdings.into_iter().map(|holding| holding.into()).collect()),
         }
     }
 }
@@ -231,6 +284,8 @@ impl TryFrom<TransactionStatusMeta> for StoredTransactionStatusMeta {
             loaded_addresses,
             return_data,
             compute_units_consumed,
+            pre_nft_holdings,
+            post_nft_holdings,
         } = value;
 
         if !loaded_addresses.is_empty() {
@@ -256,6 +311,10 @@ impl TryFrom<TransactionStatusMeta> for StoredTransactionStatusMeta {
                 .map(|rewards| rewards.into_iter().map(|reward| reward.into()).collect()),
             return_data,
             compute_units_consumed,
+            pre_nft_holdings: pre_nft_holdings
+                .map(|holdings| holdings.into_iter().map(|holding| holding.into()).collect()),
+            post_nft_holdings: post_nft_holdings
+                .map(|holdings| holdings.into_iter().map(|holding| holding.into()).collect()),
         })
     }
 }
@@ -14,7 +14,7 @@ use {
     indicatif::{ProgressBar, ProgressStyle},
     pickledb::PickleDb,
     serde::{Deserialize, Serialize},
-    put_account_decoder::parse_token::real_number_string,
+    put_account_decoder::parse_token::{is_native_mint, real_number_string},
     put_rpc_client::rpc_client::RpcClient,
     put_rpc_client_api::{
         client_error::{Error as ClientError, Result as ClientResult},
@@ -27,7 +27,7 @@ use {
         hash::Hash,
         instruction::Instruction,
         message::Message,
-        native_token::{lamports_to_put, put_to_lamports},
+        native_token::{lamports_to_put, put_to_lamports, try_put_to_lamports},
         signature::{unique_signers, Signature, Signer},
         stake::{
             instruction::{self as stake_instruction, LockupArgs},
@@ -474,6 +474,15 @@ fn distribute_allocations(
     Ok(())
 }
 
+fn parse_allocation_amount(amount: &str) -> io::Result<u128> {
+    try_put_to_lamports(amount).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid allocation amount {amount}: {err}"),
+        )
+    })
+}
+
 #[allow(clippy::needless_collect)]
 fn read_allocations(
     input_csv: &str,
@@ -496,18 +505,20 @@ fn read_allocations(
             })
             .collect()
     } else if require_lockup_heading {
-        let recipients: Vec<(String, f64, String)> = rdr
+        let recipients: Vec<(String, String, String)> = rdr
             .deserialize()
             .map(|recipient| recipient.unwrap())
             .collect();
         recipients
             .into_iter()
-            .map(|(recipient, amount, lockup_date)| Allocation {
-                recipient,
-                amount: put_to_lamports(amount.to_string().as_ref()),
-                lockup_date,
+            .map(|(recipient, amount, lockup_date)| {
+                Ok(Allocation {
+                    recipient,
+                    amount: parse_allocation_amount(&amount)?,
+                    lockup_date,
+                })
             })
-            .collect()
+            .collect::<io::Result<_>>()?
     } else if raw_amount {
         let recipients: Vec<(String, u128)> = rdr
             .deserialize()
@@ -522,18 +533,20 @@ fn read_allocations(
             })
             .collect()
     } else {
-        let recipients: Vec<(String, f64)> = rdr
+        let recipients: Vec<(String, String)> = rdr
             .deserialize()
             .map(|recipient| recipient.unwrap())
             .collect();
         recipients
             .into_iter()
-            .map(|(recipient, amount)| Allocation {
-                recipient,
-                amount: put_to_lamports(&amount.to_string()),
-                lockup_date: "".to_string(),
+            .map(|(recipient, amount)| {
+                Ok(Allocation {
+                    recipient,
+                    amount: parse_allocation_amount(&amount)?,
+                    lockup_date: "".to_string(),
+                })
             })
-            .collect()
+            .collect::<io::Result<_>>()?
     };
     Ok(allocations)
 }
@@ -564,7 +577,11 @@ pub fn process_allocations(
 
     let starting_total_tokens = allocations.iter().map(|x| x.amount).sum();
     let starting_total_tokens = if let Some(ppl_token_args) = &args.ppl_token_args {
-        Token::ppl_token(starting_total_tokens, ppl_token_args.decimals)
+        Token::ppl_token(
+            starting_total_tokens,
+            ppl_token_args.decimals,
+            &ppl_token_args.mint,
+        )
     } else {
         Token::put(starting_total_tokens)
     };
@@ -592,8 +609,16 @@ pub fn process_allocations(
     let (distributed_tokens, undistributed_tokens) =
         if let Some(ppl_token_args) = &args.ppl_token_args {
             (
-                Token::ppl_token(distributed_tokens, ppl_token_args.decimals),
-                Token::ppl_token(undistributed_tokens, ppl_token_args.decimals),
+                Token::ppl_token(
+                    distributed_tokens,
+                    ppl_token_args.decimals,
+                    &ppl_token_args.mint,
+                ),
+                Token::ppl_token(
+                    undistributed_tokens,
+                    ppl_token_args.decimals,
+                    &ppl_token_args.mint,
+                ),
             )
         } else {
             (
@@ -850,7 +875,11 @@ pub fn process_balances(
     let allocations = merge_allocations(&allocations);
 
     let token = if let Some(ppl_token_args) = &args.ppl_token_args {
-        ppl_token_args.mint.to_string()
+        if is_native_mint(&ppl_token_args.mint) {
+            format!("{} (wrapped ◎)", ppl_token_args.mint)
+        } else {
+            ppl_token_args.mint.to_string()
+        }
     } else {
         "◎".to_string()
     };
@@ -1380,6 +1409,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_allocations_exact_amounts() {
+        let pubkey0 = put_sdk::pubkey::new_rand();
+        let file = NamedTempFile::new().unwrap();
+        let input_csv = file.path().to_str().unwrap().to_string();
+        let mut wtr = csv::WriterBuilder::new().from_writer(file);
+        wtr.serialize(("recipient".to_string(), "amount".to_string()))
+            .unwrap();
+        wtr.serialize((&pubkey0.to_string(), "123456789.123456789"))
+            .unwrap();
+        wtr.flush().unwrap();
+        assert_eq!(
+            read_allocations(&input_csv, None, false, false).unwrap(),
+            vec![Allocation {
+                recipient: pubkey0.to_string(),
+                amount: 123_456_789_123_456_789,
+                lockup_date: "".to_string(),
+            }]
+        );
+
+        let file = NamedTempFile::new().unwrap();
+        let input_csv = file.path().to_str().unwrap().to_string();
+        let mut wtr = csv::WriterBuilder::new().from_writer(file);
+        wtr.serialize(("recipient".to_string(), "amount".to_string()))
+            .unwrap();
+        wtr.serialize((&pubkey0.to_string(), "1e9")).unwrap();
+        wtr.flush().unwrap();
+        assert_eq!(
+            read_allocations(&input_csv, None, false, false)
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_read_allocations_malformed() {
@@ -6,6 +6,7 @@ use {
     console::style,
     put_account_decoder::parse_token::{
         pubkey_from_ppl_token, real_number_string, real_number_string_trimmed, ppl_token_pubkey,
+        token_account_balance,
     },
     put_rpc_client::rpc_client::RpcClient,
     put_sdk::{instruction::Instruction, message::Message, native_token::lamports_to_put},
@@ -133,22 +134,21 @@ pub fn print_token_balances(
     let recipient_account = client
         .get_account(&pubkey_from_ppl_token(&associated_token_address))
         .unwrap_or_default();
-    let (actual, difference) = if let Ok(recipient_token) =
-        PplTokenAccount::unpack(&recipient_account.data)
-    {
-        let actual_ui_amount = real_number_string(recipient_token.amount, ppl_token_args.decimals);
-        let delta_string =
-            real_number_string(recipient_token.amount - expected, ppl_token_args.decimals);
-        (
-            style(format!("{:>24}", actual_ui_amount)),
-            format!("{:>24}", delta_string),
-        )
-    } else {
-        (
-            style("Associated token account not yet created".to_string()).yellow(),
-            "".to_string(),
-        )
-    };
+    let (actual, difference) =
+        if let Ok(recipient_token) = PplTokenAccount::unpack(&recipient_account.data) {
+            let actual = token_account_balance(&recipient_token, Some(recipient_account.lamports));
+            let actual_ui_amount = real_number_string(actual, ppl_token_args.decimals);
+            let delta_string = real_number_string(actual - expected, ppl_token_args.decimals);
+            (
+                style(format!("{:>24}", actual_ui_amount)),
+                format!("{:>24}", delta_string),
+            )
+        } else {
+            (
+                style("Associated token account not yet created".to_string()).yellow(),
+                "".to_string(),
+            )
+        };
     println!(
         "{:<44}  {:>24}  {:>24}  {:>24}",
         allocation.recipient,
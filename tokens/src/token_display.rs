@@ -1,6 +1,6 @@
 use {
-    put_account_decoder::parse_token::real_number_string_trimmed,
-    put_sdk::native_token::lamports_to_put,
+    put_account_decoder::parse_token::{is_native_mint, real_number_string_trimmed},
+    put_sdk::{native_token::lamports_to_put, pubkey::Pubkey},
     std::{
         fmt::{Debug, Display, Formatter, Result},
         ops::Add,
@@ -13,6 +13,7 @@ const PUT_SYMBOL: &str = "◎";
 pub enum TokenType {
     PUT,
     PplToken,
+    WrappedPut,
 }
 
 pub struct Token {
@@ -32,6 +33,10 @@ impl Token {
                 let amount = real_number_string_trimmed(self.amount, self.decimals);
                 write!(f, "{amount} tokens")
             }
+            TokenType::WrappedPut => {
+                let amount = lamports_to_put(self.amount);
+                write!(f, "{PUT_SYMBOL}{amount} wrapped")
+            }
         }
     }
 
@@ -43,11 +48,15 @@ impl Token {
         }
     }
 
-    pub fn ppl_token(amount: u128, decimals: u8) -> Self {
+    pub fn ppl_token(amount: u128, decimals: u8, mint: &Pubkey) -> Self {
         Self {
             amount,
             decimals,
-            token_type: TokenType::PplToken,
+            token_type: if is_native_mint(mint) {
+                TokenType::WrappedPut
+            } else {
+                TokenType::PplToken
+            },
         }
     }
 }
@@ -1,5 +1,6 @@
 use {
     crate::{parse_instruction::parse_memo_data, VersionedTransactionWithStatusMeta},
+    put_runtime::inline_ppl_memo,
     put_sdk::{
         instruction::CompiledInstruction,
         message::{AccountKeys, Message, SanitizedMessage},
@@ -7,21 +8,25 @@ use {
     },
 };
 
-// A helper function to convert ppl_memo::v1::id() as ppl_sdk::pubkey::Pubkey to
-// put_sdk::pubkey::Pubkey
+// A helper function to convert ppl_memo::id(), the memo v1 program id, as
+// ppl_sdk::pubkey::Pubkey to put_sdk::pubkey::Pubkey
 pub fn ppl_memo_id_v1() -> Pubkey {
     *MEMO_PROGRAM_ID_V1
 }
 
-// A helper function to convert ppl_memo::id() as ppl_sdk::pubkey::Pubkey to
-// put_sdk::pubkey::Pubkey
-// pub fn ppl_memo_id_v3() -> Pubkey {
-//     *MEMO_PROGRAM_ID_V3
-// }
+// Returns the memo v3 program id
+pub fn ppl_memo_id_v3() -> Pubkey {
+    *MEMO_PROGRAM_ID_V3
+}
+
+// Check if the provided program id as a known PPL Memo program id
+pub fn is_known_ppl_memo_id(program_id: &Pubkey) -> bool {
+    *program_id == *MEMO_PROGRAM_ID_V1 || *program_id == *MEMO_PROGRAM_ID_V3
+}
 
 lazy_static! {
     static ref MEMO_PROGRAM_ID_V1: Pubkey = Pubkey::new_from_array(ppl_memo::id().to_bytes());
-    // static ref MEMO_PROGRAM_ID_V3: Pubkey = Pubkey::new_from_array(ppl_memo::id().to_bytes());
+    static ref MEMO_PROGRAM_ID_V3: Pubkey = inline_ppl_memo::v3::id();
 }
 
 pub fn extract_and_fmt_memos<T: ExtractMemos>(message: &T) -> Option<String> {
@@ -87,7 +92,7 @@ fn extract_memos_inner(
                 KeyType::MemoProgram => Some(&ix.data),
                 KeyType::OtherProgram => None,
                 KeyType::Unknown(program_id) => {
-                    if **program_id == *MEMO_PROGRAM_ID_V1 /*|| **program_id == *MEMO_PROGRAM_ID_V3*/ {
+                    if is_known_ppl_memo_id(program_id) {
                         account_keys[index] = KeyType::MemoProgram;
                         Some(&ix.data)
                     } else {
@@ -145,4 +150,36 @@ mod test {
             expected_memos
         );
     }
+
+    #[test]
+    fn test_extract_memos_inner_v3() {
+        let fee_payer = Pubkey::new_unique();
+        let memo0 = "Test memo";
+        let memo1 = "🦖";
+        let memo_instructions = vec![
+            CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![],
+                data: memo0.as_bytes().to_vec(),
+            },
+            CompiledInstruction {
+                program_id_index: 2,
+                accounts: vec![],
+                data: memo1.as_bytes().to_vec(),
+            },
+        ];
+        let static_keys = vec![fee_payer, ppl_memo_id_v3(), ppl_memo_id_v1()];
+        let account_keys = AccountKeys::new(&static_keys, None);
+
+        assert_eq!(ppl_memo_id_v1(), inline_ppl_memo::id());
+        assert_ne!(ppl_memo_id_v3(), ppl_memo_id_v1());
+        assert!(is_known_ppl_memo_id(&ppl_memo_id_v3()));
+        assert_eq!(
+            extract_memos_inner(&account_keys, &memo_instructions),
+            vec![
+                format!("[{}] {}", memo0.len(), memo0),
+                format!("[{}] {}", memo1.len(), memo1),
+            ]
+        );
+    }
 }
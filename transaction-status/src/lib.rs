@@ -444,6 +444,34 @@ impl From<TransactionTokenBalance> for UiTransactionTokenBalance {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionNftHolding {
+    pub account_index: u8,
+    pub mint: String,
+    pub token_id: u64,
+    pub owner: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTransactionNftHolding {
+    pub account_index: u8,
+    pub mint: String,
+    pub token_id: u64,
+    pub owner: String,
+}
+
+impl From<TransactionNftHolding> for UiTransactionNftHolding {
+    fn from(nft_holding: TransactionNftHolding) -> Self {
+        Self {
+            account_index: nft_holding.account_index,
+            mint: nft_holding.mint,
+            token_id: nft_holding.token_id,
+            owner: nft_holding.owner,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct TransactionStatusMeta {
     pub status: TransactionResult<()>,
@@ -458,6 +486,8 @@ pub struct TransactionStatusMeta {
     pub loaded_addresses: LoadedAddresses,
     pub return_data: Option<TransactionReturnData>,
     pub compute_units_consumed: Option<u64>,
+    pub pre_nft_holdings: Option<Vec<TransactionNftHolding>>,
+    pub post_nft_holdings: Option<Vec<TransactionNftHolding>>,
 }
 
 impl Default for TransactionStatusMeta {
@@ -475,6 +505,8 @@ impl Default for TransactionStatusMeta {
             loaded_addresses: LoadedAddresses::default(),
             return_data: None,
             compute_units_consumed: None,
+            pre_nft_holdings: None,
+            post_nft_holdings: None,
         }
     }
 }
@@ -531,6 +563,16 @@ pub struct UiTransactionStatusMeta {
         skip_serializing_if = "OptionSerializer::should_skip"
     )]
     pub compute_units_consumed: OptionSerializer<u64>,
+    #[serde(
+        default = "OptionSerializer::skip",
+        skip_serializing_if = "OptionSerializer::should_skip"
+    )]
+    pub pre_nft_holdings: OptionSerializer<Vec<UiTransactionNftHolding>>,
+    #[serde(
+        default = "OptionSerializer::skip",
+        skip_serializing_if = "OptionSerializer::should_skip"
+    )]
+    pub post_nft_holdings: OptionSerializer<Vec<UiTransactionNftHolding>>,
 }
 
 /// A duplicate representation of LoadedAddresses
@@ -590,6 +632,14 @@ impl UiTransactionStatusMeta {
                 meta.return_data.map(|return_data| return_data.into()),
             ),
             compute_units_consumed: OptionSerializer::or_skip(meta.compute_units_consumed),
+            pre_nft_holdings: OptionSerializer::or_skip(
+                meta.pre_nft_holdings
+                    .map(|holdings| holdings.into_iter().map(Into::into).collect()),
+            ),
+            post_nft_holdings: OptionSerializer::or_skip(
+                meta.post_nft_holdings
+                    .map(|holdings| holdings.into_iter().map(Into::into).collect()),
+            ),
         }
     }
 
@@ -618,6 +668,14 @@ impl UiTransactionStatusMeta {
             loaded_addresses: OptionSerializer::Skip,
             return_data: OptionSerializer::Skip,
             compute_units_consumed: OptionSerializer::Skip,
+            pre_nft_holdings: OptionSerializer::or_skip(
+                meta.pre_nft_holdings
+                    .map(|holdings| holdings.into_iter().map(Into::into).collect()),
+            ),
+            post_nft_holdings: OptionSerializer::or_skip(
+                meta.post_nft_holdings
+                    .map(|holdings| holdings.into_iter().map(Into::into).collect()),
+            ),
         }
     }
 }
@@ -649,6 +707,14 @@ impl From<TransactionStatusMeta> for UiTransactionStatusMeta {
                 meta.return_data.map(|return_data| return_data.into()),
             ),
             compute_units_consumed: OptionSerializer::or_skip(meta.compute_units_consumed),
+            pre_nft_holdings: OptionSerializer::or_skip(
+                meta.pre_nft_holdings
+                    .map(|holdings| holdings.into_iter().map(Into::into).collect()),
+            ),
+            post_nft_holdings: OptionSerializer::or_skip(
+                meta.post_nft_holdings
+                    .map(|holdings| holdings.into_iter().map(Into::into).collect()),
+            ),
         }
     }
 }
@@ -1621,6 +1687,8 @@ mod test {
             },
             return_data: None,
             compute_units_consumed: None,
+            pre_nft_holdings: None,
+            post_nft_holdings: None,
         };
         let expected_json_output_value: serde_json::Value = serde_json::from_str(
             "{\
@@ -1674,4 +1742,46 @@ mod test {
             expected_json_output_value
         );
     }
+
+    #[test]
+    fn test_ui_transaction_status_meta_nft_holdings() {
+        let holding = TransactionNftHolding {
+            account_index: 1,
+            mint: "DXM2yVSouSg1twmQgHLKoSReqXhtUroehWxrTgPmmfWi".to_string(),
+            token_id: 3,
+            owner: "8Tkn5ZGwCFc6bBwXS8nFaQBsB4N3WkG1Sx6exZhS6MuU".to_string(),
+        };
+        let meta = TransactionStatusMeta {
+            pre_nft_holdings: Some(vec![holding.clone()]),
+            post_nft_holdings: Some(vec![]),
+            ..TransactionStatusMeta::default()
+        };
+        let expected_holdings = json!([{
+            "accountIndex": 1,
+            "mint": "DXM2yVSouSg1twmQgHLKoSReqXhtUroehWxrTgPmmfWi",
+            "tokenId": 3,
+            "owner": "8Tkn5ZGwCFc6bBwXS8nFaQBsB4N3WkG1Sx6exZhS6MuU",
+        }]);
+
+        let ui_meta_from =
+            serde_json::to_value(UiTransactionStatusMeta::from(meta.clone())).unwrap();
+        assert_eq!(ui_meta_from["preNftHoldings"], expected_holdings);
+        assert_eq!(ui_meta_from["postNftHoldings"], json!([]));
+
+        let ui_meta_parse =
+            serde_json::to_value(UiTransactionStatusMeta::parse(meta.clone(), &[], false)).unwrap();
+        assert_eq!(ui_meta_parse["preNftHoldings"], expected_holdings);
+
+        let ui_meta_simple =
+            serde_json::to_value(UiTransactionStatusMeta::build_simple(meta, false)).unwrap();
+        assert_eq!(ui_meta_simple["preNftHoldings"], expected_holdings);
+
+        // Metadata without nft holdings leaves the fields out entirely
+        let ui_meta = serde_json::to_value(UiTransactionStatusMeta::from(
+            TransactionStatusMeta::default(),
+        ))
+        .unwrap();
+        assert!(ui_meta.get("preNftHoldings").is_none());
+        assert!(ui_meta.get("postNftHoldings").is_none());
+    }
 }
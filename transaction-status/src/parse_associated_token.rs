@@ -3,6 +3,7 @@ use {
         check_num_accounts, ParsableProgram, ParseInstructionError, ParsedInstructionEnum,
     },
     serde_json::json,
+    put_runtime::inline_ppl_associated_token_account,
     put_sdk::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
 };
 
@@ -12,6 +13,23 @@ pub fn ppl_associated_token_id() -> Pubkey {
     Pubkey::new_from_array(ppl_associated_token_account::id().to_bytes())
 }
 
+lazy_static! {
+    static ref PPL_ASSOCIATED_TOKEN_IDS: [Pubkey; 2] = [
+        ppl_associated_token_id(),
+        inline_ppl_associated_token_account::program_v1_0_4::id(),
+    ];
+}
+
+// Returns all known PPL Associated Token Account program ids
+pub fn ppl_associated_token_ids() -> &'static [Pubkey] {
+    PPL_ASSOCIATED_TOKEN_IDS.as_slice()
+}
+
+// Check if the provided program id as a known PPL Associated Token Account program id
+pub fn is_known_ppl_associated_token_id(program_id: &Pubkey) -> bool {
+    ppl_associated_token_ids().contains(program_id)
+}
+
 pub fn parse_associated_token(
     instruction: &CompiledInstruction,
     account_keys: &AccountKeys,
@@ -25,9 +43,50 @@ pub fn parse_associated_token(
             ));
         }
     }
-    check_num_associated_token_accounts(&instruction.accounts, 7)?;
+    // The original program only knows `create`, takes no instruction data and expects the rent
+    // sysvar; later releases prefix a one-byte discriminant and drop the sysvar
+    let instruction_type = match instruction.data.first() {
+        None => {
+            check_num_associated_token_accounts(&instruction.accounts, 7)?;
+            return Ok(ParsedInstructionEnum {
+                instruction_type: "create".to_string(),
+                info: json!({
+                    "source": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "account": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "wallet": account_keys[instruction.accounts[2] as usize].to_string(),
+                    "mint": account_keys[instruction.accounts[3] as usize].to_string(),
+                    "systemProgram": account_keys[instruction.accounts[4] as usize].to_string(),
+                    "tokenProgram": account_keys[instruction.accounts[5] as usize].to_string(),
+                    "rentSysvar": account_keys[instruction.accounts[6] as usize].to_string(),
+                }),
+            });
+        }
+        Some(0) if instruction.data.len() == 1 => "create",
+        Some(1) if instruction.data.len() == 1 => "createIdempotent",
+        Some(2) if instruction.data.len() == 1 => {
+            check_num_associated_token_accounts(&instruction.accounts, 7)?;
+            return Ok(ParsedInstructionEnum {
+                instruction_type: "recoverNested".to_string(),
+                info: json!({
+                    "nestedSource": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "nestedMint": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "destination": account_keys[instruction.accounts[2] as usize].to_string(),
+                    "nestedOwner": account_keys[instruction.accounts[3] as usize].to_string(),
+                    "ownerMint": account_keys[instruction.accounts[4] as usize].to_string(),
+                    "wallet": account_keys[instruction.accounts[5] as usize].to_string(),
+                    "tokenProgram": account_keys[instruction.accounts[6] as usize].to_string(),
+                }),
+            });
+        }
+        _ => {
+            return Err(ParseInstructionError::InstructionNotParsable(
+                ParsableProgram::PplAssociatedTokenAccount,
+            ))
+        }
+    };
+    check_num_associated_token_accounts(&instruction.accounts, 6)?;
     Ok(ParsedInstructionEnum {
-        instruction_type: "create".to_string(),
+        instruction_type: instruction_type.to_string(),
         info: json!({
             "source": account_keys[instruction.accounts[0] as usize].to_string(),
             "account": account_keys[instruction.accounts[1] as usize].to_string(),
@@ -35,7 +94,6 @@ pub fn parse_associated_token(
             "mint": account_keys[instruction.accounts[3] as usize].to_string(),
             "systemProgram": account_keys[instruction.accounts[4] as usize].to_string(),
             "tokenProgram": account_keys[instruction.accounts[5] as usize].to_string(),
-            "rentSysvar": account_keys[instruction.accounts[6] as usize].to_string(),
         }),
     })
 }
@@ -119,4 +177,58 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn test_parse_associated_token_with_discriminant() {
+        let keys: Vec<Pubkey> = (0..7).map(|_| Pubkey::new_unique()).collect();
+        let account_keys = AccountKeys::new(&keys, None);
+        let mut instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![0, 1, 2, 3, 4, 5],
+            data: vec![1],
+        };
+        assert_eq!(
+            parse_associated_token(&instruction, &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "createIdempotent".to_string(),
+                info: json!({
+                    "source": keys[0].to_string(),
+                    "account": keys[1].to_string(),
+                    "wallet": keys[2].to_string(),
+                    "mint": keys[3].to_string(),
+                    "systemProgram": keys[4].to_string(),
+                    "tokenProgram": keys[5].to_string(),
+                })
+            }
+        );
+        instruction.data = vec![0];
+        assert_eq!(
+            parse_associated_token(&instruction, &account_keys)
+                .unwrap()
+                .instruction_type,
+            "create"
+        );
+
+        instruction.data = vec![2];
+        assert!(parse_associated_token(&instruction, &account_keys).is_err());
+        instruction.accounts = vec![0, 1, 2, 3, 4, 5, 6];
+        assert_eq!(
+            parse_associated_token(&instruction, &account_keys).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "recoverNested".to_string(),
+                info: json!({
+                    "nestedSource": keys[0].to_string(),
+                    "nestedMint": keys[1].to_string(),
+                    "destination": keys[2].to_string(),
+                    "nestedOwner": keys[3].to_string(),
+                    "ownerMint": keys[4].to_string(),
+                    "wallet": keys[5].to_string(),
+                    "tokenProgram": keys[6].to_string(),
+                })
+            }
+        );
+
+        instruction.data = vec![3];
+        assert!(parse_associated_token(&instruction, &account_keys).is_err());
+    }
 }
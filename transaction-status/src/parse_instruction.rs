@@ -1,8 +1,8 @@
 use {
     crate::{
-        extract_memos::ppl_memo_id_v1,
+        extract_memos::{ppl_memo_id_v1, ppl_memo_id_v3},
         parse_address_lookup_table::parse_address_lookup_table,
-        parse_associated_token::{parse_associated_token, ppl_associated_token_id},
+        parse_associated_token::{parse_associated_token, ppl_associated_token_ids},
         parse_bpf_loader::{parse_bpf_loader, parse_bpf_upgradeable_loader},
         parse_name::parse_name,
         parse_nft::parse_nft,
@@ -27,11 +27,10 @@ use {
 
 lazy_static! {
     static ref ADDRESS_LOOKUP_PROGRAM_ID: Pubkey = put_address_lookup_table_program::id();
-    static ref ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = ppl_associated_token_id();
     static ref BPF_LOADER_PROGRAM_ID: Pubkey = put_sdk::bpf_loader::id();
     static ref BPF_UPGRADEABLE_LOADER_PROGRAM_ID: Pubkey = put_sdk::bpf_loader_upgradeable::id();
     static ref MEMO_V1_PROGRAM_ID: Pubkey = ppl_memo_id_v1();
-    // static ref MEMO_V3_PROGRAM_ID: Pubkey = ppl_memo_id_v3();
+    static ref MEMO_V3_PROGRAM_ID: Pubkey = ppl_memo_id_v3();
     static ref STAKE_PROGRAM_ID: Pubkey = stake::program::id();
     static ref SYSTEM_PROGRAM_ID: Pubkey = system_program::id();
     static ref VOTE_PROGRAM_ID: Pubkey = put_vote_program::id();
@@ -43,12 +42,14 @@ lazy_static! {
             *ADDRESS_LOOKUP_PROGRAM_ID,
             ParsableProgram::AddressLookupTable,
         );
-        m.insert(
-            *ASSOCIATED_TOKEN_PROGRAM_ID,
-            ParsableProgram::PplAssociatedTokenAccount,
-        );
+        for ppl_associated_token_id in ppl_associated_token_ids() {
+            m.insert(
+                *ppl_associated_token_id,
+                ParsableProgram::PplAssociatedTokenAccount,
+            );
+        }
         m.insert(*MEMO_V1_PROGRAM_ID, ParsableProgram::PplMemo);
-        // m.insert(*MEMO_V3_PROGRAM_ID, ParsableProgram::PplMemo);
+        m.insert(*MEMO_V3_PROGRAM_ID, ParsableProgram::PplMemo);
         for ppl_token_id in ppl_token_ids() {
             m.insert(ppl_token_id, ParsableProgram::PplToken);
         }
@@ -208,6 +209,16 @@ mod test {
             }
         );
 
+        assert_eq!(
+            parse(&MEMO_V3_PROGRAM_ID, &memo_instruction, &no_keys, None).unwrap(),
+            ParsedInstruction {
+                program: "ppl-memo".to_string(),
+                program_id: MEMO_V3_PROGRAM_ID.to_string(),
+                parsed: json!("🦖"),
+                stack_height: None,
+            }
+        );
+
         let non_parsable_program_id = Pubkey::from([1; 32]);
         assert!(parse(&non_parsable_program_id, &memo_instruction, &no_keys, None).is_err());
     }
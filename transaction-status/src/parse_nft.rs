@@ -6,9 +6,11 @@ use {
         instruction::{
             AuthorityType, InitializeMintArgs, SetAuthorityArgs, TokenInstruction, UpdateType,
         },
+        put_program::instruction::Instruction as PplNftInstruction,
     },
+    put_account_decoder::parse_nft::pubkey_from_ppl_nft,
     put_sdk::{
-        instruction::CompiledInstruction,
+        instruction::{AccountMeta, CompiledInstruction, Instruction},
         message::AccountKeys,
     },
     serde_json::{json, Map, Value},
@@ -220,3 +222,19 @@ fn parse_signers(
 fn check_num_token_accounts(accounts: &[u8], num: usize) -> Result<(), ParseInstructionError> {
     check_num_accounts(accounts, num, ParsableProgram::PplNft)
 }
+
+pub fn ppl_nft_instruction(instruction: PplNftInstruction) -> Instruction {
+    Instruction {
+        program_id: pubkey_from_ppl_nft(&instruction.program_id),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: pubkey_from_ppl_nft(&meta.pubkey),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: instruction.data,
+    }
+}
@@ -1,36 +1,41 @@
 use {
-    crate::TransactionTokenBalance,
+    crate::{TransactionNftHolding, TransactionTokenBalance},
     put_account_decoder::parse_token::{
         is_known_ppl_token_id, pubkey_from_ppl_token, ppl_token_native_mint,
-        token_amount_to_ui_amount, UiTokenAmount,
+        token_account_balance, token_amount_to_ui_amount, unpack_token_account,
+        unpack_token_mint, UiTokenAmount,
     },
     put_measure::measure::Measure,
     put_metrics::datapoint_debug,
     put_runtime::{bank::Bank, transaction_batch::TransactionBatch},
     put_sdk::{account::ReadableAccount, pubkey::Pubkey},
-    ppl_token::{
-        put_program::program_pack::Pack,
-        state::{Account as TokenAccount, Mint},
-    },
     std::collections::HashMap,
 };
 
 pub type TransactionTokenBalances = Vec<Vec<TransactionTokenBalance>>;
+pub type TransactionNftHoldings = Vec<Vec<TransactionNftHolding>>;
 
 pub struct TransactionTokenBalancesSet {
     pub pre_token_balances: TransactionTokenBalances,
     pub post_token_balances: TransactionTokenBalances,
+    pub pre_nft_holdings: TransactionNftHoldings,
+    pub post_nft_holdings: TransactionNftHoldings,
 }
 
 impl TransactionTokenBalancesSet {
     pub fn new(
         pre_token_balances: TransactionTokenBalances,
         post_token_balances: TransactionTokenBalances,
+        pre_nft_holdings: TransactionNftHoldings,
+        post_nft_holdings: TransactionNftHoldings,
     ) -> Self {
         assert_eq!(pre_token_balances.len(), post_token_balances.len());
+        assert_eq!(pre_nft_holdings.len(), post_nft_holdings.len());
         Self {
             pre_token_balances,
             post_token_balances,
+            pre_nft_holdings,
+            post_nft_holdings,
         }
     }
 }
@@ -45,9 +50,7 @@ fn get_mint_decimals(bank: &Bank, mint: &Pubkey) -> Option<u8> {
             return None;
         }
 
-        let decimals = Mint::unpack(mint_account.data())
-            .map(|mint| mint.decimals)
-            .ok()?;
+        let decimals = unpack_token_mint(mint_account.data()).map(|mint| mint.decimals)?;
 
         Some(decimals)
     }
@@ -118,7 +121,7 @@ fn collect_token_balance_from_account(
         return None;
     }
 
-    let token_account = TokenAccount::unpack(account.data()).ok()?;
+    let token_account = unpack_token_account(account.data())?;
     let mint = pubkey_from_ppl_token(&token_account.mint);
 
     let decimals = mint_decimals.get(&mint).cloned().or_else(|| {
@@ -130,7 +133,10 @@ fn collect_token_balance_from_account(
     Some(TokenBalanceData {
         mint: token_account.mint.to_string(),
         owner: token_account.owner.to_string(),
-        ui_token_amount: token_amount_to_ui_amount(token_account.amount, decimals),
+        ui_token_amount: token_amount_to_ui_amount(
+            token_account_balance(&token_account, Some(account.lamports())),
+            decimals,
+        ),
         program_id: account.owner().to_string(),
     })
 }
@@ -142,7 +148,10 @@ mod test {
         super::*,
         put_account_decoder::parse_token::{pubkey_from_ppl_token, ppl_token_pubkey},
         put_sdk::{account::Account, genesis_config::create_genesis_config},
-        ppl_token::put_program::program_option::COption,
+        ppl_token::{
+            put_program::{program_option::COption, program_pack::Pack},
+            state::{Account as TokenAccount, Mint},
+        },
         std::collections::BTreeMap,
         ppl_token::state::MintMeta,
     };
@@ -230,6 +239,28 @@ mod test {
             rent_epoch: 0,
         };
 
+        // Wrapped PUT holding more lamports than its last synced amount
+        let wrapped_put_data = TokenAccount {
+            mint: ppl_token::native_mint::id(),
+            owner: ppl_token_pubkey(&token_owner),
+            amount: 42,
+            delegate: COption::None,
+            state: ppl_token::state::AccountState::Initialized,
+            is_native: COption::Some(100),
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        let mut data = [0; TokenAccount::LEN];
+        TokenAccount::pack(wrapped_put_data, &mut data).unwrap();
+
+        let wrapped_put_account = Account {
+            lamports: 100 + 1_500_000_000,
+            data: data.to_vec(),
+            owner: pubkey_from_ppl_token(&ppl_token::id()),
+            executable: false,
+            rent_epoch: 0,
+        };
+
         let mut accounts = BTreeMap::new();
 
         let account_pubkey = Pubkey::new_unique();
@@ -242,6 +273,8 @@ mod test {
         accounts.insert(other_account_pubkey, other_account);
         let other_mint_account_pubkey = Pubkey::new_unique();
         accounts.insert(other_mint_account_pubkey, other_mint_token_account);
+        let wrapped_put_account_pubkey = Pubkey::new_unique();
+        accounts.insert(wrapped_put_account_pubkey, wrapped_put_account);
 
         genesis_config.accounts = accounts;
 
@@ -290,6 +323,25 @@ mod test {
             ),
             None
         );
+
+        assert_eq!(
+            collect_token_balance_from_account(
+                &bank,
+                &wrapped_put_account_pubkey,
+                &mut mint_decimals
+            ),
+            Some(TokenBalanceData {
+                mint: ppl_token::native_mint::id().to_string(),
+                owner: token_owner.to_string(),
+                ui_token_amount: UiTokenAmount {
+                    ui_amount: Some(1.5),
+                    decimals: 9,
+                    amount: "1500000000".to_string(),
+                    ui_amount_string: "1.5".to_string(),
+                },
+                program_id: ppl_token::id().to_string(),
+            })
+        );
     }
     
     #[test]
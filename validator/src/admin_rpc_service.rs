@@ -9,6 +9,7 @@ use {
     jsonrpc_server_utils::tokio,
     log::*,
     serde::{de::Deserializer, Deserialize, Serialize},
+    put_account_decoder::{UiAccount, UiAccountEncoding},
     put_core::{
         admin_rpc_post_init::AdminRpcRequestMetadataPostInit, consensus::Tower,
         tower_storage::TowerStorage, validator::ValidatorStartProgress,
@@ -16,15 +17,18 @@ use {
     put_geyser_plugin_manager::GeyserPluginManagerRequest,
     put_gossip::contact_info::{ContactInfo, Protocol, SOCKET_ADDR_UNSPECIFIED},
     put_rpc::rpc::verify_pubkey,
-    put_rpc_client_api::{config::RpcAccountIndex, custom_error::RpcCustomError},
-    put_runtime::accounts_index::AccountIndex,
+    put_rpc_client_api::{
+        config::RpcAccountIndex, custom_error::RpcCustomError, response::RpcKeyedAccount,
+    },
+    put_runtime::accounts_index::{AccountIndex, ScanConfig},
     put_sdk::{
+        clock::Slot,
         exit::Exit,
         pubkey::Pubkey,
         signature::{read_keypair_file, Keypair, Signer},
     },
     std::{
-        collections::{HashMap, HashSet},
+        collections::{BTreeMap, HashMap, HashSet},
         error,
         fmt::{self, Display},
         net::SocketAddr,
@@ -87,6 +91,15 @@ pub struct AdminRpcRepairWhitelist {
     pub whitelist: Vec<Pubkey>,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AdminRpcDumpedAccounts {
+    /// Slot of the working bank the accounts were read from
+    pub slot: Slot,
+    pub accounts: Vec<RpcKeyedAccount>,
+    /// Explicitly requested addresses that have no account at `slot`
+    pub missing_addresses: Vec<Pubkey>,
+}
+
 impl From<ContactInfo> for AdminRpcContactInfo {
     fn from(node: ContactInfo) -> Self {
         macro_rules! unwrap_socket {
@@ -241,6 +254,14 @@ pub trait AdminRpc {
         meta: Self::Metadata,
         public_tpu_forwards_addr: SocketAddr,
     ) -> Result<()>;
+
+    #[rpc(meta, name = "dumpAccounts")]
+    fn dump_accounts(
+        &self,
+        meta: Self::Metadata,
+        owners: Vec<Pubkey>,
+        addresses: Vec<Pubkey>,
+    ) -> Result<AdminRpcDumpedAccounts>;
 }
 
 pub struct AdminRpcImpl;
@@ -679,6 +700,60 @@ impl AdminRpc for AdminRpcImpl {
             Ok(())
         })
     }
+
+    fn dump_accounts(
+        &self,
+        meta: Self::Metadata,
+        owners: Vec<Pubkey>,
+        addresses: Vec<Pubkey>,
+    ) -> Result<AdminRpcDumpedAccounts> {
+        debug!(
+            "dump_accounts rpc request received: {} owners, {} addresses",
+            owners.len(),
+            addresses.len()
+        );
+        meta.with_post_init(|post_init| {
+            // The root trails the tip by the whole confirmation depth, which would leave out the
+            // accounts written by the latest transactions
+            let bank = post_init.bank_forks.read().unwrap().working_bank();
+            // Ordered by pubkey so that dumps of the same state are identical
+            let mut accounts = BTreeMap::new();
+            for owner in &owners {
+                let keyed_accounts = bank
+                    .get_program_accounts(owner, &ScanConfig::default())
+                    .map_err(|err| RpcCustomError::ScanError {
+                        message: err.to_string(),
+                    })?;
+                accounts.extend(keyed_accounts);
+            }
+            let mut missing_addresses = vec![];
+            for address in addresses {
+                match bank.get_account(&address) {
+                    Some(account) => {
+                        accounts.insert(address, account);
+                    }
+                    None => missing_addresses.push(address),
+                }
+            }
+            Ok(AdminRpcDumpedAccounts {
+                slot: bank.slot(),
+                accounts: accounts
+                    .into_iter()
+                    .map(|(pubkey, account)| RpcKeyedAccount {
+                        pubkey: pubkey.to_string(),
+                        account: UiAccount::encode(
+                            &pubkey,
+                            &account,
+                            UiAccountEncoding::Base64,
+                            None,
+                            None,
+                        ),
+                    })
+                    .collect(),
+                missing_addresses,
+            })
+        })
+    }
 }
 
 impl AdminRpcImpl {
@@ -735,6 +810,7 @@ fn rpc_account_index_from_account_index(account_index: &AccountIndex) -> RpcAcco
         AccountIndex::PplTokenMint => RpcAccountIndex::PplTokenMint,
         AccountIndex::PplNameOwner => RpcAccountIndex::PplNameOwner,
         AccountIndex::PplNftOwner => RpcAccountIndex::PplNftOwner,
+        AccountIndex::PplNftMint => RpcAccountIndex::PplNftMint,
     }
 }
 
@@ -745,6 +821,7 @@ fn account_index_from_rpc_account_index(rpc_account_index: &RpcAccountIndex) ->
         RpcAccountIndex::PplTokenMint => AccountIndex::PplTokenMint,
         RpcAccountIndex::PplNameOwner => AccountIndex::PplNameOwner,
         RpcAccountIndex::PplNftOwner => AccountIndex::PplNftOwner,
+        RpcAccountIndex::PplNftMint => AccountIndex::PplNftMint,
     }
 }
 
@@ -830,6 +907,20 @@ pub fn runtime() -> jsonrpc_server_utils::tokio::runtime::Runtime {
     jsonrpc_server_utils::tokio::runtime::Runtime::new().expect("new tokio runtime")
 }
 
+/// Writes accounts returned by `dumpAccounts` to `output_dir`, one JSON file per account in the
+/// format read by `put-test-validator --account-dir`
+pub fn write_dumped_accounts(
+    keyed_accounts: &[RpcKeyedAccount],
+    output_dir: &Path,
+) -> std::result::Result<(), Box<dyn error::Error>> {
+    std::fs::create_dir_all(output_dir)?;
+    for keyed_account in keyed_accounts {
+        let path = output_dir.join(format!("{}.json", keyed_account.pubkey));
+        std::fs::write(&path, serde_json::to_string_pretty(keyed_account)?)?;
+    }
+    Ok(())
+}
+
 #[derive(Default, Deserialize, Clone)]
 pub struct StakedNodesOverrides {
     #[serde(deserialize_with = "deserialize_pubkey_map")]
@@ -861,3 +952,89 @@ pub fn load_staked_nodes_overrides(
         Err(format!("Staked nodes overrides provided '{path}' a non-existing file path.").into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        put_core::tower_storage::NullTowerStorage,
+        put_sdk::account::{Account, AccountSharedData, ReadableAccount},
+        put_test_validator::TestValidatorGenesis,
+    };
+
+    #[test]
+    fn test_dump_accounts_round_trip() {
+        let owner = Pubkey::new_unique();
+        let account = |data: Vec<u8>, owner: Pubkey| {
+            AccountSharedData::from(Account {
+                lamports: 1_000_000_000,
+                data,
+                owner,
+                ..Account::default()
+            })
+        };
+        let address = Pubkey::new_unique();
+        let missing_address = Pubkey::new_unique();
+        let accounts = vec![
+            (Pubkey::new_unique(), account(vec![1; 3], owner)),
+            (Pubkey::new_unique(), account(vec![2; 5], owner)),
+            (address, account(vec![42; 4], Pubkey::new_unique())),
+        ];
+
+        let (test_validator, _payer) = TestValidatorGenesis::default()
+            .add_accounts(accounts.clone())
+            .start();
+        let meta = AdminRpcRequestMetadata {
+            rpc_addr: None,
+            start_time: SystemTime::now(),
+            start_progress: Arc::<RwLock<ValidatorStartProgress>>::default(),
+            validator_exit: Arc::<RwLock<Exit>>::default(),
+            authorized_voter_keypairs: Arc::<RwLock<Vec<Arc<Keypair>>>>::default(),
+            tower_storage: Arc::new(NullTowerStorage::default()),
+            staked_nodes_overrides: Arc::<RwLock<HashMap<Pubkey, u128>>>::default(),
+            post_init: Arc::new(RwLock::new(Some(AdminRpcRequestMetadataPostInit {
+                cluster_info: test_validator.cluster_info(),
+                bank_forks: test_validator.bank_forks(),
+                vote_account: test_validator.vote_account_address(),
+                repair_whitelist: test_validator.repair_whitelist(),
+            }))),
+            rpc_to_plugin_manager_sender: None,
+        };
+        let mut io = MetaIoHandler::default();
+        io.extend_with(AdminRpcImpl.to_delegate());
+
+        let req = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "dumpAccounts",
+            "params": [
+                [owner.to_string()],
+                [address.to_string(), missing_address.to_string()],
+            ],
+        });
+        let res = io.handle_request_sync(&req.to_string(), meta);
+        let result: serde_json::Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let dumped_accounts: AdminRpcDumpedAccounts =
+            serde_json::from_value(result["result"].clone()).unwrap();
+        assert_eq!(dumped_accounts.accounts.len(), accounts.len());
+        assert_eq!(dumped_accounts.missing_addresses, vec![missing_address]);
+
+        let output_dir = tempfile::tempdir().unwrap();
+        write_dumped_accounts(&dumped_accounts.accounts, output_dir.path()).unwrap();
+        drop(test_validator);
+
+        let (test_validator, _payer) = TestValidatorGenesis::default()
+            .add_accounts_from_directories([output_dir.path().display().to_string()])
+            .unwrap()
+            .start();
+        let bank = test_validator.bank_forks().read().unwrap().root_bank();
+        for (pubkey, account) in &accounts {
+            let loaded_account = bank.get_account(pubkey).unwrap();
+            assert_eq!(loaded_account.lamports(), account.lamports());
+            assert_eq!(loaded_account.owner(), account.owner());
+            assert_eq!(loaded_account.data(), account.data());
+            assert_eq!(loaded_account.executable(), account.executable());
+        }
+    }
+}
@@ -8,7 +8,9 @@ use {
         input_validators::normalize_to_url_if_moniker,
         
     },
-    put_core::tower_storage::FileTowerStorage,
+    put_core::{
+        admin_rpc_post_init::AdminRpcRequestMetadataPostInit, tower_storage::FileTowerStorage,
+    },
     put_faucet::faucet::run_local_faucet_with_port,
     put_rpc::{
         rpc::{JsonRpcConfig, RpcBigtableConfig},
@@ -74,6 +76,7 @@ fn main() {
             "ppl-token-mint" => AccountIndex::PplTokenMint,
             "ppl-token-owner" => AccountIndex::PplTokenOwner,
             "ppl-nft-owner" => AccountIndex::PplNftOwner,
+            "ppl-nft-mint" => AccountIndex::PplNftMint,
             "ppl-name-owner" => AccountIndex::PplNameOwner,
             _ => unreachable!(),
         })
@@ -404,7 +407,7 @@ fn main() {
             validator_exit: genesis.validator_exit.clone(),
             authorized_voter_keypairs: genesis.authorized_voter_keypairs.clone(),
             staked_nodes_overrides: genesis.staked_nodes_overrides.clone(),
-            post_init: admin_service_post_init,
+            post_init: admin_service_post_init.clone(),
             tower_storage: tower_storage.clone(),
             rpc_to_plugin_manager_sender,
         },
@@ -563,6 +566,12 @@ fn main() {
         rpc_to_plugin_manager_receiver,
     ) {
         Ok(test_validator) => {
+            *admin_service_post_init.write().unwrap() = Some(AdminRpcRequestMetadataPostInit {
+                cluster_info: test_validator.cluster_info(),
+                bank_forks: test_validator.bank_forks(),
+                vote_account: test_validator.vote_account_address(),
+                repair_whitelist: test_validator.repair_whitelist(),
+            });
             if let Some(dashboard) = dashboard {
                 dashboard.run(Duration::from_millis(250));
             }
@@ -44,7 +44,7 @@ use {
         self, MAX_BATCH_SEND_RATE_MS, MAX_TRANSACTION_BATCH_SIZE,
     },
     put_tpu_client::tpu_client::DEFAULT_TPU_CONNECTION_POOL_SIZE,
-    std::{path::PathBuf, str::FromStr},
+    std::{net::IpAddr, path::PathBuf, str::FromStr},
 };
 
 const EXCLUDE_KEY: &str = "account-index-exclude-key";
@@ -256,6 +256,23 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .validator(put_net_utils::is_host_port)
                 .help("Hbase instance read RPC URL"),
         )
+        .arg(
+            Arg::with_name("hbase_namespace")
+                .long("hbase-namespace")
+                .takes_value(true)
+                .value_name("NAMESPACE")
+                .default_value(&default_args.hbase_namespace)
+                .help("Hbase namespace holding the ledger tables"),
+        )
+        .arg(
+            Arg::with_name("rpc_hbase_timeout")
+                .long("rpc-hbase-timeout")
+                .value_name("SECONDS")
+                .validator(is_parsable::<u64>)
+                .takes_value(true)
+                .default_value(&default_args.rpc_hbase_timeout)
+                .help("Number of seconds before timing out requests to the HBase instance"),
+        )
         .arg(
             Arg::with_name("enable_extended_tx_metadata_storage")
                 .long("enable-extended-tx-metadata-storage")
@@ -713,6 +730,17 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                        Sending the SIGUSR1 signal to the validator process will cause it \
                        to re-open the log file"),
         )
+        .arg(
+            Arg::with_name("metrics_prometheus_address")
+                .long("metrics-prometheus-address")
+                .value_name("HOST:PORT")
+                .takes_value(true)
+                .env("PUT_METRICS_PROMETHEUS_ADDRESS")
+                .validator(put_net_utils::is_host_port)
+                .help("Serve metrics to Prometheus scrapes at http://HOST:PORT/metrics \
+                       instead of sending them to the InfluxDB instance configured by \
+                       PUT_METRICS_CONFIG. The validator exits if the address cannot be bound"),
+        )
         .arg(
             Arg::with_name("wait_for_supermajority")
                 .long("wait-for-supermajority")
@@ -1082,6 +1110,63 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .default_value(&default_args.rpc_max_request_body_size)
                 .help("The maximum request body size accepted by rpc service"),
         )
+        .arg(
+            Arg::with_name("rpc_rate_limit")
+                .long("rpc-rate-limit")
+                .value_name("CREDITS_PER_SECOND")
+                .takes_value(true)
+                .requires("rpc_rate_limit_trusted_proxies")
+                .validator(|s| is_within_range(s, 1usize..))
+                .help("Rate limit the JSON RPC calls of each client to this many credits per second. \
+                       Each call is charged the cost of its method. Clients are identified by the \
+                       rightmost X-Forwarded-For entry that is not a trusted proxy, or else the \
+                       X-Real-IP header set by a reverse proxy, and requests without either share \
+                       a single limit. The RPC port must only be reachable through the proxies \
+                       given with --rpc-rate-limit-trusted-proxies"),
+        )
+        .arg(
+            Arg::with_name("rpc_rate_limit_burst")
+                .long("rpc-rate-limit-burst")
+                .value_name("CREDITS")
+                .takes_value(true)
+                .requires("rpc_rate_limit")
+                .validator(|s| is_within_range(s, 1usize..))
+                .help("The number of credits each client can accumulate \
+                       [default: 10 seconds of --rpc-rate-limit]"),
+        )
+        .arg(
+            Arg::with_name("rpc_rate_limit_method_cost")
+                .long("rpc-rate-limit-method-cost")
+                .value_name("METHOD=CREDITS")
+                .takes_value(true)
+                .multiple(true)
+                .requires("rpc_rate_limit")
+                .validator(rpc_method_value_validator)
+                .help("Override the credits charged for calls of a JSON RPC method. \
+                       Methods cost 1 credit unless expensive to serve, such as getProgramAccounts"),
+        )
+        .arg(
+            Arg::with_name("rpc_rate_limit_method_calls")
+                .long("rpc-rate-limit-method-calls")
+                .value_name("METHOD=CALLS_PER_SECOND")
+                .takes_value(true)
+                .multiple(true)
+                .requires("rpc_rate_limit")
+                .validator(rpc_method_value_validator)
+                .help("Additionally limit each client to this many calls per second of a JSON RPC method"),
+        )
+        .arg(
+            Arg::with_name("rpc_rate_limit_trusted_proxies")
+                .long("rpc-rate-limit-trusted-proxies")
+                .value_name("IP_ADDRESS")
+                .takes_value(true)
+                .multiple(true)
+                .requires("rpc_rate_limit")
+                .validator(is_parsable::<IpAddr>)
+                .help("Address of a reverse proxy in front of the RPC service, trusted to report \
+                       the client of a request. Its own X-Forwarded-For entries are skipped when \
+                       identifying the client"),
+        )
         .arg(
             Arg::with_name("enable_accountsdb_repl")
                 .long("enable-accountsdb-repl")
@@ -1195,7 +1280,14 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .long("account-index")
                 .takes_value(true)
                 .multiple(true)
-                .possible_values(&["program-id", "ppl-token-owner", "ppl-token-mint"])
+                .possible_values(&[
+                    "program-id",
+                    "ppl-token-owner",
+                    "ppl-token-mint",
+                    "ppl-nft-owner",
+                    "ppl-nft-mint",
+                    "ppl-name-owner",
+                ])
                 .value_name("INDEX")
                 .help("Enable an accounts index, indexed by the selected account field"),
         )
@@ -1525,6 +1617,45 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                                     running validator instance")
                 )
         )
+        .subcommand(
+            SubCommand::with_name("dump-accounts")
+                .about("Write accounts of the running validator to a directory, \
+                        in the JSON format loaded by put-test-validator --account-dir")
+                .setting(AppSettings::ArgRequiredElseHelp)
+                .arg(
+                    Arg::with_name("output_dir")
+                        .long("output-dir")
+                        .value_name("DIRECTORY")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Directory to write one ADDRESS.json file per account to")
+                )
+                .arg(
+                    Arg::with_name("owner")
+                        .long("owner")
+                        .value_name("PROGRAM_ID")
+                        .takes_value(true)
+                        .multiple(true)
+                        .validator(is_pubkey)
+                        .help("Dump all accounts owned by this program")
+                )
+                .arg(
+                    Arg::with_name("account")
+                        .long("account")
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .multiple(true)
+                        .validator(is_pubkey)
+                        .help("Dump the account at this address")
+                )
+                .arg(
+                    Arg::with_name("ppl_accounts")
+                        .long("ppl-accounts")
+                        .takes_value(false)
+                        .help("Dump all accounts owned by the PPL token, token-2022, NFT and name programs")
+                )
+                .after_help("Note: accounts are read from the working bank, including transactions that are not yet confirmed")
+        )
         .subcommand(
             SubCommand::with_name("init")
                 .about("Initialize the ledger directory then exit")
@@ -1901,6 +2032,8 @@ pub struct DefaultArgs {
     pub rpc_bigtable_timeout: String,
     pub rpc_bigtable_instance_name: String,
     pub rpc_bigtable_app_profile_id: String,
+    pub hbase_namespace: String,
+    pub rpc_hbase_timeout: String,
     pub rpc_max_request_body_size: String,
     pub rpc_pubsub_worker_threads: String,
 
@@ -1987,6 +2120,8 @@ impl DefaultArgs {
             rpc_bigtable_instance_name: put_storage_bigtable::DEFAULT_INSTANCE_NAME.to_string(),
             rpc_bigtable_app_profile_id: put_storage_bigtable::DEFAULT_APP_PROFILE_ID
                 .to_string(),
+            hbase_namespace: put_storage_hbase::DEFAULT_HBASE_NAMESPACE.to_string(),
+            rpc_hbase_timeout: "30".to_string(),
             rpc_pubsub_worker_threads: "4".to_string(),
             accountsdb_repl_threads: num_cpus::get().to_string(),
             accounts_filler_count: "0".to_string(),
@@ -2053,6 +2188,16 @@ pub fn port_range_validator(port_range: String) -> Result<(), String> {
     }
 }
 
+fn rpc_method_value_validator(method_value: String) -> Result<(), String> {
+    match method_value.split_once('=') {
+        Some((method, value)) if !method.is_empty() => value
+            .parse::<u64>()
+            .map(|_| ())
+            .map_err(|e| format!("{e:?}")),
+        _ => Err(format!("expected METHOD=NUMBER, found {method_value}")),
+    }
+}
+
 fn hash_validator(hash: String) -> Result<(), String> {
     Hash::from_str(&hash)
         .map(|_| ())
@@ -2142,7 +2287,14 @@ pub fn test_app<'a>(version: &'a str, default_args: &'a DefaultTestArgs) -> App<
                 .long("account-index")
                 .takes_value(true)
                 .multiple(true)
-                .possible_values(&["program-id", "ppl-token-owner", "ppl-token-mint"])
+                .possible_values(&[
+                    "program-id",
+                    "ppl-token-owner",
+                    "ppl-token-mint",
+                    "ppl-nft-owner",
+                    "ppl-nft-mint",
+                    "ppl-name-owner",
+                ])
                 .value_name("INDEX")
                 .help("Enable an accounts index, indexed by the selected account field"),
         )
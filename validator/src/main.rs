@@ -28,6 +28,7 @@ use {
     put_rpc::{
         rpc::{JsonRpcConfig, RpcBigtableConfig,RpcHBaseConfig},
         rpc_pubsub_service::PubSubConfig,
+        rpc_rate_limiter::RpcRateLimitConfig,
     },
     put_rpc_client::rpc_client::RpcClient,
     put_rpc_client_api::config::RpcLeaderScheduleConfig,
@@ -40,6 +41,7 @@ use {
             AccountIndex, AccountSecondaryIndexes, AccountSecondaryIndexesIncludeExclude,
             AccountsIndexConfig, IndexLimitMb,
         },
+        inline_name, inline_nft, inline_ppl_token, inline_ppl_token_2022,
         partitioned_rewards::TestPartitionedEpochRewards,
         runtime_config::RuntimeConfig,
         snapshot_config::{SnapshotConfig, SnapshotUsage},
@@ -373,6 +375,37 @@ fn set_repair_whitelist(
     Ok(())
 }
 
+/// Writes the requested accounts of a running validator to `output_dir`, one JSON file per
+/// account in the format read by `put-test-validator --account-dir`
+fn dump_accounts(
+    ledger_path: &Path,
+    owners: Vec<Pubkey>,
+    addresses: Vec<Pubkey>,
+    output_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let admin_client = admin_rpc_service::connect(ledger_path);
+    let dumped_accounts = admin_rpc_service::runtime()
+        .block_on(async move { admin_client.await?.dump_accounts(owners, addresses).await })
+        .map_err(|err| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("dumpAccounts request failed: {err}"),
+            )
+        })?;
+
+    for address in &dumped_accounts.missing_addresses {
+        eprintln!("Account {address} not found");
+    }
+    admin_rpc_service::write_dumped_accounts(&dumped_accounts.accounts, output_dir)?;
+    println!(
+        "Dumped {} accounts at slot {} to {}",
+        dumped_accounts.accounts.len(),
+        dumped_accounts.slot,
+        output_dir.display()
+    );
+    Ok(())
+}
+
 /// Returns the default fifo shred storage size (include both data and coding
 /// shreds) based on the validator config.
 fn default_fifo_shred_storage_size(vc: &ValidatorConfig) -> Option<u64> {
@@ -784,6 +817,24 @@ pub fn main() {
             });
             return;
         }
+        ("dump-accounts", Some(subcommand_matches)) => {
+            let mut owners = values_t!(subcommand_matches, "owner", Pubkey).unwrap_or_default();
+            if subcommand_matches.is_present("ppl_accounts") {
+                owners.extend([
+                    inline_ppl_token::id(),
+                    inline_ppl_token_2022::id(),
+                    inline_nft::id(),
+                    inline_name::id(),
+                ]);
+            }
+            let addresses = values_t!(subcommand_matches, "account", Pubkey).unwrap_or_default();
+            let output_dir = PathBuf::from(subcommand_matches.value_of("output_dir").unwrap());
+            dump_accounts(&ledger_path, owners, addresses, &output_dir).unwrap_or_else(|err| {
+                eprintln!("{err}");
+                exit(1);
+            });
+            return;
+        }
         ("repair-whitelist", Some(repair_whitelist_subcommand_matches)) => {
             match repair_whitelist_subcommand_matches.subcommand() {
                 ("get", Some(subcommand_matches)) => {
@@ -902,6 +953,17 @@ pub fn main() {
     info!("{} {}", crate_name!(), put_version::version!());
     info!("Starting validator with: {:#?}", std::env::args_os());
 
+    if let Some(address) = matches.value_of("metrics_prometheus_address") {
+        let address = put_net_utils::parse_host_port(address).unwrap_or_else(|err| {
+            eprintln!("Failed to parse --metrics-prometheus-address: {err}");
+            exit(1);
+        });
+        if let Err(err) = put_metrics::set_prometheus_bind_address(address) {
+            eprintln!("Failed to serve prometheus metrics on {address}: {err}");
+            exit(1);
+        }
+    }
+
     let cuda = matches.is_present("cuda");
     if cuda {
         put_perf::perf_libs::init_cuda();
@@ -1226,6 +1288,10 @@ pub fn main() {
             hbase_rpc_url: matches.value_of("hbase_rpc_url").map(|address| {
                 put_net_utils::parse_host_port(address).expect("failed to parse faucet address").to_string()
             }).unwrap(),
+            hbase_namespace: value_t_or_exit!(matches, "hbase_namespace", String),
+            timeout: value_t!(matches, "rpc_hbase_timeout", u64)
+                .ok()
+                .map(Duration::from_secs),
         })
     } else {
         None
@@ -1301,6 +1367,7 @@ pub fn main() {
                 "rpc_max_request_body_size",
                 usize
             )),
+            rate_limit: process_rpc_rate_limit(&matches),
         },
         on_start_geyser_plugin_config_files,
         rpc_addrs: value_t!(matches, "rpc_port", u16).ok().map(|rpc_port| {
@@ -1885,6 +1952,35 @@ pub fn main() {
     info!("Validator exiting..");
 }
 
+fn process_rpc_rate_limit(matches: &ArgMatches) -> Option<RpcRateLimitConfig> {
+    let credits_per_second = value_t!(matches, "rpc_rate_limit", u64).ok()?;
+    let method_values = |name: &str| {
+        matches
+            .values_of(name)
+            .unwrap_or_default()
+            .map(|method_value| {
+                let (method, value) = method_value.split_once('=').unwrap();
+                (method.to_string(), value.parse::<u64>().unwrap())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut config = RpcRateLimitConfig::new(credits_per_second);
+    if let Ok(burst_credits) = value_t!(matches, "rpc_rate_limit_burst", u64) {
+        config.burst_credits = burst_credits;
+    }
+    config
+        .method_costs
+        .extend(method_values("rpc_rate_limit_method_cost"));
+    config
+        .method_calls_per_second
+        .extend(method_values("rpc_rate_limit_method_calls"));
+    if let Ok(trusted_proxies) = values_t!(matches, "rpc_rate_limit_trusted_proxies", IpAddr) {
+        config.trusted_proxies.extend(trusted_proxies);
+    }
+    Some(config)
+}
+
 fn process_account_indexes(matches: &ArgMatches) -> AccountSecondaryIndexes {
     let account_indexes: HashSet<AccountIndex> = matches
         .values_of("account_indexes")
@@ -1894,6 +1990,7 @@ fn process_account_indexes(matches: &ArgMatches) -> AccountSecondaryIndexes {
             "ppl-token-mint" => AccountIndex::PplTokenMint,
             "ppl-token-owner" => AccountIndex::PplTokenOwner,
             "ppl-nft-owner" => AccountIndex::PplNftOwner,
+            "ppl-nft-mint" => AccountIndex::PplNftMint,
             "ppl-name-owner" => AccountIndex::PplNameOwner,
             _ => unreachable!(),
         })